@@ -1,6 +1,7 @@
+use crate::client_response::{ConsensusStateInfo, ValidatorSetResponse};
 use crate::types::{VerifyChannelState, VerifyPacketAcknowledgement, VerifyPacketData};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Order};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -15,6 +16,22 @@ impl Default for InstantiateMsg {
     }
 }
 
+/// Order of paginated results, `cosmwasm_std::Order` can not be part of a message.
+#[cw_serde]
+pub enum OrderBy {
+    Ascending,
+    Descending,
+}
+
+impl From<OrderBy> for Order {
+    fn from(order: OrderBy) -> Self {
+        match order {
+            OrderBy::Ascending => Order::Ascending,
+            OrderBy::Descending => Order::Descending,
+        }
+    }
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -59,21 +76,37 @@ pub enum QueryMsg {
         client_id: String,
         verify_channel_state: VerifyChannelState,
         next_seq_recv_verification_result: LightClientPacketMessage,
+        #[serde(default)]
+        delay_time_period: u64,
+        #[serde(default)]
+        delay_block_period: u64,
     },
     #[returns(bool)]
     PacketTimeout {
         client_id: String,
         next_seq_recv_verification_result: LightClientPacketMessage,
+        #[serde(default)]
+        delay_time_period: u64,
+        #[serde(default)]
+        delay_block_period: u64,
     },
     #[returns(bool)]
     VerifyPacketData {
         client_id: String,
         verify_packet_data: VerifyPacketData,
+        #[serde(default)]
+        delay_time_period: u64,
+        #[serde(default)]
+        delay_block_period: u64,
     },
     #[returns(bool)]
     VerifyPacketAcknowledgement {
         client_id: String,
         verify_packet_acknowledge: VerifyPacketAcknowledgement,
+        #[serde(default)]
+        delay_time_period: u64,
+        #[serde(default)]
+        delay_block_period: u64,
     },
     #[returns(bool)]
     VerifyConnectionOpenTry(VerifyConnectionPayload),
@@ -83,6 +116,26 @@ pub enum QueryMsg {
     GetPreviousConsensusState { client_id: String, height: u64 },
     #[returns(u64)]
     GetTimestampAtHeight { client_id: String, height: u64 },
+    #[returns(u64)]
+    GetConsensusStateCount { client_id: String },
+    #[returns(Vec<u64>)]
+    ListConsensusStateHeights {
+        client_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        // defaults to ascending
+        order: Option<OrderBy>,
+    },
+    #[returns(Vec<ConsensusStateInfo>)]
+    ListConsensusStates {
+        client_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        // defaults to ascending
+        order: Option<OrderBy>,
+    },
+    #[returns(ValidatorSetResponse)]
+    GetValidatorSet { client_id: String, height: u64 },
 }
 
 #[cw_serde]
@@ -203,7 +256,17 @@ pub enum ExecuteMsg {
         signed_header: Vec<u8>,
     },
 
+    UpdateClientBatch {
+        client_id: String,
+        // any bytes of each header, ordered by height
+        signed_headers: Vec<Vec<u8>>,
+        // heights whose consensus state must be stored besides the last one
+        store_heights: Vec<u64>,
+    },
+
     UpgradeClient {
+        client_id: String,
+        upgrade_height: u64,
         upgraded_client_state: Vec<u8>,
         upgraded_consensus_state: Vec<u8>,
         proof_upgrade_client: Vec<u8>,
@@ -214,6 +277,14 @@ pub enum ExecuteMsg {
         client_id: String,
         misbehaviour: Vec<u8>,
     },
+
+    PruneConsensusStates {
+        client_id: String,
+        // consensus states below this height are removed, the latest height is always kept
+        before_height: u64,
+        // maximum number of consensus states removed in this call
+        limit: u32,
+    },
 }
 
 #[cw_serde]
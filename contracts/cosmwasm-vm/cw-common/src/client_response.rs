@@ -139,6 +139,43 @@ impl UpdateClientResponse {
     }
 }
 
+#[cw_serde]
+pub struct UpdateClientBatchResponse {
+    pub client_id: String,
+    // one entry per stored consensus state, ordered by height
+    pub updates: Vec<UpdateClientResponse>,
+}
+
+impl UpdateClientBatchResponse {
+    pub fn new(client_id: String, updates: Vec<UpdateClientResponse>) -> Self {
+        Self { client_id, updates }
+    }
+
+    pub fn client_id(&self) -> Result<IbcClientId, CwErrors> {
+        IbcClientId::from_str(&self.client_id)
+            .map_err(|e| CwErrors::InvalidClientId(self.client_id.to_string(), e))
+    }
+}
+
+#[cw_serde]
+pub struct ConsensusStateInfo {
+    pub height: u64,
+    // block time of the counterparty chain in nanoseconds, if the consensus state carries one
+    pub timestamp: Option<u64>,
+    // block time and height of this chain when the consensus state was stored
+    pub processed_time: Option<u64>,
+    pub processed_height: Option<u64>,
+    // any bytes
+    pub consensus_state: Vec<u8>,
+}
+
+#[cw_serde]
+pub struct ValidatorSetResponse {
+    // height at which the validator set was committed
+    pub height: u64,
+    pub validators: Vec<Vec<u8>>,
+}
+
 #[cw_serde]
 pub struct UpgradeClientResponse {
     pub client_id: String,
@@ -6,8 +6,9 @@ use common::ibc::core::ics04_channel::msgs::acknowledgement::Acknowledgement;
 use common::ibc::core::ics04_channel::packet::Sequence;
 use common::ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use common::ibc::core::ics24_host::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
-    ConnectionPath, PortPath, ReceiptPath, SeqRecvPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath,
+    ClientStatePath, ClientUpgradePath, CommitmentPath, ConnectionPath, PortPath, ReceiptPath,
+    SeqRecvPath,
 };
 use common::ibc::{
     core::ics04_channel::{commitment::PacketCommitment, timeout::TimeoutHeight},
@@ -38,6 +39,18 @@ pub fn consensus_state_path(client_id: &ClientId, height: &Height) -> Vec<u8> {
         .to_string()
         .into_bytes()
 }
+pub fn upgraded_client_state_path(upgrade_height: u64) -> Vec<u8> {
+    ClientUpgradePath::UpgradedClientState(upgrade_height)
+        .to_string()
+        .into_bytes()
+}
+
+pub fn upgraded_consensus_state_path(upgrade_height: u64) -> Vec<u8> {
+    ClientUpgradePath::UpgradedClientConsensusState(upgrade_height)
+        .to_string()
+        .into_bytes()
+}
+
 pub fn connection_path(connection_id: &ConnectionId) -> Vec<u8> {
     ConnectionPath::new(connection_id).to_string().into_bytes()
 }
@@ -48,6 +61,18 @@ pub fn channel_path(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
         .into_bytes()
 }
 
+pub fn channel_upgrade_path(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    ChannelUpgradePath::new(port_id, channel_id)
+        .to_string()
+        .into_bytes()
+}
+
+pub fn channel_upgrade_error_path(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    ChannelUpgradeErrorPath::new(port_id, channel_id)
+        .to_string()
+        .into_bytes()
+}
+
 pub fn acknowledgement_commitment_path(
     port_id: &PortId,
     channel_id: &ChannelId,
@@ -188,6 +213,14 @@ pub fn channel_commitment_key(port_id: &PortId, channel_id: &ChannelId) -> Vec<u
     commitment_path_hash(&channel_path(port_id, channel_id))
 }
 
+pub fn channel_upgrade_commitment_key(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    commitment_path_hash(&channel_upgrade_path(port_id, channel_id))
+}
+
+pub fn channel_upgrade_error_commitment_key(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    commitment_path_hash(&channel_upgrade_error_path(port_id, channel_id))
+}
+
 pub fn packet_commitment_key(
     port_id: &PortId,
     channel_id: &ChannelId,
@@ -23,10 +23,27 @@ pub enum ExecuteMsg {
         //ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit,
         msg: HexString,
     },
-    // Not included in this version of ibc core
-    UpgradeClient {},
+    UpdateClientBatch {
+        client_id: String,
+        //raw message bytes of each header, encoded as:
+        //ibc_proto::google::protobuf::Any
+        headers: Vec<HexString>,
+        //heights of the headers whose consensus state must be stored
+        store_heights: Vec<u64>,
+    },
+    UpgradeClient {
+        //raw message bytes:
+        //ibc_proto::ibc::core::client::v1::MsgUpgradeClient
+        msg: HexString,
+        //counterparty height at which the upgrade plan committed the upgraded states
+        upgrade_height: u64,
+    },
 
-    ClientMisbehaviour {},
+    ClientMisbehaviour {
+        //raw message bytes:
+        //ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour
+        msg: HexString,
+    },
 
     // Connection Messsages
     ConnectionOpenInit {
@@ -81,6 +98,36 @@ pub enum ExecuteMsg {
         //ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm
         msg: HexString,
     },
+    ChannelUpgradeInit {
+        //raw message bytes:
+        //ibc.core.channel.v1.MsgChannelUpgradeInit
+        msg: HexString,
+    },
+    ChannelUpgradeTry {
+        //raw message bytes:
+        //ibc.core.channel.v1.MsgChannelUpgradeTry
+        msg: HexString,
+    },
+    ChannelUpgradeAck {
+        //raw message bytes:
+        //ibc.core.channel.v1.MsgChannelUpgradeAck
+        msg: HexString,
+    },
+    ChannelUpgradeConfirm {
+        //raw message bytes:
+        //ibc.core.channel.v1.MsgChannelUpgradeConfirm
+        msg: HexString,
+    },
+    ChannelUpgradeOpen {
+        //raw message bytes:
+        //ibc.core.channel.v1.MsgChannelUpgradeOpen
+        msg: HexString,
+    },
+    ChannelUpgradeCancel {
+        //raw message bytes:
+        //ibc.core.channel.v1.MsgChannelUpgradeCancel
+        msg: HexString,
+    },
 
     // Packet Messages
     SendPacket {
@@ -112,6 +159,21 @@ pub enum ExecuteMsg {
         //ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose
         msg: HexString,
     },
+    ReceivePacketBatch {
+        //raw message bytes of each packet, all proven at the same height:
+        //ibc_proto::ibc::core::channel::v1::MsgRecvPacket
+        msgs: Vec<HexString>,
+    },
+    AcknowledgementPacketBatch {
+        //raw message bytes of each packet, all proven at the same height:
+        //ibc_proto::ibc::core::channel::v1::MsgAcknowledgement
+        msgs: Vec<HexString>,
+    },
+    TimeoutPacketBatch {
+        //raw message bytes of each packet, all proven at the same height:
+        //ibc_proto::ibc::core::channel::v1::MsgTimeout
+        msgs: Vec<HexString>,
+    },
 
     // Storage Messages
     BindPort {
@@ -136,8 +198,49 @@ pub enum QueryMsg {
     GetClientImplementation { client_id: String },
     #[returns(String)]
     GetConnection { connection_id: String },
+    #[returns(Vec<String>)]
+    GetClientConnections {
+        client_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(String)]
     GetChannel { port_id: String, channel_id: String },
+    #[returns(IdentifiedConnection)]
+    GetDecodedConnection { connection_id: String },
+    #[returns(IdentifiedChannel)]
+    GetDecodedChannel { port_id: String, channel_id: String },
+    #[returns(DecodedClientState)]
+    GetDecodedClientState { client_id: String },
+    /// Latest consensus state of the client when `height` is not provided.
+    #[returns(DecodedConsensusState)]
+    GetDecodedConsensusState {
+        client_id: String,
+        height: Option<u64>,
+    },
+    #[returns(Vec<IdentifiedClient>)]
+    ListClients {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<IdentifiedConnection>)]
+    ListConnections {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<IdentifiedChannel>)]
+    ListChannels {
+        connection_id: Option<String>,
+        port_id: Option<String>,
+        start_after: Option<ChannelKey>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<IdentifiedChannel>)]
+    GetConnectionChannels {
+        connection_id: String,
+        start_after: Option<ChannelKey>,
+        limit: Option<u32>,
+    },
     #[returns(u64)]
     GetNextSequenceSend { port_id: String, channel_id: String },
     #[returns(u64)]
@@ -215,3 +318,101 @@ pub enum QueryMsg {
         end_sequence: u64,
     },
 }
+
+/// Position of a channel in the channel listings, pages start after it.
+#[cw_serde]
+pub struct ChannelKey {
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+/// Client registered on the IBC host and the light client contract implementing it.
+#[cw_serde]
+pub struct IdentifiedClient {
+    pub client_id: String,
+    pub client_type: String,
+    pub light_client: String,
+}
+
+#[cw_serde]
+pub struct ConnectionVersion {
+    pub identifier: String,
+    pub features: Vec<String>,
+}
+
+/// Counterparty of a connection, `prefix` is the hex encoded commitment prefix.
+#[cw_serde]
+pub struct ConnectionCounterparty {
+    pub client_id: String,
+    pub connection_id: Option<String>,
+    pub prefix: String,
+}
+
+/// Decoded connection end, `delay_period` is given in nanoseconds.
+#[cw_serde]
+pub struct IdentifiedConnection {
+    pub connection_id: String,
+    pub state: String,
+    pub client_id: String,
+    pub counterparty: ConnectionCounterparty,
+    pub versions: Vec<ConnectionVersion>,
+    pub delay_period: u64,
+}
+
+#[cw_serde]
+pub struct ChannelCounterparty {
+    pub port_id: String,
+    pub channel_id: Option<String>,
+}
+
+/// Decoded channel end.
+#[cw_serde]
+pub struct IdentifiedChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub state: String,
+    pub ordering: String,
+    pub counterparty: ChannelCounterparty,
+    pub connection_hops: Vec<String>,
+    pub version: String,
+}
+
+#[cw_serde]
+pub struct IconTrustLevel {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+/// Client state of the ICON light client.
+#[cw_serde]
+pub struct IconClientState {
+    pub trusting_period: u64,
+    pub frozen_height: u64,
+    pub max_clock_drift: u64,
+    pub latest_height: u64,
+    pub src_network_id: String,
+    pub network_id: u64,
+    pub network_type_id: u64,
+    pub trust_level: Option<IconTrustLevel>,
+}
+
+/// Consensus state of the ICON light client, hashes are hex encoded.
+#[cw_serde]
+pub struct IconConsensusState {
+    pub message_root: String,
+    pub next_proof_context_hash: String,
+}
+
+/// Client state decoded from its `Any` encoding. States of unknown light clients are returned as
+/// their type url and hex encoded protobuf value.
+#[cw_serde]
+pub enum DecodedClientState {
+    Icon(IconClientState),
+    Raw { type_url: String, value: String },
+}
+
+#[cw_serde]
+pub enum DecodedConsensusState {
+    Icon(IconConsensusState),
+    Raw { type_url: String, value: String },
+}
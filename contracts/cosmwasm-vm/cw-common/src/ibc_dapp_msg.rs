@@ -1,9 +1,17 @@
 use crate::cw_types::{
-    CwChannelCloseMsg, CwChannelConnectMsg, CwChannelOpenMsg, CwPacketAckMsg, CwPacketReceiveMsg,
-    CwPacketTimeoutMsg,
+    CwChannel, CwChannelCloseMsg, CwChannelConnectMsg, CwChannelOpenMsg, CwPacketAckMsg,
+    CwPacketReceiveMsg, CwPacketTimeoutMsg,
 };
 use cosmwasm_schema::cw_serde;
 
+/// Passed to the module of a channel on each step of a channel upgrade handshake. `channel` is the
+/// channel as it was opened and `upgrade` is the channel it turns into once the upgrade is open.
+#[cw_serde]
+pub struct CwChannelUpgradeMsg {
+    pub channel: CwChannel,
+    pub upgrade: CwChannel,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     #[cfg(not(feature = "native_ibc"))]
@@ -19,4 +27,12 @@ pub enum ExecuteMsg {
     IbcPacketAck { msg: CwPacketAckMsg },
     #[cfg(not(feature = "native_ibc"))]
     IbcPacketTimeout { msg: CwPacketTimeoutMsg },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeInit { msg: CwChannelUpgradeMsg },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeTry { msg: CwChannelUpgradeMsg },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeAck { msg: CwChannelUpgradeMsg },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeOpen { msg: CwChannelUpgradeMsg },
 }
@@ -0,0 +1,141 @@
+use cosmwasm_std::{
+    to_json_binary as to_binary, Addr, Coin, CosmosMsg, Deps, Event, StdError, Storage, WasmMsg,
+};
+use debug_print::debug_println;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    core_msg::{ExecuteMsg, QueryMsg},
+    cw_types::CwPacket,
+    hex_string::HexString,
+    query_helpers::build_smart_query,
+    raw_types::channel::RawMsgChannelOpenInit,
+    ProstMessage,
+};
+
+/// Application bound to a port of the IBC host. Provides the check that channel and packet
+/// callbacks come from the host, and the messages and queries the application sends to it.
+pub trait IbcHostApp {
+    type Error: From<StdError>;
+
+    /// Returns the address of the IBC host the application is bound to.
+    fn get_ibc_host(&self, store: &dyn Storage) -> Result<Addr, Self::Error>;
+
+    /// Error returned for callbacks not sent by the IBC host.
+    fn only_ibc_handler_error(&self) -> Self::Error;
+
+    /// Checks that `address` is the IBC host. Channel and packet callbacks are only accepted from
+    /// the host.
+    fn ensure_ibc_handler(&self, store: &dyn Storage, address: Addr) -> Result<(), Self::Error> {
+        let ibc_host = self.get_ibc_host(store)?;
+
+        if ibc_host != address {
+            debug_println!("Invalid IBC Handler {address}");
+            return Err(self.only_ibc_handler_error());
+        }
+        Ok(())
+    }
+
+    /// Builds the message binding `port_id` to `address` on `ibc_host`. The host is passed in as
+    /// the port is bound while the application is instantiated.
+    fn call_host_bind_port(
+        &self,
+        ibc_host: &Addr,
+        port_id: &str,
+        address: &Addr,
+    ) -> Result<CosmosMsg, Self::Error> {
+        let message = ExecuteMsg::BindPort {
+            port_id: port_id.to_string(),
+            address: address.to_string(),
+        };
+        host_message(ibc_host, &message, vec![])
+    }
+
+    /// Builds the message starting the handshake of a channel on the IBC host.
+    fn call_host_channel_open_init(
+        &self,
+        store: &dyn Storage,
+        message: RawMsgChannelOpenInit,
+    ) -> Result<CosmosMsg, Self::Error> {
+        let message = ExecuteMsg::ChannelOpenInit {
+            msg: HexString::from_bytes(&message.encode_to_vec()),
+        };
+        host_message(&self.get_ibc_host(store)?, &message, vec![])
+    }
+
+    /// Builds the message sending `packet` through the IBC host.
+    fn call_host_send_packet(
+        &self,
+        store: &dyn Storage,
+        packet: HexString,
+        funds: Vec<Coin>,
+    ) -> Result<CosmosMsg, Self::Error> {
+        let ibc_host = self.get_ibc_host(store)?;
+        debug_println!("Packet Forwarded To IBCHost {ibc_host} ");
+        host_message(&ibc_host, &ExecuteMsg::SendPacket { packet }, funds)
+    }
+
+    /// Builds the message writing `acknowledgement` for `packet` on the IBC host.
+    fn call_host_write_acknowledgement(
+        &self,
+        store: &dyn Storage,
+        packet: CwPacket,
+        acknowledgement: Vec<u8>,
+    ) -> Result<CosmosMsg, Self::Error> {
+        let message = ExecuteMsg::WriteAcknowledgement {
+            packet,
+            acknowledgement: HexString::from_bytes(&acknowledgement),
+        };
+        host_message(&self.get_ibc_host(store)?, &message, vec![])
+    }
+
+    /// Runs `message` against the IBC host.
+    fn query_host<T: DeserializeOwned>(
+        &self,
+        deps: Deps,
+        message: &QueryMsg,
+    ) -> Result<T, Self::Error> {
+        let ibc_host = self.get_ibc_host(deps.storage)?;
+        let query = build_smart_query(ibc_host.to_string(), to_binary(message)?);
+        Ok(deps.querier.query(&query)?)
+    }
+
+    /// Returns the sequence of the next packet sent on `port_id`/`channel_id`.
+    fn query_host_sequence_no(
+        &self,
+        deps: Deps,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<u64, Self::Error> {
+        self.query_host(
+            deps,
+            &QueryMsg::GetNextSequenceSend {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            },
+        )
+    }
+}
+
+/// Event emitted when the application fails to process `packet`. The error is not guaranteed to
+/// be deterministic, so the acknowledgement only carries `Ack::module_error()` and the error is
+/// reported here, like the IBC host does for failing applications.
+pub fn packet_receive_error_event(packet: &CwPacket, error: &str) -> Event {
+    Event::new("receive_packet_error")
+        .add_attribute("packet_sequence", packet.sequence.to_string())
+        .add_attribute("packet_dst_port", packet.dest.port_id.as_str())
+        .add_attribute("packet_dst_channel", packet.dest.channel_id.as_str())
+        .add_attribute("packet_error", error)
+}
+
+fn host_message<E: From<StdError>>(
+    ibc_host: &Addr,
+    message: &ExecuteMsg,
+    funds: Vec<Coin>,
+) -> Result<CosmosMsg, E> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: ibc_host.to_string(),
+        msg: to_binary(message)?,
+        funds,
+    }))
+}
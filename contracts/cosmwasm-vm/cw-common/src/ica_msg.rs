@@ -0,0 +1,130 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_string, to_json_vec, Binary, CosmosMsg, StdResult,
+};
+
+/// Version negotiated on interchain account channels.
+pub const ICA_VERSION: &str = "ics27-1";
+/// Port the interchain account host binds.
+pub const ICA_HOST_PORT_ID: &str = "icahost";
+/// Prefix of the ports bound by the controller, followed by the address of the account owner.
+pub const ICA_CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+/// Packet data carries the JSON encoding of a list of `CosmosMsg`.
+pub const ENCODING_JSON: &str = "json";
+/// Every packet is executed as one atomic transaction of several messages.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+/// Only packet type defined by ICS-27.
+pub const TYPE_EXECUTE_TX: &str = "TYPE_EXECUTE_TX";
+
+/// Version of interchain account channels as defined by ICS-27. `address` is only known once the
+/// host registered the account.
+#[cw_serde]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    #[serde(default)]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    pub fn new(controller_connection_id: &str, host_connection_id: &str) -> Self {
+        Self {
+            version: ICA_VERSION.to_string(),
+            controller_connection_id: controller_connection_id.to_string(),
+            host_connection_id: host_connection_id.to_string(),
+            address: String::new(),
+            encoding: ENCODING_JSON.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    pub fn encode(&self) -> StdResult<String> {
+        to_json_string(self)
+    }
+
+    pub fn decode(version: &str) -> StdResult<Self> {
+        from_json(version.as_bytes())
+    }
+}
+
+/// Packet data of interchain account packets. For `TYPE_EXECUTE_TX` packets `data` is the JSON
+/// encoded list of `CosmosMsg` the account executes.
+#[cw_serde]
+pub struct InterchainAccountPacketData {
+    #[serde(rename = "type")]
+    pub packet_type: String,
+    pub data: Binary,
+    #[serde(default)]
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    pub fn execute_tx(msgs: &[CosmosMsg], memo: String) -> StdResult<Self> {
+        Ok(Self {
+            packet_type: TYPE_EXECUTE_TX.to_string(),
+            data: to_json_binary(msgs)?,
+            memo,
+        })
+    }
+
+    pub fn encode(&self) -> StdResult<Vec<u8>> {
+        to_json_vec(self)
+    }
+
+    pub fn decode(data: &[u8]) -> StdResult<Self> {
+        from_json(data)
+    }
+
+    pub fn messages(&self) -> StdResult<Vec<CosmosMsg>> {
+        from_json(&self.data)
+    }
+}
+
+/// Result of an executed transaction, the successful acknowledgement of the host carries it. It
+/// holds the response data of each message in order, empty for messages without data.
+#[cw_serde]
+pub struct InterchainAccountTxResult {
+    pub results: Vec<Binary>,
+}
+
+/// Instantiates the proxy contract acting as interchain account. The sender becomes the only
+/// address allowed to execute messages through it.
+#[cw_serde]
+pub struct ProxyInstantiateMsg {}
+
+#[cw_serde]
+pub enum ProxyExecuteMsg {
+    /// Executes `msgs` from the proxy, the response data is an `InterchainAccountTxResult`.
+    Execute { msgs: Vec<CosmosMsg> },
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, BankMsg};
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_roundtrip() {
+        let metadata = Metadata::new("connection-0", "connection-1");
+        let decoded = Metadata::decode(&metadata.encode().unwrap()).unwrap();
+        assert_eq!(metadata, decoded);
+    }
+
+    #[test]
+    fn test_packet_data_type_field() {
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "receiver".to_string(),
+            amount: coins(10, "uarch"),
+        })];
+        let data = InterchainAccountPacketData::execute_tx(&msgs, String::new()).unwrap();
+        let encoded = String::from_utf8(data.encode().unwrap()).unwrap();
+        assert!(encoded.starts_with(r#"{"type":"TYPE_EXECUTE_TX""#));
+
+        let decoded = InterchainAccountPacketData::decode(encoded.as_bytes()).unwrap();
+        assert_eq!(decoded.messages().unwrap(), msgs);
+    }
+}
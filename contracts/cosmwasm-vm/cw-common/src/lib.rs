@@ -6,7 +6,9 @@ pub mod cw_types;
 pub mod errors;
 pub mod hex_string;
 pub mod ibc_dapp_msg;
+pub mod ibc_host;
 pub mod ibc_types;
+pub mod ica_msg;
 pub mod query_helpers;
 pub mod raw_types;
 pub mod types;
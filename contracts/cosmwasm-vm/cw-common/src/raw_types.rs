@@ -43,6 +43,188 @@ pub mod channel {
     };
 }
 
+/// Channel upgrade messages of `ibc.core.channel.v1` (ibc-go v8), which the `ibc-proto`
+/// version in use does not ship yet. Field numbers follow the upstream proto definitions.
+pub mod upgrade {
+    use super::RawHeight;
+    use ibc_proto::ibc::core::channel::v1::{
+        Channel as RawChannel, Counterparty as RawCounterparty,
+    };
+
+    /// `Channel` including the `upgrade_sequence` field. Encodes exactly like the channel of
+    /// `ibc-proto` as long as the upgrade sequence is zero.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawUpgradeChannel {
+        #[prost(int32, tag = "1")]
+        pub state: i32,
+        #[prost(int32, tag = "2")]
+        pub ordering: i32,
+        #[prost(message, optional, tag = "3")]
+        pub counterparty: Option<RawCounterparty>,
+        #[prost(string, repeated, tag = "4")]
+        pub connection_hops: Vec<String>,
+        #[prost(string, tag = "5")]
+        pub version: String,
+        #[prost(uint64, tag = "6")]
+        pub upgrade_sequence: u64,
+    }
+
+    impl RawUpgradeChannel {
+        pub fn new(channel: RawChannel, upgrade_sequence: u64) -> Self {
+            RawUpgradeChannel {
+                state: channel.state,
+                ordering: channel.ordering,
+                counterparty: channel.counterparty,
+                connection_hops: channel.connection_hops,
+                version: channel.version,
+                upgrade_sequence,
+            }
+        }
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawTimeout {
+        #[prost(message, optional, tag = "1")]
+        pub height: Option<RawHeight>,
+        #[prost(uint64, tag = "2")]
+        pub timestamp: u64,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawUpgradeFields {
+        #[prost(int32, tag = "1")]
+        pub ordering: i32,
+        #[prost(string, repeated, tag = "2")]
+        pub connection_hops: Vec<String>,
+        #[prost(string, tag = "3")]
+        pub version: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawUpgrade {
+        #[prost(message, optional, tag = "1")]
+        pub fields: Option<RawUpgradeFields>,
+        #[prost(message, optional, tag = "2")]
+        pub timeout: Option<RawTimeout>,
+        #[prost(uint64, tag = "3")]
+        pub next_sequence_send: u64,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawErrorReceipt {
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        #[prost(string, tag = "2")]
+        pub message: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawMsgChannelUpgradeInit {
+        #[prost(string, tag = "1")]
+        pub port_id: String,
+        #[prost(string, tag = "2")]
+        pub channel_id: String,
+        #[prost(message, optional, tag = "3")]
+        pub fields: Option<RawUpgradeFields>,
+        #[prost(string, tag = "4")]
+        pub signer: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawMsgChannelUpgradeTry {
+        #[prost(string, tag = "1")]
+        pub port_id: String,
+        #[prost(string, tag = "2")]
+        pub channel_id: String,
+        #[prost(string, repeated, tag = "3")]
+        pub proposed_upgrade_connection_hops: Vec<String>,
+        #[prost(message, optional, tag = "4")]
+        pub counterparty_upgrade_fields: Option<RawUpgradeFields>,
+        #[prost(uint64, tag = "5")]
+        pub counterparty_upgrade_sequence: u64,
+        #[prost(bytes = "vec", tag = "6")]
+        pub proof_channel: Vec<u8>,
+        #[prost(bytes = "vec", tag = "7")]
+        pub proof_upgrade: Vec<u8>,
+        #[prost(message, optional, tag = "8")]
+        pub proof_height: Option<RawHeight>,
+        #[prost(string, tag = "9")]
+        pub signer: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawMsgChannelUpgradeAck {
+        #[prost(string, tag = "1")]
+        pub port_id: String,
+        #[prost(string, tag = "2")]
+        pub channel_id: String,
+        #[prost(message, optional, tag = "3")]
+        pub counterparty_upgrade: Option<RawUpgrade>,
+        #[prost(bytes = "vec", tag = "4")]
+        pub proof_channel: Vec<u8>,
+        #[prost(bytes = "vec", tag = "5")]
+        pub proof_upgrade: Vec<u8>,
+        #[prost(message, optional, tag = "6")]
+        pub proof_height: Option<RawHeight>,
+        #[prost(string, tag = "7")]
+        pub signer: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawMsgChannelUpgradeConfirm {
+        #[prost(string, tag = "1")]
+        pub port_id: String,
+        #[prost(string, tag = "2")]
+        pub channel_id: String,
+        #[prost(int32, tag = "3")]
+        pub counterparty_channel_state: i32,
+        #[prost(message, optional, tag = "4")]
+        pub counterparty_upgrade: Option<RawUpgrade>,
+        #[prost(bytes = "vec", tag = "5")]
+        pub proof_channel: Vec<u8>,
+        #[prost(bytes = "vec", tag = "6")]
+        pub proof_upgrade: Vec<u8>,
+        #[prost(message, optional, tag = "7")]
+        pub proof_height: Option<RawHeight>,
+        #[prost(string, tag = "8")]
+        pub signer: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawMsgChannelUpgradeOpen {
+        #[prost(string, tag = "1")]
+        pub port_id: String,
+        #[prost(string, tag = "2")]
+        pub channel_id: String,
+        #[prost(int32, tag = "3")]
+        pub counterparty_channel_state: i32,
+        #[prost(uint64, tag = "4")]
+        pub counterparty_upgrade_sequence: u64,
+        #[prost(bytes = "vec", tag = "5")]
+        pub proof_channel: Vec<u8>,
+        #[prost(message, optional, tag = "6")]
+        pub proof_height: Option<RawHeight>,
+        #[prost(string, tag = "7")]
+        pub signer: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawMsgChannelUpgradeCancel {
+        #[prost(string, tag = "1")]
+        pub port_id: String,
+        #[prost(string, tag = "2")]
+        pub channel_id: String,
+        #[prost(message, optional, tag = "3")]
+        pub error_receipt: Option<RawErrorReceipt>,
+        #[prost(bytes = "vec", tag = "4")]
+        pub proof_error_receipt: Vec<u8>,
+        #[prost(message, optional, tag = "5")]
+        pub proof_height: Option<RawHeight>,
+        #[prost(string, tag = "6")]
+        pub signer: String,
+    }
+}
+
 pub use ibc_proto::google::protobuf::Any;
 pub use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 pub use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
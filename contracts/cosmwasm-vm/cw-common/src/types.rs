@@ -42,6 +42,29 @@ pub struct VerifyPacketAcknowledgement {
     // commitment byte
     pub ack: Vec<u8>,
 }
+/// Outcome of one packet of a `ReceivePacketBatch`, `AcknowledgementPacketBatch` or
+/// `TimeoutPacketBatch` message. The list of outcomes, in message order, is set as the
+/// response data.
+#[cw_serde]
+pub struct PacketBatchResult {
+    // port and channel on this chain
+    pub port_id: String,
+    pub channel_id: String,
+    pub sequence: u64,
+    pub status: PacketBatchStatus,
+}
+
+#[cw_serde]
+pub enum PacketBatchStatus {
+    Success,
+    /// The packet was already received, acknowledged or timed out.
+    Noop,
+    /// The packet failed validation and nothing was written for it.
+    Failed {
+        error: String,
+    },
+}
+
 use crate::raw_types::channel::RawMessageTimeout;
 use crate::raw_types::channel::RawMessageTimeoutOnclose;
 pub enum TimeoutMsgType {
@@ -108,3 +131,13 @@ pub enum Ack {
     Result(Binary),
     Error(String),
 }
+
+/// Error acknowledgement written when a module fails to process a packet. The module error is not
+/// guaranteed to be deterministic, so it is emitted in an event instead of being committed.
+pub const MODULE_ERROR_ACK: &str = "module failed to process packet";
+
+impl Ack {
+    pub fn module_error() -> Self {
+        Ack::Error(MODULE_ERROR_ACK.to_string())
+    }
+}
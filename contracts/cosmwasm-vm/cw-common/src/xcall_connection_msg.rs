@@ -2,8 +2,10 @@ use crate::cw_types::{
     CwChannelCloseMsg, CwChannelConnectMsg, CwChannelOpenMsg, CwPacketAckMsg, CwPacketReceiveMsg,
     CwPacketTimeoutMsg,
 };
+use crate::ibc_dapp_msg::CwChannelUpgradeMsg;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw20::Cw20ReceiveMsg;
 use cw_xcall_lib::network_address::NetId;
 
 #[cw_serde]
@@ -19,12 +21,17 @@ pub enum ExecuteMsg {
         sn: i64,
         msg: Vec<u8>,
     },
+    /// CW20 receive hook, `msg` is a JSON encoded `Cw20HookMsg` sent by the xcall host to pay
+    /// the fee in CW20 tokens.
+    Receive(Cw20ReceiveMsg),
     ConfigureConnection {
         connection_id: String,
         counterparty_port_id: String,
         counterparty_nid: NetId,
         client_id: String,
         timeout_height: u64,
+        #[serde(default)]
+        timeout_duration: u64,
     },
     OverrideConnection {
         connection_id: String,
@@ -32,16 +39,86 @@ pub enum ExecuteMsg {
         counterparty_nid: NetId,
         client_id: String,
         timeout_height: u64,
+        #[serde(default)]
+        timeout_duration: u64,
     },
+    /// Moves `channel_ids` to the front of the channels of `nid`, in the given order. Channels
+    /// left out keep their relative order behind them.
+    SetChannelPriority {
+        nid: NetId,
+        channel_ids: Vec<String>,
+    },
+    /// Stops sending messages of `nid` over `channel_id`.
+    RemoveChannel {
+        nid: NetId,
+        channel_id: String,
+    },
+    /// Stops message flow with `nid`, or with every network when not provided. Messages sent to a
+    /// paused network are rejected and packets received from it are queued.
+    Pause {
+        #[serde(default)]
+        nid: Option<NetId>,
+    },
+    /// Resumes message flow paused by `Pause`. Packets queued meanwhile are delivered with
+    /// `ProcessQueued`.
+    Unpause {
+        #[serde(default)]
+        nid: Option<NetId>,
+    },
+    /// Delivers up to `limit` packets queued for `nid`, which must not be paused.
+    ProcessQueued {
+        nid: NetId,
+        limit: u32,
+    },
+    /// Sets the outbound limits of `nid`, messages exceeding them are rejected.
+    SetNetworkLimits {
+        nid: NetId,
+        limits: NetworkLimits,
+    },
+    /// Claims the fees accrued for packets from `nid` paid in `denom` there, the default denom of
+    /// `nid` when not provided.
     ClaimFees {
         nid: NetId,
         address: String,
+        #[serde(default)]
+        denom: Option<String>,
     },
 
+    /// Pays the fees held by this contract for the sender on `nid` in `denom` to `recipient`. Fees
+    /// are only held for senders that enabled `SetFeeCrediting`, packet fees accrued on `nid` are
+    /// claimed with `ClaimFees`.
+    WithdrawFees {
+        nid: NetId,
+        #[serde(default)]
+        denom: Option<String>,
+        recipient: String,
+    },
+
+    /// Sets whether the ack fees of the sender and the fees claimed to it are held by this contract
+    /// until withdrawn with `WithdrawFees`, rather than paid out on arrival.
+    SetFeeCrediting {
+        enabled: bool,
+    },
+
+    /// Sets the fees of `nid` in `denom`, a bank denom or `cw20:{address}`. The default denom of
+    /// the contract when not provided.
     SetFees {
         nid: NetId,
         packet_fee: u128,
         ack_fee: u128,
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    /// Sets whether the counterparty of `nid` decodes the fee denom of messages. Networks that
+    /// drop it, like ICON, only accept fees in the default denom.
+    SetFeeDenomSupport {
+        nid: NetId,
+        supported: bool,
+    },
+    /// Stops accepting `denom` for the fees of `nid`.
+    RemoveFees {
+        nid: NetId,
+        denom: String,
     },
 
     #[cfg(not(feature = "native_ibc"))]
@@ -69,6 +146,76 @@ pub enum ExecuteMsg {
     IbcPacketTimeout {
         msg: CwPacketTimeoutMsg,
     },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeInit {
+        msg: CwChannelUpgradeMsg,
+    },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeTry {
+        msg: CwChannelUpgradeMsg,
+    },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeAck {
+        msg: CwChannelUpgradeMsg,
+    },
+    #[cfg(not(feature = "native_ibc"))]
+    IbcChannelUpgradeOpen {
+        msg: CwChannelUpgradeMsg,
+    },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    SendMessage { to: NetId, sn: i64, msg: Vec<u8> },
+}
+
+/// Fee of a network in one accepted denom.
+#[cw_serde]
+pub struct FeeOption {
+    pub denom: String,
+    pub send_packet_fee: u128,
+    pub ack_fee: u128,
+}
+
+/// Packet fees a relayer accrued for packets from `nid` paid in `denom` there, `None` for the
+/// default denom of `nid`.
+#[cw_serde]
+pub struct UnclaimedFee {
+    pub nid: NetId,
+    pub denom: Option<String>,
+    pub amount: u128,
+}
+
+/// Outbound limits of a network. `max_data_size` and `max_rollback_size` bound the payload and the
+/// rollback data of a message. `max_messages` and `max_value_messages` bound the messages, and the
+/// messages carrying funds, sent in each window of `window_blocks` blocks, 0 disables them. Messages
+/// to networks without fees carry no funds and only count towards `max_messages`.
+#[cw_serde]
+pub struct NetworkLimits {
+    pub max_data_size: u64,
+    pub max_rollback_size: u64,
+    pub window_blocks: u64,
+    pub max_messages: u64,
+    pub max_value_messages: u64,
+}
+
+impl Default for NetworkLimits {
+    fn default() -> Self {
+        Self {
+            max_data_size: 2048,
+            max_rollback_size: 1024,
+            window_blocks: 0,
+            max_messages: 0,
+            max_value_messages: 0,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub paused: bool,
+    pub paused_networks: Vec<NetId>,
+    pub queued_packets: u64,
 }
 
 #[cw_serde]
@@ -79,6 +226,7 @@ pub struct ConfigResponse {
     pub destination_port_id: String,
     pub light_client_id: String,
     pub timeout_height: u64,
+    pub timeout_duration: u64,
 }
 
 #[cw_serde]
@@ -92,9 +240,34 @@ pub enum QueryMsg {
     #[returns(u64)]
     GetTimeoutHeight { channel_id: String },
     #[returns(u64)]
+    GetTimeoutDuration { channel_id: String },
+    /// Fee of a message to `nid` in the default denom, the ack fee is included when `response` is
+    /// set. The fees in other denoms are listed by `GetFeeOptions`.
+    #[returns(u128)]
     GetFee { nid: NetId, response: bool },
+    /// Fees of `nid` in every accepted denom, the default denom first.
+    #[returns(Vec<FeeOption>)]
+    GetFeeOptions { nid: NetId },
+    /// Packet fees accrued by `relayer` for packets from `nid` paid in `denom` there, the default
+    /// denom of `nid` when not provided.
     #[returns(u64)]
-    GetUnclaimedFee { nid: NetId, relayer: String },
+    GetUnclaimedFee {
+        nid: NetId,
+        relayer: String,
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    #[returns(PauseStatusResponse)]
+    GetPauseStatus {},
+    /// Outbound limits of `nid`, the defaults when none were set.
+    #[returns(NetworkLimits)]
+    GetNetworkLimits { nid: NetId },
+    /// Packet fees accrued by `relayer` on every network and denom.
+    #[returns(Vec<UnclaimedFee>)]
+    GetRelayerFeeSummary { relayer: String },
     #[returns(ConfigResponse)]
     GetIbcConfig { nid: NetId },
+    /// Configs of all channels of `nid`, in priority order.
+    #[returns(Vec<ConfigResponse>)]
+    GetIbcConfigs { nid: NetId },
 }
@@ -4,6 +4,7 @@ pub const EXECUTE_CREATE_CLIENT: u64 = 21;
 pub const EXECUTE_UPDATE_CLIENT: u64 = 22;
 pub const EXECUTE_UPGRADE_CLIENT: u64 = 23;
 pub const MISBEHAVIOUR: u64 = 24;
+pub const EXECUTE_UPDATE_CLIENT_BATCH: u64 = 25;
 
 pub const EXECUTE_CONNECTION_OPENTRY: u64 = 31;
 pub const EXECUTE_CONNECTION_OPENACK: u64 = 32;
@@ -19,10 +20,20 @@ pub const EXECUTE_ON_CHANNEL_OPEN_CONFIRM_ON_MODULE: u64 = 442;
 pub const EXECUTE_ON_CHANNEL_CLOSE_INIT: u64 = 45;
 
 pub const EXECUTE_ON_CHANNEL_CLOSE_CONFIRM_ON_MODULE: u64 = 462;
+
+pub const EXECUTE_ON_CHANNEL_UPGRADE_INIT: u64 = 47;
+pub const EXECUTE_ON_CHANNEL_UPGRADE_TRY_ON_MODULE: u64 = 472;
+pub const EXECUTE_ON_CHANNEL_UPGRADE_ACK_ON_MODULE: u64 = 482;
+pub const EXECUTE_ON_CHANNEL_UPGRADE_OPEN_ON_MODULE: u64 = 492;
+
 pub const VALIDATE_ON_PACKET_TIMEOUT_ON_MODULE: u64 = 542;
 pub const VALIDATE_ON_PACKET_RECEIVE_ON_MODULE: u64 = 522;
+pub const VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE: u64 = 523;
 pub const VALIDATE_ON_PACKET_ACKNOWLEDGEMENT_ON_MODULE: u64 = 532;
 
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 100;
+
 // Errors
 
 pub const PACKET_ERROR: &str = "Packet Error";
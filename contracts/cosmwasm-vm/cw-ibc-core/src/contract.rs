@@ -4,6 +4,10 @@ use super::*;
 
 use cosmwasm_std::to_json_binary as to_binary;
 
+use crate::conversions::{
+    to_channel_key, to_decoded_client_state, to_decoded_consensus_state, to_identified_channel,
+    to_identified_connection,
+};
 use cw_common::hex_string::HexString;
 use cw_common::query_helpers::build_smart_query;
 use cw_common::raw_types::channel::RawMsgChannelCloseInit;
@@ -12,9 +16,15 @@ use cw_common::raw_types::channel::{
     RawMessageTimeoutOnclose, RawMsgChannelCloseConfirm, RawMsgChannelOpenAck,
     RawMsgChannelOpenConfirm, RawMsgChannelOpenInit, RawMsgChannelOpenTry, RawPacket,
 };
-use cw_common::raw_types::client::{RawMsgCreateClient, RawMsgUpdateClient};
+use cw_common::raw_types::client::{
+    RawMsgCreateClient, RawMsgSubmitMisbehaviour, RawMsgUpdateClient, RawMsgUpgradeClient,
+};
 use cw_common::raw_types::connection::*;
-use cw_common::raw_types::Protobuf;
+use cw_common::raw_types::upgrade::{
+    RawMsgChannelUpgradeAck, RawMsgChannelUpgradeCancel, RawMsgChannelUpgradeConfirm,
+    RawMsgChannelUpgradeInit, RawMsgChannelUpgradeOpen, RawMsgChannelUpgradeTry,
+};
+use cw_common::raw_types::{Any, Protobuf};
 
 use cw_common::{cw_println, to_checked_address};
 
@@ -115,11 +125,30 @@ impl<'a> CwIbcCoreContext<'a> {
                 let message: RawMsgUpdateClient = Self::raw_from_hex(&msg)?;
                 self.update_client(deps, info, message)
             }
-            CoreExecuteMsg::UpgradeClient {} => {
-                unimplemented!()
+            CoreExecuteMsg::UpdateClientBatch {
+                client_id,
+                headers,
+                store_heights,
+            } => {
+                cw_println!(deps, "[IBCCore] UpdateClientBatch Called");
+                let headers = headers
+                    .iter()
+                    .map(Self::raw_from_hex::<Any>)
+                    .collect::<Result<Vec<Any>, ContractError>>()?;
+                self.update_client_batch(deps, info, client_id, headers, store_heights)
+            }
+            CoreExecuteMsg::UpgradeClient {
+                msg,
+                upgrade_height,
+            } => {
+                cw_println!(deps, "[IBCCore] UpgradeClient Called");
+                let message: RawMsgUpgradeClient = Self::raw_from_hex(&msg)?;
+                self.upgrade_client(deps, info, env, message, upgrade_height)
             }
-            CoreExecuteMsg::ClientMisbehaviour {} => {
-                unimplemented!()
+            CoreExecuteMsg::ClientMisbehaviour { msg } => {
+                cw_println!(deps, "[IBCCore] Client Misbehaviour Called");
+                let message: RawMsgSubmitMisbehaviour = Self::raw_from_hex(&msg)?;
+                self.misbehaviour(deps, info, message)
             }
             CoreExecuteMsg::ConnectionOpenInit { msg } => {
                 cw_println!(deps, "[IBCCore] Connection Open Init Called");
@@ -172,6 +201,37 @@ impl<'a> CwIbcCoreContext<'a> {
                 let message: RawMsgChannelCloseConfirm = Self::raw_from_hex(&msg)?;
                 self.validate_channel_close_confirm(deps, info, &message)
             }
+            CoreExecuteMsg::ChannelUpgradeInit { msg } => {
+                cw_println!(deps, "[IBCCore] Channel Upgrade Init Called");
+                self.check_sender_is_owner(deps.as_ref().storage, info.sender.clone())?;
+                let message: RawMsgChannelUpgradeInit = Self::raw_from_hex(&msg)?;
+                self.validate_channel_upgrade_init(deps, info, &message)
+            }
+            CoreExecuteMsg::ChannelUpgradeTry { msg } => {
+                cw_println!(deps, "[IBCCore] Channel Upgrade Try Called");
+                let message: RawMsgChannelUpgradeTry = Self::raw_from_hex(&msg)?;
+                self.validate_channel_upgrade_try(deps, info, &message)
+            }
+            CoreExecuteMsg::ChannelUpgradeAck { msg } => {
+                cw_println!(deps, "[IBCCore] Channel Upgrade Ack Called");
+                let message: RawMsgChannelUpgradeAck = Self::raw_from_hex(&msg)?;
+                self.validate_channel_upgrade_ack(deps, info, &message)
+            }
+            CoreExecuteMsg::ChannelUpgradeConfirm { msg } => {
+                cw_println!(deps, "[IBCCore] Channel Upgrade Confirm Called");
+                let message: RawMsgChannelUpgradeConfirm = Self::raw_from_hex(&msg)?;
+                self.validate_channel_upgrade_confirm(deps, info, &message)
+            }
+            CoreExecuteMsg::ChannelUpgradeOpen { msg } => {
+                cw_println!(deps, "[IBCCore] Channel Upgrade Open Called");
+                let message: RawMsgChannelUpgradeOpen = Self::raw_from_hex(&msg)?;
+                self.validate_channel_upgrade_open(deps, info, &message)
+            }
+            CoreExecuteMsg::ChannelUpgradeCancel { msg } => {
+                cw_println!(deps, "[IBCCore] Channel Upgrade Cancel Called");
+                let message: RawMsgChannelUpgradeCancel = Self::raw_from_hex(&msg)?;
+                self.validate_channel_upgrade_cancel(deps, info, &message)
+            }
             CoreExecuteMsg::SendPacket { packet } => {
                 cw_println!(deps, "[IBCCore] Send Packet Called");
                 let packet_bytes = packet.to_bytes().map_err(Into::<ContractError>::into)?;
@@ -211,6 +271,30 @@ impl<'a> CwIbcCoreContext<'a> {
                     cw_common::types::TimeoutMsgType::TimeoutOnClose(message),
                 )
             }
+            CoreExecuteMsg::ReceivePacketBatch { msgs } => {
+                cw_println!(deps, "[IBCCore] Receive Packet Batch Called");
+                let messages = msgs
+                    .iter()
+                    .map(Self::raw_from_hex::<RawMessageRecvPacket>)
+                    .collect::<Result<Vec<RawMessageRecvPacket>, ContractError>>()?;
+                self.validate_receive_packet_batch(deps, env, &messages)
+            }
+            CoreExecuteMsg::AcknowledgementPacketBatch { msgs } => {
+                cw_println!(deps, "[IBCCore] Acknowledgement Packet Batch Called");
+                let messages = msgs
+                    .iter()
+                    .map(Self::raw_from_hex::<RawMessageAcknowledgement>)
+                    .collect::<Result<Vec<RawMessageAcknowledgement>, ContractError>>()?;
+                self.acknowledgement_packet_batch_validate(deps, info, env, &messages)
+            }
+            CoreExecuteMsg::TimeoutPacketBatch { msgs } => {
+                cw_println!(deps, "[IBCCore] Timeout Packet Batch Called");
+                let messages = msgs
+                    .iter()
+                    .map(Self::raw_from_hex::<RawMessageTimeout>)
+                    .collect::<Result<Vec<RawMessageTimeout>, ContractError>>()?;
+                self.timeout_packet_batch_validate(deps, info, env, messages)
+            }
             CoreExecuteMsg::BindPort { port_id, address } => {
                 cw_println!(deps, "[IBCCore] Bind Port Called");
                 let port_id = IbcPortId::from_str(&port_id).map_err(|error| {
@@ -310,6 +394,25 @@ impl<'a> CwIbcCoreContext<'a> {
                 let raw_connection_end: RawConnectionEnd = connection_end.into();
                 to_binary(&hex::encode(raw_connection_end.encode_to_vec()))
             }
+            QueryMsg::GetClientConnections {
+                client_id,
+                start_after,
+                limit,
+            } => {
+                let client_id = IbcClientId::from_str(&client_id).unwrap();
+                let start_after = start_after
+                    .map(|conn_id| ConnectionId::from_str(&conn_id))
+                    .transpose()
+                    .unwrap();
+                let connections = self
+                    .list_client_connections(deps.storage, &client_id, start_after, limit)
+                    .unwrap();
+                let connections: Vec<String> = connections
+                    .into_iter()
+                    .map(|conn_id| conn_id.to_string())
+                    .collect();
+                to_binary(&connections)
+            }
             QueryMsg::GetChannel {
                 port_id,
                 channel_id,
@@ -322,6 +425,94 @@ impl<'a> CwIbcCoreContext<'a> {
                 let raw: RawChannel = res.into();
                 to_binary(&hex::encode(raw.encode_to_vec()))
             }
+            QueryMsg::GetDecodedConnection { connection_id } => {
+                let connection_id = ConnectionId::from_str(&connection_id).unwrap();
+                let connection_end = self.connection_end(deps.storage, &connection_id).unwrap();
+                to_binary(&to_identified_connection(&connection_id, &connection_end))
+            }
+            QueryMsg::GetDecodedChannel {
+                port_id,
+                channel_id,
+            } => {
+                let port_id = PortId::from_str(&port_id).unwrap();
+                let channel_id = IbcChannelId::from_str(&channel_id).unwrap();
+                let channel_end = self
+                    .get_channel_end(deps.storage, &port_id, &channel_id)
+                    .unwrap();
+                to_binary(&to_identified_channel(&port_id, &channel_id, &channel_end))
+            }
+            QueryMsg::GetDecodedClientState { client_id } => {
+                let res = self
+                    .client_state_any(deps, &IbcClientId::from_str(&client_id).unwrap())
+                    .map_err(|_| ContractError::InvalidClientId { client_id })
+                    .unwrap();
+                to_binary(&to_decoded_client_state(res).unwrap())
+            }
+            QueryMsg::GetDecodedConsensusState { client_id, height } => {
+                let client_val = IbcClientId::from_str(&client_id).unwrap();
+                let res = match height {
+                    Some(height) => {
+                        self.get_light_client(deps.storage, &client_val)
+                            .and_then(|client| {
+                                client.get_consensus_state_any(deps, &client_val, height)
+                            })
+                    }
+                    None => self.consensus_state_any(deps, &client_val),
+                }
+                .map_err(|_| ContractError::InvalidClientId { client_id })
+                .unwrap();
+                to_binary(&to_decoded_consensus_state(res).unwrap())
+            }
+            QueryMsg::ListClients { start_after, limit } => {
+                let start_after = start_after
+                    .map(|client_id| IbcClientId::from_str(&client_id))
+                    .transpose()
+                    .unwrap();
+                let clients = self.list_clients(deps.storage, start_after, limit).unwrap();
+                to_binary(&clients)
+            }
+            QueryMsg::ListConnections { start_after, limit } => {
+                let start_after = start_after
+                    .map(|conn_id| ConnectionId::from_str(&conn_id))
+                    .transpose()
+                    .unwrap();
+                let connections = self
+                    .list_connections(deps.storage, start_after, limit)
+                    .unwrap();
+                to_binary(&connections)
+            }
+            QueryMsg::ListChannels {
+                connection_id,
+                port_id,
+                start_after,
+                limit,
+            } => {
+                let port_id = port_id
+                    .map(|port_id| PortId::from_str(&port_id))
+                    .transpose()
+                    .unwrap();
+                let connection_id = connection_id
+                    .map(|conn_id| ConnectionId::from_str(&conn_id))
+                    .transpose()
+                    .unwrap();
+                let start_after = start_after.map(to_channel_key).transpose().unwrap();
+                let channels = self
+                    .list_channels(deps.storage, port_id, connection_id, start_after, limit)
+                    .unwrap();
+                to_binary(&channels)
+            }
+            QueryMsg::GetConnectionChannels {
+                connection_id,
+                start_after,
+                limit,
+            } => {
+                let connection_id = ConnectionId::from_str(&connection_id).unwrap();
+                let start_after = start_after.map(to_channel_key).transpose().unwrap();
+                let channels = self
+                    .list_channels(deps.storage, None, Some(connection_id), start_after, limit)
+                    .unwrap();
+                to_binary(&channels)
+            }
             QueryMsg::GetNextSequenceSend {
                 port_id,
                 channel_id,
@@ -551,9 +742,18 @@ impl<'a> CwIbcCoreContext<'a> {
     ) -> Result<Response, ContractError> {
         match message.id {
             EXECUTE_UPDATE_CLIENT => self.execute_update_client_reply(deps, env, message),
+            EXECUTE_UPDATE_CLIENT_BATCH => {
+                self.execute_update_client_batch_reply(deps, env, message)
+            }
             EXECUTE_UPGRADE_CLIENT => self.execute_upgrade_client_reply(deps, env, message),
             MISBEHAVIOUR => self.execute_misbehaviour_reply(deps, env, message),
             VALIDATE_ON_PACKET_RECEIVE_ON_MODULE => self.execute_receive_packet(deps, message),
+            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE => {
+                self.execute_receive_packet_batch(deps, message)
+            }
+            EXECUTE_ON_CHANNEL_UPGRADE_TRY_ON_MODULE | EXECUTE_ON_CHANNEL_UPGRADE_ACK_ON_MODULE => {
+                self.execute_channel_upgrade_reply(deps, message)
+            }
 
             _ => Err(ContractError::ReplyError {
                 code: message.id,
@@ -572,6 +772,7 @@ impl<'a> CwIbcCoreContext<'a> {
             let store = CwIbcStore::default();
             store.clear_storage(deps.storage);
         }
+        self.migrate_client_connections(deps.storage)?;
         set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
             .map_err(ContractError::Std)?;
         Ok(Response::default().add_attribute("migrate", "successful"))
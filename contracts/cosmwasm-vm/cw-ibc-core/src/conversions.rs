@@ -1,15 +1,26 @@
 use std::str::FromStr;
 
 use crate::ContractError;
+use common::constants::{ICON_CLIENT_STATE_TYPE_URL, ICON_CONSENSUS_STATE_TYPE_URL};
 use common::ibc::core::ics03_connection::version::Version;
 use common::ibc::{
     core::{
-        ics03_connection::{connection::Counterparty, error::ConnectionError},
+        ics03_connection::{
+            connection::{ConnectionEnd, Counterparty},
+            error::ConnectionError,
+        },
         ics04_channel::timeout::TimeoutHeight,
     },
     Height,
 };
+use common::icon::icon::lightclient::v1::{ClientState, ConsensusState};
+use common::traits::AnyTypes;
 use cosmwasm_std::{IbcEndpoint, IbcPacket, IbcTimeout, IbcTimeoutBlock};
+use cw_common::core_msg::{
+    ChannelCounterparty, ChannelKey, ConnectionCounterparty, ConnectionVersion, DecodedClientState,
+    DecodedConsensusState, IconClientState, IconConsensusState, IconTrustLevel, IdentifiedChannel,
+    IdentifiedConnection,
+};
 use cw_common::ibc_types::Sequence;
 use cw_common::raw_types::channel::RawPacket;
 use cw_common::raw_types::connection::RawCounterparty;
@@ -18,7 +29,7 @@ use cw_common::{
         ChannelEnd, ChannelError, IbcChannelId, IbcClientId, IbcConnectionId, IbcPortId,
         IbcTimestamp,
     },
-    raw_types::{channel::RawChannel, RawHeight, RawVersion},
+    raw_types::{channel::RawChannel, Any, RawHeight, RawVersion},
 };
 
 pub fn to_ibc_port_id(port_id: &str) -> Result<IbcPortId, ContractError> {
@@ -33,6 +44,14 @@ pub fn to_ibc_channel_id(channel_id: &str) -> Result<IbcChannelId, ContractError
     Ok(channel_id)
 }
 
+/// Parses the position of a channel in the channel listings.
+pub fn to_channel_key(key: ChannelKey) -> Result<(IbcPortId, IbcChannelId), ContractError> {
+    Ok((
+        to_ibc_port_id(&key.port_id)?,
+        to_ibc_channel_id(&key.channel_id)?,
+    ))
+}
+
 pub fn to_ibc_height(height: Option<RawHeight>) -> Result<Height, ContractError> {
     if let Some(height) = height {
         let height =
@@ -121,6 +140,21 @@ pub fn to_ibc_timeout_block(packet_timeout_height: &TimeoutHeight) -> IbcTimeout
     }
 }
 
+/// Builds the timeout handed to modules from the packet's timeout height and timestamp, keeping
+/// whichever of the two is set.
+pub fn to_ibc_timeout(
+    packet_timeout_height: &TimeoutHeight,
+    packet_timestamp: &IbcTimestamp,
+) -> IbcTimeout {
+    let timeout_block = to_ibc_timeout_block(packet_timeout_height);
+    let timestamp = cosmwasm_std::Timestamp::from_nanos(packet_timestamp.nanoseconds());
+    match (packet_timeout_height, packet_timestamp.nanoseconds()) {
+        (_, 0) => IbcTimeout::with_block(timeout_block),
+        (TimeoutHeight::Never, _) => IbcTimeout::with_timestamp(timestamp),
+        (TimeoutHeight::At(_), _) => IbcTimeout::with_both(timeout_block, timestamp),
+    }
+}
+
 pub fn to_ibc_packet(packet: RawPacket) -> Result<IbcPacket, ContractError> {
     let packet_timeout_height = to_ibc_timeout_height(packet.timeout_height.clone())?;
     let packet_timestamp = to_ibc_timestamp(packet.timeout_timestamp)?;
@@ -133,10 +167,107 @@ pub fn to_ibc_packet(packet: RawPacket) -> Result<IbcPacket, ContractError> {
         port_id: packet.destination_port.to_string(),
         channel_id: packet.destination_channel.to_string(),
     };
-    let timeoutblock = to_ibc_timeout_block(&packet_timeout_height);
-    let timestamp = packet_timestamp.nanoseconds();
-    let ibctimestamp = cosmwasm_std::Timestamp::from_nanos(timestamp);
-    let timeout = IbcTimeout::with_both(timeoutblock, ibctimestamp);
+    let timeout = to_ibc_timeout(&packet_timeout_height, &packet_timestamp);
     let ibc_packet = IbcPacket::new(packet.data, src, dest, packet.sequence, timeout);
     Ok(ibc_packet)
 }
+
+/// Decodes a connection end into the structure returned by the connection listings.
+pub fn to_identified_connection(
+    connection_id: &IbcConnectionId,
+    connection_end: &ConnectionEnd,
+) -> IdentifiedConnection {
+    let counterparty = connection_end.counterparty();
+    IdentifiedConnection {
+        connection_id: connection_id.to_string(),
+        state: connection_end.state().as_str().to_string(),
+        client_id: connection_end.client_id().to_string(),
+        counterparty: ConnectionCounterparty {
+            client_id: counterparty.client_id().to_string(),
+            connection_id: counterparty.connection_id().map(|id| id.to_string()),
+            prefix: hex::encode(counterparty.prefix().as_bytes()),
+        },
+        versions: connection_end
+            .versions()
+            .iter()
+            .map(|version| {
+                let raw: RawVersion = version.clone().into();
+                ConnectionVersion {
+                    identifier: raw.identifier,
+                    features: raw.features,
+                }
+            })
+            .collect(),
+        delay_period: connection_end.delay_period().as_nanos() as u64,
+    }
+}
+
+/// Decodes a channel end into the structure returned by the channel listings.
+pub fn to_identified_channel(
+    port_id: &IbcPortId,
+    channel_id: &IbcChannelId,
+    channel_end: &ChannelEnd,
+) -> IdentifiedChannel {
+    let counterparty = channel_end.counterparty();
+    IdentifiedChannel {
+        port_id: port_id.to_string(),
+        channel_id: channel_id.to_string(),
+        state: channel_end.state().as_string().to_string(),
+        ordering: channel_end.ordering().as_str().to_string(),
+        counterparty: ChannelCounterparty {
+            port_id: counterparty.port_id().to_string(),
+            channel_id: counterparty.channel_id().map(|id| id.to_string()),
+        },
+        connection_hops: channel_end
+            .connection_hops()
+            .iter()
+            .map(|id| id.to_string())
+            .collect(),
+        version: channel_end.version().as_str().to_string(),
+    }
+}
+
+/// Decodes a client state into its JSON form, states of other light clients than ICON are kept as
+/// hex encoded protobuf.
+pub fn to_decoded_client_state(client_state: Any) -> Result<DecodedClientState, ContractError> {
+    if client_state.type_url != ICON_CLIENT_STATE_TYPE_URL {
+        return Ok(DecodedClientState::Raw {
+            type_url: client_state.type_url,
+            value: hex::encode(client_state.value),
+        });
+    }
+    let state = ClientState::from_any(client_state)
+        .map_err(|error| ContractError::IbcDecodeError { error })?;
+    Ok(DecodedClientState::Icon(IconClientState {
+        trusting_period: state.trusting_period,
+        frozen_height: state.frozen_height,
+        max_clock_drift: state.max_clock_drift,
+        latest_height: state.latest_height,
+        src_network_id: state.src_network_id,
+        network_id: state.network_id,
+        network_type_id: state.network_type_id,
+        trust_level: state.trust_level.map(|level| IconTrustLevel {
+            numerator: level.numerator,
+            denominator: level.denominator,
+        }),
+    }))
+}
+
+/// Decodes a consensus state into its JSON form, states of other light clients than ICON are kept
+/// as hex encoded protobuf.
+pub fn to_decoded_consensus_state(
+    consensus_state: Any,
+) -> Result<DecodedConsensusState, ContractError> {
+    if consensus_state.type_url != ICON_CONSENSUS_STATE_TYPE_URL {
+        return Ok(DecodedConsensusState::Raw {
+            type_url: consensus_state.type_url,
+            value: hex::encode(consensus_state.value),
+        });
+    }
+    let state = ConsensusState::from_any(consensus_state)
+        .map_err(|error| ContractError::IbcDecodeError { error })?;
+    Ok(DecodedConsensusState::Icon(IconConsensusState {
+        message_root: hex::encode(state.message_root),
+        next_proof_context_hash: hex::encode(state.next_proof_context_hash),
+    }))
+}
@@ -82,6 +82,12 @@ pub enum ContractError {
     PacketNotExpired,
     #[error("CallAlreadyInProgress")]
     CallAlreadyInProgress,
+
+    #[error("PacketBatchProofHeightMismatch")]
+    PacketBatchProofHeightMismatch,
+
+    #[error("TimestampTimeoutNotSupported {client_id}")]
+    TimestampTimeoutNotSupported { client_id: String },
 }
 
 impl From<FromHexError> for ContractError {
@@ -1,16 +1,13 @@
-use common::constants::ICON_CLIENT_STATE_TYPE_URL;
-use common::constants::ICON_CONSENSUS_STATE_TYPE_URL;
 use common::ibc::core::ics24_host::identifier::ConnectionId;
 
-use common::icon::icon::lightclient::v1::ClientState;
-use common::icon::icon::lightclient::v1::ConsensusState;
-use common::traits::AnyTypes;
 use common::{client_state::IClientState, consensus_state::IConsensusState};
 use cosmwasm_std::Deps;
 use cosmwasm_std::Env;
 
 use crate::ics24_host::LastProcessedOn;
 use crate::light_client::light_client::LightClient;
+use crate::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+use cw_common::core_msg::IdentifiedClient;
 
 use super::*;
 
@@ -183,6 +180,38 @@ impl<'a> CwIbcCoreContext<'a> {
             })
     }
 
+    /// This method returns one page of the clients of the host, ordered by client id.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is a reference to a trait object of type `dyn Storage`.
+    /// * `start_after`: the page starts after this client when provided.
+    /// * `limit`: the maximum number of clients returned, capped at `MAX_QUERY_LIMIT`.
+    ///
+    /// Returns:
+    ///
+    /// a `Result` object that contains the `IdentifiedClient`s of the page or a `ContractError`.
+    pub fn list_clients(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<ClientId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<IdentifiedClient>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        self.ibc_store()
+            .list_client_types(store, start_after.as_ref(), limit)?
+            .into_iter()
+            .map(|(client_id, client_type)| {
+                let light_client = self.get_client_implementations(store, &client_id)?;
+                Ok(IdentifiedClient {
+                    client_id: client_id.to_string(),
+                    client_type: client_type.as_str().to_string(),
+                    light_client: light_client.get_address(),
+                })
+            })
+            .collect()
+    }
+
     /// This method stores the client type for a given client ID in a storage object.
     ///
     /// Arguments:
@@ -498,29 +527,13 @@ impl<'a> CwIbcCoreContext<'a> {
         &self,
         client_state: Any,
     ) -> Result<Box<dyn IClientState>, ContractError> {
-        match client_state.type_url.as_str() {
-            ICON_CLIENT_STATE_TYPE_URL => {
-                let client_state = ClientState::from_any(client_state)
-                    .map_err(|e| ContractError::IbcDecodeError { error: e })?;
-
-                Ok(Box::new(client_state))
-            }
-            _ => Err(ContractError::FailedConversion),
-        }
+        LightClient::decode_client_state(client_state)
     }
     pub fn decode_consensus_state(
         &self,
         consensus_state: Any,
     ) -> Result<Box<dyn IConsensusState>, ContractError> {
-        match consensus_state.type_url.as_str() {
-            ICON_CONSENSUS_STATE_TYPE_URL => {
-                let consensus_state = ConsensusState::from_any(consensus_state)
-                    .map_err(|e| ContractError::IbcDecodeError { error: e })?;
-
-                Ok(Box::new(consensus_state))
-            }
-            _ => Err(ContractError::FailedConversion),
-        }
+        LightClient::decode_consensus_state(consensus_state)
     }
 }
 
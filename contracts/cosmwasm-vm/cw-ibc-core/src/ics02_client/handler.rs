@@ -1,6 +1,6 @@
 use crate::{
     conversions::to_ibc_client_id, light_client::light_client::LightClient, EXECUTE_CREATE_CLIENT,
-    EXECUTE_UPDATE_CLIENT, EXECUTE_UPGRADE_CLIENT, MISBEHAVIOUR,
+    EXECUTE_UPDATE_CLIENT, EXECUTE_UPDATE_CLIENT_BATCH, EXECUTE_UPGRADE_CLIENT, MISBEHAVIOUR,
 };
 
 use super::{events::client_misbehaviour_event, *};
@@ -158,6 +158,64 @@ impl<'a> IbcClient for CwIbcCoreContext<'a> {
             .add_attribute("client_id", client_id.as_str()))
     }
 
+    /// This method updates a client with an ordered batch of headers in a single light client
+    /// execution.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object, which provides mutable access to the contract's
+    /// dependencies such as storage, API, and querier.
+    /// * `_info`: `_info` is a `MessageInfo` struct that contains information about the message sender.
+    /// * `client_id`: `client_id` is the identifier of the client to be updated.
+    /// * `headers`: `headers` is the ordered list of headers, each encoded as an `Any`. The light
+    /// client verifies that every header is linked to the one before it.
+    /// * `store_heights`: `store_heights` is the list of heights in the batch whose consensus state
+    /// must be stored. The consensus state of the last header is always stored.
+    ///
+    /// Returns:
+    ///
+    /// This function returns a `Result<Response, ContractError>` where `Response` is a struct
+    /// representing the response to a message and `ContractError` is an enum representing the possible
+    /// errors that can occur during contract execution.
+    fn update_client_batch(
+        &self,
+        deps: DepsMut,
+        _info: MessageInfo,
+        client_id: String,
+        headers: Vec<Any>,
+        store_heights: Vec<u64>,
+    ) -> Result<Response, ContractError> {
+        let client_id = to_ibc_client_id(&client_id)?;
+        if headers.is_empty() {
+            return Err(ContractError::IbcClientError {
+                error: ClientError::MissingRawHeader,
+            });
+        }
+
+        let client = self.get_light_client(deps.as_ref().storage, &client_id)?;
+        let client_state = self.client_state(deps.as_ref(), &client_id)?;
+
+        if client_state.is_frozen() {
+            return Err(Into::<ContractError>::into(ClientError::ClientFrozen {
+                client_id: client_id.clone(),
+            }));
+        }
+
+        self.store_callback_data(deps.storage, EXECUTE_UPDATE_CLIENT_BATCH, &client_id)?;
+
+        let sub_msg: SubMsg = client.update_client_batch(&client_id, &headers, store_heights)?;
+        cw_println!(
+            deps,
+            "Called Update Client Batch On Lightclient for client id:{} with {} headers",
+            client_id.as_str(),
+            headers.len()
+        );
+        Ok(Response::new()
+            .add_submessage(sub_msg)
+            .add_attribute("method", "update_client_batch")
+            .add_attribute("client_id", client_id.as_str()))
+    }
+
     /// This method upgrades a client's state and consensus state and verifies proofs against the
     /// root.
     ///
@@ -170,6 +228,8 @@ impl<'a> IbcClient for CwIbcCoreContext<'a> {
     /// * `message`: `message` is a struct of type `MsgUpgradeClient` which contains the necessary
     /// information to upgrade a client. It includes the `client_id` of the client to be upgraded, the
     /// new `client_state` and `consensus_state`, and the proofs for the upgrade.
+    /// * `upgrade_height`: the counterparty height at which the upgrade plan committed the upgraded
+    /// states. The proofs are verified against the consensus state stored at this height.
     ///
     /// Returns:
     ///
@@ -180,6 +240,7 @@ impl<'a> IbcClient for CwIbcCoreContext<'a> {
         info: MessageInfo,
         env: Env,
         message: RawMsgUpgradeClient,
+        upgrade_height: u64,
     ) -> Result<Response, ContractError> {
         let client_id = to_ibc_client_id(&message.client_id)?;
         let old_client_state = self.client_state(deps.as_ref(), &client_id)?;
@@ -221,12 +282,12 @@ impl<'a> IbcClient for CwIbcCoreContext<'a> {
         };
 
         let wasm_exec_message = LightClientMessage::UpgradeClient {
-            upgraded_client_state: new_client_state.value,
-            upgraded_consensus_state: new_consensus_state.value,
-            proof_upgrade_client: to_vec(&message.proof_upgrade_client)
-                .map_err(ContractError::Std)?,
-            proof_upgrade_consensus_state: to_vec(&message.proof_upgrade_consensus_state)
-                .map_err(ContractError::Std)?,
+            client_id: client_id.to_string(),
+            upgrade_height,
+            upgraded_client_state: new_client_state.encode_to_vec(),
+            upgraded_consensus_state: new_consensus_state.encode_to_vec(),
+            proof_upgrade_client: message.proof_upgrade_client,
+            proof_upgrade_consensus_state: message.proof_upgrade_consensus_state,
         };
 
         let client = self.get_light_client(deps.storage, &client_id)?;
@@ -364,6 +425,86 @@ impl<'a> IbcClient for CwIbcCoreContext<'a> {
             }
         }
     }
+    /// This function handles the reply of a batched client update. The client state commitment is
+    /// stored once and a consensus state commitment is stored for every height the light client
+    /// returned, followed by a single update client event listing all of those heights.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a mutable reference to the dependencies of the contract, which includes
+    /// access to the storage, API, and other modules needed to execute the contract's logic.
+    /// * `env`: `env` is an `Env` struct that contains information about the current blockchain
+    /// environment, such as the current block height and time.
+    /// * `message`: `message` is a `Reply` struct that contains the result of the batched update
+    /// sub-message sent to the light client.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` where `Response` is a struct representing the response from
+    /// the contract and `ContractError` is an enum representing the possible errors that can occur
+    /// during the execution of the function.
+    fn execute_update_client_batch_reply(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        message: Reply,
+    ) -> Result<Response, ContractError> {
+        match message.result {
+            cosmwasm_std::SubMsgResult::Ok(result) => match result.data {
+                Some(data) => {
+                    let response: UpdateClientBatchResponse = from_binary_response(&data)?;
+                    cw_println!(deps, "Received Client Update Batch Callback with data");
+                    let client_id: ClientId =
+                        self.get_callback_data(deps.as_ref().storage, EXECUTE_UPDATE_CLIENT_BATCH)?;
+                    self.clear_callback_data(deps.storage, EXECUTE_UPDATE_CLIENT_BATCH);
+
+                    let latest = response
+                        .updates
+                        .last()
+                        .ok_or(ContractError::IbcClientError {
+                            error: ClientError::Other {
+                                description: "empty update client batch response".to_string(),
+                            },
+                        })?;
+                    self.store_client_commitment(
+                        deps.storage,
+                        &env,
+                        &client_id,
+                        latest.client_state_commitment.to_vec(),
+                    )?;
+
+                    let mut heights = Vec::with_capacity(response.updates.len());
+                    for update in response.updates.iter() {
+                        let height = update.height();
+                        self.store_consensus_commitment(
+                            deps.storage,
+                            &client_id,
+                            height,
+                            update.consensus_state_commitment.to_vec(),
+                        )?;
+                        heights.push(height);
+                    }
+
+                    let client_type = IbcClientType::from(client_id.clone());
+                    let height = latest.height();
+                    let event = update_client_event(client_type, height, heights, &client_id);
+
+                    Ok(Response::new()
+                        .add_event(event)
+                        .add_attribute("methods", "execute_update_client_batch_reply")
+                        .add_attribute("height", height))
+                }
+                None => Err(Into::<ContractError>::into(ClientError::Other {
+                    description: "UNKNOWN ERROR".to_string(),
+                })),
+            },
+            cosmwasm_std::SubMsgResult::Err(error) => {
+                Err(Into::<ContractError>::into(ClientError::Other {
+                    description: error,
+                }))
+            }
+        }
+    }
     /// This function executes an upgrade client reply and stores the client and consensus state
     /// commitments.
     ///
@@ -464,11 +605,14 @@ impl<'a> IbcClient for CwIbcCoreContext<'a> {
                 client_id: client_id.clone(),
             }));
         }
+        let misbehaviour = message.misbehaviour.ok_or(ContractError::IbcClientError {
+            error: ClientError::MissingRawMisbehaviour,
+        })?;
         let client = self.get_light_client(deps.as_ref().storage, &client_id)?;
 
         let clinet_message = LightClientMessage::Misbehaviour {
             client_id: client_id.to_string(),
-            misbehaviour: to_vec(&message.misbehaviour)?,
+            misbehaviour: misbehaviour.encode_to_vec(),
         };
 
         let wasm_exec_message: CosmosMsg = CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
@@ -10,11 +10,11 @@ use crate::{
 use common::ibc::core::ics24_host::identifier::ClientId;
 
 use cosmwasm_std::{
-    from_json as from_binary, to_json_binary as to_binary, to_json_vec as to_vec, Addr, CosmosMsg,
-    DepsMut, Event, MessageInfo, Reply, Response, Storage, SubMsg,
+    from_json as from_binary, to_json_binary as to_binary, Addr, CosmosMsg, DepsMut, Event,
+    MessageInfo, Reply, Response, Storage, SubMsg,
 };
 use cw_common::client_response::{
-    MisbehaviourResponse, UpdateClientResponse, UpgradeClientResponse,
+    MisbehaviourResponse, UpdateClientBatchResponse, UpdateClientResponse, UpgradeClientResponse,
 };
 use cw_common::commitment;
 use cw_common::ibc_types::*;
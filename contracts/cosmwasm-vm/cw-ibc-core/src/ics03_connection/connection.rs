@@ -1,7 +1,11 @@
 use common::utils::keccak256;
-use cw_common::{get_address_storage_prefix, query_helpers::get_contract_info};
+use cw_common::{
+    core_msg::IdentifiedConnection, get_address_storage_prefix, query_helpers::get_contract_info,
+};
 use prost::DecodeError;
 
+use crate::conversions::to_identified_connection;
+
 use super::*;
 
 impl<'a> CwIbcCoreContext<'a> {
@@ -69,7 +73,7 @@ impl<'a> CwIbcCoreContext<'a> {
         Ok(connection_end)
     }
 
-    /// This method stores a connection ID for a given client ID in a storage object.
+    /// This method adds a connection ID to the connections of a given client ID in a storage object.
     ///
     /// Arguments:
     ///
@@ -78,12 +82,12 @@ impl<'a> CwIbcCoreContext<'a> {
     /// * `client_id`: The `client_id` parameter is a unique identifier for an IBC client. It is used to
     /// associate a connection with a specific client.
     /// * `conn_id`: `conn_id` is a unique identifier for a connection between two IBC-enabled
-    /// blockchains. It is used to store the connection information in the IBC store.
+    /// blockchains. It is appended to the connections of the client unless it is already present.
     ///
     /// Returns:
     ///
     /// a `Result` type with either an `Ok(())` value indicating success or an `Err` value with a
-    /// `ContractError::Std` variant indicating an error occurred while saving the client connection to
+    /// `ContractError::Std` variant indicating an error occurred while saving the client connections to
     /// the storage.
     pub fn store_connection_to_client(
         &self,
@@ -91,41 +95,123 @@ impl<'a> CwIbcCoreContext<'a> {
         client_id: &ClientId,
         conn_id: &ConnectionId,
     ) -> Result<(), ContractError> {
-        match self
-            .ibc_store()
+        self.ibc_store()
             .client_connections()
-            .save(store, client_id, conn_id)
-        {
-            Ok(_) => Ok(()),
-            Err(error) => Err(ContractError::Std(error)),
-        }
+            .update(store, client_id, |connections| -> StdResult<_> {
+                let mut connections = connections.unwrap_or_default();
+                if !connections.contains(conn_id) {
+                    connections.push(conn_id.clone());
+                }
+                Ok(connections)
+            })
+            .map_err(ContractError::Std)?;
+        Ok(())
     }
 
-    /// This method loads a client's connection from the IBC store using the provided client ID.
+    /// This method loads all the connections of a client from the IBC store using the provided client
+    /// ID.
     ///
     /// Arguments:
     ///
     /// * `store`: `store` is a reference to a trait object of type `dyn Storage`. This is an abstract
-    /// type that represents a key-value store where data can be stored and retrieved. The specific
-    /// implementation of the `Storage` trait is provided by the underlying blockchain platform on which
-    /// the smart contract is deployed. The
+    /// type that represents a key-value store where data can be stored and retrieved.
     /// * `client_id`: The `client_id` parameter is an identifier for a specific client in the IBC
-    /// (Inter-Blockchain Communication) protocol. It is used to retrieve information about the client's
-    /// connections to other blockchains.
+    /// (Inter-Blockchain Communication) protocol.
     ///
     /// Returns:
     ///
-    /// a `Result` object that contains either a `ConnectionId` or a `ContractError`.
-    pub fn client_connection(
+    /// a `Result` object that contains the `ConnectionId`s of the client in creation order, empty if
+    /// the client has no connection, or a `ContractError`.
+    pub fn client_connections(
         &self,
         store: &dyn Storage,
         client_id: &ClientId,
-    ) -> Result<ConnectionId, ContractError> {
+    ) -> Result<Vec<ConnectionId>, ContractError> {
         self.ibc_store()
             .client_connections()
-            .load(store, client_id)
+            .may_load(store, client_id)
+            .map(Option::unwrap_or_default)
             .map_err(ContractError::Std)
     }
+
+    /// This method returns one page of the connections of a client, in creation order.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is a reference to a trait object of type `dyn Storage`.
+    /// * `client_id`: The identifier of the client whose connections are listed.
+    /// * `start_after`: the page starts after this connection when provided.
+    /// * `limit`: the maximum number of connections returned, capped at `MAX_QUERY_LIMIT`.
+    ///
+    /// Returns:
+    ///
+    /// a `Result` object that contains the `ConnectionId`s of the page or a `ContractError`.
+    pub fn list_client_connections(
+        &self,
+        store: &dyn Storage,
+        client_id: &ClientId,
+        start_after: Option<ConnectionId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<ConnectionId>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let connections = self.client_connections(store, client_id)?;
+        let start = match start_after {
+            Some(start_after) => connections
+                .iter()
+                .position(|conn_id| conn_id == &start_after)
+                .map_or(connections.len(), |index| index + 1),
+            None => 0,
+        };
+        Ok(connections.into_iter().skip(start).take(limit).collect())
+    }
+
+    /// This method returns one page of the connections of the host, ordered by connection id.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is a reference to a trait object of type `dyn Storage`.
+    /// * `start_after`: the page starts after this connection when provided.
+    /// * `limit`: the maximum number of connections returned, capped at `MAX_QUERY_LIMIT`.
+    ///
+    /// Returns:
+    ///
+    /// a `Result` object that contains the decoded connections of the page or a `ContractError`.
+    pub fn list_connections(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<ConnectionId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<IdentifiedConnection>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        self.ibc_store()
+            .list_connections(store, start_after.as_ref(), limit)?
+            .into_iter()
+            .map(|(connection_id, data)| {
+                let connection_end = ConnectionEnd::decode(&*data).map_err(|error| {
+                    ContractError::IbcDecodeError {
+                        error: DecodeError::new(error.to_string()),
+                    }
+                })?;
+                Ok(to_identified_connection(&connection_id, &connection_end))
+            })
+            .collect()
+    }
+
+    /// This method moves the client connections saved before clients could have several connections
+    /// into the current one-to-many index.
+    pub fn migrate_client_connections(&self, store: &mut dyn Storage) -> Result<(), ContractError> {
+        let legacy: Map<&ClientId, ConnectionId> = Map::new(StorageKey::ClientConnection.as_str());
+        let entries = legacy
+            .range(store, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<(ClientId, ConnectionId)>>>()
+            .map_err(ContractError::Std)?;
+        for (client_id, conn_id) in entries {
+            self.store_connection_to_client(store, &client_id, &conn_id)?;
+            legacy.remove(store, &client_id);
+        }
+        Ok(())
+    }
+
     /// This method is used to increase the connection counter in the IBC store. It takes a mutable
     /// reference to a storage object and returns a `Result` containing the new sequence number or a
     /// `ContractError` if there was an error while updating the counter.
@@ -229,41 +315,6 @@ impl<'a> CwIbcCoreContext<'a> {
             Err(error) => Err(ContractError::Std(error)),
         }
     }
-    /// This method checks if a connection already exists for a given client ID in a storage and
-    /// returns an error if it does, otherwise it returns Ok.
-    ///
-    /// Arguments:
-    ///
-    /// * `store`: `store` is a reference to a trait object of type `dyn Storage`. It is used to
-    /// interact with the storage of the smart contract.
-    /// * `client_id`: The `client_id` parameter is the identifier of the client for which the function
-    /// is checking if a connection already exists.
-    ///
-    /// Returns:
-    ///
-    /// a `Result` with either `Ok(())` if there is no existing connection for the given `client_id`, or
-    /// an `Err` with a `ContractError` if there is an error or if a connection already exists for the
-    /// given `client_id`.
-    pub fn check_for_connection(
-        &self,
-        store: &dyn Storage,
-        client_id: &ClientId,
-    ) -> Result<(), ContractError> {
-        match self
-            .ibc_store()
-            .client_connections()
-            .may_load(store, client_id)
-        {
-            Ok(result) => match result {
-                Some(id) => Err(Into::<ContractError>::into(
-                    ConnectionError::ConnectionExists(id.to_string()),
-                )),
-                None => Ok(()),
-            },
-            Err(error) => Err(ContractError::Std(error)),
-        }
-    }
-
     /// This method updates the commitment of a connection in a storage using the provided connection
     /// ID and connection end.
     ///
@@ -26,7 +26,6 @@ pub use common::ibc::core::ics03_connection::{
 use common::ibc::core::ics23_commitment::commitment::CommitmentPrefix;
 pub use common::ibc::core::ics24_host::identifier::ConnectionId as IbcConnectionId;
 use common::ibc::events::IbcEventType;
-use common::ibc::Height;
 
 use cw_common::commitment;
 use cw_common::raw_types::Protobuf;
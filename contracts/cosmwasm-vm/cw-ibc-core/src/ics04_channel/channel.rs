@@ -1,6 +1,11 @@
 use super::*;
+use crate::conversions::to_identified_channel;
 use common::utils::keccak256;
-use cw_common::{commitment, raw_types::channel::RawChannel};
+use cw_common::{
+    commitment,
+    core_msg::IdentifiedChannel,
+    raw_types::{channel::RawChannel, upgrade::RawUpgradeChannel},
+};
 use prost::{DecodeError, Message};
 impl<'a> CwIbcCoreContext<'a> {
     /// This function retrieves the channel_end of a specified channel from storage and returns it as a result.
@@ -37,6 +42,46 @@ impl<'a> CwIbcCoreContext<'a> {
         }
     }
 
+    /// This function returns one page of the channels of the host, grouped by port.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is a reference to a trait object of type `dyn Storage`.
+    /// * `port_id`: only the channels bound to this port are listed when provided.
+    /// * `connection_id`: only the channels on this connection are listed when provided.
+    /// * `start_after`: the page starts after this port and channel when provided.
+    /// * `limit`: the maximum number of channels returned, capped at `MAX_QUERY_LIMIT`.
+    ///
+    /// Returns:
+    ///
+    /// This function returns a `Result` containing the decoded channels of the page or a
+    /// `ContractError`.
+    pub fn list_channels(
+        &self,
+        store: &dyn Storage,
+        port_id: Option<PortId>,
+        connection_id: Option<ConnectionId>,
+        start_after: Option<(PortId, ChannelId)>,
+        limit: Option<u32>,
+    ) -> Result<Vec<IdentifiedChannel>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let channels = self.ibc_store().list_channels(
+            store,
+            port_id.as_ref(),
+            connection_id.as_ref(),
+            start_after
+                .as_ref()
+                .map(|(port_id, channel_id)| (port_id, channel_id)),
+            limit,
+        )?;
+        Ok(channels
+            .iter()
+            .map(|((port_id, channel_id), channel_end)| {
+                to_identified_channel(port_id, channel_id, channel_end)
+            })
+            .collect())
+    }
+
     /// This function stores a channel end in the IBC store for a given port and channel ID.
     ///
     /// Arguments:
@@ -528,7 +573,9 @@ impl<'a> CwIbcCoreContext<'a> {
         let channel_commitment_key = commitment::channel_commitment_key(port_id, channel_id);
 
         let raw_channel: RawChannel = channel_end.clone().into();
-        let channel_end_commitment = raw_channel.encode_to_vec();
+        let upgrade_sequence = self.get_channel_upgrade_sequence(store, port_id, channel_id)?;
+        let channel_end_commitment =
+            RawUpgradeChannel::new(raw_channel, upgrade_sequence).encode_to_vec();
 
         self.ibc_store().save_commitment(
             store,
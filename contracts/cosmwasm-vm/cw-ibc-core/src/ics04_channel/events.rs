@@ -2,6 +2,7 @@ use crate::ics03_connection::event::CONN_ID_ATTRIBUTE_KEY;
 
 use super::*;
 use cw_common::raw_types::channel::RawPacket;
+use upgrade::UpgradeFields;
 
 pub const CHANNEL_ID_ATTRIBUTE_KEY: &str = "channel_id";
 pub const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
@@ -23,6 +24,15 @@ pub const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp"
 pub const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
 pub const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
 pub const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
+pub const PKT_ERROR_ATTRIBUTE_KEY: &str = "packet_error";
+
+// Channel upgrade constants
+pub const CHANNEL_STATE_ATTRIBUTE_KEY: &str = "channel_state";
+pub const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+pub const UPGRADE_VERSION_ATTRIBUTE_KEY: &str = "upgrade_version";
+pub const UPGRADE_ORDERING_ATTRIBUTE_KEY: &str = "upgrade_ordering";
+pub const UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY: &str = "upgrade_connection_hops";
+pub const ERROR_RECEIPT_ATTRIBUTE_KEY: &str = "error_receipt";
 
 pub fn create_channel_event(
     event_type: IbcEventType,
@@ -44,6 +54,7 @@ pub fn create_channel_event(
         IbcEventType::OpenTryChannel
         | IbcEventType::CloseInitChannel
         | IbcEventType::CloseConfirmChannel
+        | IbcEventType::ChannelClosed
         | IbcEventType::OpenAckChannel
         | IbcEventType::OpenConfirmChannel => {
             event = event.add_attribute(
@@ -60,6 +71,65 @@ pub fn create_channel_event(
     }
 }
 
+/// Creates the event emitted by a step of the channel upgrade handshake. The upgrade `fields`
+/// are included by the steps that propose or accept them.
+pub fn create_channel_upgrade_event(
+    event_type: IbcEventType,
+    port_id: &str,
+    channel_id: &str,
+    channel: &ChannelEnd,
+    upgrade_sequence: u64,
+    fields: Option<&UpgradeFields>,
+) -> Result<Event, ContractError> {
+    match event_type {
+        IbcEventType::UpgradeInitChannel
+        | IbcEventType::UpgradeTryChannel
+        | IbcEventType::UpgradeAckChannel
+        | IbcEventType::UpgradeConfirmChannel
+        | IbcEventType::UpgradeOpenChannel
+        | IbcEventType::UpgradeCancelChannel
+        | IbcEventType::UpgradeErrorChannel => {}
+        _ => {
+            return Err(ContractError::InvalidEventType {
+                event: "Channel Upgrade Event".to_string(),
+                event_type: event_type.as_str().to_string(),
+            })
+        }
+    }
+    let mut event = Event::new(event_type.as_str())
+        .add_attribute(PORT_ID_ATTRIBUTE_KEY, port_id)
+        .add_attribute(CHANNEL_ID_ATTRIBUTE_KEY, channel_id)
+        .add_attribute(
+            COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY,
+            channel.counterparty().port_id.as_str(),
+        )
+        .add_attribute(
+            COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
+            channel
+                .counterparty()
+                .channel_id()
+                .map(|channel_id| channel_id.to_string())
+                .unwrap_or_default(),
+        )
+        .add_attribute(CHANNEL_STATE_ATTRIBUTE_KEY, channel.state.as_string())
+        .add_attribute(UPGRADE_SEQUENCE_ATTRIBUTE_KEY, upgrade_sequence.to_string());
+    if let Some(fields) = fields {
+        event = event
+            .add_attribute(UPGRADE_VERSION_ATTRIBUTE_KEY, fields.version.to_string())
+            .add_attribute(UPGRADE_ORDERING_ATTRIBUTE_KEY, fields.ordering.as_str())
+            .add_attribute(
+                UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY,
+                fields
+                    .connection_hops
+                    .iter()
+                    .map(|connection_id| connection_id.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            );
+    }
+    Ok(event)
+}
+
 /// This function creates an event with a "channel_id_created" tag and adds a channel ID attribute to
 /// it.
 ///
@@ -130,3 +200,13 @@ pub fn create_packet_event(
         }),
     }
 }
+
+/// Returns the `receive_packet_error` event carrying the error of the module that failed to
+/// process `packet`. The acknowledgement written for the packet only holds a fixed message.
+pub fn create_packet_receive_error_event(packet: &CwPacket, error: &str) -> Event {
+    Event::new("receive_packet_error")
+        .add_attribute(PKT_SEQ_ATTRIBUTE_KEY, packet.sequence.to_string())
+        .add_attribute(PKT_DST_PORT_ATTRIBUTE_KEY, packet.dest.port_id.as_str())
+        .add_attribute(PKT_DST_CHANNEL_ATTRIBUTE_KEY, packet.dest.channel_id.as_str())
+        .add_attribute(PKT_ERROR_ATTRIBUTE_KEY, error)
+}
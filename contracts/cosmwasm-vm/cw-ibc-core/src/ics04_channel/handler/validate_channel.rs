@@ -34,6 +34,22 @@ pub fn ensure_channel_state(
     Ok(())
 }
 
+pub fn ensure_channel_state_in(
+    channel_id: &ChannelId,
+    channel_end: &ChannelEnd,
+    states: &[State],
+) -> Result<(), ContractError> {
+    if !states.iter().any(|state| channel_end.state_matches(state)) {
+        return Err(ContractError::IbcChannelError {
+            error: ChannelError::InvalidChannelState {
+                channel_id: channel_id.clone(),
+                state: channel_end.state,
+            },
+        });
+    }
+    Ok(())
+}
+
 pub fn validate_connection_length(channel_end: &ChannelEnd) -> Result<(), ContractError> {
     if channel_end.connection_hops().len() != 1 {
         return Err(ContractError::IbcChannelError {
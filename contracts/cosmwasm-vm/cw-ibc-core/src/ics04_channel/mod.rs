@@ -3,6 +3,7 @@ pub mod channel;
 pub mod events;
 pub use events::*;
 pub mod handler;
+pub mod upgrade;
 pub use super::*;
 use crate::context::CwIbcCoreContext;
 pub use handler::*;
@@ -30,5 +31,5 @@ use common::ibc::core::ics04_channel::commitment::PacketCommitment;
 pub use packet::*;
 
 use common::ibc::timestamp::Expiry;
-use cw_common::cw_types::{CwEndPoint, CwPacket, CwTimeout, CwTimeoutBlock};
+use cw_common::cw_types::{CwEndPoint, CwPacket, CwTimeoutBlock};
 use cw_common::types::*;
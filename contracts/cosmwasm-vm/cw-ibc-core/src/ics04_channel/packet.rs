@@ -1,6 +1,7 @@
 use super::*;
 
 pub mod acknowledgement;
+pub mod batch;
 pub mod receive_packet;
 pub mod send_packet;
 pub mod timeout;
@@ -1,5 +1,5 @@
 use crate::conversions::{
-    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout_block, to_ibc_timeout_height,
+    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout, to_ibc_timeout_height,
     to_ibc_timestamp,
 };
 
@@ -35,11 +35,13 @@ impl<'a> CwIbcCoreContext<'a> {
         &self,
         deps: DepsMut,
         _info: MessageInfo,
-        env: Env,
+        _env: Env,
         msg: &RawMessageAcknowledgement,
     ) -> Result<Response, ContractError> {
         cw_println!(deps, "inside acknowledge packet validate ");
-        let packet = msg.packet.clone().unwrap();
+        let packet = msg.packet.clone().ok_or(ContractError::IbcPacketError {
+            error: PacketError::MissingPacket,
+        })?;
         let src_port = to_ibc_port_id(&packet.source_port)?;
         let src_channel = to_ibc_channel_id(&packet.source_channel)?;
 
@@ -51,7 +53,10 @@ impl<'a> CwIbcCoreContext<'a> {
         let proof_height = to_ibc_height(msg.proof_height.clone())?;
 
         let chan_end_on_a = self.get_channel_end(deps.storage, &src_port, &src_channel)?;
-        if !chan_end_on_a.state_matches(&State::Open) {
+        // packets sent before an upgrade are still acknowledged while the channel flushes
+        if !chan_end_on_a.state_matches(&State::Open)
+            && !chan_end_on_a.state_matches(&State::Flushing)
+        {
             return Err(ContractError::IbcPacketError {
                 error: PacketError::ChannelClosed {
                     channel_id: src_channel,
@@ -142,12 +147,6 @@ impl<'a> CwIbcCoreContext<'a> {
             });
         }
         let consensus_state = self.consensus_state(deps.as_ref(), client_id_on_a, &proof_height)?;
-        self.verify_connection_delay_passed(
-            deps.storage,
-            env,
-            proof_height,
-            conn_end_on_a.clone(),
-        )?;
 
         let ack_path_on_b =
             commitment::acknowledgement_commitment_path(&dst_port, &dst_channel, packet_sequence);
@@ -166,6 +165,7 @@ impl<'a> CwIbcCoreContext<'a> {
             deps.as_ref(),
             verify_packet_acknowledge,
             client_id_on_a,
+            self.connection_delay_periods(&conn_end_on_a),
         )?;
 
         let acknowledgement = msg.acknowledgement.clone();
@@ -182,10 +182,7 @@ impl<'a> CwIbcCoreContext<'a> {
             port_id: dst_port.to_string(),
             channel_id: dst_channel.to_string(),
         };
-        let timeoutblock = to_ibc_timeout_block(&packet_timeout_height);
-        let timestamp = packet_timestamp.nanoseconds();
-        let cw_timestamp = cosmwasm_std::Timestamp::from_nanos(timestamp);
-        let timeout = CwTimeout::with_both(timeoutblock, cw_timestamp);
+        let timeout = to_ibc_timeout(&packet_timeout_height, &packet_timestamp);
 
         let cw_packet = CwPacket::new(packet.data.clone(), src, dest, packet.sequence, timeout);
         let address = to_checked_address(deps.as_ref(), &msg.signer);
@@ -214,6 +211,7 @@ impl<'a> CwIbcCoreContext<'a> {
             packet.sequence,
             0,
         )?;
+        self.complete_channel_upgrade_flush(deps.storage, &src_port, &src_channel)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
@@ -0,0 +1,290 @@
+use cw_common::cw_println;
+use cw_common::raw_types::{
+    channel::{RawMessageAcknowledgement, RawMessageRecvPacket, RawMessageTimeout, RawPacket},
+    RawHeight,
+};
+
+use crate::conversions::{to_ibc_channel_id, to_ibc_port_id};
+
+use super::*;
+
+impl<'a> CwIbcCoreContext<'a> {
+    /// This function receives a batch of packets proven at the same height. Each packet is
+    /// validated on its own and a packet that was already received is skipped. A packet that fails
+    /// validation is reported as failed; its receipt or next receive sequence is only written
+    /// once every check has passed, so nothing is stored for it.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object, which provides access to the contract's dependencies
+    /// such as storage, API, and querier.
+    /// * `env`: `env` is a struct of type `Env` which contains information about the current block.
+    /// * `msgs`: the `MsgRecvPacket` messages of the batch.
+    ///
+    /// Returns:
+    ///
+    /// A `Result<Response, ContractError>` whose data is the list of `PacketBatchResult`, one per
+    /// message. Received packets are delivered to their module in order; no funds are forwarded.
+    pub fn validate_receive_packet_batch(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        msgs: &[RawMessageRecvPacket],
+    ) -> Result<Response, ContractError> {
+        ensure_same_proof_height(msgs.iter().map(|msg| &msg.proof_height))?;
+
+        let mut response = Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_recieve_packet_batch_validation");
+        let mut received: Vec<CwPacket> = Vec::new();
+        let mut results: Vec<PacketBatchResult> = Vec::with_capacity(msgs.len());
+
+        for msg in msgs {
+            let packet = msg.packet.clone().unwrap_or_default();
+            let status = match self.process_receive_packet(deps.branch(), &env, Vec::new(), msg) {
+                Ok(Some((ibc_packet, receive_packet_message, event))) => {
+                    received.push(ibc_packet);
+                    response = response
+                        .add_event(event)
+                        .add_submessage(SubMsg::reply_always(
+                            receive_packet_message,
+                            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+                        ));
+                    PacketBatchStatus::Success
+                }
+                Ok(None) => PacketBatchStatus::Noop,
+                Err(error) => PacketBatchStatus::Failed {
+                    error: error.to_string(),
+                },
+            };
+            cw_println!(
+                deps,
+                "batch receive packet {:?}: {:?}",
+                packet.sequence,
+                status
+            );
+            results.push(PacketBatchResult {
+                port_id: packet.destination_port,
+                channel_id: packet.destination_channel,
+                sequence: packet.sequence,
+                status,
+            });
+        }
+
+        // replies arrive in submessage order, so the packets are consumed front to back
+        if !received.is_empty() {
+            self.store_callback_data(
+                deps.storage,
+                VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+                &received,
+            )?;
+        }
+
+        Ok(response.set_data(to_binary(&results).map_err(ContractError::Std)?))
+    }
+
+    /// This function handles the module reply for one packet of a receive batch and writes its
+    /// acknowledgement.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object, which provides mutable access to the contract's
+    /// dependencies such as storage, API, and querier.
+    /// * `message`: `message` is the `Reply` of the module for the oldest pending packet of the batch.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn execute_receive_packet_batch(
+        &self,
+        deps: DepsMut,
+        message: Reply,
+    ) -> Result<Response, ContractError> {
+        let mut pending: Vec<CwPacket> = self.get_callback_data(
+            deps.as_ref().storage,
+            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+        )?;
+        self.clear_callback_data(deps.storage, VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE);
+        if pending.is_empty() {
+            return Err(ContractError::ReplyError {
+                code: message.id,
+                msg: "MissingBatchPacket".to_string(),
+            });
+        }
+        let packet = pending.remove(0);
+        if !pending.is_empty() {
+            self.store_callback_data(
+                deps.storage,
+                VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+                &pending,
+            )?;
+        }
+
+        self.write_receive_packet_acknowledgement(deps, packet, message.result)
+    }
+
+    /// This function acknowledges a batch of packets proven at the same height. Each packet is
+    /// validated on its own and a packet whose commitment is already cleared is skipped. A packet
+    /// that fails validation is reported as failed; its commitment is only deleted once every
+    /// check has passed, so nothing is written for it.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object, which provides access to the contract's dependencies
+    /// such as storage, API, and querier.
+    /// * `info`: `info` is a struct of type `MessageInfo` which contains information about the message
+    /// being processed.
+    /// * `env`: `env` is a struct of type `Env` which contains information about the current block.
+    /// * `msgs`: the `MsgAcknowledgement` messages of the batch.
+    ///
+    /// Returns:
+    ///
+    /// A `Result<Response, ContractError>` whose data is the list of `PacketBatchResult`, one per
+    /// message.
+    pub fn acknowledgement_packet_batch_validate(
+        &self,
+        mut deps: DepsMut,
+        info: MessageInfo,
+        env: Env,
+        msgs: &[RawMessageAcknowledgement],
+    ) -> Result<Response, ContractError> {
+        ensure_same_proof_height(msgs.iter().map(|msg| &msg.proof_height))?;
+
+        let mut response = Response::new()
+            .add_attribute("action", "packet")
+            .add_attribute("method", "packet_acknowledgement_batch_module");
+        let mut results: Vec<PacketBatchResult> = Vec::with_capacity(msgs.len());
+
+        for msg in msgs {
+            let packet = msg.packet.clone().unwrap_or_default();
+            let status = if self.is_packet_commitment_cleared(deps.storage, &packet) {
+                PacketBatchStatus::Noop
+            } else {
+                match self.acknowledgement_packet_validate(
+                    deps.branch(),
+                    info.clone(),
+                    env.clone(),
+                    msg,
+                ) {
+                    Ok(res) => {
+                        response = response
+                            .add_submessages(res.messages)
+                            .add_events(res.events);
+                        PacketBatchStatus::Success
+                    }
+                    Err(error) => PacketBatchStatus::Failed {
+                        error: error.to_string(),
+                    },
+                }
+            };
+            results.push(PacketBatchResult {
+                port_id: packet.source_port,
+                channel_id: packet.source_channel,
+                sequence: packet.sequence,
+                status,
+            });
+        }
+
+        Ok(response.set_data(to_binary(&results).map_err(ContractError::Std)?))
+    }
+
+    /// This function times out a batch of packets proven at the same height. Each packet is
+    /// validated on its own and a packet whose commitment is already cleared is skipped. A packet
+    /// that fails validation is reported as failed; its commitment is only deleted once every
+    /// check has passed, so nothing is written for it.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object, which provides access to the contract's dependencies
+    /// such as storage, API, and querier.
+    /// * `info`: `info` is a struct of type `MessageInfo` which contains information about the message
+    /// being processed. Funds are not forwarded to the modules.
+    /// * `env`: `env` is a struct of type `Env` which contains information about the current block.
+    /// * `msgs`: the `MsgTimeout` messages of the batch.
+    ///
+    /// Returns:
+    ///
+    /// A `Result<Response, ContractError>` whose data is the list of `PacketBatchResult`, one per
+    /// message.
+    pub fn timeout_packet_batch_validate(
+        &self,
+        mut deps: DepsMut,
+        info: MessageInfo,
+        env: Env,
+        msgs: Vec<RawMessageTimeout>,
+    ) -> Result<Response, ContractError> {
+        ensure_same_proof_height(msgs.iter().map(|msg| &msg.proof_height))?;
+
+        let mut response = Response::new()
+            .add_attribute("action", "packet")
+            .add_attribute("method", "packet_timeout_batch_module_validation");
+        let mut results: Vec<PacketBatchResult> = Vec::with_capacity(msgs.len());
+        let info = MessageInfo {
+            sender: info.sender,
+            funds: Vec::new(),
+        };
+
+        for msg in msgs {
+            let packet = msg.packet.clone().unwrap_or_default();
+            let status = if self.is_packet_commitment_cleared(deps.storage, &packet) {
+                PacketBatchStatus::Noop
+            } else {
+                match self.timeout_packet_validate_to_light_client(
+                    deps.branch(),
+                    info.clone(),
+                    env.clone(),
+                    msg,
+                ) {
+                    Ok(res) => {
+                        response = response
+                            .add_submessages(res.messages)
+                            .add_events(res.events);
+                        PacketBatchStatus::Success
+                    }
+                    Err(error) => PacketBatchStatus::Failed {
+                        error: error.to_string(),
+                    },
+                }
+            };
+            results.push(PacketBatchResult {
+                port_id: packet.source_port,
+                channel_id: packet.source_channel,
+                sequence: packet.sequence,
+                status,
+            });
+        }
+
+        Ok(response.set_data(to_binary(&results).map_err(ContractError::Std)?))
+    }
+
+    /// Returns true when `packet` was sent from this chain and has already been acknowledged or
+    /// timed out, i.e. its sent height was reset to zero.
+    fn is_packet_commitment_cleared(&self, store: &dyn Storage, packet: &RawPacket) -> bool {
+        let port_id = to_ibc_port_id(&packet.source_port);
+        let channel_id = to_ibc_channel_id(&packet.source_channel);
+        match (port_id, channel_id) {
+            (Ok(port_id), Ok(channel_id)) => {
+                let sent_height = self
+                    .ibc_store()
+                    .sent_packets()
+                    .may_load(store, (&port_id, &channel_id, packet.sequence))
+                    .unwrap_or_default();
+                sent_height == Some(0)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Checks that the batch is not empty and that every message is proven at the same height.
+fn ensure_same_proof_height<'b>(
+    mut heights: impl Iterator<Item = &'b Option<RawHeight>>,
+) -> Result<(), ContractError> {
+    let first = heights.next().ok_or(ContractError::IbcPacketError {
+        error: PacketError::MissingPacket,
+    })?;
+    if heights.any(|height| height != first) {
+        return Err(ContractError::PacketBatchProofHeightMismatch);
+    }
+    Ok(())
+}
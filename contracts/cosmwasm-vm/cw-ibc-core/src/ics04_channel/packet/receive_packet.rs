@@ -9,10 +9,10 @@ use cw_common::{
 };
 
 use cw_common::cw_println;
-use handler::validate_channel::ensure_channel_state;
+use handler::validate_channel::ensure_channel_state_in;
 
 use crate::conversions::{
-    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout_block, to_ibc_timeout_height,
+    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout, to_ibc_timeout_height,
     to_ibc_timestamp,
 };
 use common::ibc::timestamp::Timestamp;
@@ -39,12 +39,51 @@ impl<'a> CwIbcCoreContext<'a> {
     /// A `Result<Response, ContractError>` is being returned.
     pub fn validate_receive_packet(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         info: MessageInfo,
         env: Env,
         msg: &RawMessageRecvPacket,
     ) -> Result<Response, ContractError> {
-        let packet = &msg.packet.clone().unwrap();
+        let (ibc_packet, receive_packet_message, event_recieve_packet) =
+            match self.process_receive_packet(deps.branch(), &env, info.funds, msg)? {
+                Some(received) => received,
+                None => {
+                    return Err(ContractError::IbcPacketError {
+                        error: PacketError::Other("Already Received".to_string()),
+                    })
+                }
+            };
+        self.store_callback_data(
+            deps.storage,
+            VALIDATE_ON_PACKET_RECEIVE_ON_MODULE,
+            &ibc_packet,
+        )?;
+
+        let sub_msg: SubMsg =
+            SubMsg::reply_always(receive_packet_message, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE);
+
+        Ok(Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_recieve_packet_validation")
+            .add_event(event_recieve_packet)
+            .add_submessage(sub_msg))
+    }
+
+    /// Validates a received packet against the light client and records its receipt (or bumps
+    /// `next_sequence_recv` on ordered channels).
+    ///
+    /// Returns the packet, the message delivering it to the destination module and the
+    /// `recv_packet` event, or `None` when the packet was already received.
+    pub fn process_receive_packet(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        funds: Vec<cosmwasm_std::Coin>,
+        msg: &RawMessageRecvPacket,
+    ) -> Result<Option<(CwPacket, CosmosMsg, Event)>, ContractError> {
+        let packet = &msg.packet.clone().ok_or(ContractError::IbcPacketError {
+            error: PacketError::MissingPacket,
+        })?;
         let src_port = to_ibc_port_id(&packet.source_port)?;
         let src_channel = to_ibc_channel_id(&packet.source_channel)?;
 
@@ -53,7 +92,11 @@ impl<'a> CwIbcCoreContext<'a> {
         let packet_sequence = Sequence::from(packet.sequence);
 
         let channel_end = self.get_channel_end(deps.storage, &dst_port, &dst_channel)?;
-        ensure_channel_state(&dst_channel, &channel_end, &State::Open)?;
+        ensure_channel_state_in(
+            &dst_channel,
+            &channel_end,
+            &[State::Open, State::Flushing, State::FlushComplete],
+        )?;
 
         cw_println!(deps, "validate recevie packet state_matched");
         let counterparty = Counterparty::new(src_port.clone(), Some(src_channel.clone()));
@@ -66,6 +109,23 @@ impl<'a> CwIbcCoreContext<'a> {
                 },
             ))?;
         }
+        // while upgrading, only packets the counterparty sent before it started flushing are
+        // accepted, its next send sequence is recorded once it is flushing
+        if channel_end.state().is_upgrading() {
+            if let Some(counterparty_upgrade) =
+                self.get_counterparty_upgrade(deps.storage, &dst_port, &dst_channel)?
+            {
+                let next_sequence_send = counterparty_upgrade.next_sequence_send;
+                if next_sequence_send != 0 && packet.sequence >= next_sequence_send {
+                    return Err(ContractError::IbcPacketError {
+                        error: PacketError::PacketSentAfterUpgradeFlush {
+                            sequence: packet_sequence,
+                            counterparty_next_sequence_send: next_sequence_send,
+                        },
+                    });
+                }
+            }
+        }
 
         let packet_already_received = self.is_packet_already_received(
             deps.as_ref(),
@@ -75,9 +135,7 @@ impl<'a> CwIbcCoreContext<'a> {
             packet_sequence,
         )?;
         if packet_already_received {
-            return Err(ContractError::IbcPacketError {
-                error: PacketError::Other("Already Received".to_string()),
-            });
+            return Ok(None);
         }
 
         let connection_id = &channel_end.connection_hops()[0];
@@ -89,8 +147,8 @@ impl<'a> CwIbcCoreContext<'a> {
                 },
             ))?;
         }
-        let current_host_height = self.host_height(&env)?;
-        let current_host_timestamp = self.host_timestamp(&env)?;
+        let current_host_height = self.host_height(env)?;
+        let current_host_timestamp = self.host_timestamp(env)?;
         let packet_timeout_height = to_ibc_timeout_height(packet.timeout_height.clone())?;
         let proof_height = to_ibc_height(msg.proof_height.clone())?;
         // validate packet not expired on receive
@@ -129,7 +187,6 @@ impl<'a> CwIbcCoreContext<'a> {
             Sequence::from(packet.sequence),
         );
 
-        cw_println!(deps, "verify connection delay passed");
         let verify_packet_data = VerifyPacketData {
             height: proof_height.to_string(),
             prefix: connection_end.counterparty().prefix().clone().into_vec(),
@@ -140,7 +197,12 @@ impl<'a> CwIbcCoreContext<'a> {
         };
 
         let client = self.get_light_client(deps.as_ref().storage, client_id)?;
-        client.verify_packet_data(deps.as_ref(), verify_packet_data, client_id)?;
+        client.verify_packet_data(
+            deps.as_ref(),
+            verify_packet_data,
+            client_id,
+            self.connection_delay_periods(&connection_end),
+        )?;
 
         cw_println!(deps, "before packet already received ");
 
@@ -156,24 +218,26 @@ impl<'a> CwIbcCoreContext<'a> {
             channel_id: packet.destination_channel.to_string(),
         };
         let data = Binary::from(packet.data.clone());
-        let timeoutblock = to_ibc_timeout_block(&packet_timeout_height);
-        let timeout = CwTimeout::with_block(timeoutblock);
+        let timeout = to_ibc_timeout(&packet_timeout_height, &packet_timestamp);
         let ibc_packet = CwPacket::new(data, src, dest, packet.sequence, timeout);
         let address = to_checked_address(deps.as_ref(), &msg.signer);
-        self.store_callback_data(
-            deps.storage,
-            VALIDATE_ON_PACKET_RECEIVE_ON_MODULE,
-            &ibc_packet,
-        )?;
         let cosm_msg = cw_common::ibc_dapp_msg::ExecuteMsg::IbcPacketReceive {
-            msg: cosmwasm_std::IbcPacketReceiveMsg::new(ibc_packet, address),
+            msg: cosmwasm_std::IbcPacketReceiveMsg::new(ibc_packet.clone(), address),
         };
         let receive_packet_message: CosmosMsg = CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
             contract_addr: contract_address,
             msg: to_binary(&cosm_msg).unwrap(),
-            funds: info.funds,
+            funds,
         });
 
+        let event_recieve_packet = create_packet_event(
+            IbcEventType::ReceivePacket,
+            packet,
+            channel_end.ordering(),
+            &channel_end.connection_hops[0],
+            None,
+        )?;
+
         match channel_end.ordering {
             Order::Unordered => {
                 self.store_packet_receipt(
@@ -190,24 +254,13 @@ impl<'a> CwIbcCoreContext<'a> {
             _ => {}
         };
 
-        let event_recieve_packet = create_packet_event(
-            IbcEventType::ReceivePacket,
-            packet,
-            channel_end.ordering(),
-            &channel_end.connection_hops[0],
-            None,
-        )?;
-
         cw_println!(deps, "event recieve packet: {:?}", event_recieve_packet);
 
-        let sub_msg: SubMsg =
-            SubMsg::reply_on_success(receive_packet_message, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE);
-
-        Ok(Response::new()
-            .add_attribute("action", "channel")
-            .add_attribute("method", "channel_recieve_packet_validation")
-            .add_event(event_recieve_packet)
-            .add_submessage(sub_msg))
+        Ok(Some((
+            ibc_packet,
+            receive_packet_message,
+            event_recieve_packet,
+        )))
     }
 
     pub fn is_packet_already_received(
@@ -339,6 +392,33 @@ impl<'a> CwIbcCoreContext<'a> {
         Ok(())
     }
 
+    /// Returns the counterparty time at `height` used to check the timeout timestamp of `packet`.
+    /// Clients that can not prove the counterparty time fail the timestamp query. For those only
+    /// the timeout height is checked, and packets that time out by timestamp alone are rejected.
+    pub fn counterparty_timestamp_for_packet(
+        &self,
+        deps: Deps,
+        client_id: &ClientId,
+        height: Height,
+        packet: &RawPacket,
+    ) -> Result<Timestamp, ContractError> {
+        if packet.timeout_timestamp == 0 {
+            return to_ibc_timestamp(0);
+        }
+        let has_timeout_height = packet
+            .timeout_height
+            .as_ref()
+            .is_some_and(|h| h.revision_height > 0);
+        let client = self.get_light_client(deps.storage, client_id)?;
+        match client.get_timestamp_at_height(deps, client_id, height.revision_height()) {
+            Ok(timestamp) => to_ibc_timestamp(timestamp),
+            Err(_) if has_timeout_height => to_ibc_timestamp(0),
+            Err(_) => Err(ContractError::TimestampTimeoutNotSupported {
+                client_id: client_id.to_string(),
+            }),
+        }
+    }
+
     /// This function handles the receiving and processing of an IBC packet and update the seq_on_a accordingly.
     ///
     /// Arguments:
@@ -359,55 +439,75 @@ impl<'a> CwIbcCoreContext<'a> {
         deps: DepsMut,
         message: Reply,
     ) -> Result<Response, ContractError> {
-        match message.result {
-            cosmwasm_std::SubMsgResult::Ok(res) => {
-                let ack: Vec<u8> = match res.data {
+        let packet: CwPacket =
+            self.get_callback_data(deps.as_ref().storage, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE)?;
+        self.clear_callback_data(deps.storage, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE);
+
+        self.write_receive_packet_acknowledgement(deps, packet, message.result)
+    }
+
+    /// Stores the acknowledgement for a received `packet` and emits the `write_acknowledgement`
+    /// event. When the destination module failed, its state changes are already reverted and an
+    /// error acknowledgement is written instead, so the source chain can roll the packet back. The
+    /// acknowledgement holds a fixed message, the module error is emitted in an event.
+    pub fn write_receive_packet_acknowledgement(
+        &self,
+        deps: DepsMut,
+        packet: CwPacket,
+        result: cosmwasm_std::SubMsgResult,
+    ) -> Result<Response, ContractError> {
+        let mut res = Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "execute_receive_packet");
+
+        let ack: Vec<u8> = match result {
+            cosmwasm_std::SubMsgResult::Ok(response) => {
+                res = res.add_attribute("message", "success: packet receive");
+                match response.data {
                     Some(data) => data.0,
                     None => Vec::new(),
-                };
-                let packet: CwPacket = self.get_callback_data(
-                    deps.as_ref().storage,
-                    VALIDATE_ON_PACKET_RECEIVE_ON_MODULE,
-                )?;
-                self.clear_callback_data(deps.storage, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE);
-
-                let mut res = Response::new()
-                    .add_attribute("action", "channel")
-                    .add_attribute("method", "execute_receive_packet")
-                    .add_attribute("message", "success: packet receive");
-
-                if !ack.is_empty() {
-                    self.validate_write_acknowledgement(deps.storage, &to_raw_packet(&packet))?;
-                    let seq = packet.sequence;
-                    let channel_id = to_ibc_channel_id(&packet.dest.channel_id)?;
-                    let port_id = to_ibc_port_id(&packet.dest.port_id)?;
-                    let channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
-
-                    self.store_packet_acknowledgement(
-                        deps.storage,
-                        &port_id,
-                        &channel_id,
-                        seq.into(),
-                        commitment::compute_ack_commitment(&Acknowledgement::from_bytes(&ack)),
-                    )?;
-
-                    let write_ack_event = create_packet_event(
-                        IbcEventType::WriteAck,
-                        &to_raw_packet(&packet),
-                        &channel_end.ordering,
-                        &channel_end.connection_hops[0],
-                        Some(ack),
-                    )?;
-
-                    res = res
-                        .add_attribute("message", "success: packet write acknowledgement")
-                        .add_event(write_ack_event);
                 }
-
-                Ok(res)
             }
-            cosmwasm_std::SubMsgResult::Err(e) => Err(ContractError::IbcContextError { error: e }),
+            cosmwasm_std::SubMsgResult::Err(error) => {
+                cw_println!(deps, "module failed to receive packet: {:?}", error);
+                res = res
+                    .add_attribute("message", "failure: packet receive")
+                    .add_event(create_packet_receive_error_event(&packet, &error));
+                to_binary(&Ack::module_error())
+                    .map_err(ContractError::Std)?
+                    .0
+            }
+        };
+
+        if !ack.is_empty() {
+            self.validate_write_acknowledgement(deps.storage, &to_raw_packet(&packet))?;
+            let seq = packet.sequence;
+            let channel_id = to_ibc_channel_id(&packet.dest.channel_id)?;
+            let port_id = to_ibc_port_id(&packet.dest.port_id)?;
+            let channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+
+            self.store_packet_acknowledgement(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                seq.into(),
+                commitment::compute_ack_commitment(&Acknowledgement::from_bytes(&ack)),
+            )?;
+
+            let write_ack_event = create_packet_event(
+                IbcEventType::WriteAck,
+                &to_raw_packet(&packet),
+                &channel_end.ordering,
+                &channel_end.connection_hops[0],
+                Some(ack),
+            )?;
+
+            res = res
+                .add_attribute("message", "success: packet write acknowledgement")
+                .add_event(write_ack_event);
         }
+
+        Ok(res)
     }
 
     pub fn timeout_height_to_str(&self, timeout: CwTimeoutBlock) -> String {
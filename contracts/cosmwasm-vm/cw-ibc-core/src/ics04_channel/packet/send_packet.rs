@@ -80,13 +80,12 @@ impl<'a> CwIbcCoreContext<'a> {
             });
         }
         let counterparty_height = client_state_of_b_on_a.latest_height();
-        let client = self.get_light_client(deps.storage, client_id_on_a)?;
-        let timestamp_at_height = client.get_timestamp_at_height(
+        let counterparty_timestamp = self.counterparty_timestamp_for_packet(
             deps.as_ref(),
             client_id_on_a,
-            counterparty_height.revision_height(),
+            counterparty_height,
+            &packet,
         )?;
-        let counterparty_timestamp = to_ibc_timestamp(timestamp_at_height)?;
         // validate packet not expired before send
         self.validate_packet_not_expired(&packet, counterparty_height, counterparty_timestamp)?;
         cw_println!(deps, " check pass: packet  expired");
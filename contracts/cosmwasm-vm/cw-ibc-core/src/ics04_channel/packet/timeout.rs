@@ -1,7 +1,7 @@
 use cw_common::{raw_types::channel::RawMessageTimeout, to_checked_address};
 
 use crate::conversions::{
-    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout_block, to_ibc_timeout_height,
+    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout, to_ibc_timeout_height,
     to_ibc_timestamp,
 };
 
@@ -30,10 +30,12 @@ impl<'a> CwIbcCoreContext<'a> {
         &self,
         deps: DepsMut,
         info: MessageInfo,
-        env: Env,
+        _env: Env,
         msg: RawMessageTimeout,
     ) -> Result<Response, ContractError> {
-        let packet = &msg.packet.clone().unwrap();
+        let packet = &msg.packet.clone().ok_or(ContractError::IbcPacketError {
+            error: PacketError::MissingPacket,
+        })?;
         let src_port = to_ibc_port_id(&packet.source_port)?;
         let src_channel = to_ibc_channel_id(&packet.source_channel)?;
 
@@ -47,7 +49,8 @@ impl<'a> CwIbcCoreContext<'a> {
         let next_sequence_recv = Sequence::from(msg.next_sequence_recv);
 
         let mut channel_end = self.get_channel_end(deps.storage, &src_port, &src_channel)?;
-        if !channel_end.state_matches(&State::Open) {
+        if !channel_end.state_matches(&State::Open) && !channel_end.state_matches(&State::Flushing)
+        {
             return Err(ContractError::IbcPacketError {
                 error: PacketError::ChannelClosed {
                     channel_id: src_channel,
@@ -66,13 +69,8 @@ impl<'a> CwIbcCoreContext<'a> {
         let conn_id_on_a = channel_end.connection_hops()[0].clone();
         let conn_end_on_a = self.connection_end(deps.storage, &conn_id_on_a)?;
         let client_id = conn_end_on_a.client_id();
-        let client = self.get_light_client(deps.storage, client_id)?;
-        let timestamp_at_height = client.get_timestamp_at_height(
-            deps.as_ref(),
-            client_id,
-            proof_height.revision_height(),
-        )?;
-        let proof_timestamp = to_ibc_timestamp(timestamp_at_height)?;
+        let proof_timestamp =
+            self.counterparty_timestamp_for_packet(deps.as_ref(), client_id, proof_height, packet)?;
         // validate packet is actually expired at proof height
         self.validate_packet_expired(packet, proof_height, proof_timestamp)?;
 
@@ -97,13 +95,6 @@ impl<'a> CwIbcCoreContext<'a> {
         let consensus_state_of_b_on_a =
             self.consensus_state(deps.as_ref(), client_id_on_a, &proof_height)?;
 
-        self.verify_connection_delay_passed(
-            deps.storage,
-            env,
-            proof_height,
-            conn_end_on_a.clone(),
-        )?;
-
         let next_seq_recv_verification_result: LightClientPacketMessage =
             if channel_end.order_matches(&Order::Ordered) {
                 if packet_sequence < next_sequence_recv {
@@ -123,7 +114,7 @@ impl<'a> CwIbcCoreContext<'a> {
                     proof: msg.proof_unreceived.clone(),
                     root: consensus_state_of_b_on_a.root().into_vec(),
                     seq_recv_path: seq_recv_path_on_b,
-                    sequence: packet_sequence.into(),
+                    sequence: next_sequence_recv.into(),
                 }
             } else {
                 let receipt_path_on_b =
@@ -143,6 +134,7 @@ impl<'a> CwIbcCoreContext<'a> {
             deps.as_ref(),
             client_id_on_a,
             next_seq_recv_verification_result,
+            self.connection_delay_periods(&conn_end_on_a),
         )?;
 
         let contract_address = self.lookup_modules(deps.storage, src_port.as_bytes().to_vec())?;
@@ -156,10 +148,20 @@ impl<'a> CwIbcCoreContext<'a> {
             channel_id: dst_channel.to_string(),
         };
         let data = Binary::from(packet.data.clone());
-        let timeoutblock = to_ibc_timeout_block(&packet_timeout_height);
-        let timeout = CwTimeout::with_block(timeoutblock);
+        let timeout = to_ibc_timeout(&packet_timeout_height, &packet_timeout_timestamp);
         let ibc_packet = CwPacket::new(data, src, dest, packet.sequence, timeout);
 
+        // every check runs before the first write, a packet failing in a batch leaves no state
+        let event = create_packet_event(
+            IbcEventType::Timeout,
+            packet,
+            channel_end.ordering(),
+            &conn_id_on_a,
+            None,
+        )?;
+        let close_event =
+            self.close_ordered_channel(deps.storage, &src_port, &src_channel, &mut channel_end)?;
+
         self.delete_packet_commitment(
             deps.storage,
             &src_port,
@@ -174,19 +176,7 @@ impl<'a> CwIbcCoreContext<'a> {
             packet.sequence,
             0,
         )?;
-
-        if let Order::Ordered = channel_end.ordering {
-            channel_end.state = State::Closed;
-            self.store_channel_end(deps.storage, &src_port, &src_channel, &channel_end)?;
-        }
-
-        let event = create_packet_event(
-            IbcEventType::Timeout,
-            packet,
-            channel_end.ordering(),
-            &conn_id_on_a,
-            None,
-        )?;
+        self.complete_channel_upgrade_flush(deps.storage, &src_port, &src_channel)?;
 
         let address = to_checked_address(deps.as_ref(), &msg.signer);
         let cosm_msg = cw_common::xcall_connection_msg::ExecuteMsg::IbcPacketTimeout {
@@ -209,6 +199,38 @@ impl<'a> CwIbcCoreContext<'a> {
             .add_attribute("action", "packet")
             .add_attribute("method", "packet_timeout_module_validation")
             .add_submessage(sub_msg)
-            .add_event(event))
+            .add_event(event)
+            .add_events(close_event))
+    }
+
+    /// Closes `channel_end` once a packet sent on it has timed out, as ICS-04 requires for
+    /// ORDERED channels: the receiving end can no longer process any later packet in order.
+    /// The channel commitment is updated so the counterparty can prove the closed state.
+    ///
+    /// Returns the `channel_close` event to emit, or `None` when the channel is unordered or
+    /// already closed.
+    pub fn close_ordered_channel(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        channel_end: &mut ChannelEnd,
+    ) -> Result<Option<Event>, ContractError> {
+        if !channel_end.order_matches(&Order::Ordered) || channel_end.state_matches(&State::Closed)
+        {
+            return Ok(None);
+        }
+        let mut closed = channel_end.clone();
+        closed.set_state(State::Closed);
+        let event = create_channel_event(
+            IbcEventType::ChannelClosed,
+            port_id.as_str(),
+            channel_id.as_str(),
+            &closed,
+        )?;
+        *channel_end = closed;
+        self.store_channel_end(store, port_id, channel_id, channel_end)?;
+        self.store_channel_commitment(store, port_id, channel_id, channel_end)?;
+        Ok(Some(event))
     }
 }
@@ -1,7 +1,7 @@
 use cw_common::{raw_types::channel::RawMessageTimeoutOnclose, to_checked_address};
 
 use crate::conversions::{
-    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout_block, to_ibc_timeout_height,
+    to_ibc_channel_id, to_ibc_height, to_ibc_port_id, to_ibc_timeout, to_ibc_timeout_height,
     to_ibc_timestamp,
 };
 use cw_common::cw_println;
@@ -32,7 +32,7 @@ impl<'a> CwIbcCoreContext<'a> {
         &self,
         deps: DepsMut,
         info: MessageInfo,
-        env: Env,
+        _env: Env,
         msg: RawMessageTimeoutOnclose,
     ) -> Result<Response, ContractError> {
         let packet = &msg.packet.clone().unwrap();
@@ -112,12 +112,6 @@ impl<'a> CwIbcCoreContext<'a> {
         let chan_end_path_on_b = commitment::channel_path(&dst_port, &dst_channel);
         let vector = to_vec(&expected_chan_end_on_b);
 
-        self.verify_connection_delay_passed(
-            deps.storage,
-            env,
-            proof_height,
-            connection_end.clone(),
-        )?;
         let verify_channel_state = VerifyChannelState {
             proof_height: proof_height.to_string(),
             counterparty_prefix: prefix_on_b.clone().into_vec(),
@@ -145,7 +139,7 @@ impl<'a> CwIbcCoreContext<'a> {
                 proof: msg.proof_unreceived.clone(),
                 root: consensus_state_of_b_on_a.root().into_vec(),
                 seq_recv_path: seq_recv_path_on_b,
-                sequence: packet_sequence.into(),
+                sequence: next_sequence_recv.into(),
             }
         } else {
             let receipt_path_on_b =
@@ -165,13 +159,12 @@ impl<'a> CwIbcCoreContext<'a> {
             client_id,
             verify_channel_state,
             next_seq_recv_verification_result,
+            self.connection_delay_periods(&connection_end),
         )?;
         cw_println!(deps, "Light Client Validation Passed");
 
-        if let Order::Ordered = channel_end.ordering {
-            channel_end.state = State::Closed;
-            self.store_channel_end(deps.storage, &src_port, &src_channel, &channel_end)?;
-        }
+        let close_event =
+            self.close_ordered_channel(deps.storage, &src_port, &src_channel, &mut channel_end)?;
 
         let contract_address = self.lookup_modules(deps.storage, src_port.as_bytes().to_vec())?;
 
@@ -184,8 +177,7 @@ impl<'a> CwIbcCoreContext<'a> {
             channel_id: dst_channel.to_string(),
         };
         let data = Binary::from(packet.data.clone());
-        let timeoutblock = to_ibc_timeout_block(&packet_timeout_height);
-        let timeout = CwTimeout::with_block(timeoutblock);
+        let timeout = to_ibc_timeout(&packet_timeout_height, &packet_timestamp);
         let ibc_packet = CwPacket::new(data, src, dest, packet.sequence, timeout);
         self.delete_packet_commitment(
             deps.storage,
@@ -222,6 +214,7 @@ impl<'a> CwIbcCoreContext<'a> {
             .add_attribute("action", "packet")
             .add_attribute("method", "packet_timeout_module_validation")
             .add_submessage(sub_msg)
-            .add_event(event))
+            .add_event(event)
+            .add_events(close_event))
     }
 }
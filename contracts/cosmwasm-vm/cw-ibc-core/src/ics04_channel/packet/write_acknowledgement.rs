@@ -6,7 +6,7 @@ use common::{
 };
 use cosmwasm_std::{DepsMut, MessageInfo, Response};
 use cw_common::{ibc_types::Sequence, raw_types::to_raw_packet};
-use handler::validate_channel::ensure_channel_state;
+use handler::validate_channel::ensure_channel_state_in;
 
 use crate::{context::CwIbcCoreContext, ContractError};
 
@@ -35,7 +35,11 @@ impl<'a> CwIbcCoreContext<'a> {
         }
 
         let channel = self.get_channel_end(deps.as_ref().storage, &ibc_port, &ibc_channel)?;
-        ensure_channel_state(&ibc_channel, &channel, &State::Open)?;
+        ensure_channel_state_in(
+            &ibc_channel,
+            &channel,
+            &[State::Open, State::Flushing, State::FlushComplete],
+        )?;
 
         let ack_commitment = keccak256(&ack).to_vec();
         let raw_packet = to_raw_packet(&packet);
@@ -0,0 +1,1306 @@
+//! ICS 04: Channel upgrade handshake. Renegotiates the `version`, `ordering` and
+//! `connection_hops` of an OPEN channel without closing it. Both ends flush the packets sent
+//! under the current fields before the upgraded fields take effect.
+use super::*;
+use crate::{
+    conversions::{to_ibc_channel_id, to_ibc_connection_id, to_ibc_height, to_ibc_port_id},
+    light_client::light_client::LightClient,
+    validations::ensure_connection_state,
+};
+use common::{ibc::core::ics04_channel::Version, utils::keccak256};
+use cosmwasm_std::{Coin, Deps, DepsMut, ReplyOn, SubMsgResult};
+use cw_common::{
+    cw_println,
+    cw_types::{CwEndPoint, CwIbcChannel},
+    ibc_dapp_msg::{CwChannelUpgradeMsg, ExecuteMsg as DappExecuteMsg},
+    raw_types::{
+        channel::RawChannel,
+        upgrade::{
+            RawErrorReceipt, RawMsgChannelUpgradeAck, RawMsgChannelUpgradeCancel,
+            RawMsgChannelUpgradeConfirm, RawMsgChannelUpgradeInit, RawMsgChannelUpgradeOpen,
+            RawMsgChannelUpgradeTry, RawUpgrade, RawUpgradeChannel, RawUpgradeFields,
+        },
+    },
+};
+use handler::{
+    open_init::{channel_open_init_msg_validate, create_channel_submesssage},
+    validate_channel::{ensure_channel_state, ensure_channel_state_in, validate_connection_length},
+};
+use prost::Message;
+
+/// Fields of a channel end that an upgrade renegotiates.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<IbcConnectionId>,
+    pub version: Version,
+}
+
+/// Upgrade proposed for a channel end. `next_sequence_send` is recorded once the end starts
+/// flushing, so the counterparty knows which packets were sent before the upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChannelUpgrade {
+    pub fields: UpgradeFields,
+    pub next_sequence_send: u64,
+}
+
+impl UpgradeFields {
+    /// Returns `channel_end` with its fields replaced by the upgraded ones.
+    pub fn apply(&self, channel_end: &ChannelEnd) -> ChannelEnd {
+        ChannelEnd::new(
+            *channel_end.state(),
+            self.ordering,
+            channel_end.counterparty().clone(),
+            self.connection_hops.clone(),
+            self.version.clone(),
+        )
+    }
+}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ContractError;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = Order::from_i32(value.ordering)
+            .map_err(|error| ContractError::IbcChannelError { error })?;
+        let connection_hops = value
+            .connection_hops
+            .iter()
+            .map(|connection_id| to_ibc_connection_id(connection_id))
+            .collect::<Result<Vec<IbcConnectionId>, ContractError>>()?;
+        Ok(Self {
+            ordering,
+            connection_hops,
+            version: Version::from(value.version),
+        })
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|connection_id| connection_id.to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+impl TryFrom<RawUpgrade> for ChannelUpgrade {
+    type Error = ContractError;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        let fields = value
+            .fields
+            .ok_or_else(|| invalid_upgrade("missing upgrade fields"))?;
+        Ok(Self {
+            fields: fields.try_into()?,
+            next_sequence_send: value.next_sequence_send,
+        })
+    }
+}
+
+impl From<ChannelUpgrade> for RawUpgrade {
+    fn from(value: ChannelUpgrade) -> Self {
+        RawUpgrade {
+            fields: Some(value.fields.into()),
+            timeout: None,
+            next_sequence_send: value.next_sequence_send,
+        }
+    }
+}
+
+fn invalid_upgrade(description: &str) -> ContractError {
+    ContractError::IbcChannelError {
+        error: ChannelError::InvalidUpgrade {
+            description: description.to_string(),
+        },
+    }
+}
+
+/// Builds the message passed to the channel module: the channel as currently open and the
+/// channel it becomes with the upgraded `fields`.
+pub fn on_chan_upgrade_submessage(
+    port_id: &IbcPortId,
+    channel_id: &IbcChannelId,
+    channel_end: &ChannelEnd,
+    fields: &UpgradeFields,
+) -> Result<CwChannelUpgradeMsg, ContractError> {
+    let to_cw_channel = |channel_end: &ChannelEnd| -> Result<CwIbcChannel, ContractError> {
+        let counterparty_channel_id =
+            channel_end
+                .counterparty()
+                .channel_id()
+                .ok_or(ContractError::IbcChannelError {
+                    error: ChannelError::InvalidCounterpartyChannelId,
+                })?;
+        Ok(CwIbcChannel::new(
+            CwEndPoint {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            },
+            CwEndPoint {
+                port_id: channel_end.counterparty().port_id().to_string(),
+                channel_id: counterparty_channel_id.to_string(),
+            },
+            channel_end
+                .ordering()
+                .to_ibc_order()
+                .map_err(|error| ContractError::IbcChannelError { error })?,
+            channel_end.version().to_string(),
+            channel_end.connection_hops()[0].as_str(),
+        ))
+    };
+
+    Ok(CwChannelUpgradeMsg {
+        channel: to_cw_channel(channel_end)?,
+        upgrade: to_cw_channel(&fields.apply(channel_end))?,
+    })
+}
+
+/// Builds the channel end expected on the counterparty chain, whose counterparty is this end.
+fn counterparty_channel_end(
+    port_id: &IbcPortId,
+    channel_id: &IbcChannelId,
+    state: State,
+    ordering: Order,
+    version: Version,
+    counterparty_connection_id: IbcConnectionId,
+) -> ChannelEnd {
+    ChannelEnd::new(
+        state,
+        ordering,
+        Counterparty::new(port_id.clone(), Some(channel_id.clone())),
+        vec![counterparty_connection_id],
+        version,
+    )
+}
+
+impl<'a> CwIbcCoreContext<'a> {
+    pub fn get_channel_upgrade_sequence(
+        &self,
+        store: &dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+    ) -> Result<u64, ContractError> {
+        Ok(self
+            .ibc_store()
+            .channel_upgrade_sequences()
+            .may_load(store, (port_id, channel_id))?
+            .unwrap_or_default())
+    }
+
+    pub fn store_channel_upgrade_sequence(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        sequence: u64,
+    ) -> Result<(), ContractError> {
+        self.ibc_store().channel_upgrade_sequences().save(
+            store,
+            (port_id, channel_id),
+            &sequence,
+        )?;
+        Ok(())
+    }
+
+    pub fn get_channel_upgrade(
+        &self,
+        store: &dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+    ) -> Result<ChannelUpgrade, ContractError> {
+        self.ibc_store()
+            .channel_upgrades()
+            .may_load(store, (port_id, channel_id))?
+            .ok_or(ContractError::IbcChannelError {
+                error: ChannelError::UpgradeNotFound {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                },
+            })
+    }
+
+    /// Stores the upgrade proposed for a channel and commits it under the
+    /// `channelUpgrades/upgrades` path so the counterparty can verify it.
+    pub fn store_channel_upgrade(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        upgrade: &ChannelUpgrade,
+    ) -> Result<(), ContractError> {
+        self.ibc_store()
+            .channel_upgrades()
+            .save(store, (port_id, channel_id), upgrade)?;
+        let raw_upgrade: RawUpgrade = upgrade.clone().into();
+        self.ibc_store().save_commitment(
+            store,
+            commitment::channel_upgrade_commitment_key(port_id, channel_id),
+            &keccak256(&raw_upgrade.encode_to_vec()),
+        )?;
+        Ok(())
+    }
+
+    /// Returns the upgrade proven for the counterparty of a channel, if any.
+    pub fn get_counterparty_upgrade(
+        &self,
+        store: &dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+    ) -> Result<Option<ChannelUpgrade>, ContractError> {
+        Ok(self
+            .ibc_store()
+            .counterparty_upgrades()
+            .may_load(store, (port_id, channel_id))?)
+    }
+
+    pub fn store_counterparty_upgrade(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        upgrade: &ChannelUpgrade,
+    ) -> Result<(), ContractError> {
+        self.ibc_store()
+            .counterparty_upgrades()
+            .save(store, (port_id, channel_id), upgrade)?;
+        Ok(())
+    }
+
+    pub fn delete_channel_upgrade(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+    ) {
+        self.ibc_store()
+            .channel_upgrades()
+            .remove(store, (port_id, channel_id));
+        self.ibc_store()
+            .counterparty_upgrades()
+            .remove(store, (port_id, channel_id));
+        self.ibc_store().remove_commitment(
+            store,
+            commitment::channel_upgrade_commitment_key(port_id, channel_id),
+        );
+    }
+
+    /// Commits an error receipt under the `channelUpgrades/upgradeError` path. The counterparty
+    /// proves it to cancel its side of the upgrade with the same `sequence`.
+    pub fn store_upgrade_error_receipt(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        sequence: u64,
+        message: &str,
+    ) -> Result<(), ContractError> {
+        let receipt = RawErrorReceipt {
+            sequence,
+            message: message.to_string(),
+        };
+        self.ibc_store().save_commitment(
+            store,
+            commitment::channel_upgrade_error_commitment_key(port_id, channel_id),
+            &keccak256(&receipt.encode_to_vec()),
+        )?;
+        Ok(())
+    }
+
+    /// Returns whether packets sent on the channel are still waiting for an acknowledgement or
+    /// a timeout.
+    pub fn has_inflight_packets(
+        &self,
+        store: &dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+    ) -> bool {
+        self.ibc_store()
+            .sent_packets()
+            .prefix((port_id, channel_id))
+            .range(store, None, None, cosmwasm_std::Order::Ascending)
+            .any(|entry| entry.map(|(_, height)| height != 0).unwrap_or(false))
+    }
+
+    /// Moves a FLUSHING channel to FLUSHCOMPLETE once its last packet in flight has been
+    /// acknowledged or timed out.
+    pub fn complete_channel_upgrade_flush(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+    ) -> Result<(), ContractError> {
+        let mut channel_end = self.get_channel_end(store, port_id, channel_id)?;
+        if !channel_end.state_matches(&State::Flushing)
+            || self.has_inflight_packets(store, port_id, channel_id)
+        {
+            return Ok(());
+        }
+        channel_end.set_state(State::FlushComplete);
+        self.store_channel_end(store, port_id, channel_id, &channel_end)?;
+        self.store_channel_commitment(store, port_id, channel_id, &channel_end)
+    }
+
+    /// Puts the channel back to OPEN with its current fields and drops the pending upgrade.
+    fn restore_channel(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        channel_end: &mut ChannelEnd,
+    ) -> Result<(), ContractError> {
+        channel_end.set_state(State::Open);
+        self.delete_channel_upgrade(store, port_id, channel_id);
+        self.store_channel_end(store, port_id, channel_id, channel_end)?;
+        self.store_channel_commitment(store, port_id, channel_id, channel_end)
+    }
+
+    /// Aborts the upgrade in progress: writes an error receipt for the current upgrade sequence
+    /// and restores the channel. Returns the `channel_upgrade_error` event.
+    pub fn abort_channel_upgrade(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        channel_end: &mut ChannelEnd,
+        message: &str,
+    ) -> Result<Event, ContractError> {
+        let upgrade_sequence = self.get_channel_upgrade_sequence(store, port_id, channel_id)?;
+        self.store_upgrade_error_receipt(store, port_id, channel_id, upgrade_sequence, message)?;
+        self.restore_channel(store, port_id, channel_id, channel_end)?;
+
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeErrorChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            channel_end,
+            upgrade_sequence,
+            None,
+        )?
+        .add_attribute(ERROR_RECEIPT_ATTRIBUTE_KEY, message);
+        Ok(event)
+    }
+
+    /// Checks that `fields` can replace the fields of `channel_end` and that the proposed
+    /// connection is open and supports the proposed ordering.
+    fn validate_upgrade_fields(
+        &self,
+        store: &dyn Storage,
+        channel_end: &ChannelEnd,
+        fields: &UpgradeFields,
+    ) -> Result<ConnectionEnd, ContractError> {
+        let upgraded_channel = fields.apply(channel_end);
+        validate_connection_length(&upgraded_channel)?;
+        if upgraded_channel == *channel_end {
+            return Err(invalid_upgrade(
+                "upgrade fields are identical to the current channel",
+            ));
+        }
+        if channel_end.order_matches(&Order::Unordered) && fields.ordering == Order::Ordered {
+            return Err(invalid_upgrade(
+                "an UNORDERED channel cannot be upgraded to ORDERED",
+            ));
+        }
+
+        let connection_id = &fields.connection_hops[0];
+        let connection_end = self.connection_end(store, connection_id)?;
+        ensure_connection_state(connection_id, &connection_end, &ConnectionState::Open)?;
+        channel_open_init_msg_validate(&upgraded_channel, connection_end.clone())?;
+
+        Ok(connection_end)
+    }
+
+    /// Checks that the upgrade proposed on this end matches the one of the counterparty.
+    fn check_upgrade_compatibility(
+        &self,
+        store: &dyn Storage,
+        fields: &UpgradeFields,
+        counterparty_fields: &UpgradeFields,
+    ) -> Result<(), ContractError> {
+        if fields.ordering != counterparty_fields.ordering {
+            return Err(invalid_upgrade("proposed orderings do not match"));
+        }
+        if fields.version != counterparty_fields.version {
+            return Err(invalid_upgrade("proposed versions do not match"));
+        }
+        let connection_id = &fields.connection_hops[0];
+        let connection_end = self.connection_end(store, connection_id)?;
+        ensure_connection_state(connection_id, &connection_end, &ConnectionState::Open)?;
+        if connection_end.counterparty().connection_id()
+            != counterparty_fields.connection_hops.first()
+        {
+            return Err(invalid_upgrade(
+                "proposed connection does not match the counterparty connection",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the counterparty of the open connection `connection_id`.
+    fn counterparty_connection_id(
+        &self,
+        store: &dyn Storage,
+        connection_id: &IbcConnectionId,
+    ) -> Result<IbcConnectionId, ContractError> {
+        let connection_end = self.connection_end(store, connection_id)?;
+        ensure_connection_state(connection_id, &connection_end, &ConnectionState::Open)?;
+        connection_end
+            .counterparty()
+            .connection_id()
+            .cloned()
+            .ok_or(ContractError::IbcChannelError {
+                error: ChannelError::UndefinedConnectionCounterparty {
+                    connection_id: connection_id.clone(),
+                },
+            })
+    }
+
+    /// Returns the light client of the connection `channel_end` currently runs on, checking that
+    /// the connection is open and the client not frozen.
+    fn channel_light_client(
+        &self,
+        deps: Deps,
+        channel_end: &ChannelEnd,
+    ) -> Result<(LightClient, ConnectionEnd), ContractError> {
+        let connection_id = &channel_end.connection_hops()[0];
+        let connection_end = self.connection_end(deps.storage, connection_id)?;
+        ensure_connection_state(connection_id, &connection_end, &ConnectionState::Open)?;
+
+        let client_id = connection_end.client_id();
+        let client_state = self.client_state(deps, client_id)?;
+        if client_state.is_frozen() {
+            return Err(ContractError::IbcChannelError {
+                error: ChannelError::FrozenClient {
+                    client_id: client_id.clone(),
+                },
+            });
+        }
+        let client = self.get_light_client(deps.storage, client_id)?;
+        Ok((client, connection_end))
+    }
+
+    /// Verifies that the counterparty of `channel_end` stores `expected_channel` with the given
+    /// upgrade sequence at `proof_height`.
+    fn verify_counterparty_channel(
+        &self,
+        deps: Deps,
+        channel_end: &ChannelEnd,
+        expected_channel: ChannelEnd,
+        upgrade_sequence: u64,
+        proof: &[u8],
+        proof_height: &Height,
+    ) -> Result<(), ContractError> {
+        let (client, connection_end) = self.channel_light_client(deps, channel_end)?;
+        let client_id = connection_end.client_id();
+        let consensus_state = self.consensus_state(deps, client_id, proof_height)?;
+        let counterparty = channel_end.counterparty();
+        let counterparty_channel_id =
+            counterparty
+                .channel_id()
+                .ok_or(ContractError::IbcChannelError {
+                    error: ChannelError::InvalidCounterpartyChannelId,
+                })?;
+
+        let expected_counterparty_channel_end =
+            RawUpgradeChannel::new(RawChannel::from(expected_channel), upgrade_sequence)
+                .encode_to_vec();
+        let verify_channel_state = VerifyChannelState {
+            proof_height: proof_height.to_string(),
+            counterparty_prefix: connection_end.counterparty().prefix().clone().into_vec(),
+            proof: proof.to_vec(),
+            root: consensus_state.root().into_vec(),
+            counterparty_chan_end_path: commitment::channel_path(
+                counterparty.port_id(),
+                counterparty_channel_id,
+            ),
+            expected_counterparty_channel_end,
+            client_id: client_id.to_string(),
+        };
+        client.verify_channel(deps, verify_channel_state)
+    }
+
+    /// Verifies that the counterparty of `channel_end` committed `value` under `path` at
+    /// `proof_height`.
+    fn verify_counterparty_commitment(
+        &self,
+        deps: Deps,
+        channel_end: &ChannelEnd,
+        path: Vec<u8>,
+        value: Vec<u8>,
+        proof: &[u8],
+        proof_height: &Height,
+    ) -> Result<(), ContractError> {
+        let (client, connection_end) = self.channel_light_client(deps, channel_end)?;
+        client.verify_membership(
+            deps,
+            connection_end.client_id(),
+            path,
+            value,
+            proof.to_vec(),
+            proof_height.revision_height(),
+        )
+    }
+
+    /// Verifies that the counterparty of `channel_end` proposed `upgrade`.
+    fn verify_counterparty_upgrade(
+        &self,
+        deps: Deps,
+        channel_end: &ChannelEnd,
+        upgrade: &ChannelUpgrade,
+        proof: &[u8],
+        proof_height: &Height,
+    ) -> Result<(), ContractError> {
+        let counterparty = channel_end.counterparty();
+        let counterparty_channel_id =
+            counterparty
+                .channel_id()
+                .ok_or(ContractError::IbcChannelError {
+                    error: ChannelError::InvalidCounterpartyChannelId,
+                })?;
+        let raw_upgrade: RawUpgrade = upgrade.clone().into();
+        self.verify_counterparty_commitment(
+            deps,
+            channel_end,
+            commitment::channel_upgrade_path(counterparty.port_id(), counterparty_channel_id),
+            raw_upgrade.encode_to_vec(),
+            proof,
+            proof_height,
+        )
+    }
+
+    /// Creates the submessage calling the channel module back. With `ReplyOn::Always` the channel
+    /// is kept as callback data, so the upgrade can be aborted when the module rejects it.
+    #[allow(clippy::too_many_arguments)]
+    fn create_channel_upgrade_submessage(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        msg: DappExecuteMsg,
+        funds: Vec<Coin>,
+        id: u64,
+        reply_on: ReplyOn,
+    ) -> Result<SubMsg, ContractError> {
+        let contract_address = self.lookup_modules(store, port_id.as_bytes().to_vec())?;
+        let data = to_binary(&msg).map_err(ContractError::Std)?;
+        if reply_on == ReplyOn::Always {
+            let endpoint = CwEndPoint {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            };
+            self.store_callback_data(store, id, &endpoint)?;
+        }
+        let mut sub_msg = create_channel_submesssage(contract_address, data, funds, id);
+        sub_msg.reply_on = reply_on;
+        Ok(sub_msg)
+    }
+
+    /// Replaces the fields of `channel_end` with the upgraded ones and reopens it.
+    fn open_channel_upgrade(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &IbcPortId,
+        channel_id: &IbcChannelId,
+        channel_end: &mut ChannelEnd,
+        upgrade: &ChannelUpgrade,
+        funds: Vec<Coin>,
+    ) -> Result<(Event, SubMsg), ContractError> {
+        let msg = on_chan_upgrade_submessage(port_id, channel_id, channel_end, &upgrade.fields)?;
+
+        *channel_end = upgrade.fields.apply(channel_end);
+        channel_end.set_state(State::Open);
+        self.delete_channel_upgrade(store, port_id, channel_id);
+        self.store_channel_end(store, port_id, channel_id, channel_end)?;
+        self.store_channel_commitment(store, port_id, channel_id, channel_end)?;
+
+        let upgrade_sequence = self.get_channel_upgrade_sequence(store, port_id, channel_id)?;
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeOpenChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            channel_end,
+            upgrade_sequence,
+            None,
+        )?;
+        let sub_msg = self.create_channel_upgrade_submessage(
+            store,
+            port_id,
+            channel_id,
+            DappExecuteMsg::IbcChannelUpgradeOpen { msg },
+            funds,
+            EXECUTE_ON_CHANNEL_UPGRADE_OPEN_ON_MODULE,
+            ReplyOn::Never,
+        )?;
+        Ok((event, sub_msg))
+    }
+
+    /// Proposes new fields for an OPEN channel. The channel keeps sending packets under its
+    /// current fields until the counterparty accepts the upgrade.
+    pub fn validate_channel_upgrade_init(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        message: &RawMsgChannelUpgradeInit,
+    ) -> Result<Response, ContractError> {
+        let port_id = to_ibc_port_id(&message.port_id)?;
+        let channel_id = to_ibc_channel_id(&message.channel_id)?;
+        let channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+        ensure_channel_state(&channel_id, &channel_end, &State::Open)?;
+
+        let fields: UpgradeFields = message
+            .fields
+            .clone()
+            .ok_or_else(|| invalid_upgrade("missing upgrade fields"))?
+            .try_into()?;
+        self.validate_upgrade_fields(deps.storage, &channel_end, &fields)?;
+
+        let mut upgrade_sequence =
+            self.get_channel_upgrade_sequence(deps.storage, &port_id, &channel_id)?;
+        if self
+            .get_channel_upgrade(deps.storage, &port_id, &channel_id)
+            .is_ok()
+        {
+            // the receipt lets the counterparty drop its side of the superseded upgrade
+            self.store_upgrade_error_receipt(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                upgrade_sequence,
+                "upgrade superseded",
+            )?;
+        }
+        upgrade_sequence += 1;
+        self.store_channel_upgrade_sequence(deps.storage, &port_id, &channel_id, upgrade_sequence)?;
+
+        let upgrade = ChannelUpgrade {
+            fields,
+            next_sequence_send: 0,
+        };
+        self.store_channel_upgrade(deps.storage, &port_id, &channel_id, &upgrade)?;
+        self.store_channel_commitment(deps.storage, &port_id, &channel_id, &channel_end)?;
+
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeInitChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            &channel_end,
+            upgrade_sequence,
+            Some(&upgrade.fields),
+        )?;
+        let msg = on_chan_upgrade_submessage(&port_id, &channel_id, &channel_end, &upgrade.fields)?;
+        let sub_msg = self.create_channel_upgrade_submessage(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            DappExecuteMsg::IbcChannelUpgradeInit { msg },
+            info.funds,
+            EXECUTE_ON_CHANNEL_UPGRADE_INIT,
+            ReplyOn::Never,
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_upgrade_init")
+            .add_submessage(sub_msg)
+            .add_event(event))
+    }
+
+    /// Accepts the upgrade proposed by the counterparty and starts flushing the packets sent
+    /// on this end. Writes an error receipt instead when the counterparty upgrade sequence is
+    /// behind or the proposals are incompatible.
+    pub fn validate_channel_upgrade_try(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        message: &RawMsgChannelUpgradeTry,
+    ) -> Result<Response, ContractError> {
+        let port_id = to_ibc_port_id(&message.port_id)?;
+        let channel_id = to_ibc_channel_id(&message.channel_id)?;
+        let mut channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+        ensure_channel_state(&channel_id, &channel_end, &State::Open)?;
+
+        let counterparty_fields: UpgradeFields = message
+            .counterparty_upgrade_fields
+            .clone()
+            .ok_or_else(|| invalid_upgrade("missing counterparty upgrade fields"))?
+            .try_into()?;
+        let proposed_connection_hops = message
+            .proposed_upgrade_connection_hops
+            .iter()
+            .map(|connection_id| to_ibc_connection_id(connection_id))
+            .collect::<Result<Vec<IbcConnectionId>, ContractError>>()?;
+
+        let mut upgrade_sequence =
+            self.get_channel_upgrade_sequence(deps.storage, &port_id, &channel_id)?;
+        let mut upgrade = match self.get_channel_upgrade(deps.storage, &port_id, &channel_id) {
+            Ok(upgrade) => {
+                if upgrade.fields.connection_hops != proposed_connection_hops {
+                    return Err(invalid_upgrade(
+                        "proposed connection hops do not match the upgrade in progress",
+                    ));
+                }
+                upgrade
+            }
+            Err(_) => {
+                let fields = UpgradeFields {
+                    ordering: counterparty_fields.ordering,
+                    connection_hops: proposed_connection_hops,
+                    version: counterparty_fields.version.clone(),
+                };
+                self.validate_upgrade_fields(deps.storage, &channel_end, &fields)?;
+                upgrade_sequence += 1;
+                ChannelUpgrade {
+                    fields,
+                    next_sequence_send: 0,
+                }
+            }
+        };
+
+        let res = Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_upgrade_try");
+
+        if message.counterparty_upgrade_sequence < upgrade_sequence {
+            // the counterparty cancels with this receipt and proposes again with a newer sequence
+            let error = ChannelError::InvalidUpgradeSequence {
+                sequence: upgrade_sequence,
+                counterparty_sequence: message.counterparty_upgrade_sequence,
+            }
+            .to_string();
+            self.store_channel_upgrade_sequence(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                upgrade_sequence,
+            )?;
+            self.store_upgrade_error_receipt(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                upgrade_sequence,
+                &error,
+            )?;
+            self.store_channel_commitment(deps.storage, &port_id, &channel_id, &channel_end)?;
+            let event = create_channel_upgrade_event(
+                IbcEventType::UpgradeErrorChannel,
+                port_id.as_str(),
+                channel_id.as_str(),
+                &channel_end,
+                upgrade_sequence,
+                None,
+            )?
+            .add_attribute(ERROR_RECEIPT_ATTRIBUTE_KEY, error);
+            return Ok(res.add_event(event));
+        }
+        let upgrade_sequence = message.counterparty_upgrade_sequence;
+
+        let proof_height = to_ibc_height(message.proof_height.clone())?;
+        let counterparty_connection_id =
+            self.counterparty_connection_id(deps.storage, &channel_end.connection_hops()[0])?;
+        let expected_channel = counterparty_channel_end(
+            &port_id,
+            &channel_id,
+            State::Open,
+            *channel_end.ordering(),
+            channel_end.version().clone(),
+            counterparty_connection_id,
+        );
+        self.verify_counterparty_channel(
+            deps.as_ref(),
+            &channel_end,
+            expected_channel,
+            upgrade_sequence,
+            &message.proof_channel,
+            &proof_height,
+        )?;
+        let counterparty_upgrade = ChannelUpgrade {
+            fields: counterparty_fields,
+            next_sequence_send: 0,
+        };
+        self.verify_counterparty_upgrade(
+            deps.as_ref(),
+            &channel_end,
+            &counterparty_upgrade,
+            &message.proof_upgrade,
+            &proof_height,
+        )?;
+
+        self.store_channel_upgrade_sequence(deps.storage, &port_id, &channel_id, upgrade_sequence)?;
+        if let Err(error) = self.check_upgrade_compatibility(
+            deps.storage,
+            &upgrade.fields,
+            &counterparty_upgrade.fields,
+        ) {
+            let event = self.abort_channel_upgrade(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                &mut channel_end,
+                &error.to_string(),
+            )?;
+            return Ok(res.add_event(event));
+        }
+
+        upgrade.next_sequence_send = self
+            .get_next_sequence_send(deps.storage, &port_id, &channel_id)?
+            .into();
+        channel_end.set_state(State::Flushing);
+        self.store_channel_upgrade(deps.storage, &port_id, &channel_id, &upgrade)?;
+        self.store_counterparty_upgrade(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            &counterparty_upgrade,
+        )?;
+        self.store_channel_end(deps.storage, &port_id, &channel_id, &channel_end)?;
+        self.store_channel_commitment(deps.storage, &port_id, &channel_id, &channel_end)?;
+
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeTryChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            &channel_end,
+            upgrade_sequence,
+            Some(&upgrade.fields),
+        )?;
+        let msg = on_chan_upgrade_submessage(&port_id, &channel_id, &channel_end, &upgrade.fields)?;
+        let sub_msg = self.create_channel_upgrade_submessage(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            DappExecuteMsg::IbcChannelUpgradeTry { msg },
+            info.funds,
+            EXECUTE_ON_CHANNEL_UPGRADE_TRY_ON_MODULE,
+            ReplyOn::Always,
+        )?;
+
+        Ok(res.add_submessage(sub_msg).add_event(event))
+    }
+
+    /// Handles the counterparty accepting the upgrade proposed on this end: starts flushing,
+    /// or completes the flush at once when no packet is in flight.
+    pub fn validate_channel_upgrade_ack(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        message: &RawMsgChannelUpgradeAck,
+    ) -> Result<Response, ContractError> {
+        let port_id = to_ibc_port_id(&message.port_id)?;
+        let channel_id = to_ibc_channel_id(&message.channel_id)?;
+        let mut channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+        ensure_channel_state_in(&channel_id, &channel_end, &[State::Open, State::Flushing])?;
+
+        let mut upgrade = self.get_channel_upgrade(deps.storage, &port_id, &channel_id)?;
+        let counterparty_upgrade: ChannelUpgrade = message
+            .counterparty_upgrade
+            .clone()
+            .ok_or_else(|| invalid_upgrade("missing counterparty upgrade"))?
+            .try_into()?;
+        let upgrade_sequence =
+            self.get_channel_upgrade_sequence(deps.storage, &port_id, &channel_id)?;
+
+        let proof_height = to_ibc_height(message.proof_height.clone())?;
+        let counterparty_connection_id =
+            self.counterparty_connection_id(deps.storage, &channel_end.connection_hops()[0])?;
+        let expected_channel = counterparty_channel_end(
+            &port_id,
+            &channel_id,
+            State::Flushing,
+            *channel_end.ordering(),
+            channel_end.version().clone(),
+            counterparty_connection_id,
+        );
+        self.verify_counterparty_channel(
+            deps.as_ref(),
+            &channel_end,
+            expected_channel,
+            upgrade_sequence,
+            &message.proof_channel,
+            &proof_height,
+        )?;
+        self.verify_counterparty_upgrade(
+            deps.as_ref(),
+            &channel_end,
+            &counterparty_upgrade,
+            &message.proof_upgrade,
+            &proof_height,
+        )?;
+
+        let res = Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_upgrade_ack");
+
+        if let Err(error) = self.check_upgrade_compatibility(
+            deps.storage,
+            &upgrade.fields,
+            &counterparty_upgrade.fields,
+        ) {
+            let event = self.abort_channel_upgrade(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                &mut channel_end,
+                &error.to_string(),
+            )?;
+            return Ok(res.add_event(event));
+        }
+
+        if channel_end.state_matches(&State::Open) {
+            upgrade.next_sequence_send = self
+                .get_next_sequence_send(deps.storage, &port_id, &channel_id)?
+                .into();
+            channel_end.set_state(State::Flushing);
+        }
+        if !self.has_inflight_packets(deps.storage, &port_id, &channel_id) {
+            channel_end.set_state(State::FlushComplete);
+        }
+        self.store_channel_upgrade(deps.storage, &port_id, &channel_id, &upgrade)?;
+        self.store_counterparty_upgrade(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            &counterparty_upgrade,
+        )?;
+        self.store_channel_end(deps.storage, &port_id, &channel_id, &channel_end)?;
+        self.store_channel_commitment(deps.storage, &port_id, &channel_id, &channel_end)?;
+
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeAckChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            &channel_end,
+            upgrade_sequence,
+            Some(&upgrade.fields),
+        )?;
+        let msg = on_chan_upgrade_submessage(&port_id, &channel_id, &channel_end, &upgrade.fields)?;
+        let sub_msg = self.create_channel_upgrade_submessage(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            DappExecuteMsg::IbcChannelUpgradeAck { msg },
+            info.funds,
+            EXECUTE_ON_CHANNEL_UPGRADE_ACK_ON_MODULE,
+            ReplyOn::Always,
+        )?;
+
+        Ok(res.add_submessage(sub_msg).add_event(event))
+    }
+
+    /// Records that the counterparty is flushing as well. Opens the upgrade right away when both
+    /// ends have completed their flush.
+    pub fn validate_channel_upgrade_confirm(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        message: &RawMsgChannelUpgradeConfirm,
+    ) -> Result<Response, ContractError> {
+        let port_id = to_ibc_port_id(&message.port_id)?;
+        let channel_id = to_ibc_channel_id(&message.channel_id)?;
+        let mut channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+        ensure_channel_state(&channel_id, &channel_end, &State::Flushing)?;
+
+        let counterparty_state = State::from_i32(message.counterparty_channel_state)
+            .map_err(|error| ContractError::IbcChannelError { error })?;
+        if !counterparty_state.is_upgrading() {
+            return Err(ContractError::IbcChannelError {
+                error: ChannelError::InvalidCounterpartyChannelState {
+                    state: counterparty_state,
+                },
+            });
+        }
+        let upgrade = self.get_channel_upgrade(deps.storage, &port_id, &channel_id)?;
+        let counterparty_upgrade: ChannelUpgrade = message
+            .counterparty_upgrade
+            .clone()
+            .ok_or_else(|| invalid_upgrade("missing counterparty upgrade"))?
+            .try_into()?;
+        let upgrade_sequence =
+            self.get_channel_upgrade_sequence(deps.storage, &port_id, &channel_id)?;
+
+        let proof_height = to_ibc_height(message.proof_height.clone())?;
+        let counterparty_connection_id =
+            self.counterparty_connection_id(deps.storage, &channel_end.connection_hops()[0])?;
+        let expected_channel = counterparty_channel_end(
+            &port_id,
+            &channel_id,
+            counterparty_state,
+            *channel_end.ordering(),
+            channel_end.version().clone(),
+            counterparty_connection_id,
+        );
+        self.verify_counterparty_channel(
+            deps.as_ref(),
+            &channel_end,
+            expected_channel,
+            upgrade_sequence,
+            &message.proof_channel,
+            &proof_height,
+        )?;
+        self.verify_counterparty_upgrade(
+            deps.as_ref(),
+            &channel_end,
+            &counterparty_upgrade,
+            &message.proof_upgrade,
+            &proof_height,
+        )?;
+        self.store_counterparty_upgrade(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            &counterparty_upgrade,
+        )?;
+
+        if !self.has_inflight_packets(deps.storage, &port_id, &channel_id) {
+            channel_end.set_state(State::FlushComplete);
+            self.store_channel_end(deps.storage, &port_id, &channel_id, &channel_end)?;
+            self.store_channel_commitment(deps.storage, &port_id, &channel_id, &channel_end)?;
+        }
+
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeConfirmChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            &channel_end,
+            upgrade_sequence,
+            Some(&upgrade.fields),
+        )?;
+        let mut res = Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_upgrade_confirm")
+            .add_event(event);
+
+        if channel_end.state_matches(&State::FlushComplete)
+            && counterparty_state == State::FlushComplete
+        {
+            let (event, sub_msg) = self.open_channel_upgrade(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                &mut channel_end,
+                &upgrade,
+                info.funds,
+            )?;
+            res = res.add_submessage(sub_msg).add_event(event);
+        }
+
+        Ok(res)
+    }
+
+    /// Opens the upgrade once this end has completed its flush and the counterparty has either
+    /// completed its flush or already opened the upgrade.
+    pub fn validate_channel_upgrade_open(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        message: &RawMsgChannelUpgradeOpen,
+    ) -> Result<Response, ContractError> {
+        let port_id = to_ibc_port_id(&message.port_id)?;
+        let channel_id = to_ibc_channel_id(&message.channel_id)?;
+        let mut channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+        ensure_channel_state(&channel_id, &channel_end, &State::FlushComplete)?;
+
+        let upgrade = self.get_channel_upgrade(deps.storage, &port_id, &channel_id)?;
+        let upgrade_sequence =
+            self.get_channel_upgrade_sequence(deps.storage, &port_id, &channel_id)?;
+        let counterparty_state = State::from_i32(message.counterparty_channel_state)
+            .map_err(|error| ContractError::IbcChannelError { error })?;
+
+        let (expected_channel, counterparty_upgrade_sequence) = match counterparty_state {
+            State::FlushComplete => {
+                let counterparty_connection_id = self
+                    .counterparty_connection_id(deps.storage, &channel_end.connection_hops()[0])?;
+                let expected_channel = counterparty_channel_end(
+                    &port_id,
+                    &channel_id,
+                    State::FlushComplete,
+                    *channel_end.ordering(),
+                    channel_end.version().clone(),
+                    counterparty_connection_id,
+                );
+                (expected_channel, upgrade_sequence)
+            }
+            State::Open => {
+                if message.counterparty_upgrade_sequence < upgrade_sequence {
+                    return Err(ContractError::IbcChannelError {
+                        error: ChannelError::InvalidUpgradeSequence {
+                            sequence: upgrade_sequence,
+                            counterparty_sequence: message.counterparty_upgrade_sequence,
+                        },
+                    });
+                }
+                let counterparty_connection_id = self
+                    .counterparty_connection_id(deps.storage, &upgrade.fields.connection_hops[0])?;
+                let expected_channel = counterparty_channel_end(
+                    &port_id,
+                    &channel_id,
+                    State::Open,
+                    upgrade.fields.ordering,
+                    upgrade.fields.version.clone(),
+                    counterparty_connection_id,
+                );
+                (expected_channel, message.counterparty_upgrade_sequence)
+            }
+            state => {
+                return Err(ContractError::IbcChannelError {
+                    error: ChannelError::InvalidCounterpartyChannelState { state },
+                })
+            }
+        };
+
+        let proof_height = to_ibc_height(message.proof_height.clone())?;
+        self.verify_counterparty_channel(
+            deps.as_ref(),
+            &channel_end,
+            expected_channel,
+            counterparty_upgrade_sequence,
+            &message.proof_channel,
+            &proof_height,
+        )?;
+
+        let (event, sub_msg) = self.open_channel_upgrade(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            &mut channel_end,
+            &upgrade,
+            info.funds,
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_upgrade_open")
+            .add_submessage(sub_msg)
+            .add_event(event))
+    }
+
+    /// Cancels the upgrade in progress and restores the channel. The contract owner may cancel
+    /// at any time; anyone else has to prove an error receipt written by the counterparty for
+    /// the current upgrade sequence or a later one.
+    pub fn validate_channel_upgrade_cancel(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        message: &RawMsgChannelUpgradeCancel,
+    ) -> Result<Response, ContractError> {
+        let port_id = to_ibc_port_id(&message.port_id)?;
+        let channel_id = to_ibc_channel_id(&message.channel_id)?;
+        let mut channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+        self.get_channel_upgrade(deps.storage, &port_id, &channel_id)?;
+
+        let mut upgrade_sequence =
+            self.get_channel_upgrade_sequence(deps.storage, &port_id, &channel_id)?;
+        if self
+            .check_sender_is_owner(deps.storage, info.sender)
+            .is_err()
+        {
+            // the counterparty may already have opened the upgrade
+            if channel_end.state_matches(&State::FlushComplete) {
+                return Err(ContractError::IbcChannelError {
+                    error: ChannelError::InvalidChannelState {
+                        channel_id,
+                        state: channel_end.state,
+                    },
+                });
+            }
+            let error_receipt = message
+                .error_receipt
+                .clone()
+                .ok_or_else(|| invalid_upgrade("missing error receipt"))?;
+            if error_receipt.sequence < upgrade_sequence {
+                return Err(ContractError::IbcChannelError {
+                    error: ChannelError::InvalidUpgradeSequence {
+                        sequence: upgrade_sequence,
+                        counterparty_sequence: error_receipt.sequence,
+                    },
+                });
+            }
+            let counterparty = channel_end.counterparty();
+            let counterparty_channel_id =
+                counterparty
+                    .channel_id()
+                    .ok_or(ContractError::IbcChannelError {
+                        error: ChannelError::InvalidCounterpartyChannelId,
+                    })?;
+            let proof_height = to_ibc_height(message.proof_height.clone())?;
+            self.verify_counterparty_commitment(
+                deps.as_ref(),
+                &channel_end,
+                commitment::channel_upgrade_error_path(
+                    counterparty.port_id(),
+                    counterparty_channel_id,
+                ),
+                error_receipt.encode_to_vec(),
+                &message.proof_error_receipt,
+                &proof_height,
+            )?;
+            upgrade_sequence = error_receipt.sequence;
+            self.store_channel_upgrade_sequence(
+                deps.storage,
+                &port_id,
+                &channel_id,
+                upgrade_sequence,
+            )?;
+        }
+
+        self.store_upgrade_error_receipt(
+            deps.storage,
+            &port_id,
+            &channel_id,
+            upgrade_sequence,
+            "upgrade cancelled",
+        )?;
+        self.restore_channel(deps.storage, &port_id, &channel_id, &mut channel_end)?;
+
+        let event = create_channel_upgrade_event(
+            IbcEventType::UpgradeCancelChannel,
+            port_id.as_str(),
+            channel_id.as_str(),
+            &channel_end,
+            upgrade_sequence,
+            None,
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "channel_upgrade_cancel")
+            .add_event(event))
+    }
+
+    /// Handles the reply of the channel module to the upgrade try and ack steps. When the module
+    /// rejected the upgrade, its changes are already reverted and the upgrade is aborted here.
+    pub fn execute_channel_upgrade_reply(
+        &self,
+        deps: DepsMut,
+        message: Reply,
+    ) -> Result<Response, ContractError> {
+        let endpoint: CwEndPoint = self.get_callback_data(deps.storage, message.id)?;
+        self.clear_callback_data(deps.storage, message.id);
+
+        let res = Response::new()
+            .add_attribute("action", "channel")
+            .add_attribute("method", "execute_channel_upgrade_reply");
+        match message.result {
+            SubMsgResult::Ok(_) => Ok(res),
+            SubMsgResult::Err(error) => {
+                cw_println!(deps, "module rejected the channel upgrade: {:?}", error);
+                let port_id = to_ibc_port_id(&endpoint.port_id)?;
+                let channel_id = to_ibc_channel_id(&endpoint.channel_id)?;
+                let mut channel_end = self.get_channel_end(deps.storage, &port_id, &channel_id)?;
+                let event = self.abort_channel_upgrade(
+                    deps.storage,
+                    &port_id,
+                    &channel_id,
+                    &mut channel_end,
+                    &error,
+                )?;
+                Ok(res.add_event(event))
+            }
+        }
+    }
+}
@@ -1,7 +1,14 @@
-use crate::{ContractError, EXECUTE_UPDATE_CLIENT};
+use crate::{ContractError, EXECUTE_UPDATE_CLIENT, EXECUTE_UPDATE_CLIENT_BATCH};
 use common::client_state::IClientState;
 use common::consensus_state::IConsensusState;
+use common::constants::{
+    ICON_CLIENT_STATE_TYPE_URL, ICON_CONSENSUS_STATE_TYPE_URL, TENDERMINT_CLIENT_STATE_TYPE_URL,
+    TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
 use common::icon::icon::lightclient::v1::{ClientState, ConsensusState};
+use common::icon::tendermint::light::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState,
+};
 use common::traits::AnyTypes;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary as to_binary, Binary, CosmosMsg, Deps, SubMsg};
@@ -42,6 +49,27 @@ impl LightClient {
         Ok(sub_msg)
     }
 
+    pub fn update_client_batch(
+        &self,
+        client_id: &IbcClientId,
+        headers: &[Any],
+        store_heights: Vec<u64>,
+    ) -> Result<SubMsg, ContractError> {
+        let exec_message = cw_common::client_msg::ExecuteMsg::UpdateClientBatch {
+            client_id: client_id.as_str().to_string(),
+            signed_headers: headers.iter().map(|h| h.encode_to_vec()).collect(),
+            store_heights,
+        };
+        let client_update_message: CosmosMsg = CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+            contract_addr: self.address.clone(),
+            msg: to_binary(&exec_message).map_err(ContractError::Std)?,
+            funds: vec![],
+        });
+        let sub_msg: SubMsg =
+            SubMsg::reply_on_success(client_update_message, EXECUTE_UPDATE_CLIENT_BATCH);
+        Ok(sub_msg)
+    }
+
     pub fn verify_connection_open_ack(
         &self,
         deps: Deps,
@@ -91,11 +119,14 @@ impl LightClient {
         deps: Deps,
         verify_packet_acknowledge: VerifyPacketAcknowledgement,
         client_id: &IbcClientId,
+        (delay_time_period, delay_block_period): (u64, u64),
     ) -> Result<(), ContractError> {
         let msg = to_binary(
             &cw_common::client_msg::QueryMsg::VerifyPacketAcknowledgement {
                 client_id: client_id.to_string(),
                 verify_packet_acknowledge,
+                delay_time_period,
+                delay_block_period,
             },
         )
         .map_err(ContractError::Std)?;
@@ -109,10 +140,13 @@ impl LightClient {
         deps: Deps,
         verify_packet_data: VerifyPacketData,
         client_id: &IbcClientId,
+        (delay_time_period, delay_block_period): (u64, u64),
     ) -> Result<(), ContractError> {
         let msg = to_binary(&cw_common::client_msg::QueryMsg::VerifyPacketData {
             client_id: client_id.to_string(),
             verify_packet_data,
+            delay_time_period,
+            delay_block_period,
         })
         .map_err(ContractError::Std)?;
         let query = build_smart_query(self.address.clone(), msg);
@@ -126,11 +160,14 @@ impl LightClient {
         client_id: &IbcClientId,
         verify_channel_state: VerifyChannelState,
         next_seq_recv_verification_result: LightClientPacketMessage,
+        (delay_time_period, delay_block_period): (u64, u64),
     ) -> Result<(), ContractError> {
         let msg = to_binary(&cw_common::client_msg::QueryMsg::TimeoutOnCLose {
             client_id: client_id.to_string(),
             verify_channel_state,
             next_seq_recv_verification_result,
+            delay_time_period,
+            delay_block_period,
         })
         .map_err(ContractError::Std)?;
         let query = build_smart_query(self.address.clone(), msg);
@@ -142,12 +179,14 @@ impl LightClient {
         &self,
         deps: Deps,
         client_id: &IbcClientId,
-
         next_seq_recv_verification_result: LightClientPacketMessage,
+        (delay_time_period, delay_block_period): (u64, u64),
     ) -> Result<(), ContractError> {
         let msg = to_binary(&cw_common::client_msg::QueryMsg::PacketTimeout {
             client_id: client_id.to_string(),
             next_seq_recv_verification_result,
+            delay_time_period,
+            delay_block_period,
         })
         .map_err(ContractError::Std)?;
         let query = build_smart_query(self.address.clone(), msg);
@@ -170,6 +209,30 @@ impl LightClient {
         self.to_validation_result(result, "verify channel state")
     }
 
+    pub fn verify_membership(
+        &self,
+        deps: Deps,
+        client_id: &IbcClientId,
+        path: Vec<u8>,
+        value: Vec<u8>,
+        proofs: Vec<u8>,
+        height: u64,
+    ) -> Result<(), ContractError> {
+        let msg = to_binary(&cw_common::client_msg::QueryMsg::VerifyMembership {
+            client_id: client_id.to_string(),
+            message_bytes: value,
+            path,
+            proofs,
+            height,
+            delay_time_period: 0,
+            delay_block_period: 0,
+        })
+        .map_err(ContractError::Std)?;
+        let query = build_smart_query(self.address.clone(), msg);
+        let result: bool = deps.querier.query(&query).unwrap_or(false);
+        self.to_validation_result(result, "verify membership")
+    }
+
     pub fn get_address(&self) -> String {
         self.address.clone()
     }
@@ -180,9 +243,45 @@ impl LightClient {
         client_id: &IbcClientId,
     ) -> Result<Box<dyn IClientState>, ContractError> {
         let client_state_any = self.get_client_state_any(deps, client_id)?;
-        let client_state = ClientState::from_any(client_state_any)
-            .map_err(|e| ContractError::IbcDecodeError { error: e })?;
-        Ok(Box::new(client_state))
+        Self::decode_client_state(client_state_any)
+    }
+
+    pub fn decode_client_state(client_state: Any) -> Result<Box<dyn IClientState>, ContractError> {
+        match client_state.type_url.as_str() {
+            ICON_CLIENT_STATE_TYPE_URL => {
+                let client_state = ClientState::from_any(client_state)
+                    .map_err(|e| ContractError::IbcDecodeError { error: e })?;
+
+                Ok(Box::new(client_state))
+            }
+            TENDERMINT_CLIENT_STATE_TYPE_URL => {
+                let client_state = TmClientState::from_any(client_state)
+                    .map_err(|e| ContractError::IbcDecodeError { error: e })?;
+
+                Ok(Box::new(client_state))
+            }
+            _ => Err(ContractError::FailedConversion),
+        }
+    }
+
+    pub fn decode_consensus_state(
+        consensus_state: Any,
+    ) -> Result<Box<dyn IConsensusState>, ContractError> {
+        match consensus_state.type_url.as_str() {
+            ICON_CONSENSUS_STATE_TYPE_URL => {
+                let consensus_state = ConsensusState::from_any(consensus_state)
+                    .map_err(|e| ContractError::IbcDecodeError { error: e })?;
+
+                Ok(Box::new(consensus_state))
+            }
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL => {
+                let consensus_state = TmConsensusState::from_any(consensus_state)
+                    .map_err(|e| ContractError::IbcDecodeError { error: e })?;
+
+                Ok(Box::new(consensus_state))
+            }
+            _ => Err(ContractError::FailedConversion),
+        }
     }
 
     pub fn build_client_state_query(client_id: &IbcClientId) -> Result<Binary, ContractError> {
@@ -266,9 +365,7 @@ impl LightClient {
         height: u64,
     ) -> Result<Box<dyn IConsensusState>, ContractError> {
         let consensus_state_any = self.get_consensus_state_any(deps, client_id, height)?;
-        let consensus_state = ConsensusState::from_any(consensus_state_any)
-            .map_err(|e| ContractError::IbcDecodeError { error: e })?;
-        Ok(Box::new(consensus_state))
+        Self::decode_consensus_state(consensus_state_any)
     }
     pub fn get_timestamp_at_height_query(
         client_id: &IbcClientId,
@@ -1,6 +1,9 @@
 use std::marker::PhantomData;
 
-use crate::{ics24_host::LastProcessedOn, light_client::light_client::LightClient};
+use crate::{
+    ics04_channel::upgrade::ChannelUpgrade, ics24_host::LastProcessedOn,
+    light_client::light_client::LightClient,
+};
 use cosmwasm_std::Order;
 use cw_storage_plus::Bound;
 use std::collections::HashMap;
@@ -9,6 +12,9 @@ use debug_print::debug_println;
 
 use super::*;
 
+/// Channel ends keyed by port and channel id, as listed by `CwIbcStore::list_channels`.
+type ChannelEntry = ((PortId, ChannelId), ChannelEnd);
+
 /// The `CwIbcStore` struct stores various data related to the Inter-Blockchain Communication (IBC).
 ///
 /// Properties:
@@ -54,8 +60,8 @@ use super::*;
 /// seq_on_a number for a channel. It is used to ensure that each channel has a unique seq_on_a number
 /// when it is created.
 ///
-/// * `client_connections`: A mapping between a client ID and its associated connection ID. This is used
-/// to keep track of the connection associated with each client.
+/// * `client_connections`: A mapping between a client ID and the IDs of the connections opened on it,
+/// in the order they were created.
 ///
 /// * `connections`: `connections` is a mapping between `ConnectionId` and a byte vector (`Vec<u8>`). It
 /// stores the connection state associated with each connection identifier. This state can include
@@ -90,6 +96,15 @@ use super::*;
 ///
 /// * `callback_data`: Map of reply id to bytes that can be used as context when callback returns.
 ///
+/// * `channel_upgrade_sequences`: The number of upgrades attempted on each channel, identified by
+/// `(PortId, ChannelId)`. It is committed as part of the channel end once non-zero.
+///
+/// * `channel_upgrades`: The upgrade currently being negotiated on each channel, identified by
+/// `(PortId, ChannelId)`. Removed once the upgrade is opened or cancelled.
+///
+/// * `counterparty_upgrades`: The upgrade proven for the counterparty of each channel, identified
+/// by `(PortId, ChannelId)`. Removed together with `channel_upgrades`.
+///
 pub struct CwIbcStore<'a> {
     client_registry: Map<'a, IbcClientType, String>,
     client_types: Map<'a, &'a IbcClientId, IbcClientType>,
@@ -100,7 +115,7 @@ pub struct CwIbcStore<'a> {
     next_client_sequence: Item<'a, u64>,
     next_connection_sequence: Item<'a, u64>,
     next_channel_sequence: Item<'a, u64>,
-    client_connections: Map<'a, &'a IbcClientId, IbcConnectionId>,
+    client_connections: Map<'a, &'a IbcClientId, Vec<IbcConnectionId>>,
     connections: Map<'a, &'a IbcConnectionId, Vec<u8>>,
     channels: Map<'a, (&'a PortId, &'a ChannelId), ChannelEnd>,
     port_to_module: Map<'a, &'a PortId, IbcModuleId>,
@@ -117,6 +132,9 @@ pub struct CwIbcStore<'a> {
     callback_data: Map<'a, u64, Vec<u8>>,
     sent_packets: Map<'a, (&'a PortId, &'a ChannelId, u64), u64>,
     write_acks: Map<'a, (&'a PortId, &'a ChannelId, u64), u64>,
+    channel_upgrade_sequences: Map<'a, (&'a PortId, &'a ChannelId), u64>,
+    channel_upgrades: Map<'a, (&'a PortId, &'a ChannelId), ChannelUpgrade>,
+    counterparty_upgrades: Map<'a, (&'a PortId, &'a ChannelId), ChannelUpgrade>,
 }
 
 impl<'a> Default for CwIbcStore<'a> {
@@ -138,7 +156,7 @@ impl<'a> CwIbcStore<'a> {
             next_connection_sequence: Item::new(StorageKey::NextConnectionSequence.as_str()),
             next_channel_sequence: Item::new(StorageKey::NextChannelSequence.as_str()),
             connections: Map::new(StorageKey::Connections.as_str()),
-            client_connections: Map::new(StorageKey::ClientConnection.as_str()),
+            client_connections: Map::new(StorageKey::ClientConnections.as_str()),
             channels: Map::new(StorageKey::Channels.as_str()),
             port_to_module: Map::new(StorageKey::PortToModule.as_str()),
             capabilities: Map::new(StorageKey::Capabilities.as_str()),
@@ -149,6 +167,9 @@ impl<'a> CwIbcStore<'a> {
             callback_data: Map::new(StorageKey::CallbackData.as_str()),
             sent_packets: Map::new(StorageKey::SentPackets.as_str()),
             write_acks: Map::new(StorageKey::WriteAcks.as_str()),
+            channel_upgrade_sequences: Map::new(StorageKey::ChannelUpgradeSequences.as_str()),
+            channel_upgrades: Map::new(StorageKey::ChannelUpgrades.as_str()),
+            counterparty_upgrades: Map::new(StorageKey::CounterpartyUpgrades.as_str()),
         }
     }
     pub fn client_registry(&self) -> &Map<'a, IbcClientType, String> {
@@ -183,7 +204,7 @@ impl<'a> CwIbcStore<'a> {
     pub fn connections(&self) -> &Map<'a, &'a ConnectionId, Vec<u8>> {
         &self.connections
     }
-    pub fn client_connections(&self) -> &Map<'a, &'a ClientId, ConnectionId> {
+    pub fn client_connections(&self) -> &Map<'a, &'a ClientId, Vec<ConnectionId>> {
         &self.client_connections
     }
     pub fn channels(&self) -> &Map<'a, (&'a PortId, &'a ChannelId), ChannelEnd> {
@@ -220,6 +241,18 @@ impl<'a> CwIbcStore<'a> {
         &self.write_acks
     }
 
+    pub fn channel_upgrade_sequences(&self) -> &Map<'a, (&'a PortId, &'a ChannelId), u64> {
+        &self.channel_upgrade_sequences
+    }
+
+    pub fn channel_upgrades(&self) -> &Map<'a, (&'a PortId, &'a ChannelId), ChannelUpgrade> {
+        &self.channel_upgrades
+    }
+
+    pub fn counterparty_upgrades(&self) -> &Map<'a, (&'a PortId, &'a ChannelId), ChannelUpgrade> {
+        &self.counterparty_upgrades
+    }
+
     pub fn clear_storage(&self, store: &mut dyn Storage) {
         let keys: Vec<_> = store
             .range(None, None, Order::Ascending)
@@ -318,6 +351,86 @@ impl<'a> CwIbcStore<'a> {
         Ok(result)
     }
 
+    /// Returns one page of the registered clients and their types, ordered by client id.
+    pub fn list_client_types(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<&ClientId>,
+        limit: usize,
+    ) -> Result<Vec<(ClientId, IbcClientType)>, ContractError> {
+        let start =
+            start_after.map(|client_id| Bound::Exclusive::<&ClientId>((client_id, PhantomData)));
+        self.client_types
+            .range(store, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<(ClientId, IbcClientType)>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns one page of the encoded connection ends, ordered by connection id.
+    pub fn list_connections(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<&ConnectionId>,
+        limit: usize,
+    ) -> Result<Vec<(ConnectionId, Vec<u8>)>, ContractError> {
+        let start = start_after
+            .map(|connection_id| Bound::Exclusive::<&ConnectionId>((connection_id, PhantomData)));
+        self.connections
+            .range(store, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<(ConnectionId, Vec<u8>)>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns one page of the channel ends grouped by port, in key order. Only the channels of
+    /// `port_id` are listed when provided, `start_after` on another port is then ignored. Channels
+    /// are skipped unless `connection_id` is one of their connection hops.
+    pub fn list_channels(
+        &self,
+        store: &dyn Storage,
+        port_id: Option<&PortId>,
+        connection_id: Option<&ConnectionId>,
+        start_after: Option<(&PortId, &ChannelId)>,
+        limit: usize,
+    ) -> Result<Vec<ChannelEntry>, ContractError> {
+        let channels: Box<dyn Iterator<Item = StdResult<ChannelEntry>> + '_> = match port_id {
+            Some(port_id) => {
+                let start = start_after
+                    .filter(|(start_port_id, _)| *start_port_id == port_id)
+                    .map(|(_, channel_id)| {
+                        Bound::Exclusive::<&ChannelId>((channel_id, PhantomData))
+                    });
+                Box::new(
+                    self.channels
+                        .prefix(port_id)
+                        .range(store, start, None, Order::Ascending)
+                        .map(|item| {
+                            item.map(|(channel_id, channel_end)| {
+                                ((port_id.clone(), channel_id), channel_end)
+                            })
+                        }),
+                )
+            }
+            None => {
+                let start = start_after
+                    .map(|key| Bound::Exclusive::<(&PortId, &ChannelId)>((key, PhantomData)));
+                Box::new(self.channels.range(store, start, None, Order::Ascending))
+            }
+        };
+
+        channels
+            .filter(|item| match (item, connection_id) {
+                (Ok((_, channel_end)), Some(connection_id)) => {
+                    channel_end.connection_hops().contains(connection_id)
+                }
+                _ => true,
+            })
+            .take(limit)
+            .collect::<StdResult<Vec<ChannelEntry>>>()
+            .map_err(ContractError::Std)
+    }
+
     pub fn save_commitment(
         &self,
         store: &mut dyn Storage,
@@ -2,7 +2,7 @@ use super::*;
 
 #[cw_serde]
 
-/// This is defining an enumeration called `StorageKey` with 24 possible values. Each value represents a
+/// This is defining an enumeration called `StorageKey` with 27 possible values. Each value represents a
 /// key that can be used to access a specific piece of data in a storage system. The `as_str` method is
 /// also defined to convert each value to its corresponding string representation. This code is likely
 /// part of a larger system that uses a key-value store to persist data.
@@ -18,6 +18,7 @@ pub enum StorageKey {
     NextChannelSequence,
     Connections,
     ClientConnection,
+    ClientConnections,
     Channels,
     Router,
     PortToModule,
@@ -31,6 +32,9 @@ pub enum StorageKey {
     CallbackData,
     SentPackets,
     WriteAcks,
+    ChannelUpgradeSequences,
+    ChannelUpgrades,
+    CounterpartyUpgrades,
 }
 
 impl StorageKey {
@@ -47,6 +51,7 @@ impl StorageKey {
             StorageKey::NextChannelSequence => "next_channel_Sequence",
             StorageKey::Connections => "connections",
             StorageKey::ClientConnection => "client_connections",
+            StorageKey::ClientConnections => "client_connection_list",
             StorageKey::Channels => "channels",
             StorageKey::Router => "router",
             StorageKey::PortToModule => "port_to_module",
@@ -60,6 +65,9 @@ impl StorageKey {
             StorageKey::CallbackData => "callback_data",
             StorageKey::SentPackets => "sent_packets",
             StorageKey::WriteAcks => "write_acks",
+            StorageKey::ChannelUpgradeSequences => "channel_upgrade_sequences",
+            StorageKey::ChannelUpgrades => "channel_upgrades",
+            StorageKey::CounterpartyUpgrades => "counterparty_upgrades",
         }
     }
 }
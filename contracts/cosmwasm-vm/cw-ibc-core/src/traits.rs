@@ -6,6 +6,7 @@ use cw_common::raw_types::{
     client::{
         RawMsgCreateClient, RawMsgSubmitMisbehaviour, RawMsgUpdateClient, RawMsgUpgradeClient,
     },
+    Any,
 };
 
 use super::*;
@@ -36,12 +37,27 @@ pub trait IbcClient {
         env: Env,
         message: Reply,
     ) -> Result<Response, ContractError>;
+    fn update_client_batch(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        client_id: String,
+        headers: Vec<Any>,
+        store_heights: Vec<u64>,
+    ) -> Result<Response, ContractError>;
+    fn execute_update_client_batch_reply(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        message: Reply,
+    ) -> Result<Response, ContractError>;
     fn upgrade_client(
         &self,
         deps: DepsMut,
         info: MessageInfo,
         env: Env,
         message: RawMsgUpgradeClient,
+        upgrade_height: u64,
     ) -> Result<Response, ContractError>;
     fn execute_upgrade_client_reply(
         &self,
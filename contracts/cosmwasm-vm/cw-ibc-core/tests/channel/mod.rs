@@ -17,12 +17,14 @@ use cw_common::raw_types::RawHeight;
 
 pub mod test_acknowledgement;
 pub mod test_channel_closeinit;
+pub mod test_channel_upgrade;
 pub mod test_close_confirm;
 pub mod test_execution_channel;
 pub mod test_handler;
 pub mod test_open_ack;
 pub mod test_open_confirm;
 pub mod test_packet;
+pub mod test_packet_batch;
 pub mod test_receive_packet;
 pub mod test_timeout;
 pub mod test_timeout_on_close;
@@ -31,6 +31,39 @@ fn test_acknowledgement_packet_validate_ordered() {
     let res = contract.acknowledgement_packet_validate(deps.as_mut(), info, env, &msg);
     println!("{:?}", res);
     assert!(res.is_ok());
+
+    let next_sequence_ack = contract
+        .get_next_sequence_ack(deps.as_ref().storage, &src_port, &src_channel)
+        .unwrap();
+    assert_eq!(next_sequence_ack, Sequence::from(2));
+}
+
+#[test]
+#[should_panic(expected = "InvalidPacketSequence")]
+fn test_acknowledgement_packet_validate_ordered_fails_out_of_order() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+    let env = get_mock_env();
+
+    let msg = get_dummy_raw_msg_acknowledgement(10);
+    let mut test_context = TestContext::for_acknowledge_packet(env.clone(), &msg);
+    let mut channel_end = test_context.channel_end();
+    channel_end.ordering = Order::Ordered;
+    test_context.channel_end = Some(channel_end);
+
+    let packet = msg.packet.clone().unwrap();
+    let src_port = to_ibc_port_id(&packet.source_port).unwrap();
+    let src_channel = to_ibc_channel_id(&packet.source_channel).unwrap();
+    test_context.init_acknowledge_packet(deps.as_mut().storage, &contract);
+    contract
+        .store_next_sequence_ack(&mut deps.storage, &src_port, &src_channel, &2.into())
+        .unwrap();
+
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+    contract
+        .acknowledgement_packet_validate(deps.as_mut(), info, env, &msg)
+        .unwrap();
 }
 
 #[test]
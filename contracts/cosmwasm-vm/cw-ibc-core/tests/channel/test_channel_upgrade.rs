@@ -0,0 +1,588 @@
+use super::*;
+
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Empty, OwnedDeps};
+use cw_common::raw_types::upgrade::{
+    RawErrorReceipt, RawMsgChannelUpgradeAck, RawMsgChannelUpgradeCancel,
+    RawMsgChannelUpgradeConfirm, RawMsgChannelUpgradeInit, RawMsgChannelUpgradeOpen,
+    RawMsgChannelUpgradeTry, RawUpgrade, RawUpgradeFields,
+};
+use cw_ibc_core::ics04_channel::{
+    EXECUTE_ON_CHANNEL_UPGRADE_ACK_ON_MODULE, EXECUTE_ON_CHANNEL_UPGRADE_INIT,
+    EXECUTE_ON_CHANNEL_UPGRADE_OPEN_ON_MODULE, EXECUTE_ON_CHANNEL_UPGRADE_TRY_ON_MODULE,
+};
+
+fn get_dummy_upgrade_fields(version: &str) -> RawUpgradeFields {
+    RawUpgradeFields {
+        ordering: Order::Unordered as i32,
+        connection_hops: vec![ConnectionId::default().to_string()],
+        version: version.to_string(),
+    }
+}
+
+fn get_dummy_raw_msg_chan_upgrade_init(version: &str) -> RawMsgChannelUpgradeInit {
+    RawMsgChannelUpgradeInit {
+        port_id: PortId::default().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        fields: Some(get_dummy_upgrade_fields(version)),
+        signer: get_dummy_bech32_account(),
+    }
+}
+
+fn get_dummy_raw_msg_chan_upgrade_try(
+    counterparty_upgrade_sequence: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeTry {
+    RawMsgChannelUpgradeTry {
+        port_id: PortId::default().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        proposed_upgrade_connection_hops: vec![ConnectionId::default().to_string()],
+        counterparty_upgrade_fields: Some(get_dummy_upgrade_fields("ics20-2")),
+        counterparty_upgrade_sequence,
+        proof_channel: get_dummy_proof(),
+        proof_upgrade: get_dummy_proof(),
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: get_dummy_bech32_account(),
+    }
+}
+
+fn get_dummy_raw_msg_chan_upgrade_ack(proof_height: u64) -> RawMsgChannelUpgradeAck {
+    RawMsgChannelUpgradeAck {
+        port_id: PortId::default().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        counterparty_upgrade: Some(RawUpgrade {
+            fields: Some(get_dummy_upgrade_fields("ics20-2")),
+            timeout: None,
+            next_sequence_send: 1,
+        }),
+        proof_channel: get_dummy_proof(),
+        proof_upgrade: get_dummy_proof(),
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: get_dummy_bech32_account(),
+    }
+}
+
+fn get_dummy_raw_msg_chan_upgrade_confirm(
+    counterparty_channel_state: State,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeConfirm {
+    RawMsgChannelUpgradeConfirm {
+        port_id: PortId::default().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        counterparty_channel_state: counterparty_channel_state as i32,
+        counterparty_upgrade: Some(RawUpgrade {
+            fields: Some(get_dummy_upgrade_fields("ics20-2")),
+            timeout: None,
+            next_sequence_send: 1,
+        }),
+        proof_channel: get_dummy_proof(),
+        proof_upgrade: get_dummy_proof(),
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: get_dummy_bech32_account(),
+    }
+}
+
+/// Sets up an OPEN channel `defaultPort/channel-0` on `connection-0` with the light client
+/// accepting every proof.
+fn setup_open_channel(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    contract: &CwIbcCoreContext,
+) -> TestContext {
+    let msg = get_dummy_raw_msg_chan_close_init();
+    let mut test_context = TestContext::for_channel_close_init(get_mock_env(), &msg);
+    test_context.init_channel_close_init(deps.as_mut().storage, contract);
+    contract
+        .store_next_sequence_send(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+            &Sequence::from(1),
+        )
+        .unwrap();
+    mock_lightclient_query(test_context.mock_queries.clone(), deps);
+    test_context
+}
+
+#[test]
+fn test_validate_channel_upgrade_init() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+
+    let msg = get_dummy_raw_msg_chan_upgrade_init("ics20-2");
+    let res = contract
+        .validate_channel_upgrade_init(deps.as_mut(), info, &msg)
+        .unwrap();
+
+    assert_eq!(res.messages[0].id, EXECUTE_ON_CHANNEL_UPGRADE_INIT);
+    assert_eq!(res.events[0].ty, IbcEventType::UpgradeInitChannel.as_str());
+
+    let storage = deps.as_ref().storage;
+    let upgrade = contract
+        .get_channel_upgrade(storage, &test_context.port_id, &test_context.channel_id)
+        .unwrap();
+    assert_eq!(upgrade.fields.version, Version::new("ics20-2".to_string()));
+    assert_eq!(
+        contract
+            .get_channel_upgrade_sequence(storage, &test_context.port_id, &test_context.channel_id)
+            .unwrap(),
+        1
+    );
+    let channel_end = contract
+        .get_channel_end(storage, &test_context.port_id, &test_context.channel_id)
+        .unwrap();
+    assert_eq!(channel_end.state, State::Open);
+}
+
+#[test]
+#[should_panic(expected = "InvalidUpgrade")]
+fn test_validate_channel_upgrade_init_fail_identical_fields() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    setup_open_channel(&mut deps, &contract);
+
+    let msg = get_dummy_raw_msg_chan_upgrade_init("ics20-1");
+    contract
+        .validate_channel_upgrade_init(deps.as_mut(), info, &msg)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidChannelState")]
+fn test_validate_channel_upgrade_init_fail_channel_not_open() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    let mut channel_end = test_context.channel_end();
+    channel_end.set_state(State::Closed);
+    contract
+        .store_channel_end(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+            &channel_end,
+        )
+        .unwrap();
+
+    let msg = get_dummy_raw_msg_chan_upgrade_init("ics20-2");
+    contract
+        .validate_channel_upgrade_init(deps.as_mut(), info, &msg)
+        .unwrap();
+}
+
+#[test]
+fn test_validate_channel_upgrade_try() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+
+    let msg = get_dummy_raw_msg_chan_upgrade_try(1, 10);
+    let res = contract
+        .validate_channel_upgrade_try(deps.as_mut(), info, &msg)
+        .unwrap();
+
+    assert_eq!(res.messages[0].id, EXECUTE_ON_CHANNEL_UPGRADE_TRY_ON_MODULE);
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Flushing);
+    let upgrade = contract
+        .get_channel_upgrade(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(upgrade.next_sequence_send, 1);
+}
+
+#[test]
+fn test_validate_channel_upgrade_try_writes_error_receipt_for_stale_sequence() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .store_channel_upgrade_sequence(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+            5,
+        )
+        .unwrap();
+
+    let msg = get_dummy_raw_msg_chan_upgrade_try(1, 10);
+    let res = contract
+        .validate_channel_upgrade_try(deps.as_mut(), info, &msg)
+        .unwrap();
+
+    assert!(res.messages.is_empty());
+    assert_eq!(res.events[0].ty, IbcEventType::UpgradeErrorChannel.as_str());
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Open);
+    assert_eq!(
+        contract
+            .get_channel_upgrade_sequence(
+                deps.as_ref().storage,
+                &test_context.port_id,
+                &test_context.channel_id
+            )
+            .unwrap(),
+        6
+    );
+}
+
+#[test]
+fn test_validate_channel_upgrade_ack_completes_flush() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_init(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_init("ics20-2"),
+        )
+        .unwrap();
+
+    let res = contract
+        .validate_channel_upgrade_ack(deps.as_mut(), info, &get_dummy_raw_msg_chan_upgrade_ack(10))
+        .unwrap();
+
+    assert_eq!(res.messages[0].id, EXECUTE_ON_CHANNEL_UPGRADE_ACK_ON_MODULE);
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::FlushComplete);
+    let counterparty_upgrade = contract
+        .get_counterparty_upgrade(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(counterparty_upgrade.next_sequence_send, 1);
+}
+
+#[test]
+fn test_validate_channel_upgrade_ack_keeps_flushing_with_inflight_packets() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_init(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_init("ics20-2"),
+        )
+        .unwrap();
+    contract
+        .ibc_store()
+        .store_sent_packet(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+            1,
+            12,
+        )
+        .unwrap();
+
+    contract
+        .validate_channel_upgrade_ack(deps.as_mut(), info, &get_dummy_raw_msg_chan_upgrade_ack(10))
+        .unwrap();
+
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Flushing);
+
+    contract
+        .ibc_store()
+        .store_sent_packet(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+            1,
+            0,
+        )
+        .unwrap();
+    contract
+        .complete_channel_upgrade_flush(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::FlushComplete);
+}
+
+#[test]
+fn test_validate_channel_upgrade_confirm_opens_upgrade() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_try(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_try(1, 10),
+        )
+        .unwrap();
+
+    let msg = get_dummy_raw_msg_chan_upgrade_confirm(State::FlushComplete, 10);
+    let res = contract
+        .validate_channel_upgrade_confirm(deps.as_mut(), info, &msg)
+        .unwrap();
+
+    assert_eq!(
+        res.messages[0].id,
+        EXECUTE_ON_CHANNEL_UPGRADE_OPEN_ON_MODULE
+    );
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Open);
+    assert_eq!(channel_end.version, Version::new("ics20-2".to_string()));
+    assert!(contract
+        .get_channel_upgrade(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id
+        )
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "InvalidCounterpartyChannelState")]
+fn test_validate_channel_upgrade_confirm_fail_counterparty_not_upgrading() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_try(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_try(1, 10),
+        )
+        .unwrap();
+
+    let msg = get_dummy_raw_msg_chan_upgrade_confirm(State::Open, 10);
+    contract
+        .validate_channel_upgrade_confirm(deps.as_mut(), info, &msg)
+        .unwrap();
+}
+
+#[test]
+fn test_validate_channel_upgrade_open() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_init(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_init("ics20-2"),
+        )
+        .unwrap();
+    contract
+        .validate_channel_upgrade_ack(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_ack(10),
+        )
+        .unwrap();
+
+    let msg = RawMsgChannelUpgradeOpen {
+        port_id: test_context.port_id.to_string(),
+        channel_id: test_context.channel_id.to_string(),
+        counterparty_channel_state: State::Open as i32,
+        counterparty_upgrade_sequence: 1,
+        proof_channel: get_dummy_proof(),
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: 10,
+        }),
+        signer: get_dummy_bech32_account(),
+    };
+    let res = contract
+        .validate_channel_upgrade_open(deps.as_mut(), info, &msg)
+        .unwrap();
+
+    assert_eq!(res.events[0].ty, IbcEventType::UpgradeOpenChannel.as_str());
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Open);
+    assert_eq!(channel_end.version, Version::new("ics20-2".to_string()));
+}
+
+#[test]
+fn test_validate_channel_upgrade_cancel_with_error_receipt() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    contract
+        .set_owner(deps.as_mut().storage, Addr::unchecked("owner"))
+        .unwrap();
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_init(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_init("ics20-2"),
+        )
+        .unwrap();
+
+    let msg = RawMsgChannelUpgradeCancel {
+        port_id: test_context.port_id.to_string(),
+        channel_id: test_context.channel_id.to_string(),
+        error_receipt: Some(RawErrorReceipt {
+            sequence: 1,
+            message: "incompatible upgrade".to_string(),
+        }),
+        proof_error_receipt: get_dummy_proof(),
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: 10,
+        }),
+        signer: get_dummy_bech32_account(),
+    };
+    let res = contract
+        .validate_channel_upgrade_cancel(deps.as_mut(), info, &msg)
+        .unwrap();
+
+    assert_eq!(
+        res.events[0].ty,
+        IbcEventType::UpgradeCancelChannel.as_str()
+    );
+    assert!(contract
+        .get_channel_upgrade(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id
+        )
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "InvalidUpgradeSequence")]
+fn test_validate_channel_upgrade_cancel_fail_stale_error_receipt() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    contract
+        .set_owner(deps.as_mut().storage, Addr::unchecked("owner"))
+        .unwrap();
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_init(
+            deps.as_mut(),
+            info.clone(),
+            &get_dummy_raw_msg_chan_upgrade_init("ics20-2"),
+        )
+        .unwrap();
+
+    let msg = RawMsgChannelUpgradeCancel {
+        port_id: test_context.port_id.to_string(),
+        channel_id: test_context.channel_id.to_string(),
+        error_receipt: Some(RawErrorReceipt {
+            sequence: 0,
+            message: "incompatible upgrade".to_string(),
+        }),
+        proof_error_receipt: get_dummy_proof(),
+        proof_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: 10,
+        }),
+        signer: get_dummy_bech32_account(),
+    };
+    contract
+        .validate_channel_upgrade_cancel(deps.as_mut(), info, &msg)
+        .unwrap();
+}
+
+#[test]
+fn test_execute_channel_upgrade_reply_aborts_rejected_upgrade() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000);
+    let test_context = setup_open_channel(&mut deps, &contract);
+    contract
+        .validate_channel_upgrade_try(
+            deps.as_mut(),
+            info,
+            &get_dummy_raw_msg_chan_upgrade_try(1, 10),
+        )
+        .unwrap();
+
+    let reply = Reply {
+        id: EXECUTE_ON_CHANNEL_UPGRADE_TRY_ON_MODULE,
+        result: SubMsgResult::Err("unsupported version".to_string()),
+    };
+    let res = contract
+        .execute_channel_upgrade_reply(deps.as_mut(), reply)
+        .unwrap();
+
+    assert_eq!(res.events[0].ty, IbcEventType::UpgradeErrorChannel.as_str());
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Open);
+    assert_eq!(channel_end.version, Version::new("ics20-1".to_string()));
+}
@@ -0,0 +1,324 @@
+use cosmwasm_std::from_json;
+use cw_common::types::{PacketBatchResult, PacketBatchStatus};
+use cw_ibc_core::{
+    VALIDATE_ON_PACKET_ACKNOWLEDGEMENT_ON_MODULE, VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+    VALIDATE_ON_PACKET_TIMEOUT_ON_MODULE,
+};
+
+use super::test_receive_packet::get_dummy_raw_msg_recv_packet;
+use super::*;
+
+fn batch_statuses(res: &cosmwasm_std::Response) -> Vec<PacketBatchStatus> {
+    from_json::<Vec<PacketBatchResult>>(res.data.clone().unwrap())
+        .unwrap()
+        .into_iter()
+        .map(|result| result.status)
+        .collect()
+}
+
+fn with_sequence(mut msg: RawMsgRecvPacket, sequence: u64) -> RawMsgRecvPacket {
+    let mut packet = msg.packet.unwrap();
+    packet.sequence = sequence;
+    msg.packet = Some(packet);
+    msg
+}
+
+#[test]
+fn test_receive_packet_batch() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+
+    let first = get_dummy_raw_msg_recv_packet(12);
+    let second = with_sequence(first.clone(), 2);
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &first);
+    test_context.init_receive_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let msgs = vec![first.clone(), second, first];
+    let res = contract
+        .validate_receive_packet_batch(deps.as_mut(), env, &msgs)
+        .unwrap();
+
+    assert_eq!(
+        batch_statuses(&res),
+        vec![
+            PacketBatchStatus::Success,
+            PacketBatchStatus::Success,
+            PacketBatchStatus::Noop
+        ]
+    );
+    assert_eq!(res.messages.len(), 2);
+    assert!(res
+        .messages
+        .iter()
+        .all(|m| m.id == VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE));
+
+    let pending = contract
+        .get_callback_data::<Vec<IbcPacket>>(
+            deps.as_ref().storage,
+            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+        )
+        .unwrap();
+    assert_eq!(
+        pending.iter().map(|p| p.sequence).collect::<Vec<u64>>(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn test_receive_packet_batch_reports_failed_packet() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+
+    let valid = get_dummy_raw_msg_recv_packet(12);
+    let mut invalid = with_sequence(valid.clone(), 2);
+    let mut packet = invalid.packet.unwrap();
+    packet.source_channel = "channel-99".to_string();
+    invalid.packet = Some(packet.clone());
+
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &valid);
+    test_context.init_receive_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let res = contract
+        .validate_receive_packet_batch(deps.as_mut(), env, &[invalid, valid])
+        .unwrap();
+
+    let statuses = batch_statuses(&res);
+    assert!(matches!(statuses[0], PacketBatchStatus::Failed { .. }));
+    assert_eq!(statuses[1], PacketBatchStatus::Success);
+    assert_eq!(res.messages.len(), 1);
+
+    let dst_port = to_ibc_port_id(&packet.destination_port).unwrap();
+    let dst_channel = to_ibc_channel_id(&packet.destination_channel).unwrap();
+    assert!(contract
+        .get_packet_receipt(
+            deps.as_ref().storage,
+            &dst_port,
+            &dst_channel,
+            Sequence::from(2)
+        )
+        .is_err());
+    assert!(contract
+        .get_packet_receipt(
+            deps.as_ref().storage,
+            &dst_port,
+            &dst_channel,
+            Sequence::from(1)
+        )
+        .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "PacketBatchProofHeightMismatch")]
+fn test_receive_packet_batch_fails_on_proof_height_mismatch() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+
+    let first = get_dummy_raw_msg_recv_packet(12);
+    let mut second = with_sequence(first.clone(), 2);
+    second.proof_height = Some(RawHeight {
+        revision_number: 0,
+        revision_height: 13,
+    });
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &first);
+    test_context.init_receive_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    contract
+        .validate_receive_packet_batch(deps.as_mut(), env, &[first, second])
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "MissingPacket")]
+fn test_receive_packet_batch_fails_when_empty() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+
+    contract
+        .validate_receive_packet_batch(deps.as_mut(), get_mock_env(), &[])
+        .unwrap();
+}
+
+#[test]
+fn test_execute_receive_packet_batch() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+
+    let timeout = IbcTimeout::with_block(IbcTimeoutBlock {
+        revision: 0,
+        height: 10,
+    });
+    let (src, dst) = get_dummy_endpoints();
+    let packets: Vec<IbcPacket> = (1..=2)
+        .map(|seq| IbcPacket::new(vec![0], src.clone(), dst.clone(), seq, timeout.clone()))
+        .collect();
+    contract
+        .store_callback_data(
+            deps.as_mut().storage,
+            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+            &packets,
+        )
+        .unwrap();
+
+    let reply = || Reply {
+        id: VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            data: None,
+            events: vec![],
+        }),
+    };
+
+    contract
+        .execute_receive_packet_batch(deps.as_mut(), reply())
+        .unwrap();
+    let pending = contract
+        .get_callback_data::<Vec<IbcPacket>>(
+            deps.as_ref().storage,
+            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+        )
+        .unwrap();
+    assert_eq!(pending, packets[1..].to_vec());
+
+    contract
+        .execute_receive_packet_batch(deps.as_mut(), reply())
+        .unwrap();
+    assert!(contract
+        .get_callback_data::<Vec<IbcPacket>>(
+            deps.as_ref().storage,
+            VALIDATE_ON_PACKET_RECEIVE_BATCH_ON_MODULE,
+        )
+        .is_err());
+}
+
+#[test]
+fn test_acknowledgement_packet_batch() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+    let mut env = get_mock_env();
+    env.block.height = 100;
+
+    let msg = get_dummy_raw_msg_acknowledgement(10);
+    let mut test_context = TestContext::for_acknowledge_packet(env.clone(), &msg);
+    let mut channel_end = test_context.channel_end();
+    channel_end.ordering = Order::Unordered;
+    test_context.channel_end = Some(channel_end);
+    test_context.init_acknowledge_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let res = contract
+        .acknowledgement_packet_batch_validate(
+            deps.as_mut(),
+            info.clone(),
+            env.clone(),
+            std::slice::from_ref(&msg),
+        )
+        .unwrap();
+    assert_eq!(batch_statuses(&res), vec![PacketBatchStatus::Success]);
+    assert_eq!(
+        res.messages[0].id,
+        VALIDATE_ON_PACKET_ACKNOWLEDGEMENT_ON_MODULE
+    );
+
+    // a second relayer submitting the same acknowledgement is a no-op
+    let res = contract
+        .acknowledgement_packet_batch_validate(deps.as_mut(), info, env, &[msg])
+        .unwrap();
+    assert_eq!(batch_statuses(&res), vec![PacketBatchStatus::Noop]);
+    assert!(res.messages.is_empty());
+}
+
+#[test]
+fn test_acknowledgement_packet_batch_reports_unknown_packet() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+    let mut env = get_mock_env();
+    env.block.height = 100;
+
+    let msg = get_dummy_raw_msg_acknowledgement(10);
+    let mut test_context = TestContext::for_acknowledge_packet(env.clone(), &msg);
+    let mut channel_end = test_context.channel_end();
+    channel_end.ordering = Order::Unordered;
+    test_context.channel_end = Some(channel_end);
+    test_context.init_acknowledge_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let mut unknown = msg.clone();
+    if let Some(packet) = unknown.packet.as_mut() {
+        packet.sequence += 1;
+    }
+
+    let res = contract
+        .acknowledgement_packet_batch_validate(deps.as_mut(), info, env, &[unknown, msg])
+        .unwrap();
+    let statuses = batch_statuses(&res);
+    match &statuses[0] {
+        PacketBatchStatus::Failed { error } => assert!(error.contains("PacketCommitmentNotFound")),
+        status => panic!("unexpected status {status:?}"),
+    }
+    assert_eq!(statuses[1], PacketBatchStatus::Success);
+    assert_eq!(res.messages.len(), 1);
+}
+
+#[test]
+fn test_timeout_packet_batch() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    let proof_height = 50;
+    let msg = get_dummy_raw_msg_timeout(proof_height, proof_height - 1, 0);
+    let mut test_context = TestContext::for_packet_timeout(env.clone(), &msg);
+    test_context.init_timeout_packet(deps.as_mut().storage, &contract);
+    test_context.save_timestamp_at_height(proof_height, 0);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let res = contract
+        .timeout_packet_batch_validate(deps.as_mut(), info.clone(), env.clone(), vec![msg.clone()])
+        .unwrap();
+    assert_eq!(batch_statuses(&res), vec![PacketBatchStatus::Success]);
+    assert_eq!(res.messages[0].id, VALIDATE_ON_PACKET_TIMEOUT_ON_MODULE);
+
+    let res = contract
+        .timeout_packet_batch_validate(deps.as_mut(), info, env, vec![msg])
+        .unwrap();
+    assert_eq!(batch_statuses(&res), vec![PacketBatchStatus::Noop]);
+}
+
+#[test]
+fn test_timeout_packet_batch_reports_failed_packet() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    let proof_height = 50;
+    let msg = get_dummy_raw_msg_timeout(proof_height, proof_height - 1, 0);
+    let mut test_context = TestContext::for_packet_timeout(env.clone(), &msg);
+    test_context.init_timeout_packet(deps.as_mut().storage, &contract);
+    test_context.save_timestamp_at_height(proof_height, 0);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let mut unknown = msg.clone();
+    if let Some(packet) = unknown.packet.as_mut() {
+        packet.sequence += 1;
+    }
+
+    let res = contract
+        .timeout_packet_batch_validate(deps.as_mut(), info, env, vec![unknown, msg])
+        .unwrap();
+    let statuses = batch_statuses(&res);
+    match &statuses[0] {
+        PacketBatchStatus::Failed { error } => assert!(error.contains("PacketCommitmentNotFound")),
+        status => panic!("unexpected status {status:?}"),
+    }
+    assert_eq!(statuses[1], PacketBatchStatus::Success);
+    assert_eq!(res.messages.len(), 1);
+}
@@ -1,15 +1,21 @@
 use common::ibc::core::ics03_connection::connection::State as ConnectionState;
 
 use common::ibc::core::ics04_channel::commitment::AcknowledgementCommitment;
+use common::ibc::core::ics04_channel::msgs::acknowledgement::Acknowledgement;
 use common::ibc::core::ics04_channel::packet::Receipt;
 use common::ibc::core::ics04_channel::timeout::TimeoutHeight;
 use common::ibc::timestamp::Timestamp;
 
 use cosmwasm_std::to_vec;
+use cw_common::commitment;
 use cw_common::raw_types::channel::RawMsgRecvPacket;
-use cw_common::types::Ack;
+use cw_common::types::{Ack, MODULE_ERROR_ACK};
 use cw_ibc_core::conversions::{to_ibc_channel_id, to_ibc_port_id, to_ibc_timeout_block};
 
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Empty, OwnedDeps};
+use cw_ibc_core::ics04_channel::upgrade::{ChannelUpgrade, UpgradeFields};
+use cw_ibc_core::ics04_channel::PKT_ERROR_ATTRIBUTE_KEY;
 use cw_ibc_core::VALIDATE_ON_PACKET_RECEIVE_ON_MODULE;
 
 use super::*;
@@ -87,6 +93,122 @@ fn test_receive_packet() {
     );
 }
 
+#[test]
+fn test_receive_packet_keeps_timeout_timestamp() {
+    let mut contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+
+    let timeout_timestamp = env.block.time.plus_seconds(600).nanos();
+    let mut msg = get_dummy_raw_msg_recv_packet(12);
+    msg.packet = Some(get_dummy_raw_packet_recv(12, timeout_timestamp));
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &msg);
+    test_context.init_receive_packet(deps.as_mut().storage, &mut contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    contract
+        .validate_receive_packet(deps.as_mut(), info, env, &msg)
+        .unwrap();
+
+    let packet = contract
+        .get_callback_data::<IbcPacket>(deps.as_ref().storage, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE)
+        .unwrap();
+    assert_eq!(
+        packet.timeout,
+        IbcTimeout::with_both(
+            IbcTimeoutBlock {
+                revision: 12,
+                height: 12
+            },
+            cosmwasm_std::Timestamp::from_nanos(timeout_timestamp)
+        )
+    );
+}
+
+#[test]
+fn test_receive_packet_ordered_channel() {
+    let mut contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+
+    let msg = get_dummy_raw_msg_recv_packet(12);
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &msg);
+    if let Some(channel_end) = &mut test_context.channel_end {
+        channel_end.ordering = Order::Ordered;
+    }
+    test_context.init_receive_packet(deps.as_mut().storage, &mut contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let packet = msg.packet.clone().unwrap();
+    let dst_port = to_ibc_port_id(&packet.destination_port).unwrap();
+    let dst_channel = to_ibc_channel_id(&packet.destination_channel).unwrap();
+    contract
+        .store_next_sequence_recv(
+            deps.as_mut().storage,
+            &dst_port,
+            &dst_channel,
+            &Sequence::from(1),
+        )
+        .unwrap();
+
+    let res = contract
+        .validate_receive_packet(deps.as_mut(), info.clone(), env.clone(), &msg)
+        .unwrap();
+    assert_eq!(res.messages[0].id, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE);
+
+    let next_sequence_recv = contract
+        .get_next_sequence_recv(deps.as_ref().storage, &dst_port, &dst_channel)
+        .unwrap();
+    assert_eq!(next_sequence_recv, Sequence::from(2));
+    // ordered channels keep no receipts
+    assert!(contract
+        .get_packet_receipt(
+            deps.as_ref().storage,
+            &dst_port,
+            &dst_channel,
+            Sequence::from(1)
+        )
+        .is_err());
+
+    let replay = contract.validate_receive_packet(deps.as_mut(), info, env, &msg);
+    assert!(replay.is_err());
+}
+
+#[test]
+#[should_panic(expected = "InvalidPacketSequence")]
+fn test_receive_packet_ordered_channel_fails_out_of_order() {
+    let mut contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+
+    let mut msg = get_dummy_raw_msg_recv_packet(12);
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &msg);
+    if let Some(channel_end) = &mut test_context.channel_end {
+        channel_end.ordering = Order::Ordered;
+    }
+    test_context.init_receive_packet(deps.as_mut().storage, &mut contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let mut packet = msg.packet.clone().unwrap();
+    contract
+        .store_next_sequence_recv(
+            deps.as_mut().storage,
+            &to_ibc_port_id(&packet.destination_port).unwrap(),
+            &to_ibc_channel_id(&packet.destination_channel).unwrap(),
+            &Sequence::from(1),
+        )
+        .unwrap();
+    packet.sequence = 2;
+    msg.packet = Some(packet);
+
+    contract
+        .validate_receive_packet(deps.as_mut(), info, env, &msg)
+        .unwrap();
+}
+
 #[should_panic(
     expected = "IbcChannelError { error: InvalidChannelState { channel_id: ChannelId(\"channel-3\"), state: Closed } }"
 )]
@@ -117,6 +239,72 @@ fn test_receive_packet_fails_on_channel_closed() {
     );
 }
 
+/// Sets up the receiving channel as FLUSHING with the counterparty upgrade recording
+/// `counterparty_next_sequence_send`.
+fn setup_flushing_receive_channel(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    contract: &mut CwIbcCoreContext,
+    msg: &RawMsgRecvPacket,
+    counterparty_next_sequence_send: u64,
+) {
+    let mut test_context = TestContext::for_receive_packet(get_mock_env(), msg);
+    let mut chan_end_on_b = test_context.channel_end();
+    chan_end_on_b.set_state(State::Flushing);
+    test_context.channel_end = Some(chan_end_on_b.clone());
+    test_context.init_receive_packet(deps.as_mut().storage, contract);
+
+    let counterparty_upgrade = ChannelUpgrade {
+        fields: UpgradeFields {
+            ordering: *chan_end_on_b.ordering(),
+            connection_hops: chan_end_on_b.connection_hops().clone(),
+            version: chan_end_on_b.version().clone(),
+        },
+        next_sequence_send: counterparty_next_sequence_send,
+    };
+    contract
+        .store_counterparty_upgrade(
+            deps.as_mut().storage,
+            &test_context.port_id,
+            &test_context.channel_id,
+            &counterparty_upgrade,
+        )
+        .unwrap();
+    mock_lightclient_query(test_context.mock_queries, deps);
+}
+
+#[test]
+fn test_receive_packet_while_flushing() {
+    let mut contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+
+    let msg = get_dummy_raw_msg_recv_packet(12);
+    setup_flushing_receive_channel(&mut deps, &mut contract, &msg, 2);
+
+    let res = contract
+        .validate_receive_packet(deps.as_mut(), info, env, &msg)
+        .unwrap();
+
+    assert_eq!(res.messages[0].id, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE);
+}
+
+#[should_panic(expected = "PacketSentAfterUpgradeFlush")]
+#[test]
+fn test_receive_packet_fails_while_flushing_on_packet_sent_after_flush() {
+    let mut contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+
+    let msg = get_dummy_raw_msg_recv_packet(12);
+    setup_flushing_receive_channel(&mut deps, &mut contract, &msg, 1);
+
+    contract
+        .validate_receive_packet(deps.as_mut(), info, env, &msg)
+        .unwrap();
+}
+
 #[should_panic(
     expected = "IbcPacketError { error: ConnectionNotOpen { connection_id: ConnectionId(\"connection-0\") } }"
 )]
@@ -508,6 +696,85 @@ fn execute_receive_packet_ordered() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn execute_receive_packet_writes_error_ack_on_module_failure() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let timeout = IbcTimeout::with_block(IbcTimeoutBlock {
+        revision: 0,
+        height: 10,
+    });
+    let (src, dst) = get_dummy_endpoints();
+
+    let packet = IbcPacket::new(vec![0, 1, 2, 3], src, dst, 1, timeout);
+    contract
+        .store_callback_data(
+            deps.as_mut().storage,
+            VALIDATE_ON_PACKET_RECEIVE_ON_MODULE,
+            &packet,
+        )
+        .unwrap();
+    let port_id = IbcPortId::from_str(&packet.dest.port_id).unwrap();
+    let channel_id = IbcChannelId::from_str(&packet.dest.channel_id).unwrap();
+    contract
+        .store_channel_end(
+            &mut deps.storage,
+            &port_id,
+            &channel_id,
+            &get_dummy_channel_end(&port_id),
+        )
+        .unwrap();
+
+    let reply = Reply {
+        id: VALIDATE_ON_PACKET_RECEIVE_ON_MODULE,
+        result: SubMsgResult::Err("module error".to_string()),
+    };
+    let res = contract
+        .execute_receive_packet(deps.as_mut(), reply)
+        .unwrap();
+
+    let error_ack = to_binary(&Ack::Error(MODULE_ERROR_ACK.to_string())).unwrap();
+    let expected = commitment::compute_ack_commitment(&Acknowledgement::from_bytes(&error_ack));
+    let stored = contract
+        .get_packet_acknowledgement(deps.as_ref().storage, &port_id, &channel_id, 1.into())
+        .unwrap();
+    assert_eq!(stored, expected);
+    assert!(res
+        .events
+        .iter()
+        .any(|e| e.ty == IbcEventType::WriteAck.as_str()));
+    let error_event = res
+        .events
+        .iter()
+        .find(|e| e.ty == "receive_packet_error")
+        .unwrap();
+    assert!(error_event
+        .attributes
+        .iter()
+        .any(|a| a.key == PKT_ERROR_ATTRIBUTE_KEY && a.value == "module error"));
+    assert!(contract
+        .get_callback_data::<IbcPacket>(deps.as_ref().storage, VALIDATE_ON_PACKET_RECEIVE_ON_MODULE)
+        .is_err());
+}
+
+#[test]
+fn test_receive_packet_replies_on_module_failure() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+
+    let msg = get_dummy_raw_msg_recv_packet(12);
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &msg);
+    test_context.init_receive_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let res = contract
+        .validate_receive_packet(deps.as_mut(), info, env, &msg)
+        .unwrap();
+    assert_eq!(res.messages[0].reply_on, cosmwasm_std::ReplyOn::Always);
+}
+
 #[test]
 #[should_panic(expected = "ChannelNotFound")]
 fn test_receive_packet_fail_missing_channel() {
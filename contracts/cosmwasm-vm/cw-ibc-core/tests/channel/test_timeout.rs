@@ -199,6 +199,105 @@ fn test_timeout_packet_fails_if_timestamp_not_expired() {
         .unwrap();
 }
 
+#[test]
+fn test_timeout_packet_with_timestamp_only() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    let proof_height = 50;
+    let timeout_timestamp = 1692668413 * 1000000000;
+    let msg = get_dummy_raw_msg_timeout(proof_height, 0, timeout_timestamp);
+    let mut test_context = TestContext::for_packet_timeout(env.clone(), &msg);
+    test_context.init_timeout_packet(deps.as_mut().storage, &contract);
+    test_context.save_timestamp_at_height(proof_height, timeout_timestamp + 1);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let res = contract
+        .timeout_packet_validate_to_light_client(deps.as_mut(), info, env, msg)
+        .unwrap();
+
+    let module_msg = match &res.messages[0].msg {
+        cosmwasm_std::CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute { msg, .. }) => {
+            cosmwasm_std::from_json(msg).unwrap()
+        }
+        msg => panic!("unexpected message {msg:?}"),
+    };
+    match module_msg {
+        cw_common::xcall_connection_msg::ExecuteMsg::IbcPacketTimeout { msg } => {
+            assert_eq!(msg.packet.timeout.block(), None);
+            assert_eq!(
+                msg.packet.timeout.timestamp(),
+                Some(cosmwasm_std::Timestamp::from_nanos(timeout_timestamp))
+            );
+        }
+        msg => panic!("unexpected message {msg:?}"),
+    }
+}
+
+#[should_panic(expected = "PacketNotExpired")]
+#[test]
+fn test_timeout_packet_with_timestamp_only_fails_if_not_expired() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    let proof_height = 50;
+    let timeout_timestamp = 1692668413 * 1000000000;
+    let msg = get_dummy_raw_msg_timeout(proof_height, 0, timeout_timestamp);
+    let mut test_context = TestContext::for_packet_timeout(env.clone(), &msg);
+    test_context.init_timeout_packet(deps.as_mut().storage, &contract);
+    test_context.save_timestamp_at_height(proof_height, timeout_timestamp - 1);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    contract
+        .timeout_packet_validate_to_light_client(deps.as_mut(), info, env, msg)
+        .unwrap();
+}
+
+#[should_panic(expected = "TimestampTimeoutNotSupported")]
+#[test]
+fn test_timeout_packet_with_timestamp_only_fails_without_client_timestamp() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    let proof_height = 50;
+    let timeout_timestamp = 1692668413 * 1000000000;
+    let msg = get_dummy_raw_msg_timeout(proof_height, 0, timeout_timestamp);
+    let mut test_context = TestContext::for_packet_timeout(env.clone(), &msg);
+    test_context.init_timeout_packet(deps.as_mut().storage, &contract);
+    // no timestamp is mocked, so the client fails the timestamp query
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    contract
+        .timeout_packet_validate_to_light_client(deps.as_mut(), info, env, msg)
+        .unwrap();
+}
+
+#[test]
+fn test_timeout_packet_checks_height_only_without_client_timestamp() {
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let env = get_mock_env();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    let proof_height = 50;
+    let timeout_height = proof_height - 1;
+    let timeout_timestamp = 1692668413 * 1000000000;
+    let msg = get_dummy_raw_msg_timeout(proof_height, timeout_height, timeout_timestamp);
+    let mut test_context = TestContext::for_packet_timeout(env.clone(), &msg);
+    test_context.init_timeout_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let res = contract.timeout_packet_validate_to_light_client(deps.as_mut(), info, env, msg);
+
+    assert!(res.is_ok());
+}
+
 #[test]
 #[should_panic(expected = "ChannelClosed")]
 fn test_timeout_packet_fails_for_closed_connection() {
@@ -271,6 +370,66 @@ fn test_timeout_packet_for_ordered_channel() {
     ctx.save_timestamp_at_height(proof_height, 0);
     mock_lightclient_query(ctx.mock_queries, &mut deps);
 
-    let res = contract.timeout_packet_validate_to_light_client(deps.as_mut(), info, ctx.env, msg);
-    assert!(res.is_ok())
+    let packet = msg.packet.clone().unwrap();
+    let res = contract
+        .timeout_packet_validate_to_light_client(deps.as_mut(), info, ctx.env, msg)
+        .unwrap();
+
+    let src_port = to_ibc_port_id(&packet.source_port).unwrap();
+    let src_channel = IbcChannelId::from_str(&packet.source_channel).unwrap();
+    let channel_end = contract
+        .get_channel_end(deps.as_ref().storage, &src_port, &src_channel)
+        .unwrap();
+    assert_eq!(channel_end.state, State::Closed);
+
+    let commitment = contract
+        .get_commitment(
+            deps.as_ref().storage,
+            cw_common::commitment::channel_commitment_key(&src_port, &src_channel),
+        )
+        .unwrap();
+    let raw_channel: RawChannel = channel_end.into();
+    assert_eq!(
+        commitment,
+        common::utils::keccak256(&raw_channel.encode_to_vec()).to_vec()
+    );
+    assert!(res
+        .events
+        .iter()
+        .any(|event| event.ty == IbcEventType::ChannelClosed.as_str()));
+}
+
+#[test]
+fn test_timeout_packet_keeps_unordered_channel_open() {
+    let proof_height = 50;
+    let timeout_height = proof_height - 1;
+    let timeout_timestamp = 0;
+    let msg = get_dummy_raw_msg_timeout(proof_height, timeout_height, timeout_timestamp);
+
+    let mut ctx = TestContext::for_packet_timeout(get_mock_env(), &msg);
+    let contract = CwIbcCoreContext::default();
+    let mut deps = deps();
+    let info = create_mock_info("channel-creater", "umlg", 20000000);
+
+    ctx.init_timeout_packet(deps.as_mut().storage, &contract);
+    ctx.save_timestamp_at_height(proof_height, 0);
+    mock_lightclient_query(ctx.mock_queries, &mut deps);
+
+    let packet = msg.packet.clone().unwrap();
+    let res = contract
+        .timeout_packet_validate_to_light_client(deps.as_mut(), info, ctx.env, msg)
+        .unwrap();
+
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &to_ibc_port_id(&packet.source_port).unwrap(),
+            &IbcChannelId::from_str(&packet.source_channel).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Open);
+    assert!(!res
+        .events
+        .iter()
+        .any(|event| event.ty == IbcEventType::ChannelClosed.as_str()));
 }
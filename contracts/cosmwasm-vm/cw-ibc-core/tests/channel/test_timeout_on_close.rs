@@ -149,11 +149,21 @@ fn test_timeout_on_close_packet_validate_to_light_client_for_orderd_channel() {
     ctx.init_timeout_packet_on_close(deps.as_mut().storage, &contract);
     mock_lightclient_query(ctx.mock_queries, &mut deps);
 
-    let res = contract.timeout_on_close_packet_validate_to_light_client(
-        deps.as_mut(),
-        info,
-        ctx.env,
-        msg,
-    );
-    assert!(res.is_ok())
+    let packet = msg.packet.clone().unwrap();
+    let res = contract
+        .timeout_on_close_packet_validate_to_light_client(deps.as_mut(), info, ctx.env, msg)
+        .unwrap();
+
+    let channel_end = contract
+        .get_channel_end(
+            deps.as_ref().storage,
+            &to_ibc_port_id(&packet.source_port).unwrap(),
+            &IbcChannelId::from_str(&packet.source_channel).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(channel_end.state, State::Closed);
+    assert!(res
+        .events
+        .iter()
+        .any(|event| event.ty == IbcEventType::ChannelClosed.as_str()));
 }
@@ -166,6 +166,57 @@ fn test_write_acknowledgement() {
     assert_eq!(expected_result, ack_height_saved);
 }
 
+#[test]
+fn test_write_acknowledgement_ordered_channel() {
+    let env = get_mock_env();
+    let mut deps = mock_dependencies();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("channel-creater", "umlg", 2000000000);
+    let msg = get_dummy_raw_msg_recv_packet(12);
+    let mut test_context = TestContext::for_receive_packet(env.clone(), &msg);
+    if let Some(channel_end) = &mut test_context.channel_end {
+        channel_end.ordering = Order::Ordered;
+    }
+    test_context.init_receive_packet(deps.as_mut().storage, &contract);
+    mock_lightclient_query(test_context.mock_queries, &mut deps);
+
+    let packet = msg.packet.clone().unwrap();
+    let dst_port = to_ibc_port_id(&packet.destination_port).unwrap();
+    let dst_channel = to_ibc_channel_id(&packet.destination_channel).unwrap();
+    contract
+        .store_next_sequence_recv(
+            deps.as_mut().storage,
+            &dst_port,
+            &dst_channel,
+            &Sequence::from(1),
+        )
+        .unwrap();
+
+    contract
+        .validate_receive_packet(deps.as_mut(), info, env.clone(), &msg)
+        .unwrap();
+
+    let info = create_mock_info("moduleaddress", "umlg", 2000000000);
+    let ibc_packet = to_ibc_packet(packet.clone()).unwrap();
+    let res = contract
+        .write_acknowledgement(deps.as_mut(), info, &env, ibc_packet, vec![11, 22])
+        .unwrap();
+    assert_eq!(res.events[0].ty, IbcEventType::WriteAck.as_str());
+
+    assert!(contract
+        .get_packet_acknowledgement(
+            deps.as_ref().storage,
+            &dst_port,
+            &dst_channel,
+            Sequence::from(packet.sequence)
+        )
+        .is_ok());
+    let next_sequence_recv = contract
+        .get_next_sequence_recv(deps.as_ref().storage, &dst_port, &dst_channel)
+        .unwrap();
+    assert_eq!(next_sequence_recv, Sequence::from(2));
+}
+
 #[test]
 #[should_panic(expected = "Unauthorized")]
 pub fn test_write_acknowledgement_fails_unauthorized() {
@@ -67,6 +67,85 @@ fn test_add_channel() {
     assert_eq!(channel_end, retrived_channel_end.unwrap())
 }
 
+#[test]
+fn test_list_channels() {
+    let ctx = CwIbcCoreContext::new();
+    let mut mock_deps = deps();
+    let icahost = PortId::from_str("icahost").unwrap();
+    let transfer = PortId::from_str("transfer").unwrap();
+    let channels = [
+        (&icahost, 0, 0),
+        (&icahost, 1, 1),
+        (&transfer, 2, 0),
+        (&transfer, 3, 1),
+    ];
+    for (port_id, sequence, connection) in channels {
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(port_id.clone(), Some(ChannelId::new(sequence + 10))),
+            vec![ConnectionId::new(connection)],
+            Version::from("ics-20".to_string()),
+        );
+        ctx.store_channel_end(
+            mock_deps.as_mut().storage,
+            port_id,
+            &ChannelId::new(sequence),
+            &channel_end,
+        )
+        .unwrap();
+    }
+
+    let all = ctx
+        .list_channels(mock_deps.as_ref().storage, None, None, None, None)
+        .unwrap();
+    assert_eq!(all.len(), 4);
+    assert_eq!(all[0].port_id, "icahost");
+    assert_eq!(all[0].channel_id, "channel-0");
+    assert_eq!(all[0].state, "OPEN");
+    assert_eq!(all[0].ordering, "ORDER_UNORDERED");
+    assert_eq!(
+        all[0].counterparty.channel_id,
+        Some("channel-10".to_string())
+    );
+    assert_eq!(all[0].connection_hops, vec!["connection-0".to_string()]);
+    assert_eq!(all[0].version, "ics-20");
+
+    let page = ctx
+        .list_channels(
+            mock_deps.as_ref().storage,
+            None,
+            None,
+            Some((icahost.clone(), ChannelId::new(1))),
+            Some(1),
+        )
+        .unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].port_id, "transfer");
+    assert_eq!(page[0].channel_id, "channel-2");
+
+    let by_port = ctx
+        .list_channels(mock_deps.as_ref().storage, Some(transfer), None, None, None)
+        .unwrap();
+    let by_port: Vec<&str> = by_port.iter().map(|c| c.channel_id.as_str()).collect();
+    assert_eq!(by_port, vec!["channel-2", "channel-3"]);
+
+    let by_connection = ctx
+        .list_channels(
+            mock_deps.as_ref().storage,
+            None,
+            Some(ConnectionId::new(1)),
+            None,
+            None,
+        )
+        .unwrap();
+    let by_connection: Vec<&str> = by_connection
+        .iter()
+        .map(|c| c.channel_id.as_str())
+        .collect();
+    assert_eq!(by_connection, vec!["channel-1", "channel-3"]);
+}
+
 #[test]
 fn test_channel_sequence_initialisation() {
     let ctx = CwIbcCoreContext::new();
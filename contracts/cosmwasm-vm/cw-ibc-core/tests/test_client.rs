@@ -12,12 +12,16 @@ use common::icon::icon::lightclient::v1::{ClientState, ConsensusState};
 use common::traits::AnyTypes;
 use common::utils::keccak256;
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{to_binary, to_vec, Addr, Event, Reply, SubMsgResponse};
+use cosmwasm_std::{
+    from_json, to_binary, to_vec, Addr, CosmosMsg, Event, Reply, SubMsgResponse, WasmMsg,
+};
+use cw_common::client_msg::ExecuteMsg as LightClientMessage;
 use cw_common::client_response::{
-    MisbehaviourResponse, UpdateClientResponse, UpgradeClientResponse,
+    MisbehaviourResponse, UpdateClientBatchResponse, UpdateClientResponse, UpgradeClientResponse,
 };
 
 use cw_common::commitment;
+use cw_common::core_msg::{DecodedClientState, DecodedConsensusState};
 use cw_common::raw_types::client::{
     RawMsgCreateClient, RawMsgSubmitMisbehaviour, RawMsgUpdateClient, RawMsgUpgradeClient,
 };
@@ -26,7 +30,9 @@ use cw_common::raw_types::Any;
 use common::ibc::core::ics02_client::client_type::ClientType;
 use common::ibc::core::ics24_host::identifier::ClientId;
 use common::ibc::mock::header::MockHeader;
-use cw_ibc_core::conversions::{to_ibc_client_id, to_ibc_connection_id};
+use cw_ibc_core::conversions::{
+    to_decoded_client_state, to_decoded_consensus_state, to_ibc_client_id, to_ibc_connection_id,
+};
 use cw_ibc_core::light_client::light_client::LightClient;
 use cw_ibc_core::{
     context::CwIbcCoreContext,
@@ -36,7 +42,7 @@ use cw_ibc_core::{
     },
     traits::IbcClient,
 };
-use cw_ibc_core::{EXECUTE_UPDATE_CLIENT, EXECUTE_UPGRADE_CLIENT};
+use cw_ibc_core::{EXECUTE_UPDATE_CLIENT, EXECUTE_UPDATE_CLIENT_BATCH, EXECUTE_UPGRADE_CLIENT};
 
 use prost::Message;
 use setup::*;
@@ -101,6 +107,75 @@ fn store_client_implement_success() {
     assert_eq!(light_client_address, result)
 }
 
+#[test]
+fn test_list_clients() {
+    let mut mock = deps();
+    let contract = CwIbcCoreContext::default();
+    let client_type = ClientType::new("new_client_type".to_string());
+
+    for counter in 0..3 {
+        let client_id = ClientId::new(client_type.clone(), counter).unwrap();
+        contract
+            .store_client_type(mock.as_mut().storage, &client_id, client_type.clone())
+            .unwrap();
+        contract
+            .store_client_implementations(
+                mock.as_mut().storage,
+                &client_id,
+                LightClient::new(format!("light-client-{counter}")),
+            )
+            .unwrap();
+    }
+
+    let first_page = contract
+        .list_clients(mock.as_ref().storage, None, Some(2))
+        .unwrap();
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page[0].client_id, "new_client_type-0");
+    assert_eq!(first_page[0].client_type, "new_client_type");
+    assert_eq!(first_page[0].light_client, "light-client-0");
+
+    let start_after = ClientId::new(client_type, 1).unwrap();
+    let second_page = contract
+        .list_clients(mock.as_ref().storage, Some(start_after), None)
+        .unwrap();
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page[0].client_id, "new_client_type-2");
+}
+
+#[test]
+fn test_decode_client_states() {
+    let client_state = to_decoded_client_state(get_dummy_client_state().to_any()).unwrap();
+    match client_state {
+        DecodedClientState::Icon(state) => {
+            assert_eq!(state.trusting_period, 2);
+            assert_eq!(state.max_clock_drift, 5);
+            assert_eq!(state.latest_height, 100);
+        }
+        _ => panic!("expected icon client state"),
+    }
+
+    let consensus_state = to_decoded_consensus_state(get_dummy_consensus_state().to_any()).unwrap();
+    match consensus_state {
+        DecodedConsensusState::Icon(state) => {
+            assert_eq!(state.message_root, hex::encode("message_root"));
+        }
+        _ => panic!("expected icon consensus state"),
+    }
+
+    let unknown = Any {
+        type_url: "/other.ClientState".to_string(),
+        value: vec![1, 2],
+    };
+    assert_eq!(
+        to_decoded_client_state(unknown).unwrap(),
+        DecodedClientState::Raw {
+            type_url: "/other.ClientState".to_string(),
+            value: "0102".to_string(),
+        }
+    );
+}
+
 #[test]
 #[should_panic(expected = "InvalidClientId { client_id: \"new_client_type-1\" }")]
 fn store_client_implement_failure() {
@@ -481,6 +556,141 @@ fn fails_on_error_ressponse() {
         .unwrap();
 }
 
+#[test]
+fn check_for_update_client_batch_message() {
+    let mut ctx = TestContext::for_client_state(get_mock_env());
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("alice", "umlg", 2000);
+
+    let client_state: ClientState = ctx.client_state.clone().unwrap();
+    let consenus_state: ConsensusState = ctx.consensus_state.clone().unwrap();
+    ctx.init_client_state(deps.as_mut(), &contract);
+
+    mock_lightclient_query(ctx.mock_queries, &mut deps);
+
+    let result = contract
+        .update_client_batch(
+            deps.as_mut(),
+            info,
+            ctx.client_id.to_string(),
+            vec![client_state.to_any(), client_state.to_any()],
+            vec![15],
+        )
+        .unwrap();
+
+    assert_eq!(EXECUTE_UPDATE_CLIENT_BATCH, result.messages[0].id);
+    assert_eq!("update_client_batch", result.attributes[0].value);
+    assert_eq!(ctx.client_id.as_str(), result.attributes[1].value);
+
+    let updates = [15, 20]
+        .iter()
+        .map(|height| {
+            UpdateClientResponse::new(
+                format!("10-{height}"),
+                ctx.client_id.as_str().to_string(),
+                keccak256(&client_state.encode_to_vec()).to_vec(),
+                keccak256(&consenus_state.encode_to_vec()).to_vec(),
+                client_state.encode_to_vec(),
+                consenus_state.encode_to_vec(),
+            )
+        })
+        .collect();
+    let mock_reponse_data =
+        UpdateClientBatchResponse::new(ctx.client_id.as_str().to_string(), updates);
+    let reply_message = Reply {
+        id: EXECUTE_UPDATE_CLIENT_BATCH,
+        result: cosmwasm_std::SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(to_binary(&mock_reponse_data).unwrap()),
+        }),
+    };
+
+    let result = contract
+        .execute_update_client_batch_reply(deps.as_mut(), get_mock_env(), reply_message)
+        .unwrap();
+
+    assert_eq!(
+        "execute_update_client_batch_reply",
+        result.attributes[0].value
+    );
+    assert_eq!("10-20", result.attributes[1].value);
+    assert_eq!("update_client", result.events[0].ty);
+    assert_eq!("10-15,10-20", result.events[0].attributes[3].value);
+
+    let commitment_15 = contract
+        .get_commitment(
+            deps.as_ref().storage,
+            commitment::consensus_state_commitment_key(&ctx.client_id, 10, 15),
+        )
+        .unwrap();
+    assert_eq!(
+        keccak256(&consenus_state.encode_to_vec()).to_vec(),
+        commitment_15
+    );
+}
+
+#[test]
+#[should_panic(expected = "IbcClientError { error: MissingRawHeader }")]
+fn fails_on_empty_update_client_batch() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("alice", "umlg", 2000);
+
+    contract
+        .update_client_batch(
+            deps.as_mut(),
+            info,
+            "iconclient-0".to_string(),
+            vec![],
+            vec![],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic(
+    expected = "IbcClientError { error: ClientFrozen { client_id: ClientId(\"iconclient-0\") } }"
+)]
+fn fails_update_client_batch_for_frozen_client() {
+    let mut ctx = TestContext::for_client_state(get_mock_env());
+    let mut deps = mock_dependencies();
+    let contract = CwIbcCoreContext::new();
+    let info = create_mock_info("alice", "umlg", 2000);
+
+    if let Some(client_state) = &mut ctx.client_state {
+        client_state.frozen_height = 10;
+    }
+
+    ctx.init_client_state(deps.as_mut(), &contract);
+    mock_lightclient_query(ctx.mock_queries, &mut deps);
+
+    contract
+        .update_client_batch(
+            deps.as_mut(),
+            info,
+            ctx.client_id.to_string(),
+            vec![get_dummy_client_state().to_any()],
+            vec![],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "IbcClientError { error: Other { description: \"response_error\" } }")]
+fn fails_on_error_response_update_client_batch() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+
+    let reply_message = Reply {
+        id: EXECUTE_UPDATE_CLIENT_BATCH,
+        result: cosmwasm_std::SubMsgResult::Err("response_error".to_string()),
+    };
+    contract
+        .execute_update_client_batch_reply(deps.as_mut(), get_mock_env(), reply_message)
+        .unwrap();
+}
+
 #[test]
 fn check_for_upgrade_client() {
     let mut ctx = TestContext::for_client_state(get_mock_env());
@@ -517,10 +727,29 @@ fn check_for_upgrade_client() {
     };
 
     let result = contract
-        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message)
+        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message, 10)
         .unwrap();
 
-    assert_eq!("upgrade_client", result.attributes[0].value)
+    assert_eq!("upgrade_client", result.attributes[0].value);
+    assert_eq!(EXECUTE_UPGRADE_CLIENT, result.messages[0].id);
+
+    let light_client_message = match &result.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            from_json::<LightClientMessage>(msg).unwrap()
+        }
+        _ => panic!("unexpected message"),
+    };
+    assert_eq!(
+        light_client_message,
+        LightClientMessage::UpgradeClient {
+            client_id: ctx.client_id.to_string(),
+            upgrade_height: 10,
+            upgraded_client_state: client_state.to_any().encode_to_vec(),
+            upgraded_consensus_state: consenus_state.to_any().encode_to_vec(),
+            proof_upgrade_client: get_dummy_merkle_proof().encode_to_vec(),
+            proof_upgrade_consensus_state: get_dummy_merkle_proof().encode_to_vec(),
+        }
+    );
 }
 
 #[test]
@@ -553,7 +782,7 @@ fn fails_on_upgrade_client_invalid_trusting_period() {
         signer: "new_signer".to_string(),
     };
     contract
-        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message)
+        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message, 10)
         .unwrap();
 }
 
@@ -580,7 +809,7 @@ fn fails_on_upgrade_client_invalid_consensus_state_timestamp() {
         signer: "signer".to_string(),
     };
     contract
-        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message)
+        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message, 10)
         .unwrap();
 }
 
@@ -623,7 +852,7 @@ fn fails_on_upgrade_client_frozen_client() {
     };
 
     contract
-        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message)
+        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message, 10)
         .unwrap();
 }
 
@@ -663,7 +892,7 @@ fn check_for_execute_upgrade_client() {
     };
 
     contract
-        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message)
+        .upgrade_client(deps.as_mut(), info, ctx.env, upgrdade_client_message, 10)
         .unwrap();
 
     let upgrade_client_response = UpgradeClientResponse::new(
@@ -1012,6 +1241,29 @@ fn fails_on_frozen_client_on_misbehaviour_validate() {
         .unwrap();
 }
 
+#[test]
+#[should_panic(expected = "IbcClientError { error: MissingRawMisbehaviour }")]
+fn fails_on_missing_misbehaviour() {
+    let mut ctx = TestContext::for_client_state(get_mock_env());
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::default();
+    let info = create_mock_info("alice", "umlg", 2000);
+
+    ctx.init_client_state(deps.as_mut(), &contract);
+
+    let misbehaviour_message = RawMsgSubmitMisbehaviour {
+        client_id: ctx.client_id.to_string(),
+        misbehaviour: None,
+        signer: get_dummy_account_id().to_string(),
+    };
+
+    mock_lightclient_query(ctx.mock_queries, &mut deps);
+
+    contract
+        .misbehaviour(deps.as_mut(), info, misbehaviour_message)
+        .unwrap();
+}
+
 #[test]
 #[should_panic(
     expected = "IbcClientError { error: Other { description: \"Invalid Response Data\" } }"
@@ -1102,3 +1354,38 @@ fn fails_on_deserialising_invalid_bytes_to_client_state() {
 
     <ClientState>::decode(data.client_state.unwrap().value.as_slice()).unwrap();
 }
+
+#[test]
+fn check_for_decode_tendermint_client_and_consensus_state() {
+    use common::icon::tendermint::light::{
+        ClientState as TmClientState, ConsensusState as TmConsensusState, MerkleRoot,
+    };
+
+    let client_state = TmClientState {
+        chain_id: "cosmoshub-4".to_string(),
+        latest_height: 42,
+        ..TmClientState::default()
+    };
+    let decoded = LightClient::decode_client_state(client_state.to_any()).unwrap();
+    assert_eq!("07-tendermint", decoded.client_type().as_str());
+    assert_eq!(Height::new(0, 42).unwrap(), decoded.latest_height());
+
+    let consensus_state = TmConsensusState {
+        root: Some(MerkleRoot {
+            hash: vec![1, 2, 3],
+        }),
+        ..TmConsensusState::default()
+    };
+    let decoded = LightClient::decode_consensus_state(consensus_state.to_any()).unwrap();
+    assert_eq!(vec![1, 2, 3], decoded.root().as_bytes().to_vec());
+}
+
+#[test]
+#[should_panic(expected = "FailedConversion")]
+fn fails_on_decode_unknown_client_state_type() {
+    let client_state = Any {
+        type_url: "/ibc.mock.ClientState".to_string(),
+        value: vec![],
+    };
+    LightClient::decode_client_state(client_state).unwrap();
+}
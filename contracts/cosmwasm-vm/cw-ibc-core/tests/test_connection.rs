@@ -1,4 +1,3 @@
-use cosmwasm_std::Timestamp;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -14,6 +13,7 @@ use cosmwasm_std::testing::mock_env;
 
 use cosmwasm_std::Addr;
 
+use cw_common::core_msg::{IdentifiedConnection, QueryMsg};
 use cw_common::get_address_storage_prefix;
 
 use cw_ibc_core::context::CwIbcCoreContext;
@@ -35,6 +35,7 @@ use common::ibc::events::IbcEventType;
 use cw_common::ibc_types::IbcClientId;
 use cw_ibc_core::validations::ensure_consensus_height_valid;
 use cw_ibc_core::ConnectionEnd;
+use cw_storage_plus::Map;
 use prost::Message;
 use setup::*;
 
@@ -101,18 +102,174 @@ fn test_connection_seq_on_a() {
 fn test_client_connection() {
     let mut deps = deps();
     let client_id = ClientId::default();
-    let conn_id = ConnectionId::new(5);
     let contract = CwIbcCoreContext::new();
 
+    for conn_id in [
+        ConnectionId::new(5),
+        ConnectionId::new(2),
+        ConnectionId::new(5),
+    ] {
+        contract
+            .store_connection_to_client(deps.as_mut().storage, &client_id, &conn_id)
+            .unwrap();
+    }
+
+    let result = contract
+        .client_connections(deps.as_ref().storage, &client_id)
+        .unwrap();
+
+    assert_eq!(vec![ConnectionId::new(5), ConnectionId::new(2)], result)
+}
+
+#[test]
+fn test_list_client_connections() {
+    let mut deps = deps();
+    let client_id = ClientId::default();
+    let contract = CwIbcCoreContext::new();
+
+    for sequence in 0..12 {
+        contract
+            .store_connection_to_client(
+                deps.as_mut().storage,
+                &client_id,
+                &ConnectionId::new(sequence),
+            )
+            .unwrap();
+    }
+
+    let first_page = contract
+        .list_client_connections(deps.as_ref().storage, &client_id, None, None)
+        .unwrap();
+    assert_eq!(
+        first_page,
+        (0..10).map(ConnectionId::new).collect::<Vec<_>>()
+    );
+
+    let second_page = contract
+        .list_client_connections(
+            deps.as_ref().storage,
+            &client_id,
+            Some(ConnectionId::new(9)),
+            Some(5),
+        )
+        .unwrap();
+    assert_eq!(
+        second_page,
+        vec![ConnectionId::new(10), ConnectionId::new(11)]
+    );
+
+    let unknown = contract
+        .list_client_connections(
+            deps.as_ref().storage,
+            &client_id,
+            Some(ConnectionId::new(40)),
+            None,
+        )
+        .unwrap();
+    assert!(unknown.is_empty());
+}
+
+#[test]
+fn test_list_connections() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::new();
+    let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+    let counterparty =
+        Counterparty::new(IbcClientId::default(), Some(ConnectionId::new(7)), prefix);
+    for sequence in 0..3 {
+        let conn_end = ConnectionEnd::new(
+            State::Open,
+            IbcClientId::default(),
+            counterparty.clone(),
+            vec![Version::default()],
+            Duration::from_secs(sequence),
+        );
+        contract
+            .store_connection(
+                deps.as_mut().storage,
+                &ConnectionId::new(sequence),
+                &conn_end,
+            )
+            .unwrap();
+    }
+
+    let first_page = contract
+        .list_connections(deps.as_ref().storage, None, Some(2))
+        .unwrap();
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page[0].connection_id, "connection-0");
+    assert_eq!(first_page[0].state, "OPEN");
+    assert_eq!(first_page[0].client_id, IbcClientId::default().to_string());
+    assert_eq!(
+        first_page[0].counterparty.connection_id,
+        Some("connection-7".to_string())
+    );
+    assert_eq!(first_page[0].counterparty.prefix, hex::encode("ibc"));
+    assert_eq!(first_page[0].versions[0].identifier, "1");
+    assert_eq!(first_page[1].delay_period, 1_000_000_000);
+
+    let second_page = contract
+        .list_connections(deps.as_ref().storage, Some(ConnectionId::new(1)), None)
+        .unwrap();
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page[0].connection_id, "connection-2");
+}
+
+#[test]
+fn test_query_decoded_connection() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::new();
+    let conn_end = ConnectionEnd::new(
+        State::Init,
+        IbcClientId::default(),
+        Counterparty::default(),
+        vec![Version::default()],
+        Duration::default(),
+    );
     contract
-        .store_connection_to_client(deps.as_mut().storage, &client_id, &conn_id)
+        .store_connection(deps.as_mut().storage, &ConnectionId::new(4), &conn_end)
         .unwrap();
 
-    let result = contract
-        .client_connection(deps.as_ref().storage, &client_id)
+    let res = contract
+        .query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetDecodedConnection {
+                connection_id: "connection-4".to_string(),
+            },
+        )
+        .unwrap();
+    let connection: IdentifiedConnection = cosmwasm_std::from_json(res).unwrap();
+    assert_eq!(connection.connection_id, "connection-4");
+    assert_eq!(connection.state, "INIT");
+    assert_eq!(connection.counterparty.connection_id, None);
+    assert_eq!(connection.versions[0].features.len(), 2);
+}
+
+#[test]
+fn test_migrate_client_connections() {
+    let mut deps = deps();
+    let client_id = ClientId::default();
+    let contract = CwIbcCoreContext::new();
+    let legacy: Map<&ClientId, ConnectionId> = Map::new("client_connections");
+    legacy
+        .save(deps.as_mut().storage, &client_id, &ConnectionId::new(3))
+        .unwrap();
+
+    contract
+        .migrate_client_connections(deps.as_mut().storage)
         .unwrap();
 
-    assert_eq!(conn_id, result)
+    assert_eq!(
+        contract
+            .client_connections(deps.as_ref().storage, &client_id)
+            .unwrap(),
+        vec![ConnectionId::new(3)]
+    );
+    assert!(legacy
+        .may_load(deps.as_ref().storage, &client_id)
+        .unwrap()
+        .is_none());
 }
 
 #[test]
@@ -148,18 +305,17 @@ fn test_connection_seq_on_a_fail() {
 }
 
 #[test]
-#[should_panic(
-    expected = "Std(NotFound { kind: \"common::ibc::core::ics24_host::identifier::ConnectionId\" })"
-)]
-fn test_client_connection_fail() {
+fn test_client_connections_empty() {
     let deps = deps();
     let client_id = ClientId::default();
 
     let contract = CwIbcCoreContext::new();
 
-    contract
-        .client_connection(deps.as_ref().storage, &client_id)
+    let result = contract
+        .client_connections(deps.as_ref().storage, &client_id)
         .unwrap();
+
+    assert!(result.is_empty())
 }
 
 #[test]
@@ -190,6 +346,44 @@ fn connection_open_init() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn connection_open_init_keeps_every_connection_of_client() {
+    let mut deps = deps();
+    let contract = CwIbcCoreContext::new();
+
+    let msg = get_dummy_raw_msg_conn_open_init();
+    let mut ctx = TestContext::for_connection_open_init(get_mock_env(), &msg);
+    ctx.init_connection_open_init(deps.as_mut().storage, &contract);
+
+    mock_lightclient_query(ctx.mock_queries, &mut deps);
+
+    let mut second = msg.clone();
+    second.delay_period = 100;
+    contract.connection_open_init(deps.as_mut(), msg).unwrap();
+    contract
+        .connection_open_init(deps.as_mut(), second)
+        .unwrap();
+
+    let res = contract
+        .query(
+            deps.as_ref(),
+            get_mock_env(),
+            QueryMsg::GetClientConnections {
+                client_id: ctx.client_id.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let connections: Vec<String> = cosmwasm_std::from_json(res).unwrap();
+    assert_eq!(connections, vec!["connection-0", "connection-1"]);
+
+    let connection_end = contract
+        .connection_end(deps.as_ref().storage, &ConnectionId::new(1))
+        .unwrap();
+    assert_eq!(connection_end.delay_period(), Duration::from_nanos(100));
+}
+
 #[test]
 #[should_panic(expected = "ClientFrozen")]
 fn fail_connection_open_init_for_frozen_client() {
@@ -551,102 +745,32 @@ fn connection_open_ack_validate() {
 }
 
 #[test]
-fn connection_validate_delay() {
-    let mut deps = deps();
-    let env = get_mock_env();
-    let packet_proof_height = common::ibc::core::ics02_client::height::Height::new(1, 1).unwrap();
-    let conn_end = ConnectionEnd::default();
-    let contract = CwIbcCoreContext::new();
-    contract
-        .store_last_processed_on(deps.as_mut().storage, &env, conn_end.client_id())
-        .unwrap();
-
-    let result =
-        contract.verify_connection_delay_passed(&deps.storage, env, packet_proof_height, conn_end);
-    assert!(result.is_ok())
-}
-
-#[test]
-#[should_panic(expected = "Std(NotFound { kind: \"cw_ibc_core::ics24_host::LastProcessedOn\" })")]
-fn connection_validate_delay_fails() {
-    let deps = deps();
-    let _env = get_mock_env();
-    let packet_proof_height = common::ibc::core::ics02_client::height::Height::new(1, 1).unwrap();
+fn connection_delay_periods_without_delay() {
     let conn_end = ConnectionEnd::default();
     let contract = CwIbcCoreContext::new();
-    let env = get_mock_env();
-    contract
-        .verify_connection_delay_passed(&deps.storage, env, packet_proof_height, conn_end)
-        .unwrap();
-}
-
-#[test]
-#[should_panic(expected = "overflow")]
-fn fail_verify_connection_delay_passed_on_timestamp_overflow() {
-    let mut ctx = TestContext::default(get_mock_env());
-    let mut deps = mock_dependencies();
-    let contract = CwIbcCoreContext::new();
-
-    ctx.env.block.time = Timestamp::from_seconds(u64::MAX);
-
-    contract
-        .store_last_processed_on(deps.as_mut().storage, &ctx.env, &ctx.client_id)
-        .unwrap();
 
-    contract
-        .verify_connection_delay_passed(
-            deps.as_ref().storage,
-            ctx.env,
-            mock_height(1, 1).unwrap(),
-            ctx.connection_end.unwrap(),
-        )
-        .unwrap();
+    assert_eq!((0, 0), contract.connection_delay_periods(&conn_end));
 }
 
 #[test]
-#[should_panic(expected = "NotEnoughTimeElapsed")]
-fn fail_verify_connection_delay_passed_on_not_enough_time_elapsed() {
-    let mut ctx = TestContext::default(get_mock_env());
-    let mut deps = mock_dependencies();
-    let contract = CwIbcCoreContext::new();
-
-    contract
-        .store_last_processed_on(deps.as_mut().storage, &ctx.env, &ctx.client_id)
-        .unwrap();
-
-    ctx.env.block.time = Timestamp::from_nanos(10);
-
-    contract
-        .verify_connection_delay_passed(
-            deps.as_ref().storage,
-            ctx.env,
-            mock_height(1, 1).unwrap(),
-            ctx.connection_end.unwrap(),
-        )
-        .unwrap();
-}
-
-#[test]
-#[should_panic(expected = "NotEnoughBlocksElapsed")]
-fn fail_verify_connection_delay_passed_on_not_enough_block_elapsed() {
-    let mut ctx = TestContext::default(get_mock_env());
-    let mut deps = mock_dependencies();
+fn connection_delay_periods_in_nanos_and_blocks() {
+    let delay_period = Duration::from_secs(120);
+    let conn_end = ConnectionEnd::new(
+        State::Open,
+        IbcClientId::default(),
+        Counterparty::default(),
+        vec![Version::default()],
+        delay_period,
+    );
     let contract = CwIbcCoreContext::new();
 
-    contract
-        .store_last_processed_on(deps.as_mut().storage, &ctx.env, &ctx.client_id)
-        .unwrap();
-
-    ctx.env.block.height = 12344;
-
-    contract
-        .verify_connection_delay_passed(
-            deps.as_ref().storage,
-            ctx.env,
-            mock_height(1, 1).unwrap(),
-            ctx.connection_end.unwrap(),
-        )
-        .unwrap();
+    assert_eq!(
+        (
+            delay_period.as_nanos() as u64,
+            contract.calc_block_delay(&delay_period)
+        ),
+        contract.connection_delay_periods(&conn_end)
+    );
 }
 
 #[test]
@@ -828,30 +952,6 @@ fn test_update_connection_commitment() {
     assert!(res.is_ok())
 }
 
-#[test]
-fn test_check_connection() {
-    let ctx = TestContext::default(get_mock_env());
-    let mut deps = deps();
-    let contract = CwIbcCoreContext::new();
-
-    let res = contract.check_for_connection(&mut deps.storage, &ctx.client_id);
-    assert!(res.is_ok());
-}
-
-#[test]
-#[should_panic(expected = "IbcConnectionError { error: ConnectionExists(\"connection-0\") }")]
-fn fail_check_for_connection_on_connection_exist() {
-    let ctx = TestContext::default(get_mock_env());
-    let mut deps = mock_dependencies();
-    let contract = CwIbcCoreContext::new();
-
-    ctx.save_connection_to_client(deps.as_mut().storage, &contract);
-
-    contract
-        .check_for_connection(deps.as_ref().storage, &ctx.client_id)
-        .unwrap();
-}
-
 #[test]
 #[should_panic(expected = "Std(NotFound { kind: \"u64\" })")]
 fn test_connection_seq_on_a_fails_without_initialising() {
@@ -0,0 +1,241 @@
+use cosmwasm_std::{from_json, CosmosMsg};
+use cw_common::{
+    hex_string::HexString,
+    ica_msg::{
+        InterchainAccountPacketData, Metadata, ICA_CONTROLLER_PORT_PREFIX, ICA_HOST_PORT_ID,
+    },
+    raw_types::channel::{RawChannel, RawCounterparty, RawMsgChannelOpenInit, RawPacket},
+    types::Ack,
+    ProstMessage,
+};
+use debug_print::debug_println;
+
+use crate::types::{
+    account::{AccountState, InterchainAccount, TxStatus},
+    LOG_PREFIX,
+};
+
+use super::*;
+
+/// `STATE_INIT` of `ibc.core.channel.v1.State`.
+const RAW_STATE_INIT: i32 = 1;
+/// `ORDER_ORDERED` of `ibc.core.channel.v1.Order`.
+const RAW_ORDER_ORDERED: i32 = 2;
+
+impl<'a> CwIcaController<'a> {
+    /// This function starts the handshake of an ordered channel to the host for the interchain
+    /// account of the sender. The `icacontroller-{sender}` port is bound first if needed. A closed
+    /// account can be registered again, the host then reuses the same account.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `env`: `env` contains the address of this contract, the module of the port.
+    /// * `info`: `info` contains the sender, the owner of the account.
+    /// * `connection_id`: `connection_id` is the connection to the host chain.
+    /// * `counterparty_connection_id`: `counterparty_connection_id` is the same connection on the
+    /// host chain.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the `BindPort` and `ChannelOpenInit` messages.
+    pub fn register_account(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        connection_id: String,
+        counterparty_connection_id: String,
+    ) -> Result<Response, ContractError> {
+        let owner = info.sender;
+        let previous = self.may_get_account(deps.storage, &owner)?;
+        if let Some(account) = &previous {
+            if account.state != AccountState::Closed {
+                return Err(ContractError::AccountAlreadyRegistered {
+                    owner: owner.to_string(),
+                });
+            }
+        }
+
+        let port_id = format!("{ICA_CONTROLLER_PORT_PREFIX}{owner}");
+        let mut res = Response::new()
+            .add_attribute("action", "register_account")
+            .add_attribute("owner", owner.as_str())
+            .add_attribute("port_id", port_id.clone());
+        if !self.is_port_bound(deps.storage, &port_id) {
+            let ibc_host = self.get_ibc_host(deps.storage)?;
+            let bind_port = self.call_host_bind_port(&ibc_host, &port_id, &env.contract.address)?;
+            self.store_port_owner(deps.storage, &port_id, &owner)?;
+            res = res.add_message(bind_port);
+        }
+
+        let metadata = Metadata::new(&connection_id, &counterparty_connection_id);
+        let message = RawMsgChannelOpenInit {
+            port_id: port_id.clone(),
+            channel: Some(RawChannel {
+                state: RAW_STATE_INIT,
+                ordering: RAW_ORDER_ORDERED,
+                counterparty: Some(RawCounterparty {
+                    port_id: ICA_HOST_PORT_ID.to_string(),
+                    channel_id: String::new(),
+                }),
+                connection_hops: vec![connection_id.clone()],
+                version: metadata.encode()?,
+            }),
+            signer: env.contract.address.to_string(),
+        };
+        let open_init = self.call_host_channel_open_init(deps.storage, message)?;
+
+        let account = InterchainAccount {
+            port_id,
+            connection_id,
+            counterparty_connection_id,
+            channel_id: String::new(),
+            counterparty_channel_id: String::new(),
+            address: previous.map(|account| account.address).unwrap_or_default(),
+            state: AccountState::Pending,
+        };
+        self.store_account(deps.storage, &owner, &account)?;
+
+        Ok(res.add_message(open_init))
+    }
+
+    /// This function sends `msgs` as one transaction to be executed by the interchain account of
+    /// the sender.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and querier.
+    /// * `env`: `env` contains the current block time the packet timeout is computed from.
+    /// * `info`: `info` contains the sender, the owner of the account.
+    /// * `msgs`: `msgs` are the messages of the transaction, executed in order on the host chain.
+    /// * `memo`: `memo` is an optional memo of the packet.
+    /// * `timeout`: `timeout` in seconds, defaults to the configured default timeout.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the `SendPacket` message for the IBC host.
+    pub fn send_tx(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg>,
+        memo: Option<String>,
+        timeout: Option<u64>,
+    ) -> Result<Response, ContractError> {
+        let owner = info.sender;
+        let account = self.get_account(deps.storage, &owner)?;
+        if account.state != AccountState::Open {
+            return Err(ContractError::AccountNotOpen {
+                owner: owner.to_string(),
+            });
+        }
+        if msgs.is_empty() {
+            return Err(ContractError::EmptyTx {});
+        }
+
+        let packet_data = InterchainAccountPacketData::execute_tx(&msgs, memo.unwrap_or_default())?;
+        let timeout = match timeout {
+            Some(timeout) => timeout,
+            None => self.get_default_timeout(deps.storage)?,
+        };
+        let sequence =
+            self.query_host_sequence_no(deps.as_ref(), &account.port_id, &account.channel_id)?;
+
+        let packet = RawPacket {
+            sequence,
+            source_port: account.port_id.clone(),
+            source_channel: account.channel_id.clone(),
+            destination_port: ICA_HOST_PORT_ID.to_string(),
+            destination_channel: account.counterparty_channel_id.clone(),
+            data: packet_data.encode()?,
+            timeout_height: None,
+            timeout_timestamp: env.block.time.plus_seconds(timeout).nanos(),
+        };
+        debug_println!("{LOG_PREFIX} Raw Packet Created {:?}", &packet);
+
+        let send_packet = self.call_host_send_packet(
+            deps.storage,
+            HexString::from_bytes(&packet.encode_to_vec()),
+            vec![],
+        )?;
+        self.store_tx_status(deps.storage, &owner, sequence, &TxStatus::Pending)?;
+
+        Ok(Response::new()
+            .add_message(send_packet)
+            .add_attribute("action", "send_tx")
+            .add_attribute("owner", owner)
+            .add_attribute("channel_id", account.channel_id)
+            .add_attribute("sequence", sequence.to_string())
+            .add_attribute("msgs", msgs.len().to_string()))
+    }
+
+    /// This function records the outcome of a transaction from its acknowledgement.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the `TxStatus` is saved to.
+    /// * `msg`: `msg` is the `CwPacketAckMsg` carrying an `Ack`.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn on_packet_ack(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwPacketAckMsg,
+    ) -> Result<Response, ContractError> {
+        let packet = msg.original_packet;
+        let owner = self.get_port_owner(store, &packet.src.port_id)?;
+        let status = match from_json::<Ack>(&msg.acknowledgement.data) {
+            Ok(Ack::Result(result)) => TxStatus::Success { result },
+            Ok(Ack::Error(error)) => TxStatus::Error { error },
+            Err(e) => {
+                return Err(ContractError::DecodeFailed {
+                    error: e.to_string(),
+                })
+            }
+        };
+        let success = matches!(status, TxStatus::Success { .. });
+        self.store_tx_status(store, &owner, packet.sequence, &status)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_packet_ack")
+            .add_attribute("owner", owner)
+            .add_attribute("sequence", packet.sequence.to_string())
+            .add_attribute("success", success.to_string()))
+    }
+
+    /// This function records a transaction that timed out. The IBC host closes ordered channels on
+    /// timeout, the owner has to register the account again to send further transactions.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the `TxStatus` is saved to.
+    /// * `msg`: `msg` is the `CwPacketTimeoutMsg`.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn on_packet_timeout(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwPacketTimeoutMsg,
+    ) -> Result<Response, ContractError> {
+        let packet = msg.packet;
+        let owner = self.get_port_owner(store, &packet.src.port_id)?;
+        self.store_tx_status(store, &owner, packet.sequence, &TxStatus::Timeout)?;
+
+        let mut account = self.get_account(store, &owner)?;
+        if account.channel_id == packet.src.channel_id {
+            account.state = AccountState::Closed;
+            self.store_account(store, &owner, &account)?;
+        }
+
+        Ok(Response::new()
+            .add_attribute("method", "on_packet_timeout")
+            .add_attribute("owner", owner)
+            .add_attribute("sequence", packet.sequence.to_string()))
+    }
+}
@@ -0,0 +1,44 @@
+use cw_common::{
+    cw_types::{CwChannel, CwOrder},
+    ica_msg::{Metadata, ENCODING_JSON, ICA_HOST_PORT_ID, ICA_VERSION, TX_TYPE_SDK_MULTI_MSG},
+};
+
+use crate::error::ContractError;
+
+/// Interchain account packets are executed in order.
+pub const ICA_ORDERING: CwOrder = CwOrder::Ordered;
+
+pub fn check_order(order: &CwOrder) -> Result<(), ContractError> {
+    if order != &ICA_ORDERING {
+        Err(ContractError::InvalidChannelOrder {})
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the ordering and counterparty port of an interchain account channel.
+pub fn check_channel(channel: &CwChannel) -> Result<(), ContractError> {
+    if channel.counterparty_endpoint.port_id != ICA_HOST_PORT_ID {
+        return Err(ContractError::InvalidPortId {
+            port_id: channel.counterparty_endpoint.port_id.clone(),
+        });
+    }
+    check_order(&channel.order)
+}
+
+/// Checks a `Metadata` version of `channel` and returns it.
+pub fn check_metadata(channel: &CwChannel, version: &str) -> Result<Metadata, ContractError> {
+    let invalid = || ContractError::InvalidVersion {
+        version: version.to_string(),
+    };
+    let metadata = Metadata::decode(version).map_err(|_| invalid())?;
+
+    if metadata.version != ICA_VERSION
+        || metadata.encoding != ENCODING_JSON
+        || metadata.tx_type != TX_TYPE_SDK_MULTI_MSG
+        || metadata.controller_connection_id != channel.connection_id
+    {
+        return Err(invalid());
+    }
+    Ok(metadata)
+}
@@ -0,0 +1,292 @@
+use debug_print::debug_println;
+
+use crate::{
+    types::{account::AccountState, LOG_PREFIX},
+    MigrateMsg,
+};
+
+use super::*;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:cw-ica-controller";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl<'a> CwIcaController<'a> {
+    /// This function instantiates the contract. Ports are bound when owners register accounts.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `_env`: `_env` is not used.
+    /// * `info`: `info` contains the sender of the message, who becomes the owner of the contract.
+    /// * `msg`: `msg` is the `InstantiateMsg` with the IBC host and default timeout.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn instantiate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+        self.owner().save(deps.storage, &info.sender.to_string())?;
+        self.set_ibc_host(deps.storage, msg.ibc_host.clone())?;
+        self.set_default_timeout(deps.storage, msg.default_timeout)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "instantiate")
+            .add_attribute("method", "init")
+            .add_attribute("ibc_host", msg.ibc_host))
+    }
+
+    /// This function executes the messages supported by the controller. IBC callbacks are only
+    /// accepted from the IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+    /// querier of the contract.
+    /// * `env`: `env` contains information about the current block, used for packet timeouts.
+    /// * `info`: `info` contains the sender of the message.
+    /// * `msg`: `msg` is the `ExecuteMsg` to execute.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn execute(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::RegisterAccount {
+                connection_id,
+                counterparty_connection_id,
+            } => self.register_account(deps, env, info, connection_id, counterparty_connection_id),
+            ExecuteMsg::SendTx {
+                msgs,
+                memo,
+                timeout,
+            } => self.send_tx(deps, env, info, msgs, memo, timeout),
+            ExecuteMsg::UpdateDefaultTimeout { default_timeout } => {
+                self.ensure_owner(deps.storage, &info.sender)?;
+                self.set_default_timeout(deps.storage, default_timeout)?;
+                Ok(Response::new()
+                    .add_attribute("method", "update_default_timeout")
+                    .add_attribute("default_timeout", default_timeout.to_string()))
+            }
+
+            ExecuteMsg::IbcChannelOpen { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_open(deps.storage, msg)
+            }
+            ExecuteMsg::IbcChannelConnect { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_connect(deps.storage, msg)
+            }
+            ExecuteMsg::IbcChannelClose { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_close(deps.storage, msg)
+            }
+            ExecuteMsg::IbcPacketReceive { msg: _ } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                Err(ContractError::ReceiveNotSupported {})
+            }
+            ExecuteMsg::IbcPacketAck { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_ack(deps.storage, msg)
+            }
+            ExecuteMsg::IbcPacketTimeout { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_timeout(deps.storage, msg)
+            }
+        }
+    }
+
+    /// The `query` function answers the queries supported by the controller.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `Deps` object that provides read access to the storage and API.
+    /// * `_env`: `_env` is not used by any query.
+    /// * `msg`: `msg` is the `QueryMsg` to answer.
+    ///
+    /// Returns:
+    ///
+    /// a `StdResult<Binary>` holding the JSON encoded response of the query.
+    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::GetOwner {} => to_binary(&self.owner().load(deps.storage)?),
+            QueryMsg::GetIbcHost {} => to_binary(&self.get_ibc_host(deps.storage).map_err(to_std)?),
+            QueryMsg::GetDefaultTimeout {} => {
+                to_binary(&self.get_default_timeout(deps.storage).map_err(to_std)?)
+            }
+            QueryMsg::GetAccount { owner } => {
+                let owner = deps.api.addr_validate(&owner)?;
+                to_binary(&self.get_account(deps.storage, &owner).map_err(to_std)?)
+            }
+            QueryMsg::GetTxStatus { owner, sequence } => {
+                let owner = deps.api.addr_validate(&owner)?;
+                to_binary(
+                    &self
+                        .get_tx_status(deps.storage, &owner, sequence)
+                        .map_err(to_std)?,
+                )
+            }
+        }
+    }
+
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        _msg: MigrateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+            .map_err(ContractError::Std)?;
+        Ok(Response::default().add_attribute("migrate", "successful"))
+    }
+}
+
+impl<'a> CwIcaController<'a> {
+    /// This function validates the channel started by `register_account` and records its id on the
+    /// account of the port owner. Channels are never opened by the host.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the account is updated in.
+    /// * `msg`: `msg` is the `CwChannelOpenMsg`, only the init step is accepted.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn on_channel_open(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwChannelOpenMsg,
+    ) -> Result<Response, ContractError> {
+        debug_println!("{LOG_PREFIX} Called On channel open");
+        let channel = match msg {
+            CwChannelOpenMsg::OpenInit { channel } => channel,
+            CwChannelOpenMsg::OpenTry { .. } => return Err(ContractError::ChannelTryNotAllowed {}),
+        };
+        let owner = self.get_port_owner(store, &channel.endpoint.port_id)?;
+        check_channel(&channel)?;
+        check_metadata(&channel, &channel.version)?;
+
+        let mut account = self.get_account(store, &owner)?;
+        if account.state != AccountState::Pending || account.connection_id != channel.connection_id
+        {
+            return Err(ContractError::UnexpectedChannel {
+                channel_id: channel.endpoint.channel_id,
+            });
+        }
+        account.channel_id = channel.endpoint.channel_id.clone();
+        self.store_account(store, &owner, &account)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_open")
+            .add_attribute("owner", owner)
+            .add_attribute("channel_id", channel.endpoint.channel_id))
+    }
+
+    /// This function opens the account once the host accepted the channel. The host announces the
+    /// address of the account in its version when it knows it.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the account is updated in.
+    /// * `msg`: `msg` is the `CwChannelConnectMsg`, only the ack step is accepted.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn on_channel_connect(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwChannelConnectMsg,
+    ) -> Result<Response, ContractError> {
+        debug_println!("{LOG_PREFIX} Called On channel connect");
+        let (channel, counterparty_version) = match msg {
+            CwChannelConnectMsg::OpenAck {
+                channel,
+                counterparty_version,
+            } => (channel, counterparty_version),
+            CwChannelConnectMsg::OpenConfirm { .. } => {
+                return Err(ContractError::ChannelTryNotAllowed {})
+            }
+        };
+        let owner = self.get_port_owner(store, &channel.endpoint.port_id)?;
+        check_channel(&channel)?;
+        let metadata = check_metadata(&channel, &counterparty_version)?;
+
+        let mut account = self.get_account(store, &owner)?;
+        if account.channel_id != channel.endpoint.channel_id {
+            return Err(ContractError::UnexpectedChannel {
+                channel_id: channel.endpoint.channel_id,
+            });
+        }
+        account.counterparty_channel_id = channel.counterparty_endpoint.channel_id.clone();
+        if !metadata.address.is_empty() {
+            account.address = metadata.address;
+        }
+        account.state = AccountState::Open;
+        self.store_account(store, &owner, &account)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_connect")
+            .add_attribute("owner", owner)
+            .add_attribute("channel_id", account.channel_id)
+            .add_attribute("address", account.address))
+    }
+
+    /// This function closes the account when the host closed the channel. Closing is never initiated
+    /// by the controller, ordered channels close on their own when a transaction times out.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the account is updated in.
+    /// * `msg`: `msg` is the `CwChannelCloseMsg` of either the init or the confirm step.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`, failing for `CloseInit`.
+    pub fn on_channel_close(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwChannelCloseMsg,
+    ) -> Result<Response, ContractError> {
+        let channel = match msg {
+            CwChannelCloseMsg::CloseInit { channel: _ } => {
+                return Err(ContractError::ChannelCloseNotAllowed {})
+            }
+            CwChannelCloseMsg::CloseConfirm { channel } => channel,
+        };
+        let owner = self.get_port_owner(store, &channel.endpoint.port_id)?;
+        let mut account = self.get_account(store, &owner)?;
+        if account.channel_id == channel.endpoint.channel_id {
+            account.state = AccountState::Closed;
+            self.store_account(store, &owner, &account)?;
+        }
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_close")
+            .add_attribute("owner", owner)
+            .add_attribute("channel_id", channel.endpoint.channel_id))
+    }
+}
+
+fn to_std(error: ContractError) -> StdError {
+    match error {
+        ContractError::Std(error) => error,
+        error => StdError::NotFound {
+            kind: error.to_string(),
+        },
+    }
+}
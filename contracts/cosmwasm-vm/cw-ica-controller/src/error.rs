@@ -0,0 +1,35 @@
+use super::*;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("Unauthorized")]
+    Unauthorized {},
+    #[error("OnlyIbcHandler")]
+    OnlyIbcHandler {},
+    #[error("Channel order miss match")]
+    InvalidChannelOrder {},
+    #[error("InvalidPortId {port_id}")]
+    InvalidPortId { port_id: String },
+    #[error("InvalidVersion {version}")]
+    InvalidVersion { version: String },
+    #[error("Interchain account channels are opened by the controller")]
+    ChannelTryNotAllowed {},
+    #[error("UnexpectedChannel {channel_id}")]
+    UnexpectedChannel { channel_id: String },
+    #[error("ChannelCloseNotAllowed")]
+    ChannelCloseNotAllowed {},
+    #[error("AccountNotFound {owner}")]
+    AccountNotFound { owner: String },
+    #[error("AccountAlreadyRegistered {owner}")]
+    AccountAlreadyRegistered { owner: String },
+    #[error("AccountNotOpen {owner}")]
+    AccountNotOpen { owner: String },
+    #[error("The controller does not receive packets")]
+    ReceiveNotSupported {},
+    #[error("Transaction must contain at least one message")]
+    EmptyTx {},
+    #[error("DecodeFailed {error}")]
+    DecodeFailed { error: String },
+}
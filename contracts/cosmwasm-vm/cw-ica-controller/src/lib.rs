@@ -0,0 +1,108 @@
+pub mod account;
+pub mod assertion;
+pub mod check;
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+pub mod types;
+
+use crate::{
+    check::{check_channel, check_metadata},
+    error::ContractError,
+    msg::InstantiateMsg,
+    state::CwIcaController,
+    types::storage_keys::StorageKey,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary as to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult, Storage,
+};
+
+use cw2::set_contract_version;
+use cw_common::cw_types::{
+    CwChannelCloseMsg, CwChannelConnectMsg, CwChannelOpenMsg, CwPacketAckMsg, CwPacketReceiveMsg,
+    CwPacketTimeoutMsg,
+};
+use cw_common::ibc_host::IbcHostApp;
+
+use cw_storage_plus::{Item, Map};
+use msg::{ExecuteMsg, QueryMsg};
+use thiserror::Error;
+
+/// This function instantiates the interchain account controller.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+/// querier of the contract.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `info`: `info` contains the sender of the message, who becomes the owner of the contract.
+/// * `msg`: `msg` is the `InstantiateMsg` with the IBC host and default timeout.
+///
+/// Returns:
+///
+/// The `instantiate` function returns a `Result<Response, ContractError>`.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let controller = CwIcaController::default();
+
+    controller.instantiate(deps, env, info, msg)
+}
+
+/// This function executes a message against the interchain account controller.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+/// querier of the contract.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `info`: `info` contains the sender of the message and the funds attached to it.
+/// * `msg`: `msg` is the `ExecuteMsg` to execute.
+///
+/// Returns:
+///
+/// The `execute` function returns a `Result<Response, ContractError>`.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let controller = CwIcaController::default();
+
+    controller.execute(deps, env, info, msg)
+}
+
+/// This function answers queries against the interchain account controller.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `Deps` object that provides read access to the storage, API and querier.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `msg`: `msg` is the `QueryMsg` to answer.
+///
+/// Returns:
+///
+/// a `StdResult<Binary>` holding the JSON encoded response of the query.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let controller = CwIcaController::default();
+
+    controller.query(deps, env, msg)
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let controller = CwIcaController::default();
+    controller.migrate(deps, env, msg)
+}
@@ -0,0 +1,71 @@
+use super::*;
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::CosmosMsg;
+
+use crate::types::account::{InterchainAccount, TxStatus};
+
+/// This is a Rust struct representing a message to instantiate the interchain account controller.
+///
+/// Properties:
+///
+/// * `ibc_host`: address of the IBC host the controller ports are bound on.
+/// * `default_timeout`: timeout of transactions in seconds when none is given.
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub ibc_host: Addr,
+    pub default_timeout: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Opens an ordered channel to the host for the interchain account of the sender. Also used to
+    /// reopen the channel of the account once it was closed.
+    RegisterAccount {
+        connection_id: String,
+        counterparty_connection_id: String,
+    },
+    /// Executes `msgs` as one transaction from the interchain account of the sender. `timeout` is
+    /// given in seconds.
+    SendTx {
+        msgs: Vec<CosmosMsg>,
+        memo: Option<String>,
+        timeout: Option<u64>,
+    },
+    UpdateDefaultTimeout {
+        default_timeout: u64,
+    },
+
+    IbcChannelOpen {
+        msg: CwChannelOpenMsg,
+    },
+    IbcChannelConnect {
+        msg: CwChannelConnectMsg,
+    },
+    IbcChannelClose {
+        msg: CwChannelCloseMsg,
+    },
+    IbcPacketReceive {
+        msg: CwPacketReceiveMsg,
+    },
+    IbcPacketAck {
+        msg: CwPacketAckMsg,
+    },
+    IbcPacketTimeout {
+        msg: CwPacketTimeoutMsg,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(String)]
+    GetOwner {},
+    #[returns(Addr)]
+    GetIbcHost {},
+    #[returns(u64)]
+    GetDefaultTimeout {},
+    #[returns(InterchainAccount)]
+    GetAccount { owner: String },
+    #[returns(TxStatus)]
+    GetTxStatus { owner: String, sequence: u64 },
+}
@@ -0,0 +1,164 @@
+use crate::types::account::{InterchainAccount, TxStatus};
+
+use super::*;
+
+/// This is a Rust struct representing the ICS-27 interchain account controller.
+///
+/// Properties:
+///
+/// * `owner`: address of the owner of the contract.
+/// * `ibc_host`: address of the IBC host the controller ports are bound on.
+/// * `default_timeout`: timeout of transactions in seconds when none is given.
+/// * `accounts`: `InterchainAccount` of every owner, by owner address.
+/// * `port_owners`: owner of every bound `icacontroller-{owner}` port, by port id.
+/// * `tx_statuses`: `TxStatus` of every transaction sent, by owner and packet sequence.
+pub struct CwIcaController<'a> {
+    owner: Item<'a, String>,
+    ibc_host: Item<'a, Addr>,
+    default_timeout: Item<'a, u64>,
+    accounts: Map<'a, String, InterchainAccount>,
+    port_owners: Map<'a, String, Addr>,
+    tx_statuses: Map<'a, (String, u64), TxStatus>,
+}
+
+impl<'a> Default for CwIcaController<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CwIcaController<'a> {
+    pub fn new() -> Self {
+        Self {
+            owner: Item::new(StorageKey::Owner.as_str()),
+            ibc_host: Item::new(StorageKey::IbcHost.as_str()),
+            default_timeout: Item::new(StorageKey::DefaultTimeout.as_str()),
+            accounts: Map::new(StorageKey::Accounts.as_str()),
+            port_owners: Map::new(StorageKey::PortOwners.as_str()),
+            tx_statuses: Map::new(StorageKey::TxStatuses.as_str()),
+        }
+    }
+
+    pub fn owner(&self) -> &Item<'a, String> {
+        &self.owner
+    }
+
+    pub fn set_ibc_host(
+        &self,
+        store: &mut dyn Storage,
+        address: Addr,
+    ) -> Result<(), ContractError> {
+        self.ibc_host
+            .save(store, &address)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn set_default_timeout(
+        &self,
+        store: &mut dyn Storage,
+        timeout: u64,
+    ) -> Result<(), ContractError> {
+        self.default_timeout
+            .save(store, &timeout)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_default_timeout(&self, store: &dyn Storage) -> Result<u64, ContractError> {
+        self.default_timeout.load(store).map_err(ContractError::Std)
+    }
+
+    pub fn store_account(
+        &self,
+        store: &mut dyn Storage,
+        owner: &Addr,
+        account: &InterchainAccount,
+    ) -> Result<(), ContractError> {
+        self.accounts
+            .save(store, owner.to_string(), account)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_account(
+        &self,
+        store: &dyn Storage,
+        owner: &Addr,
+    ) -> Result<InterchainAccount, ContractError> {
+        self.accounts
+            .load(store, owner.to_string())
+            .map_err(|_| ContractError::AccountNotFound {
+                owner: owner.to_string(),
+            })
+    }
+
+    pub fn may_get_account(
+        &self,
+        store: &dyn Storage,
+        owner: &Addr,
+    ) -> Result<Option<InterchainAccount>, ContractError> {
+        self.accounts
+            .may_load(store, owner.to_string())
+            .map_err(ContractError::Std)
+    }
+
+    pub fn store_port_owner(
+        &self,
+        store: &mut dyn Storage,
+        port_id: &str,
+        owner: &Addr,
+    ) -> Result<(), ContractError> {
+        self.port_owners
+            .save(store, port_id.to_owned(), owner)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn is_port_bound(&self, store: &dyn Storage, port_id: &str) -> bool {
+        self.port_owners.has(store, port_id.to_owned())
+    }
+
+    pub fn get_port_owner(
+        &self,
+        store: &dyn Storage,
+        port_id: &str,
+    ) -> Result<Addr, ContractError> {
+        self.port_owners
+            .load(store, port_id.to_owned())
+            .map_err(|_| ContractError::InvalidPortId {
+                port_id: port_id.to_string(),
+            })
+    }
+
+    pub fn store_tx_status(
+        &self,
+        store: &mut dyn Storage,
+        owner: &Addr,
+        sequence: u64,
+        status: &TxStatus,
+    ) -> Result<(), ContractError> {
+        self.tx_statuses
+            .save(store, (owner.to_string(), sequence), status)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_tx_status(
+        &self,
+        store: &dyn Storage,
+        owner: &Addr,
+        sequence: u64,
+    ) -> Result<TxStatus, ContractError> {
+        self.tx_statuses
+            .load(store, (owner.to_string(), sequence))
+            .map_err(ContractError::Std)
+    }
+}
+
+impl<'a> IbcHostApp for CwIcaController<'a> {
+    type Error = ContractError;
+
+    fn get_ibc_host(&self, store: &dyn Storage) -> Result<Addr, ContractError> {
+        self.ibc_host.load(store).map_err(ContractError::Std)
+    }
+
+    fn only_ibc_handler_error(&self) -> ContractError {
+        ContractError::OnlyIbcHandler {}
+    }
+}
@@ -0,0 +1,47 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Binary;
+
+#[cw_serde]
+pub enum AccountState {
+    /// The channel handshake was started and has not completed yet.
+    Pending,
+    Open,
+    /// The channel was closed, the owner can open a new one for the same account.
+    Closed,
+}
+
+/// The `InterchainAccount` struct stores the interchain account of an owner.
+///
+/// Properties:
+///
+/// * `port_id`: `icacontroller-{owner}`, the port bound for the owner.
+/// * `connection_id`: connection the channel is built on.
+/// * `counterparty_connection_id`: connection on the host chain.
+/// * `channel_id`: channel of the account, empty until the handshake started.
+/// * `counterparty_channel_id`: channel on the host chain, empty until the handshake completed.
+/// * `address`: address of the account on the host chain, when announced by the host.
+/// * `state`: `AccountState` of the channel.
+#[cw_serde]
+pub struct InterchainAccount {
+    pub port_id: String,
+    pub connection_id: String,
+    pub counterparty_connection_id: String,
+    pub channel_id: String,
+    pub counterparty_channel_id: String,
+    pub address: String,
+    pub state: AccountState,
+}
+
+/// Outcome of a transaction sent to the interchain account.
+#[cw_serde]
+pub enum TxStatus {
+    Pending,
+    /// `result` is the JSON encoded `InterchainAccountTxResult` returned by the host.
+    Success {
+        result: Binary,
+    },
+    Error {
+        error: String,
+    },
+    Timeout,
+}
@@ -0,0 +1,4 @@
+pub mod account;
+pub mod storage_keys;
+
+pub const LOG_PREFIX: &str = "[ica_controller]:";
@@ -0,0 +1,24 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum StorageKey {
+    Owner,
+    IbcHost,
+    DefaultTimeout,
+    Accounts,
+    PortOwners,
+    TxStatuses,
+}
+
+impl StorageKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKey::Owner => "owner",
+            StorageKey::IbcHost => "ibc_host",
+            StorageKey::DefaultTimeout => "default_timeout",
+            StorageKey::Accounts => "accounts",
+            StorageKey::PortOwners => "port_owners",
+            StorageKey::TxStatuses => "tx_statuses",
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use cosmwasm_std::{
+    testing::{mock_env, mock_info},
+    Addr, DepsMut, IbcChannel, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacket,
+};
+use cw_common::ica_msg::{Metadata, ICA_CONTROLLER_PORT_PREFIX, ICA_HOST_PORT_ID};
+use cw_ica_controller::{
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::CwIcaController,
+};
+pub use test_utils::ibc_app::{deps, ChannelEnds, IBC_HOST, NEXT_SEQUENCE, OWNER};
+
+pub const USER: &str = "user";
+pub const CONNECTION_ID: &str = "connection-0";
+pub const COUNTERPARTY_CONNECTION_ID: &str = "connection-3";
+pub const CHANNEL_ID: &str = "channel-0";
+pub const COUNTERPARTY_CHANNEL_ID: &str = "channel-7";
+pub const ACCOUNT_ADDRESS: &str = "archway1account";
+pub const DEFAULT_TIMEOUT: u64 = 600;
+
+pub fn port_id() -> String {
+    format!("{ICA_CONTROLLER_PORT_PREFIX}{USER}")
+}
+
+pub fn metadata() -> Metadata {
+    Metadata::new(CONNECTION_ID, COUNTERPARTY_CONNECTION_ID)
+}
+
+pub fn channel_ends() -> ChannelEnds {
+    ChannelEnds::new(
+        &port_id(),
+        CHANNEL_ID,
+        ICA_HOST_PORT_ID,
+        COUNTERPARTY_CHANNEL_ID,
+    )
+}
+
+pub fn get_channel(version: &str) -> IbcChannel {
+    channel_ends().channel(IbcOrder::Ordered, version, CONNECTION_ID)
+}
+
+/// Instantiates the contract with `IBC_HOST` as host.
+pub fn setup_contract(deps: DepsMut) -> CwIcaController<'static> {
+    let contract = CwIcaController::default();
+    contract
+        .instantiate(
+            deps,
+            mock_env(),
+            mock_info(OWNER, &[]),
+            InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                default_timeout: DEFAULT_TIMEOUT,
+            },
+        )
+        .unwrap();
+    contract
+}
+
+pub fn register_account(deps: DepsMut, contract: &CwIcaController) {
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info(USER, &[]),
+            ExecuteMsg::RegisterAccount {
+                connection_id: CONNECTION_ID.to_string(),
+                counterparty_connection_id: COUNTERPARTY_CONNECTION_ID.to_string(),
+            },
+        )
+        .unwrap();
+}
+
+/// Registers the account of `USER` and completes the handshake of its channel, the host announcing
+/// `ACCOUNT_ADDRESS`.
+pub fn open_account(mut deps: DepsMut, contract: &CwIcaController) {
+    register_account(deps.branch(), contract);
+    let version = metadata().encode().unwrap();
+    contract
+        .execute(
+            deps.branch(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenInit {
+                    channel: get_channel(&version),
+                },
+            },
+        )
+        .unwrap();
+
+    let mut counterparty_metadata = metadata();
+    counterparty_metadata.address = ACCOUNT_ADDRESS.to_string();
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelConnect {
+                msg: IbcChannelConnectMsg::OpenAck {
+                    channel: get_channel(&version),
+                    counterparty_version: counterparty_metadata.encode().unwrap(),
+                },
+            },
+        )
+        .unwrap();
+}
+
+/// Builds a packet sent from the account of `USER`.
+pub fn outgoing_packet(sequence: u64) -> IbcPacket {
+    channel_ends().outgoing_packet(b"{}".to_vec(), sequence)
+}
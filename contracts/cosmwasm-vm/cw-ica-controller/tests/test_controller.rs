@@ -0,0 +1,316 @@
+pub mod setup;
+
+use cosmwasm_std::{
+    coins, from_json,
+    testing::{mock_env, mock_info},
+    to_json_binary as to_binary, Addr, BankMsg, Binary, CosmosMsg, DepsMut, IbcAcknowledgement,
+    IbcChannelCloseMsg, IbcChannelOpenMsg, IbcPacketAckMsg, IbcPacketTimeoutMsg, Response, WasmMsg,
+};
+use cw_common::{
+    core_msg::ExecuteMsg as CoreExecuteMsg,
+    ica_msg::{InterchainAccountPacketData, Metadata, ICA_HOST_PORT_ID},
+    raw_types::channel::{RawMsgChannelOpenInit, RawPacket},
+    types::Ack,
+    ProstMessage,
+};
+use cw_ica_controller::{
+    error::ContractError,
+    msg::ExecuteMsg,
+    state::CwIcaController,
+    types::account::{AccountState, TxStatus},
+};
+
+use setup::*;
+
+fn bank_msgs() -> Vec<CosmosMsg> {
+    vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: "receiver".to_string(),
+        amount: coins(10, "uarch"),
+    })]
+}
+
+fn host_msgs(res: &Response) -> Vec<CoreExecuteMsg> {
+    res.messages
+        .iter()
+        .map(|sub| match &sub.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, IBC_HOST);
+                from_json(msg).unwrap()
+            }
+            _ => panic!("expected wasm message"),
+        })
+        .collect()
+}
+
+fn send_tx(deps: DepsMut, contract: &CwIcaController) -> Result<Response, ContractError> {
+    contract.execute(
+        deps,
+        mock_env(),
+        mock_info(USER, &[]),
+        ExecuteMsg::SendTx {
+            msgs: bank_msgs(),
+            memo: Some("memo".to_string()),
+            timeout: None,
+        },
+    )
+}
+
+fn ack(deps: DepsMut, contract: &CwIcaController, ack: &Ack) -> Response {
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketAck {
+                msg: IbcPacketAckMsg::new(
+                    IbcAcknowledgement::new(to_binary(ack).unwrap()),
+                    outgoing_packet(NEXT_SEQUENCE),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_register_account_opens_ordered_channel() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            ExecuteMsg::RegisterAccount {
+                connection_id: CONNECTION_ID.to_string(),
+                counterparty_connection_id: COUNTERPARTY_CONNECTION_ID.to_string(),
+            },
+        )
+        .unwrap();
+
+    let msgs = host_msgs(&res);
+    assert_eq!(msgs.len(), 2);
+    match &msgs[0] {
+        CoreExecuteMsg::BindPort { port_id, address } => {
+            assert_eq!(port_id, &setup::port_id());
+            assert_eq!(address, mock_env().contract.address.as_str());
+        }
+        _ => panic!("expected BindPort"),
+    }
+    match &msgs[1] {
+        CoreExecuteMsg::ChannelOpenInit { msg } => {
+            let message =
+                RawMsgChannelOpenInit::decode(msg.to_bytes().unwrap().as_slice()).unwrap();
+            let channel = message.channel.unwrap();
+            assert_eq!(message.port_id, setup::port_id());
+            assert_eq!(channel.ordering, 2);
+            assert_eq!(channel.connection_hops, vec![CONNECTION_ID.to_string()]);
+            assert_eq!(channel.counterparty.unwrap().port_id, ICA_HOST_PORT_ID);
+            assert_eq!(Metadata::decode(&channel.version).unwrap(), metadata());
+        }
+        _ => panic!("expected ChannelOpenInit"),
+    }
+
+    let account = contract
+        .get_account(deps.as_ref().storage, &Addr::unchecked(USER))
+        .unwrap();
+    assert_eq!(account.state, AccountState::Pending);
+}
+
+#[test]
+fn test_register_account_twice() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    register_account(deps.as_mut(), &contract);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            ExecuteMsg::RegisterAccount {
+                connection_id: CONNECTION_ID.to_string(),
+                counterparty_connection_id: COUNTERPARTY_CONNECTION_ID.to_string(),
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ContractError::AccountAlreadyRegistered { .. }
+    ));
+}
+
+#[test]
+fn test_open_account_records_address() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_account(deps.as_mut(), &contract);
+
+    let account = contract
+        .get_account(deps.as_ref().storage, &Addr::unchecked(USER))
+        .unwrap();
+    assert_eq!(account.state, AccountState::Open);
+    assert_eq!(account.channel_id, CHANNEL_ID);
+    assert_eq!(account.counterparty_channel_id, COUNTERPARTY_CHANNEL_ID);
+    assert_eq!(account.address, ACCOUNT_ADDRESS);
+}
+
+#[test]
+fn test_open_unordered_channel_fails() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    register_account(deps.as_mut(), &contract);
+    let mut channel = get_channel(&metadata().encode().unwrap());
+    channel.order = cosmwasm_std::IbcOrder::Unordered;
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenInit { channel },
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::InvalidChannelOrder {}));
+}
+
+#[test]
+fn test_send_tx_requires_open_account() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    register_account(deps.as_mut(), &contract);
+    let err = send_tx(deps.as_mut(), &contract).unwrap_err();
+
+    assert!(matches!(err, ContractError::AccountNotOpen { .. }));
+}
+
+#[test]
+fn test_send_tx_packet() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_account(deps.as_mut(), &contract);
+    let res = send_tx(deps.as_mut(), &contract).unwrap();
+
+    let packet = match &host_msgs(&res)[0] {
+        CoreExecuteMsg::SendPacket { packet } => {
+            RawPacket::decode(packet.to_bytes().unwrap().as_slice()).unwrap()
+        }
+        _ => panic!("expected SendPacket"),
+    };
+    assert_eq!(packet.sequence, NEXT_SEQUENCE);
+    assert_eq!(packet.source_port, setup::port_id());
+    assert_eq!(packet.destination_port, ICA_HOST_PORT_ID);
+    assert_eq!(packet.destination_channel, COUNTERPARTY_CHANNEL_ID);
+    assert_eq!(
+        packet.timeout_timestamp,
+        mock_env().block.time.plus_seconds(DEFAULT_TIMEOUT).nanos()
+    );
+
+    let data = InterchainAccountPacketData::decode(&packet.data).unwrap();
+    assert_eq!(data.memo, "memo");
+    assert_eq!(data.messages().unwrap(), bank_msgs());
+
+    let status = contract
+        .get_tx_status(deps.as_ref().storage, &Addr::unchecked(USER), NEXT_SEQUENCE)
+        .unwrap();
+    assert_eq!(status, TxStatus::Pending);
+}
+
+#[test]
+fn test_ack_records_tx_status() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_account(deps.as_mut(), &contract);
+    send_tx(deps.as_mut(), &contract).unwrap();
+
+    let result = Binary::from(br#"{"results":[""]}"#.to_vec());
+    ack(deps.as_mut(), &contract, &Ack::Result(result.clone()));
+    let status = contract
+        .get_tx_status(deps.as_ref().storage, &Addr::unchecked(USER), NEXT_SEQUENCE)
+        .unwrap();
+    assert_eq!(status, TxStatus::Success { result });
+
+    ack(deps.as_mut(), &contract, &Ack::Error("failed".to_string()));
+    let status = contract
+        .get_tx_status(deps.as_ref().storage, &Addr::unchecked(USER), NEXT_SEQUENCE)
+        .unwrap();
+    assert_eq!(
+        status,
+        TxStatus::Error {
+            error: "failed".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_timeout_closes_account() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_account(deps.as_mut(), &contract);
+    send_tx(deps.as_mut(), &contract).unwrap();
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketTimeout {
+                msg: IbcPacketTimeoutMsg::new(
+                    outgoing_packet(NEXT_SEQUENCE),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    let owner = Addr::unchecked(USER);
+    let status = contract
+        .get_tx_status(deps.as_ref().storage, &owner, NEXT_SEQUENCE)
+        .unwrap();
+    assert_eq!(status, TxStatus::Timeout);
+    let account = contract.get_account(deps.as_ref().storage, &owner).unwrap();
+    assert_eq!(account.state, AccountState::Closed);
+
+    // the port is already bound, only the handshake is started again
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            ExecuteMsg::RegisterAccount {
+                connection_id: CONNECTION_ID.to_string(),
+                counterparty_connection_id: COUNTERPARTY_CONNECTION_ID.to_string(),
+            },
+        )
+        .unwrap();
+    let msgs = host_msgs(&res);
+    assert_eq!(msgs.len(), 1);
+    assert!(matches!(msgs[0], CoreExecuteMsg::ChannelOpenInit { .. }));
+    let account = contract.get_account(deps.as_ref().storage, &owner).unwrap();
+    assert_eq!(account.address, ACCOUNT_ADDRESS);
+}
+
+#[test]
+fn test_channel_close_init_not_allowed() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_account(deps.as_mut(), &contract);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelClose {
+                msg: IbcChannelCloseMsg::CloseInit {
+                    channel: get_channel(&metadata().encode().unwrap()),
+                },
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::ChannelCloseNotAllowed {}));
+}
@@ -0,0 +1,390 @@
+use cosmwasm_std::{SubMsg, SubMsgResult, WasmMsg};
+use cw_common::ica_msg::{ProxyInstantiateMsg, ICA_CONTROLLER_PORT_PREFIX, ICA_HOST_PORT_ID};
+use debug_print::debug_println;
+
+use crate::{
+    state::{AccountKey, ChannelInfo, EXECUTE_TX_REPLY_ID, INSTANTIATE_PROXY_REPLY_ID},
+    types::LOG_PREFIX,
+    MigrateMsg,
+};
+
+use super::*;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:cw-ica-host";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl<'a> CwIcaHost<'a> {
+    /// This function instantiates the contract and binds the `icahost` port on the IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `env`: `env` contains the address of this contract, which becomes the module of the port.
+    /// * `info`: `info` contains the sender of the message, who becomes the owner of the contract.
+    /// * `msg`: `msg` is the `InstantiateMsg` with the IBC host and the code id of the proxy.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` carrying the `BindPort` message for the IBC host.
+    pub fn instantiate(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+        self.owner().save(deps.storage, &info.sender.to_string())?;
+        self.set_ibc_host(deps.storage, msg.ibc_host.clone())?;
+        self.set_proxy_code_id(deps.storage, msg.proxy_code_id)?;
+
+        let bind_port =
+            self.call_host_bind_port(&msg.ibc_host, ICA_HOST_PORT_ID, &env.contract.address)?;
+
+        Ok(Response::new()
+            .add_message(bind_port)
+            .add_attribute("action", "instantiate")
+            .add_attribute("method", "init")
+            .add_attribute("ibc_host", msg.ibc_host)
+            .add_attribute("port_id", ICA_HOST_PORT_ID))
+    }
+
+    /// This function executes the messages supported by the host. IBC callbacks are only accepted
+    /// from the IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `env`: `env` contains the address of this contract, the admin of the proxies.
+    /// * `info`: `info` contains the sender of the message.
+    /// * `msg`: `msg` is the `ExecuteMsg` to execute.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn execute(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::UpdateProxyCodeId { proxy_code_id } => {
+                self.ensure_owner(deps.storage, &info.sender)?;
+                self.set_proxy_code_id(deps.storage, proxy_code_id)?;
+                Ok(Response::new()
+                    .add_attribute("method", "update_proxy_code_id")
+                    .add_attribute("proxy_code_id", proxy_code_id.to_string()))
+            }
+
+            ExecuteMsg::IbcChannelOpen { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_open(deps, env, msg)
+            }
+            ExecuteMsg::IbcChannelConnect { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_connect(deps.storage, msg)
+            }
+            ExecuteMsg::IbcChannelClose { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_close(deps.storage, msg)
+            }
+            ExecuteMsg::IbcPacketReceive { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_receive(deps, msg)
+            }
+            ExecuteMsg::IbcPacketAck { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_ack(msg)
+            }
+            ExecuteMsg::IbcPacketTimeout { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_timeout(msg)
+            }
+        }
+    }
+
+    /// The `query` function answers the queries supported by the host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `Deps` object that provides read access to the storage.
+    /// * `_env`: `_env` is not used by any query.
+    /// * `msg`: `msg` is the `QueryMsg` to answer.
+    ///
+    /// Returns:
+    ///
+    /// a `StdResult<Binary>` holding the JSON encoded response of the query.
+    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::GetOwner {} => to_binary(&self.owner().load(deps.storage)?),
+            QueryMsg::GetIbcHost {} => to_binary(&self.get_ibc_host(deps.storage).map_err(to_std)?),
+            QueryMsg::GetProxyCodeId {} => {
+                to_binary(&self.get_proxy_code_id(deps.storage).map_err(to_std)?)
+            }
+            QueryMsg::GetInterchainAccount {
+                connection_id,
+                counterparty_port_id,
+            } => {
+                let account = AccountKey {
+                    connection_id,
+                    counterparty_port_id,
+                };
+                to_binary(&self.get_account(deps.storage, &account).map_err(to_std)?)
+            }
+            QueryMsg::GetChannel { channel_id } => to_binary(
+                &self
+                    .get_channel(deps.storage, &channel_id)
+                    .map_err(to_std)?,
+            ),
+        }
+    }
+
+    /// This function handles the replies of the proxies.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and API.
+    /// * `_env`: `_env` is not used.
+    /// * `msg`: `msg` is the `Reply` of a proxy instantiation or transaction.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn reply(&self, deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+        match msg.id {
+            INSTANTIATE_PROXY_REPLY_ID => self.on_instantiate_proxy_reply(deps, msg),
+            EXECUTE_TX_REPLY_ID => self.on_execute_tx_reply(deps, msg),
+            _ => Err(ContractError::ReplyError {
+                code: msg.id,
+                msg: "Unknown".to_string(),
+            }),
+        }
+    }
+
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        _msg: MigrateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+            .map_err(ContractError::Std)?;
+        Ok(Response::default().add_attribute("migrate", "successful"))
+    }
+}
+
+impl<'a> CwIcaHost<'a> {
+    /// This function validates a channel opened by a controller and registers the interchain
+    /// account of the controller port on first use. The account is a proxy contract instantiated by
+    /// the host, later channels of the same controller port reuse it.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `env`: `env` contains the address of this contract, the admin of the proxy.
+    /// * `msg`: `msg` is the `CwChannelOpenMsg`, only the try step is accepted.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` instantiating the proxy for new accounts.
+    pub fn on_channel_open(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        msg: CwChannelOpenMsg,
+    ) -> Result<Response, ContractError> {
+        debug_println!("{LOG_PREFIX} Called On channel open");
+        let (channel, counterparty_version) = match msg {
+            CwChannelOpenMsg::OpenInit { channel: _ } => {
+                return Err(ContractError::ChannelInitNotAllowed {})
+            }
+            CwChannelOpenMsg::OpenTry {
+                channel,
+                counterparty_version,
+            } => (channel, counterparty_version),
+        };
+        let account = check_channel(&channel)?;
+        check_metadata(&channel, &channel.version)?;
+        check_metadata(&channel, &counterparty_version)?;
+
+        if let Some(channel_id) = self.get_active_channel(deps.storage, &account)? {
+            return Err(ContractError::ActiveChannelExists { channel_id });
+        }
+
+        let res = Response::new()
+            .add_attribute("method", "on_channel_open")
+            .add_attribute("connection_id", account.connection_id.clone())
+            .add_attribute("counterparty_port_id", account.counterparty_port_id.clone());
+        if self.has_account(deps.storage, &account) {
+            return Ok(res);
+        }
+
+        let instantiate = WasmMsg::Instantiate {
+            admin: Some(env.contract.address.to_string()),
+            code_id: self.get_proxy_code_id(deps.storage)?,
+            msg: to_binary(&ProxyInstantiateMsg {})?,
+            funds: vec![],
+            label: format!(
+                "ica-{}-{}",
+                account.connection_id, account.counterparty_port_id
+            ),
+        };
+        self.store_pending_account(deps.storage, &account)?;
+
+        Ok(res.add_submessage(SubMsg::reply_on_success(
+            instantiate,
+            INSTANTIATE_PROXY_REPLY_ID,
+        )))
+    }
+
+    /// This function records the address of a newly instantiated proxy as interchain account.
+    pub fn on_instantiate_proxy_reply(
+        &self,
+        deps: DepsMut,
+        msg: Reply,
+    ) -> Result<Response, ContractError> {
+        let response = match msg.result {
+            SubMsgResult::Ok(response) => response,
+            SubMsgResult::Err(error) => {
+                return Err(ContractError::ReplyError {
+                    code: msg.id,
+                    msg: error,
+                })
+            }
+        };
+        let address = response
+            .events
+            .iter()
+            .filter(|event| event.ty == "instantiate")
+            .flat_map(|event| event.attributes.iter())
+            .find(|attribute| attribute.key == "_contract_address")
+            .map(|attribute| attribute.value.clone())
+            .ok_or(ContractError::ReplyError {
+                code: msg.id,
+                msg: "Missing contract address".to_string(),
+            })?;
+        let address = deps.api.addr_validate(&address)?;
+
+        let account = self.take_pending_account(deps.storage)?;
+        self.store_account(deps.storage, &account, &address)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "register_account")
+            .add_attribute("connection_id", account.connection_id)
+            .add_attribute("counterparty_port_id", account.counterparty_port_id)
+            .add_attribute("account", address))
+    }
+
+    /// This function makes the channel the active channel of its interchain account once the
+    /// handshake completed. Packets are only accepted on active channels. Several channels of an
+    /// account can pass the try step before any of them is confirmed, only the first one confirmed
+    /// becomes active.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the `ChannelInfo` is saved to.
+    /// * `msg`: `msg` is the `CwChannelConnectMsg`, only the confirm step is accepted.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn on_channel_connect(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwChannelConnectMsg,
+    ) -> Result<Response, ContractError> {
+        debug_println!("{LOG_PREFIX} Called On channel connect");
+        let channel = match msg {
+            CwChannelConnectMsg::OpenAck { .. } => {
+                return Err(ContractError::ChannelInitNotAllowed {})
+            }
+            CwChannelConnectMsg::OpenConfirm { channel } => channel,
+        };
+        let account = check_channel(&channel)?;
+        let address = self.get_account(store, &account)?;
+        if let Some(channel_id) = self.get_active_channel(store, &account)? {
+            return Err(ContractError::ActiveChannelExists { channel_id });
+        }
+
+        let channel_id = channel.endpoint.channel_id.clone();
+        self.store_channel(
+            store,
+            &channel_id,
+            &ChannelInfo {
+                account,
+                counterparty_channel_id: channel.counterparty_endpoint.channel_id,
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_connect")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("account", address))
+    }
+
+    /// This function handles channel closing. Ordered channels close when a packet times out on
+    /// the controller, the controller can then open a new channel for the same account. Closing is
+    /// never initiated by the host.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the channel is removed from.
+    /// * `msg`: `msg` is the `CwChannelCloseMsg` of either the init or the confirm step.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`, failing for `CloseInit`.
+    pub fn on_channel_close(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwChannelCloseMsg,
+    ) -> Result<Response, ContractError> {
+        let channel = match msg {
+            CwChannelCloseMsg::CloseInit { channel: _ } => {
+                return Err(ContractError::ChannelCloseNotAllowed {})
+            }
+            CwChannelCloseMsg::CloseConfirm { channel } => channel,
+        };
+        let channel_id = channel.endpoint.channel_id;
+        self.remove_channel(store, &channel_id)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_close")
+            .add_attribute("channel_id", channel_id))
+    }
+}
+
+/// Checks the ports and ordering of an interchain account channel and returns the `AccountKey` of
+/// the controller port.
+fn check_channel(channel: &CwChannel) -> Result<AccountKey, ContractError> {
+    if channel.endpoint.port_id != ICA_HOST_PORT_ID {
+        return Err(ContractError::InvalidPortId {
+            port_id: channel.endpoint.port_id.clone(),
+        });
+    }
+    let counterparty_port_id = &channel.counterparty_endpoint.port_id;
+    if !counterparty_port_id.starts_with(ICA_CONTROLLER_PORT_PREFIX) {
+        return Err(ContractError::InvalidPortId {
+            port_id: counterparty_port_id.clone(),
+        });
+    }
+    check_order(&channel.order)?;
+
+    Ok(AccountKey {
+        connection_id: channel.connection_id.clone(),
+        counterparty_port_id: counterparty_port_id.clone(),
+    })
+}
+
+fn to_std(error: ContractError) -> StdError {
+    match error {
+        ContractError::Std(error) => error,
+        error => StdError::NotFound {
+            kind: error.to_string(),
+        },
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("Unauthorized")]
+    Unauthorized {},
+    #[error("OnlyIbcHandler")]
+    OnlyIbcHandler {},
+    #[error("ERR_REPLY_ERROR|{code:?}|{msg:?}")]
+    ReplyError { code: u64, msg: String },
+    #[error("Channel order miss match")]
+    InvalidChannelOrder {},
+    #[error("InvalidPortId {port_id}")]
+    InvalidPortId { port_id: String },
+    #[error("InvalidVersion {version}")]
+    InvalidVersion { version: String },
+    #[error("Interchain account channels are opened by the controller")]
+    ChannelInitNotAllowed {},
+    #[error("ChannelCloseNotAllowed")]
+    ChannelCloseNotAllowed {},
+    #[error("ActiveChannelExists {channel_id}")]
+    ActiveChannelExists { channel_id: String },
+    #[error("ChannelNotFound {channel_id}")]
+    ChannelNotFound { channel_id: String },
+    #[error("AccountNotFound {connection_id} {port_id}")]
+    AccountNotFound {
+        connection_id: String,
+        port_id: String,
+    },
+    #[error("UnsupportedPacketType {packet_type}")]
+    UnsupportedPacketType { packet_type: String },
+    #[error("DecodeFailed {error}")]
+    DecodeFailed { error: String },
+}
@@ -0,0 +1,63 @@
+use super::*;
+use cosmwasm_schema::QueryResponses;
+
+use crate::state::ChannelInfo;
+
+/// This is a Rust struct representing a message to instantiate the interchain account host.
+///
+/// Properties:
+///
+/// * `ibc_host`: address of the IBC host the `icahost` port is bound on.
+/// * `proxy_code_id`: code id of `cw-ica-proxy`, instantiated once per interchain account.
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub ibc_host: Addr,
+    pub proxy_code_id: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Code id used for interchain accounts registered from now on. Only the owner can update it.
+    UpdateProxyCodeId {
+        proxy_code_id: u64,
+    },
+
+    IbcChannelOpen {
+        msg: CwChannelOpenMsg,
+    },
+    IbcChannelConnect {
+        msg: CwChannelConnectMsg,
+    },
+    IbcChannelClose {
+        msg: CwChannelCloseMsg,
+    },
+    IbcPacketReceive {
+        msg: CwPacketReceiveMsg,
+    },
+    IbcPacketAck {
+        msg: CwPacketAckMsg,
+    },
+    IbcPacketTimeout {
+        msg: CwPacketTimeoutMsg,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(String)]
+    GetOwner {},
+    #[returns(Addr)]
+    GetIbcHost {},
+    #[returns(u64)]
+    GetProxyCodeId {},
+    /// Interchain account of the controller port `counterparty_port_id` on the host connection
+    /// `connection_id`.
+    #[returns(Addr)]
+    GetInterchainAccount {
+        connection_id: String,
+        counterparty_port_id: String,
+    },
+    #[returns(ChannelInfo)]
+    GetChannel { channel_id: String },
+}
@@ -0,0 +1,103 @@
+use cosmwasm_std::{SubMsg, SubMsgResult, WasmMsg};
+use cw_common::{
+    ibc_host::packet_receive_error_event,
+    ica_msg::{InterchainAccountPacketData, ProxyExecuteMsg, TYPE_EXECUTE_TX},
+    types::Ack,
+};
+use debug_print::debug_println;
+
+use crate::{state::EXECUTE_TX_REPLY_ID, types::LOG_PREFIX};
+
+use super::*;
+
+impl<'a> CwIcaHost<'a> {
+    /// This function executes the transaction carried by a packet from the interchain account of the
+    /// channel. The acknowledgement is written in `on_execute_tx_reply` once the proxy answered.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `msg`: `msg` is the `CwPacketReceiveMsg` carrying the `InterchainAccountPacketData`.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the submessage to the proxy. Failing to decode the
+    /// packet returns an error, which the IBC host turns into an error acknowledgement.
+    pub fn on_packet_receive(
+        &self,
+        deps: DepsMut,
+        msg: CwPacketReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let channel_id = &msg.packet.dest.channel_id;
+        let channel = self.get_channel(deps.storage, channel_id)?;
+        let proxy = self.get_account(deps.storage, &channel.account)?;
+
+        let packet_data = InterchainAccountPacketData::decode(&msg.packet.data).map_err(|e| {
+            ContractError::DecodeFailed {
+                error: e.to_string(),
+            }
+        })?;
+        if packet_data.packet_type != TYPE_EXECUTE_TX {
+            return Err(ContractError::UnsupportedPacketType {
+                packet_type: packet_data.packet_type,
+            });
+        }
+        let msgs = packet_data
+            .messages()
+            .map_err(|e| ContractError::DecodeFailed {
+                error: e.to_string(),
+            })?;
+        debug_println!(
+            "{LOG_PREFIX} Executing {} messages from {proxy}",
+            msgs.len()
+        );
+
+        self.store_pending_packet(deps.storage, &msg.packet)?;
+
+        let execute = WasmMsg::Execute {
+            contract_addr: proxy.to_string(),
+            msg: to_binary(&ProxyExecuteMsg::Execute { msgs })?,
+            funds: vec![],
+        };
+        Ok(Response::new()
+            .add_submessage(SubMsg::reply_always(execute, EXECUTE_TX_REPLY_ID))
+            .add_attribute("method", "on_packet_receive")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("sequence", msg.packet.sequence.to_string())
+            .add_attribute("account", proxy))
+    }
+
+    /// This function turns the outcome of a transaction into the acknowledgement of its packet. A
+    /// failed transaction is reverted and acknowledged with the fixed error acknowledgement, its
+    /// error is emitted in an event.
+    pub fn on_execute_tx_reply(
+        &self,
+        deps: DepsMut,
+        msg: Reply,
+    ) -> Result<Response, ContractError> {
+        let packet = self.take_pending_packet(deps.storage)?;
+        let res = Response::new().add_attribute("method", "on_execute_tx_reply");
+        match msg.result {
+            SubMsgResult::Ok(response) => Ok(res
+                .set_data(to_binary(&Ack::Result(response.data.unwrap_or_default()))?)
+                .add_attribute("success", true.to_string())),
+            SubMsgResult::Err(error) => {
+                debug_println!("{LOG_PREFIX} Transaction failed {error}");
+                Ok(res
+                    .set_data(to_binary(&Ack::module_error())?)
+                    .add_attribute("success", false.to_string())
+                    .add_event(packet_receive_error_event(&packet, &error)))
+            }
+        }
+    }
+
+    /// The host never sends packets, acknowledgements are not expected.
+    pub fn on_packet_ack(&self, _msg: CwPacketAckMsg) -> Result<Response, ContractError> {
+        Ok(Response::new().add_attribute("method", "on_packet_ack"))
+    }
+
+    /// The host never sends packets, timeouts are not expected.
+    pub fn on_packet_timeout(&self, _msg: CwPacketTimeoutMsg) -> Result<Response, ContractError> {
+        Ok(Response::new().add_attribute("method", "on_packet_timeout"))
+    }
+}
@@ -0,0 +1,227 @@
+use super::*;
+
+pub const INSTANTIATE_PROXY_REPLY_ID: u64 = 1;
+pub const EXECUTE_TX_REPLY_ID: u64 = 2;
+
+/// The `AccountKey` struct identifies an interchain account: the host connection and the port of
+/// the controller owning the account.
+#[cw_serde]
+pub struct AccountKey {
+    pub connection_id: String,
+    pub counterparty_port_id: String,
+}
+
+impl AccountKey {
+    fn as_key(&self) -> (String, String) {
+        (
+            self.connection_id.clone(),
+            self.counterparty_port_id.clone(),
+        )
+    }
+}
+
+/// The `ChannelInfo` struct stores an open interchain account channel.
+///
+/// Properties:
+///
+/// * `account`: the `AccountKey` of the account the channel controls.
+/// * `counterparty_channel_id`: channel on the controller chain.
+#[cw_serde]
+pub struct ChannelInfo {
+    pub account: AccountKey,
+    pub counterparty_channel_id: String,
+}
+
+/// This is a Rust struct representing the ICS-27 interchain account host.
+///
+/// Properties:
+///
+/// * `owner`: address of the owner of the contract.
+/// * `ibc_host`: address of the IBC host the `icahost` port is bound on.
+/// * `proxy_code_id`: code id of the proxy contracts acting as interchain accounts.
+/// * `accounts`: proxy address of every registered account, by connection and controller port.
+/// * `channels`: `ChannelInfo` of every open channel, by channel id.
+/// * `active_channels`: the open channel of each account, an account has at most one.
+/// * `pending_account`: account whose proxy is being instantiated.
+/// * `pending_packet`: packet whose transaction is being executed.
+pub struct CwIcaHost<'a> {
+    owner: Item<'a, String>,
+    ibc_host: Item<'a, Addr>,
+    proxy_code_id: Item<'a, u64>,
+    accounts: Map<'a, (String, String), Addr>,
+    channels: Map<'a, String, ChannelInfo>,
+    active_channels: Map<'a, (String, String), String>,
+    pending_account: Item<'a, AccountKey>,
+    pending_packet: Item<'a, CwPacket>,
+}
+
+impl<'a> Default for CwIcaHost<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CwIcaHost<'a> {
+    pub fn new() -> Self {
+        Self {
+            owner: Item::new(StorageKey::Owner.as_str()),
+            ibc_host: Item::new(StorageKey::IbcHost.as_str()),
+            proxy_code_id: Item::new(StorageKey::ProxyCodeId.as_str()),
+            accounts: Map::new(StorageKey::Accounts.as_str()),
+            channels: Map::new(StorageKey::Channels.as_str()),
+            active_channels: Map::new(StorageKey::ActiveChannels.as_str()),
+            pending_account: Item::new(StorageKey::PendingAccount.as_str()),
+            pending_packet: Item::new(StorageKey::PendingPacket.as_str()),
+        }
+    }
+
+    pub fn owner(&self) -> &Item<'a, String> {
+        &self.owner
+    }
+
+    pub fn set_ibc_host(
+        &self,
+        store: &mut dyn Storage,
+        address: Addr,
+    ) -> Result<(), ContractError> {
+        self.ibc_host
+            .save(store, &address)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn set_proxy_code_id(
+        &self,
+        store: &mut dyn Storage,
+        code_id: u64,
+    ) -> Result<(), ContractError> {
+        self.proxy_code_id
+            .save(store, &code_id)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_proxy_code_id(&self, store: &dyn Storage) -> Result<u64, ContractError> {
+        self.proxy_code_id.load(store).map_err(ContractError::Std)
+    }
+
+    pub fn store_account(
+        &self,
+        store: &mut dyn Storage,
+        account: &AccountKey,
+        address: &Addr,
+    ) -> Result<(), ContractError> {
+        self.accounts
+            .save(store, account.as_key(), address)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_account(
+        &self,
+        store: &dyn Storage,
+        account: &AccountKey,
+    ) -> Result<Addr, ContractError> {
+        self.accounts
+            .load(store, account.as_key())
+            .map_err(|_| ContractError::AccountNotFound {
+                connection_id: account.connection_id.clone(),
+                port_id: account.counterparty_port_id.clone(),
+            })
+    }
+
+    pub fn has_account(&self, store: &dyn Storage, account: &AccountKey) -> bool {
+        self.accounts.has(store, account.as_key())
+    }
+
+    pub fn store_channel(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        channel: &ChannelInfo,
+    ) -> Result<(), ContractError> {
+        self.channels.save(store, channel_id.to_owned(), channel)?;
+        self.active_channels
+            .save(store, channel.account.as_key(), &channel_id.to_string())
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_channel(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
+    ) -> Result<ChannelInfo, ContractError> {
+        self.channels
+            .load(store, channel_id.to_owned())
+            .map_err(|_| ContractError::ChannelNotFound {
+                channel_id: channel_id.to_string(),
+            })
+    }
+
+    /// Removes a closed channel. The account stays registered and is reused by the next channel the
+    /// controller opens for it.
+    pub fn remove_channel(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+    ) -> Result<(), ContractError> {
+        let channel = self.get_channel(store, channel_id)?;
+        self.channels.remove(store, channel_id.to_owned());
+        self.active_channels.remove(store, channel.account.as_key());
+        Ok(())
+    }
+
+    pub fn get_active_channel(
+        &self,
+        store: &dyn Storage,
+        account: &AccountKey,
+    ) -> Result<Option<String>, ContractError> {
+        self.active_channels
+            .may_load(store, account.as_key())
+            .map_err(ContractError::Std)
+    }
+
+    pub fn store_pending_account(
+        &self,
+        store: &mut dyn Storage,
+        account: &AccountKey,
+    ) -> Result<(), ContractError> {
+        self.pending_account
+            .save(store, account)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn take_pending_account(
+        &self,
+        store: &mut dyn Storage,
+    ) -> Result<AccountKey, ContractError> {
+        let account = self.pending_account.load(store)?;
+        self.pending_account.remove(store);
+        Ok(account)
+    }
+
+    pub fn store_pending_packet(
+        &self,
+        store: &mut dyn Storage,
+        packet: &CwPacket,
+    ) -> Result<(), ContractError> {
+        self.pending_packet
+            .save(store, packet)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn take_pending_packet(&self, store: &mut dyn Storage) -> Result<CwPacket, ContractError> {
+        let packet = self.pending_packet.load(store)?;
+        self.pending_packet.remove(store);
+        Ok(packet)
+    }
+}
+
+impl<'a> IbcHostApp for CwIcaHost<'a> {
+    type Error = ContractError;
+
+    fn get_ibc_host(&self, store: &dyn Storage) -> Result<Addr, ContractError> {
+        self.ibc_host.load(store).map_err(ContractError::Std)
+    }
+
+    fn only_ibc_handler_error(&self) -> ContractError {
+        ContractError::OnlyIbcHandler {}
+    }
+}
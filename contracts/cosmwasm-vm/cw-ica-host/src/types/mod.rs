@@ -0,0 +1,3 @@
+pub mod storage_keys;
+
+pub const LOG_PREFIX: &str = "[ica_host]:";
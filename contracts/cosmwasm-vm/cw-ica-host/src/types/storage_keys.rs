@@ -0,0 +1,28 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum StorageKey {
+    Owner,
+    IbcHost,
+    ProxyCodeId,
+    Accounts,
+    Channels,
+    ActiveChannels,
+    PendingAccount,
+    PendingPacket,
+}
+
+impl StorageKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKey::Owner => "owner",
+            StorageKey::IbcHost => "ibc_host",
+            StorageKey::ProxyCodeId => "proxy_code_id",
+            StorageKey::Accounts => "accounts",
+            StorageKey::Channels => "channels",
+            StorageKey::ActiveChannels => "active_channels",
+            StorageKey::PendingAccount => "pending_account",
+            StorageKey::PendingPacket => "pending_packet",
+        }
+    }
+}
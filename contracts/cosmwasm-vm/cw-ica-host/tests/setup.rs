@@ -0,0 +1,110 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+    Addr, DepsMut, Empty, Event, IbcChannel, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder,
+    IbcPacket, OwnedDeps, Reply, SubMsgResponse, SubMsgResult,
+};
+use cw_common::ica_msg::{Metadata, ICA_CONTROLLER_PORT_PREFIX, ICA_HOST_PORT_ID};
+use cw_ica_host::{
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::{CwIcaHost, INSTANTIATE_PROXY_REPLY_ID},
+};
+pub use test_utils::ibc_app::{ChannelEnds, IBC_HOST, OWNER};
+
+pub const PROXY_CODE_ID: u64 = 9;
+pub const PROXY: &str = "proxy";
+pub const CONNECTION_ID: &str = "connection-3";
+pub const COUNTERPARTY_CONNECTION_ID: &str = "connection-0";
+pub const CHANNEL_ID: &str = "channel-7";
+pub const COUNTERPARTY_CHANNEL_ID: &str = "channel-0";
+
+pub fn deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    mock_dependencies()
+}
+
+pub fn counterparty_port_id() -> String {
+    format!("{ICA_CONTROLLER_PORT_PREFIX}user")
+}
+
+pub fn version() -> String {
+    Metadata::new(COUNTERPARTY_CONNECTION_ID, CONNECTION_ID)
+        .encode()
+        .unwrap()
+}
+
+pub fn channel_ends() -> ChannelEnds {
+    ChannelEnds::new(
+        ICA_HOST_PORT_ID,
+        CHANNEL_ID,
+        &counterparty_port_id(),
+        COUNTERPARTY_CHANNEL_ID,
+    )
+}
+
+pub fn get_channel() -> IbcChannel {
+    channel_ends().channel(IbcOrder::Ordered, version(), CONNECTION_ID)
+}
+
+/// Instantiates the contract with `IBC_HOST` as host.
+pub fn setup_contract(deps: DepsMut) -> CwIcaHost<'static> {
+    let contract = CwIcaHost::default();
+    contract
+        .instantiate(
+            deps,
+            mock_env(),
+            mock_info(OWNER, &[]),
+            InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                proxy_code_id: PROXY_CODE_ID,
+            },
+        )
+        .unwrap();
+    contract
+}
+
+/// Reply of the proxy instantiated with address `PROXY`.
+pub fn instantiate_proxy_reply() -> Reply {
+    Reply {
+        id: INSTANTIATE_PROXY_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![Event::new("instantiate").add_attribute("_contract_address", PROXY)],
+            data: None,
+        }),
+    }
+}
+
+/// Runs the host side of the handshake of `CHANNEL_ID`, registering `PROXY` as account.
+pub fn open_channel(mut deps: DepsMut, contract: &CwIcaHost) {
+    contract
+        .execute(
+            deps.branch(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenTry {
+                    channel: get_channel(),
+                    counterparty_version: version(),
+                },
+            },
+        )
+        .unwrap();
+    contract
+        .reply(deps.branch(), mock_env(), instantiate_proxy_reply())
+        .unwrap();
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelConnect {
+                msg: IbcChannelConnectMsg::OpenConfirm {
+                    channel: get_channel(),
+                },
+            },
+        )
+        .unwrap();
+}
+
+/// Builds a packet from the controller carrying `data`.
+pub fn incoming_packet(data: Vec<u8>) -> IbcPacket {
+    channel_ends().incoming_packet(data, 1)
+}
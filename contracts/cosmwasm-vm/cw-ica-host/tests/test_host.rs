@@ -0,0 +1,350 @@
+pub mod setup;
+
+use cosmwasm_std::{
+    coins, from_json,
+    testing::{mock_env, mock_info},
+    to_json_binary as to_binary, Addr, BankMsg, Binary, CosmosMsg, DepsMut, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketReceiveMsg, Reply, Response,
+    SubMsgResponse, SubMsgResult, WasmMsg,
+};
+use cw_common::{
+    core_msg::ExecuteMsg as CoreExecuteMsg,
+    ica_msg::{
+        InterchainAccountPacketData, InterchainAccountTxResult, ProxyExecuteMsg, ICA_HOST_PORT_ID,
+    },
+    types::Ack,
+};
+use cw_ica_host::{
+    error::ContractError,
+    msg::ExecuteMsg,
+    state::{AccountKey, CwIcaHost, EXECUTE_TX_REPLY_ID},
+};
+
+use setup::*;
+
+fn account_key() -> AccountKey {
+    AccountKey {
+        connection_id: CONNECTION_ID.to_string(),
+        counterparty_port_id: counterparty_port_id(),
+    }
+}
+
+fn bank_msgs() -> Vec<CosmosMsg> {
+    vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: "receiver".to_string(),
+        amount: coins(10, "uarch"),
+    })]
+}
+
+fn open_try(
+    deps: DepsMut,
+    contract: &CwIcaHost,
+    msg: IbcChannelOpenMsg,
+) -> Result<Response, ContractError> {
+    contract.execute(
+        deps,
+        mock_env(),
+        mock_info(IBC_HOST, &[]),
+        ExecuteMsg::IbcChannelOpen { msg },
+    )
+}
+
+/// Receives a packet executing `bank_msgs()` on `CHANNEL_ID`.
+fn receive_tx(deps: DepsMut, contract: &CwIcaHost) -> Result<Response, ContractError> {
+    let data = InterchainAccountPacketData::execute_tx(&bank_msgs(), String::new())
+        .unwrap()
+        .encode()
+        .unwrap();
+    contract.execute(
+        deps,
+        mock_env(),
+        mock_info(IBC_HOST, &[]),
+        ExecuteMsg::IbcPacketReceive {
+            msg: IbcPacketReceiveMsg::new(incoming_packet(data), Addr::unchecked("relayer")),
+        },
+    )
+}
+
+fn execute_tx_reply(result: SubMsgResult) -> Reply {
+    Reply {
+        id: EXECUTE_TX_REPLY_ID,
+        result,
+    }
+}
+
+#[test]
+fn test_instantiate_binds_icahost_port() {
+    let mut deps = deps();
+    let contract = CwIcaHost::default();
+    let res = contract
+        .instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            cw_ica_host::msg::InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                proxy_code_id: PROXY_CODE_ID,
+            },
+        )
+        .unwrap();
+
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            match from_json::<CoreExecuteMsg>(msg).unwrap() {
+                CoreExecuteMsg::BindPort { port_id, .. } => assert_eq!(port_id, ICA_HOST_PORT_ID),
+                _ => panic!("expected BindPort"),
+            }
+        }
+        _ => panic!("expected wasm message"),
+    }
+}
+
+#[test]
+fn test_open_try_instantiates_proxy() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let res = open_try(
+        deps.as_mut(),
+        &contract,
+        IbcChannelOpenMsg::OpenTry {
+            channel: get_channel(),
+            counterparty_version: version(),
+        },
+    )
+    .unwrap();
+
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate { code_id, admin, .. }) => {
+            assert_eq!(*code_id, PROXY_CODE_ID);
+            assert_eq!(admin, &Some(mock_env().contract.address.to_string()));
+        }
+        _ => panic!("expected instantiate"),
+    }
+
+    contract
+        .reply(deps.as_mut(), mock_env(), instantiate_proxy_reply())
+        .unwrap();
+    let account = contract
+        .get_account(deps.as_ref().storage, &account_key())
+        .unwrap();
+    assert_eq!(account, Addr::unchecked(PROXY));
+}
+
+#[test]
+fn test_open_init_not_allowed() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let err = open_try(
+        deps.as_mut(),
+        &contract,
+        IbcChannelOpenMsg::OpenInit {
+            channel: get_channel(),
+        },
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::ChannelInitNotAllowed {}));
+}
+
+#[test]
+fn test_open_try_unordered_fails() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let mut channel = get_channel();
+    channel.order = IbcOrder::Unordered;
+    let err = open_try(
+        deps.as_mut(),
+        &contract,
+        IbcChannelOpenMsg::OpenTry {
+            channel,
+            counterparty_version: version(),
+        },
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::InvalidChannelOrder {}));
+}
+
+#[test]
+fn test_open_try_invalid_metadata() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let err = open_try(
+        deps.as_mut(),
+        &contract,
+        IbcChannelOpenMsg::OpenTry {
+            channel: get_channel(),
+            counterparty_version: "ics27-1".to_string(),
+        },
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::InvalidVersion { .. }));
+}
+
+#[test]
+fn test_one_active_channel_per_account() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract);
+
+    let err = open_try(
+        deps.as_mut(),
+        &contract,
+        IbcChannelOpenMsg::OpenTry {
+            channel: get_channel(),
+            counterparty_version: version(),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::ActiveChannelExists { .. }));
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelClose {
+                msg: IbcChannelCloseMsg::CloseConfirm {
+                    channel: get_channel(),
+                },
+            },
+        )
+        .unwrap();
+
+    // the account is reused by the next channel
+    let res = open_try(
+        deps.as_mut(),
+        &contract,
+        IbcChannelOpenMsg::OpenTry {
+            channel: get_channel(),
+            counterparty_version: version(),
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+}
+
+#[test]
+fn test_only_first_confirmed_channel_is_active() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let second_channel = ChannelEnds::new(
+        ICA_HOST_PORT_ID,
+        "channel-8",
+        &counterparty_port_id(),
+        "channel-1",
+    )
+    .channel(IbcOrder::Ordered, version(), CONNECTION_ID);
+    for channel in [get_channel(), second_channel.clone()] {
+        open_try(
+            deps.as_mut(),
+            &contract,
+            IbcChannelOpenMsg::OpenTry {
+                channel,
+                counterparty_version: version(),
+            },
+        )
+        .unwrap();
+    }
+    contract
+        .reply(deps.as_mut(), mock_env(), instantiate_proxy_reply())
+        .unwrap();
+    let confirm = |channel| ExecuteMsg::IbcChannelConnect {
+        msg: IbcChannelConnectMsg::OpenConfirm { channel },
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            confirm(get_channel()),
+        )
+        .unwrap();
+
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            confirm(second_channel),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(err, ContractError::ActiveChannelExists { channel_id } if channel_id == CHANNEL_ID)
+    );
+}
+
+#[test]
+fn test_receive_executes_from_proxy() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract);
+
+    let res = receive_tx(deps.as_mut(), &contract).unwrap();
+
+    assert_eq!(res.messages[0].id, EXECUTE_TX_REPLY_ID);
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, PROXY);
+            let ProxyExecuteMsg::Execute { msgs } = from_json(msg).unwrap();
+            assert_eq!(msgs, bank_msgs());
+        }
+        _ => panic!("expected wasm message"),
+    }
+}
+
+#[test]
+fn test_receive_unknown_channel() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let err = receive_tx(deps.as_mut(), &contract).unwrap_err();
+
+    assert!(matches!(err, ContractError::ChannelNotFound { .. }));
+}
+
+#[test]
+fn test_execute_tx_reply_acknowledges() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract);
+    let result = to_binary(&InterchainAccountTxResult {
+        results: vec![Binary::default()],
+    })
+    .unwrap();
+
+    receive_tx(deps.as_mut(), &contract).unwrap();
+    let res = contract
+        .reply(
+            deps.as_mut(),
+            mock_env(),
+            execute_tx_reply(SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(result.clone()),
+            })),
+        )
+        .unwrap();
+    assert_eq!(res.data, Some(to_binary(&Ack::Result(result)).unwrap()));
+    assert!(res.events.is_empty());
+
+    receive_tx(deps.as_mut(), &contract).unwrap();
+    let res = contract
+        .reply(
+            deps.as_mut(),
+            mock_env(),
+            execute_tx_reply(SubMsgResult::Err("failed".to_string())),
+        )
+        .unwrap();
+    assert_eq!(res.data, Some(to_binary(&Ack::module_error()).unwrap()));
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == "receive_packet_error")
+        .unwrap();
+    assert!(event
+        .attributes
+        .iter()
+        .any(|attribute| attribute.key == "packet_error" && attribute.value == "failed"));
+}
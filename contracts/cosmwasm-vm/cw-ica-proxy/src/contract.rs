@@ -0,0 +1,120 @@
+use cosmwasm_std::{CosmosMsg, SubMsg, SubMsgResult};
+use cw_common::ica_msg::InterchainAccountTxResult;
+
+use crate::MigrateMsg;
+
+use super::*;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:cw-ica-proxy";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl<'a> CwIcaProxy<'a> {
+    /// This function instantiates the proxy with the sender, the interchain account host, as owner.
+    pub fn instantiate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        _msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        self.set_owner(deps.storage, &info.sender)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "instantiate")
+            .add_attribute("owner", info.sender))
+    }
+
+    pub fn execute(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::Execute { msgs } => {
+                let owner = self.get_owner(deps.storage)?;
+                if owner != info.sender {
+                    return Err(ContractError::Unauthorized {});
+                }
+                self.execute_msgs(deps, msgs)
+            }
+        }
+    }
+
+    /// This function executes the messages of a transaction. Each message is a submessage replying
+    /// on success so its response data can be collected, any failure reverts the transaction.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `msgs`: `msgs` are the messages of the transaction, executed in order.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`, the response data of the last reply is the
+    /// `InterchainAccountTxResult` of the transaction.
+    pub fn execute_msgs(
+        &self,
+        deps: DepsMut,
+        msgs: Vec<CosmosMsg>,
+    ) -> Result<Response, ContractError> {
+        self.store_tx_results(deps.storage, vec![])?;
+        let res = Response::new()
+            .add_attribute("action", "execute")
+            .add_attribute("msgs", msgs.len().to_string());
+        if msgs.is_empty() {
+            return Ok(res.set_data(to_binary(&InterchainAccountTxResult { results: vec![] })?));
+        }
+
+        let submessages = msgs
+            .into_iter()
+            .enumerate()
+            .map(|(index, msg)| SubMsg::reply_on_success(msg, index as u64));
+        Ok(res.add_submessages(submessages))
+    }
+
+    /// This function records the response data of an executed message.
+    pub fn reply(&self, deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+        let data = match msg.result {
+            SubMsgResult::Ok(response) => response.data.unwrap_or_default(),
+            SubMsgResult::Err(error) => return Err(StdError::generic_err(error).into()),
+        };
+        let mut results = self.get_tx_results(deps.storage)?;
+        results.push(data);
+        let data = to_binary(&InterchainAccountTxResult {
+            results: results.clone(),
+        })?;
+        self.store_tx_results(deps.storage, results)?;
+
+        Ok(Response::new().set_data(data))
+    }
+
+    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::GetOwner {} => to_binary(&self.get_owner(deps.storage).map_err(to_std)?),
+        }
+    }
+
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        _msg: MigrateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+            .map_err(ContractError::Std)?;
+        Ok(Response::default().add_attribute("migrate", "successful"))
+    }
+}
+
+fn to_std(error: ContractError) -> StdError {
+    match error {
+        ContractError::Std(error) => error,
+        error => StdError::NotFound {
+            kind: error.to_string(),
+        },
+    }
+}
@@ -0,0 +1,93 @@
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+pub mod types;
+
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::CwIcaProxy,
+    types::storage_keys::StorageKey,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary as to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdError, StdResult, Storage,
+};
+
+use cw2::set_contract_version;
+use cw_storage_plus::Item;
+use thiserror::Error;
+
+/// This function instantiates the proxy of an interchain account, the sender becomes its owner.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `info`: `info` contains the sender of the message, the interchain account host.
+/// * `msg`: `msg` is the empty `InstantiateMsg`.
+///
+/// Returns:
+///
+/// The `instantiate` function returns a `Result<Response, ContractError>`.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let proxy = CwIcaProxy::default();
+
+    proxy.instantiate(deps, env, info, msg)
+}
+
+/// This function executes the messages of an interchain account transaction. Only the owner can
+/// call it.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `info`: `info` contains the sender of the message, which must be the owner.
+/// * `msg`: `msg` is the `ExecuteMsg` to execute.
+///
+/// Returns:
+///
+/// The `execute` function returns a `Result<Response, ContractError>`.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let proxy = CwIcaProxy::default();
+
+    proxy.execute(deps, env, info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let proxy = CwIcaProxy::default();
+
+    proxy.query(deps, env, msg)
+}
+
+/// This function collects the response data of an executed message.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let proxy = CwIcaProxy::default();
+
+    proxy.reply(deps, env, msg)
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let proxy = CwIcaProxy::default();
+    proxy.migrate(deps, env, msg)
+}
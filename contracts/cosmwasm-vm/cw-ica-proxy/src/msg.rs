@@ -0,0 +1,14 @@
+use super::*;
+use cosmwasm_schema::QueryResponses;
+
+pub use cw_common::ica_msg::{
+    ProxyExecuteMsg as ExecuteMsg, ProxyInstantiateMsg as InstantiateMsg,
+};
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Interchain account host the proxy executes messages for.
+    #[returns(Addr)]
+    GetOwner {},
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+/// This is a Rust struct representing an interchain account. The host instantiates one proxy per
+/// account and executes the messages of the controller from it.
+///
+/// Properties:
+///
+/// * `owner`: address of the interchain account host.
+/// * `tx_results`: response data of the messages of the transaction being executed.
+pub struct CwIcaProxy<'a> {
+    owner: Item<'a, Addr>,
+    tx_results: Item<'a, Vec<Binary>>,
+}
+
+impl<'a> Default for CwIcaProxy<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CwIcaProxy<'a> {
+    pub fn new() -> Self {
+        Self {
+            owner: Item::new(StorageKey::Owner.as_str()),
+            tx_results: Item::new(StorageKey::TxResults.as_str()),
+        }
+    }
+
+    pub fn set_owner(&self, store: &mut dyn Storage, owner: &Addr) -> Result<(), ContractError> {
+        self.owner.save(store, owner).map_err(ContractError::Std)
+    }
+
+    pub fn get_owner(&self, store: &dyn Storage) -> Result<Addr, ContractError> {
+        self.owner.load(store).map_err(ContractError::Std)
+    }
+
+    pub fn store_tx_results(
+        &self,
+        store: &mut dyn Storage,
+        results: Vec<Binary>,
+    ) -> Result<(), ContractError> {
+        self.tx_results
+            .save(store, &results)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_tx_results(&self, store: &dyn Storage) -> Result<Vec<Binary>, ContractError> {
+        Ok(self.tx_results.may_load(store)?.unwrap_or_default())
+    }
+}
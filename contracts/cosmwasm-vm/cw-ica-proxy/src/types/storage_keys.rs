@@ -0,0 +1,16 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum StorageKey {
+    Owner,
+    TxResults,
+}
+
+impl StorageKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKey::Owner => "owner",
+            StorageKey::TxResults => "tx_results",
+        }
+    }
+}
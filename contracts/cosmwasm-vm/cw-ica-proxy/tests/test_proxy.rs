@@ -0,0 +1,96 @@
+use cosmwasm_std::{
+    coins, from_json,
+    testing::{mock_dependencies, mock_env, mock_info},
+    BankMsg, Binary, CosmosMsg, Reply, SubMsgResponse, SubMsgResult,
+};
+use cw_common::ica_msg::InterchainAccountTxResult;
+use cw_ica_proxy::{
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::CwIcaProxy,
+};
+
+const HOST: &str = "ica_host";
+
+fn bank_msg(amount: u128) -> CosmosMsg {
+    CosmosMsg::Bank(BankMsg::Send {
+        to_address: "receiver".to_string(),
+        amount: coins(amount, "uarch"),
+    })
+}
+
+fn reply(id: u64, data: Option<Binary>) -> Reply {
+    Reply {
+        id,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data,
+        }),
+    }
+}
+
+#[test]
+fn test_only_owner_executes() {
+    let mut deps = mock_dependencies();
+    let contract = CwIcaProxy::default();
+    contract
+        .instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(HOST, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            ExecuteMsg::Execute {
+                msgs: vec![bank_msg(10)],
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
+
+#[test]
+fn test_execute_collects_results() {
+    let mut deps = mock_dependencies();
+    let contract = CwIcaProxy::default();
+    contract
+        .instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(HOST, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(HOST, &[]),
+            ExecuteMsg::Execute {
+                msgs: vec![bank_msg(10), bank_msg(20)],
+            },
+        )
+        .unwrap();
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(res.messages[1].id, 1);
+
+    contract
+        .reply(deps.as_mut(), mock_env(), reply(0, None))
+        .unwrap();
+    let res = contract
+        .reply(
+            deps.as_mut(),
+            mock_env(),
+            reply(1, Some(Binary::from(b"ok"))),
+        )
+        .unwrap();
+    let result: InterchainAccountTxResult = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(result.results, vec![Binary::default(), Binary::from(b"ok")]);
+}
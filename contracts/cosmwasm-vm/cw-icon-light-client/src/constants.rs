@@ -3,6 +3,12 @@ use common::icon::icon::lightclient::v1::TrustLevel;
 pub const CLIENT_STATE_HASH: &str = "client_state_hash";
 pub const CONSENSUS_STATE_HASH: &str = "consensus_state_hash";
 pub const HEIGHT: &str = "height";
+pub const FROZEN_HEIGHT: &str = "frozen_height";
+pub const PRUNED_HEIGHTS: &str = "pruned_heights";
+// upper bound on the expired consensus states removed by a single client update
+pub const MAX_PRUNE_PER_UPDATE: u32 = 10;
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 100;
 pub const TRUST_LEVEL: TrustLevel = TrustLevel {
     numerator: 2,
     denominator: 3,
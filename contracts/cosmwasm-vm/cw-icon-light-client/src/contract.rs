@@ -1,5 +1,5 @@
 use common::constants::ICON_CLIENT_TYPE;
-use common::icon::icon::lightclient::v1::{ClientState, ConsensusState};
+use common::icon::icon::lightclient::v1::{ClientState, ConsensusState, Misbehaviour};
 use common::traits::AnyTypes;
 use cosmwasm_schema::cw_serde;
 use cw_common::ibc_types::IbcHeight;
@@ -13,18 +13,23 @@ use common::icon::icon::types::v1::{MerkleProofs, SignedHeader};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary as to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult,
+    to_json_binary as to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult,
 };
 use cw2::set_contract_version;
-use cw_common::client_response::{CreateClientResponse, UpdateClientResponse};
+use cw_common::client_response::{
+    CreateClientResponse, MisbehaviourResponse, UpdateClientBatchResponse, UpdateClientResponse,
+    UpgradeClientResponse, ValidatorSetResponse,
+};
 use cw_common::raw_types::Any;
 use cw_common::types::VerifyChannelState;
 
-use crate::constants::{CLIENT_STATE_HASH, CONSENSUS_STATE_HASH, HEIGHT};
+use crate::constants::{
+    CLIENT_STATE_HASH, CONSENSUS_STATE_HASH, FROZEN_HEIGHT, HEIGHT, PRUNED_HEIGHTS,
+};
 use crate::error::ContractError;
 use crate::light_client::IconClient;
-use crate::state::CwContext;
+use crate::state::{init_consensus_state_counts, CwContext};
 use crate::traits::{Config, IContext, ILightClient};
 use cw_common::client_msg::{
     ExecuteMsg, InstantiateMsg, LightClientPacketMessage, QueryMsg, VerifyClientConsensusState,
@@ -136,20 +141,145 @@ pub fn execute(
                 .add_attribute(HEIGHT, update.height.to_string())
                 .set_data(response_data))
         }
+        ExecuteMsg::UpdateClientBatch {
+            client_id,
+            signed_headers,
+            store_heights,
+        } => {
+            let context = CwContext::new(deps_mut, _env);
+            let mut client = IconClient::new(context);
+            let headers = signed_headers
+                .iter()
+                .map(|bytes| {
+                    let header_any = any_from_byte(bytes)?;
+                    SignedHeader::from_any(header_any).map_err(ContractError::DecodeError)
+                })
+                .collect::<Result<Vec<SignedHeader>, ContractError>>()?;
+            let updates =
+                client.update_client_batch(info.sender, &client_id, headers, &store_heights)?;
+            let responses = updates
+                .iter()
+                .map(|update| {
+                    Ok(UpdateClientResponse {
+                        height: to_ibc_height(update.height).map(|h| h.to_string())?,
+                        client_id: client_id.clone(),
+                        client_state_commitment: update.client_state_commitment.to_vec(),
+                        consensus_state_commitment: update.consensus_state_commitment.to_vec(),
+                        client_state_bytes: ClientState::any_from_value(&update.client_state_bytes)
+                            .encode_to_vec(),
+                        consensus_state_bytes: ConsensusState::any_from_value(
+                            &update.consensus_state_bytes,
+                        )
+                        .encode_to_vec(),
+                    })
+                })
+                .collect::<Result<Vec<UpdateClientResponse>, ContractError>>()?;
+            let heights = updates
+                .iter()
+                .map(|update| update.height.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            let response_data = to_binary(&UpdateClientBatchResponse::new(client_id, responses))
+                .map_err(ContractError::Std)?;
+            Ok(Response::new()
+                .add_attribute(HEIGHT, heights)
+                .set_data(response_data))
+        }
         ExecuteMsg::Misbehaviour {
-            client_id: _,
-            misbehaviour: _,
+            client_id,
+            misbehaviour,
         } => {
-            todo!()
+            let context = CwContext::new(deps_mut, _env);
+            let mut client = IconClient::new(context);
+            let misbehaviour_any =
+                Any::decode(misbehaviour.as_slice()).map_err(ContractError::DecodeError)?;
+            let misbehaviour =
+                Misbehaviour::from_any(misbehaviour_any).map_err(ContractError::DecodeError)?;
+            let client_state = client.misbehaviour(info.sender, &client_id, misbehaviour)?;
+            let client_state_commitment = client_state.get_keccak_hash();
+            let response_data = to_binary(&MisbehaviourResponse::new(
+                client_id.clone(),
+                client_state_commitment.to_vec(),
+                client_state.to_any().encode_to_vec(),
+            ))
+            .map_err(ContractError::Std)?;
+            Ok(Response::new()
+                .add_attribute(CLIENT_STATE_HASH, hex::encode(client_state_commitment))
+                .add_attribute(FROZEN_HEIGHT, client_state.frozen_height.to_string())
+                .add_attribute("client_id", client_id)
+                .set_data(response_data))
         }
 
         ExecuteMsg::UpgradeClient {
-            upgraded_client_state: _,
-            upgraded_consensus_state: _,
-            proof_upgrade_client: _,
-            proof_upgrade_consensus_state: _,
+            client_id,
+            upgrade_height,
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        } => {
+            let context = CwContext::new(deps_mut, _env);
+            let mut client = IconClient::new(context);
+            let client_state_any = Any::decode(upgraded_client_state.as_slice())
+                .map_err(ContractError::DecodeError)?;
+            let consensus_state_any = Any::decode(upgraded_consensus_state.as_slice())
+                .map_err(ContractError::DecodeError)?;
+            let client_state =
+                ClientState::from_any(client_state_any).map_err(ContractError::DecodeError)?;
+            let consensus_state = ConsensusState::from_any(consensus_state_any)
+                .map_err(ContractError::DecodeError)?;
+            let proof_upgrade_client = MerkleProofs::decode(proof_upgrade_client.as_slice())
+                .map_err(ContractError::DecodeError)?;
+            let proof_upgrade_consensus_state =
+                MerkleProofs::decode(proof_upgrade_consensus_state.as_slice())
+                    .map_err(ContractError::DecodeError)?;
+            let update = client.upgrade_client(
+                info.sender,
+                &client_id,
+                upgrade_height,
+                client_state,
+                consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+            )?;
+            let response_data = to_binary(&UpgradeClientResponse::new(
+                update.client_state_commitment.to_vec(),
+                ClientState::any_from_value(&update.client_state_bytes).encode_to_vec(),
+                update.consensus_state_commitment.to_vec(),
+                ConsensusState::any_from_value(&update.consensus_state_bytes).encode_to_vec(),
+                client_id,
+                to_ibc_height(update.height).map(|h| h.to_string())?,
+            ))
+            .map_err(ContractError::Std)?;
+            Ok(Response::new()
+                .add_attribute(
+                    CLIENT_STATE_HASH,
+                    hex::encode(update.client_state_commitment),
+                )
+                .add_attribute(
+                    CONSENSUS_STATE_HASH,
+                    hex::encode(update.consensus_state_commitment),
+                )
+                .add_attribute(HEIGHT, update.height.to_string())
+                .set_data(response_data))
+        }
+        ExecuteMsg::PruneConsensusStates {
+            client_id,
+            before_height,
+            limit,
         } => {
-            todo!()
+            let context = CwContext::new(deps_mut, _env);
+            let mut client = IconClient::new(context);
+            let pruned =
+                client.prune_consensus_states(info.sender, &client_id, before_height, limit)?;
+            let pruned = pruned
+                .iter()
+                .map(|height| height.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            Ok(Response::new()
+                .add_attribute("client_id", client_id)
+                .add_attribute(PRUNED_HEIGHTS, pruned))
         }
     }
 }
@@ -157,6 +287,7 @@ pub fn execute(
 pub fn validate_channel_state(
     client_id: &str,
     deps: Deps,
+    env: &Env,
     state: &VerifyChannelState,
 ) -> Result<bool, ContractError> {
     let proofs_decoded =
@@ -164,6 +295,7 @@ pub fn validate_channel_state(
     let height = to_height_u64(&state.proof_height)?;
     let result = QueryHandler::verify_membership(
         deps,
+        env,
         client_id,
         height,
         0,
@@ -178,6 +310,7 @@ pub fn validate_channel_state(
 pub fn validate_connection_state(
     client_id: &str,
     deps: Deps,
+    env: &Env,
     state: &VerifyConnectionState,
 ) -> Result<bool, ContractError> {
     let proofs_decoded =
@@ -186,6 +319,7 @@ pub fn validate_connection_state(
 
     let result = QueryHandler::verify_membership(
         deps,
+        env,
         client_id,
         height,
         0,
@@ -200,6 +334,7 @@ pub fn validate_connection_state(
 pub fn validate_client_state(
     client_id: &str,
     deps: Deps,
+    env: &Env,
     state: &VerifyClientFullState,
 ) -> Result<bool, ContractError> {
     let proofs_decoded = MerkleProofs::decode(state.client_state_proof.as_slice())
@@ -208,6 +343,7 @@ pub fn validate_client_state(
     let height = to_height_u64(&state.proof_height)?;
     let result = QueryHandler::verify_membership(
         deps,
+        env,
         client_id,
         height,
         0,
@@ -222,6 +358,7 @@ pub fn validate_client_state(
 pub fn validate_consensus_state(
     client_id: &str,
     deps: Deps,
+    env: &Env,
     state: &VerifyClientConsensusState,
 ) -> Result<bool, ContractError> {
     let proofs_decoded = MerkleProofs::decode(state.consensus_state_proof.as_slice())
@@ -229,6 +366,7 @@ pub fn validate_consensus_state(
     let height = to_height_u64(&state.proof_height)?;
     let result = QueryHandler::verify_membership(
         deps,
+        env,
         client_id,
         height,
         0,
@@ -242,8 +380,11 @@ pub fn validate_consensus_state(
 
 pub fn validate_next_seq_recv(
     deps: Deps,
+    env: &Env,
     client_id: &str,
     state: &LightClientPacketMessage,
+    delay_time_period: u64,
+    delay_block_period: u64,
 ) -> Result<bool, ContractError> {
     let result = match state {
         LightClientPacketMessage::VerifyNextSequenceRecv {
@@ -260,10 +401,11 @@ pub fn validate_next_seq_recv(
 
             QueryHandler::verify_membership(
                 deps,
+                env,
                 client_id,
                 height,
-                0,
-                0,
+                delay_time_period,
+                delay_block_period,
                 &proofs_decoded.proofs,
                 sequence.to_be_bytes().as_ref(),
                 seq_recv_path,
@@ -282,10 +424,11 @@ pub fn validate_next_seq_recv(
 
             QueryHandler::verify_non_membership(
                 deps,
+                env,
                 client_id,
                 height,
-                0,
-                0,
+                delay_time_period,
+                delay_block_period,
                 &proofs_decoded.proofs,
                 receipt_path,
             )?
@@ -294,6 +437,18 @@ pub fn validate_next_seq_recv(
     Ok(result)
 }
 
+/// Maps a failed proof verification to `false`, except for a frozen client which must surface
+/// as an error so callers can tell a frozen client apart from an invalid proof.
+fn to_verification_result(result: Result<bool, ContractError>) -> StdResult<bool> {
+    match result {
+        Ok(valid) => Ok(valid),
+        Err(error @ ContractError::ClientStateFrozen(_)) => {
+            Err(StdError::generic_err(error.to_string()))
+        }
+        Err(_) => Ok(false),
+    }
+}
+
 fn to_height_u64(height: &str) -> Result<u64, ContractError> {
     let heights = height.split('-').collect::<Vec<&str>>();
     if heights.len() != 2 {
@@ -320,7 +475,7 @@ pub fn any_from_byte(bytes: &[u8]) -> Result<Any, ContractError> {
 // }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetClientState { client_id } => {
             let res = QueryHandler::get_client_state_any(deps.storage, &client_id).unwrap();
@@ -344,8 +499,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         } => {
             let proofs_decoded = MerkleProofs::decode(proofs.as_slice())
                 .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
-            let result = QueryHandler::verify_membership(
+            let result = to_verification_result(QueryHandler::verify_membership(
                 deps,
+                &env,
                 &client_id,
                 height,
                 delay_time_period,
@@ -353,8 +509,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 &proofs_decoded.proofs,
                 &message_bytes,
                 &path,
-            )
-            .unwrap_or(false);
+            ))?;
             to_binary(&result)
         }
         QueryMsg::VerifyNonMembership {
@@ -368,59 +523,61 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         } => {
             let proofs_decoded = MerkleProofs::decode(proofs.as_slice())
                 .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
-            let result = QueryHandler::verify_non_membership(
+            let result = to_verification_result(QueryHandler::verify_non_membership(
                 deps,
+                &env,
                 &client_id,
                 height,
                 delay_time_period,
                 delay_block_period,
                 &proofs_decoded.proofs,
                 &path,
-            )
-            .unwrap_or(false);
+            ))?;
             to_binary(&result)
         }
         QueryMsg::VerifyPacketData {
             client_id,
             verify_packet_data,
-            // packet_data,
+            delay_time_period,
+            delay_block_period,
         } => {
             let proofs_decoded = MerkleProofs::decode(verify_packet_data.proof.as_slice())
                 .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
             let height = to_height_u64(&verify_packet_data.height).unwrap();
-            let result = QueryHandler::verify_membership(
+            let result = to_verification_result(QueryHandler::verify_membership(
                 deps,
+                &env,
                 &client_id,
                 height,
-                0,
-                0,
+                delay_time_period,
+                delay_block_period,
                 &proofs_decoded.proofs,
                 &verify_packet_data.commitment,
                 &verify_packet_data.commitment_path,
-            )
-            .unwrap_or(false);
+            ))?;
 
             to_binary(&result)
         }
         QueryMsg::VerifyPacketAcknowledgement {
             client_id,
             verify_packet_acknowledge,
-            // packet_data,
+            delay_time_period,
+            delay_block_period,
         } => {
             let proofs_decoded = MerkleProofs::decode(verify_packet_acknowledge.proof.as_slice())
                 .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
             let height = to_height_u64(&verify_packet_acknowledge.height).unwrap();
-            let result = QueryHandler::verify_membership(
+            let result = to_verification_result(QueryHandler::verify_membership(
                 deps,
+                &env,
                 &client_id,
                 height,
-                0,
-                0,
+                delay_time_period,
+                delay_block_period,
                 &proofs_decoded.proofs,
                 &verify_packet_acknowledge.ack,
                 &verify_packet_acknowledge.ack_path,
-            )
-            .unwrap_or(false);
+            ))?;
 
             to_binary(&result)
         }
@@ -429,29 +586,46 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             verify_connection_state,
             //  expected_response,
         } => {
-            let result = validate_connection_state(&client_id, deps, &verify_connection_state)
-                .unwrap_or(false);
+            let result =
+                validate_connection_state(&client_id, deps, &env, &verify_connection_state)
+                    .unwrap_or(false);
             to_binary(&result)
         }
         QueryMsg::VerifyConnectionOpenTry(state) => {
             println!("checking all the valid state ");
-            let client_valid =
-                validate_client_state(&state.client_id, deps, &state.verify_client_full_state)
-                    .unwrap_or(false);
+            let client_valid = validate_client_state(
+                &state.client_id,
+                deps,
+                &env,
+                &state.verify_client_full_state,
+            )
+            .unwrap_or(false);
             println!(" is valid clientstate  {client_valid:?}");
 
-            let connection_valid =
-                validate_connection_state(&state.client_id, deps, &state.verify_connection_state)
-                    .unwrap_or(false);
+            let connection_valid = validate_connection_state(
+                &state.client_id,
+                deps,
+                &env,
+                &state.verify_connection_state,
+            )
+            .unwrap_or(false);
             to_binary(&(client_valid && connection_valid))
         }
         QueryMsg::VerifyConnectionOpenAck(state) => {
-            let connection_valid =
-                validate_connection_state(&state.client_id, deps, &state.verify_connection_state)
-                    .unwrap();
-            let client_valid =
-                validate_client_state(&state.client_id, deps, &state.verify_client_full_state)
-                    .unwrap();
+            let connection_valid = validate_connection_state(
+                &state.client_id,
+                deps,
+                &env,
+                &state.verify_connection_state,
+            )
+            .unwrap();
+            let client_valid = validate_client_state(
+                &state.client_id,
+                deps,
+                &env,
+                &state.verify_client_full_state,
+            )
+            .unwrap();
 
             to_binary(&(client_valid && connection_valid))
         }
@@ -465,6 +639,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             let result = validate_channel_state(
                 &verify_channel_state.client_id,
                 deps,
+                &env,
                 &verify_channel_state,
             )
             .unwrap();
@@ -474,22 +649,38 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::PacketTimeout {
             client_id,
             next_seq_recv_verification_result,
+            delay_time_period,
+            delay_block_period,
         } => {
-            let _sequence_valid =
-                validate_next_seq_recv(deps, &client_id, &next_seq_recv_verification_result)
-                    .unwrap();
+            let _sequence_valid = validate_next_seq_recv(
+                deps,
+                &env,
+                &client_id,
+                &next_seq_recv_verification_result,
+                delay_time_period,
+                delay_block_period,
+            )
+            .unwrap();
             to_binary(&_sequence_valid)
         }
         QueryMsg::TimeoutOnCLose {
             client_id,
             verify_channel_state,
             next_seq_recv_verification_result,
+            delay_time_period,
+            delay_block_period,
         } => {
             let is_channel_valid =
-                validate_channel_state(&client_id, deps, &verify_channel_state).unwrap();
-            let _sequence_valid =
-                validate_next_seq_recv(deps, &client_id, &next_seq_recv_verification_result)
-                    .unwrap();
+                validate_channel_state(&client_id, deps, &env, &verify_channel_state).unwrap();
+            let _sequence_valid = validate_next_seq_recv(
+                deps,
+                &env,
+                &client_id,
+                &next_seq_recv_verification_result,
+                delay_time_period,
+                delay_block_period,
+            )
+            .unwrap();
 
             to_binary(&(is_channel_valid && _sequence_valid))
         }
@@ -498,14 +689,61 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 QueryHandler::get_previous_consensus(deps.storage, height, client_id).unwrap();
             to_binary(&res)
         }
+        // BTP headers carry no block time, so the counterparty time can not be proven and
+        // timestamp timeouts are rejected for this client
         QueryMsg::GetTimestampAtHeight {
             client_id: _,
             height: _,
-        } => to_binary(&0_u64),
+        } => Err(StdError::GenericErr {
+            msg: ContractError::TimestampNotSupported.to_string(),
+        }),
         QueryMsg::GetLatestConsensusState { client_id } => {
             let res = QueryHandler::get_latest_consensus_state(deps.storage, &client_id).unwrap();
             to_binary(&res)
         }
+        QueryMsg::GetConsensusStateCount { client_id } => {
+            let res = QueryHandler::get_consensus_state_count(deps.storage, &client_id)
+                .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
+            to_binary(&res)
+        }
+        QueryMsg::GetValidatorSet { client_id, height } => {
+            let (height, validators) =
+                QueryHandler::get_validator_set(deps.storage, &client_id, height)
+                    .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
+            to_binary(&ValidatorSetResponse { height, validators })
+        }
+        QueryMsg::ListConsensusStateHeights {
+            client_id,
+            start_after,
+            limit,
+            order,
+        } => {
+            let res = QueryHandler::list_consensus_state_heights(
+                deps.storage,
+                &client_id,
+                start_after,
+                limit,
+                order.map(Order::from).unwrap_or(Order::Ascending),
+            )
+            .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
+            to_binary(&res)
+        }
+        QueryMsg::ListConsensusStates {
+            client_id,
+            start_after,
+            limit,
+            order,
+        } => {
+            let res = QueryHandler::list_consensus_states(
+                deps.storage,
+                &client_id,
+                start_after,
+                limit,
+                order.map(Order::from).unwrap_or(Order::Ascending),
+            )
+            .map_err(|e| StdError::GenericErr { msg: e.to_string() })?;
+            to_binary(&res)
+        }
     }
 }
 
@@ -516,6 +754,7 @@ pub struct MigrateMsg {}
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
         .map_err(ContractError::Std)?;
+    init_consensus_state_counts(deps.storage)?;
     Ok(Response::default().add_attribute("migrate", "successful"))
 }
 
@@ -537,19 +776,30 @@ pub fn ensure_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError>
 #[cfg(test)]
 mod tests {
 
-    use common::icon::icon::types::v1::{BtpHeader, SignedHeader};
+    use common::icon::icon::lightclient::v1::{
+        BlockUpdate, ClientState, ConsensusState, Misbehaviour, TrustLevel,
+    };
+    use common::icon::icon::types::v1::{BtpHeader, MerkleNode, MerkleProofs, SignedHeader};
+    use common::utils::keccak256;
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
-        to_json_binary as to_binary, Addr, OwnedDeps, Response,
+        to_json_binary as to_binary, Addr, OwnedDeps, Response, StdError,
     };
     use cw2::get_contract_version;
+    use cw_common::client_response::{
+        UpdateClientBatchResponse, UpgradeClientResponse, ValidatorSetResponse,
+    };
+    use cw_common::commitment::{upgraded_client_state_path, upgraded_consensus_state_path};
     use cw_common::{client_msg::QueryMsg, raw_types::Any};
+    use k256::ecdsa::SigningKey;
     use test_utils::{get_test_headers, get_test_signed_headers, to_attribute_map};
 
     use crate::{
-        constants::{CLIENT_STATE_HASH, CONSENSUS_STATE_HASH},
+        constants::{CLIENT_STATE_HASH, CONSENSUS_STATE_HASH, PRUNED_HEIGHTS},
         contract::{ensure_owner, query, to_height_u64},
         query_handler::QueryHandler,
+        state::CwContext,
+        traits::IContext,
         ContractError,
     };
     use common::traits::AnyTypes;
@@ -658,6 +908,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_execute_create_client_invalid_trust_level() {
+        let client_id = "test_client".to_string();
+        let mut deps = setup();
+
+        let start_header = &get_test_headers()[0];
+        let client_state = ClientState {
+            trust_level: Some(TrustLevel {
+                numerator: 1,
+                denominator: 4,
+            }),
+            ..start_header.to_client_state(1000000, 0)
+        };
+        let msg = ExecuteMsg::CreateClient {
+            client_id: client_id.clone(),
+            client_state: client_state.to_any().encode_to_vec(),
+            consensus_state: start_header.to_consensus_state().to_any().encode_to_vec(),
+        };
+
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidTrustLevel {
+                numerator: 1,
+                denominator: 4
+            })
+        );
+        assert!(QueryHandler::get_client_state(deps.as_ref().storage, &client_id).is_err());
+    }
+
     #[test]
     fn test_execute_update_client_with_invalid_trusting_period() {
         let start_header = &get_test_headers()[0];
@@ -880,57 +1162,817 @@ mod tests {
         );
     }
 
+    fn update_client_batch_msg(
+        client_id: &str,
+        headers: &[SignedHeader],
+        store_heights: Vec<u64>,
+    ) -> ExecuteMsg {
+        ExecuteMsg::UpdateClientBatch {
+            client_id: client_id.to_string(),
+            signed_headers: headers
+                .iter()
+                .map(|header| header.to_any().encode_to_vec())
+                .collect(),
+            store_heights,
+        }
+    }
+
     #[test]
-    fn test_query_client_state() {
+    fn test_execute_update_client_batch() {
         let start_header = &get_test_headers()[0];
         let client_id = "test_client".to_string();
-        let deps = init_client(&client_id, start_header, None);
+        let mut deps = init_client(&client_id, start_header, None);
 
-        let msg = QueryMsg::GetClientState {
-            client_id: client_id.clone(),
-        };
-        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+        let signed_headers = &get_test_signed_headers()[1..5];
+        let msg = update_client_batch_msg(&client_id, signed_headers, vec![82876]);
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let response: UpdateClientBatchResponse =
+            cosmwasm_std::from_json(result.data.unwrap()).unwrap();
+        let heights = response
+            .updates
+            .iter()
+            .map(|update| update.height.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(heights, vec!["0-82876", "0-82879"]);
+
+        let client_state =
+            QueryHandler::get_client_state(deps.as_ref().storage, &client_id).unwrap();
+        assert_eq!(client_state.latest_height, 82879);
 
-        let state =
-            QueryHandler::get_client_state_any(deps.as_ref().storage, client_id.as_str()).unwrap();
-        assert_eq!(res, to_binary(&state).unwrap());
+        for (height, stored) in [(82873, false), (82876, true), (82878, false), (82879, true)] {
+            let consensus_state =
+                QueryHandler::get_consensus_state(deps.as_ref().storage, &client_id, height);
+            assert_eq!(consensus_state.is_ok(), stored);
+        }
     }
 
     #[test]
-    fn test_query_consensus_state() {
+    fn test_update_client_batch_empty() {
         let start_header = &get_test_headers()[0];
         let client_id = "test_client".to_string();
-        let deps = init_client(&client_id, start_header, None);
+        let mut deps = init_client(&client_id, start_header, None);
 
-        let msg = QueryMsg::GetConsensusState {
-            client_id: client_id.clone(),
-            height: start_header.main_height,
+        let msg = update_client_batch_msg(&client_id, &[], vec![]);
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidHeaderUpdate(
+                "empty batch".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_update_client_batch_store_height_not_in_batch() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let signed_headers = &get_test_signed_headers()[1..3];
+        let msg = update_client_batch_msg(&client_id, signed_headers, vec![82877]);
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidHeaderUpdate(
+                "height 82877 is not in batch".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_update_client_batch_unordered_headers() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let headers = get_test_signed_headers();
+        let signed_headers = vec![headers[1].clone(), headers[3].clone(), headers[2].clone()];
+        let msg = update_client_batch_msg(&client_id, &signed_headers, vec![]);
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidHeaderUpdate(
+                "headers must be in ascending order".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_update_client_batch_unauthorized() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let signed_headers = &get_test_signed_headers()[1..3];
+        let msg = update_client_batch_msg(&client_id, signed_headers, vec![]);
+        let info = mock_info("not_ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(result, Err(ContractError::Unauthorized {}));
+    }
+
+    fn query_consensus_state_count(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        client_id: &str,
+    ) -> u64 {
+        let msg = QueryMsg::GetConsensusStateCount {
+            client_id: client_id.to_string(),
         };
-        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+        cosmwasm_std::from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
 
-        let state = QueryHandler::get_consensus_state_any(
+    #[test]
+    fn test_update_client_prunes_expired_consensus_states() {
+        let start_header = &get_test_headers()[0];
+        let signed_headers = get_test_signed_headers();
+        let client_id = "test_client".to_string();
+        let first_height = signed_headers[1].header.clone().unwrap().main_height;
+        let mut deps = init_client(
+            &client_id,
+            start_header,
+            Some(first_height - start_header.main_height),
+        );
+        let info = mock_info("ibc_host", &[]);
+
+        for signed_header in signed_headers[1..3].iter() {
+            let msg = ExecuteMsg::UpdateClient {
+                client_id: client_id.clone(),
+                signed_header: signed_header.to_any().encode_to_vec(),
+            };
+            execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        }
+
+        assert!(QueryHandler::get_consensus_state(
             deps.as_ref().storage,
-            client_id.as_str(),
-            start_header.main_height,
+            &client_id,
+            start_header.main_height
         )
-        .unwrap();
-        assert_eq!(res, to_binary(&state).unwrap());
+        .is_err());
+        assert_eq!(query_consensus_state_count(&deps, &client_id), 2);
     }
 
     #[test]
-    fn test_query_latest_height() {
+    fn test_execute_prune_consensus_states() {
         let start_header = &get_test_headers()[0];
         let client_id = "test_client".to_string();
-        let deps = init_client(&client_id, start_header, None);
+        let mut deps = init_client(&client_id, start_header, None);
 
-        let msg = QueryMsg::GetLatestHeight {
+        let msg = ExecuteMsg::UpdateClient {
             client_id: client_id.clone(),
+            signed_header: get_test_signed_headers()[1].to_any().encode_to_vec(),
         };
-        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+        execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg).unwrap();
+        assert_eq!(query_consensus_state_count(&deps, &client_id), 2);
 
-        let height =
-            QueryHandler::get_latest_height(deps.as_ref().storage, client_id.as_str()).unwrap();
-        assert_eq!(res, to_binary(&height).unwrap());
+        let msg = ExecuteMsg::PruneConsensusStates {
+            client_id: client_id.clone(),
+            before_height: u64::MAX,
+            limit: 10,
+        };
+        let result = execute(deps.as_mut(), mock_env(), mock_info(SENDER, &[]), msg).unwrap();
+
+        let attributes = to_attribute_map(&result.attributes);
+        assert_eq!(
+            attributes.get(PRUNED_HEIGHTS).unwrap(),
+            &start_header.main_height.to_string()
+        );
+        assert_eq!(query_consensus_state_count(&deps, &client_id), 1);
+    }
+
+    #[test]
+    fn test_execute_prune_consensus_states_unauthorized() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let msg = ExecuteMsg::PruneConsensusStates {
+            client_id,
+            before_height: u64::MAX,
+            limit: 10,
+        };
+        let result = execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg);
+
+        assert_eq!(result, Err(ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn test_update_client_with_stored_validator_set() {
+        let start_header = &get_test_headers()[0];
+        let signed_headers = get_test_signed_headers();
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+        let info = mock_info("ibc_host", &[]);
+
+        let msg = ExecuteMsg::UpdateClient {
+            client_id: client_id.clone(),
+            signed_header: signed_headers[1].to_any().encode_to_vec(),
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let msg = QueryMsg::GetValidatorSet {
+            client_id: client_id.clone(),
+            height: 82873,
+        };
+        let validator_set: ValidatorSetResponse =
+            cosmwasm_std::from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(validator_set.height, start_header.main_height);
+        assert_eq!(
+            validator_set.validators,
+            signed_headers[1].current_validators
+        );
+
+        let mut signed_header = signed_headers[2].clone();
+        signed_header.current_validators = vec![];
+        let msg = ExecuteMsg::UpdateClient {
+            client_id: client_id.clone(),
+            signed_header: signed_header.to_any().encode_to_vec(),
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        assert_eq!(
+            QueryHandler::get_latest_height(deps.as_ref().storage, &client_id).unwrap(),
+            82876
+        );
+    }
+
+    #[test]
+    fn test_update_client_without_validators_and_no_stored_set() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let mut signed_header = get_test_signed_headers()[1].clone();
+        signed_header.current_validators = vec![];
+        let msg = ExecuteMsg::UpdateClient {
+            client_id: client_id.clone(),
+            signed_header: signed_header.to_any().encode_to_vec(),
+        };
+        let result = execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::ValidatorSetNotFound {
+                height: start_header.main_height,
+                client_id
+            })
+        );
+    }
+
+    fn freeze_client(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, client_id: &str) {
+        let mut context = CwContext::new(deps.as_mut(), mock_env());
+        let mut client_state = context.get_client_state(client_id).unwrap();
+        client_state.frozen_height = client_state.latest_height;
+        context
+            .insert_client_state(client_id, client_state)
+            .unwrap();
+    }
+
+    fn misbehaviour_bytes(
+        client_id: &str,
+        header_1: &SignedHeader,
+        header_2: &SignedHeader,
+    ) -> Vec<u8> {
+        Misbehaviour {
+            client_id: client_id.to_string(),
+            header_1: Some(BlockUpdate {
+                header: Some(header_1.clone()),
+            }),
+            header_2: Some(BlockUpdate {
+                header: Some(header_2.clone()),
+            }),
+        }
+        .to_any()
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn test_execute_misbehaviour_non_conflicting_headers() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let signed_header = &get_test_signed_headers()[1];
+        let msg = ExecuteMsg::Misbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: misbehaviour_bytes(&client_id, signed_header, signed_header),
+        };
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidMisbehaviour(
+                "headers do not conflict".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_execute_misbehaviour_client_id_mismatch() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let signed_header = &get_test_signed_headers()[1];
+        let msg = ExecuteMsg::Misbehaviour {
+            client_id,
+            misbehaviour: misbehaviour_bytes("other_client", signed_header, signed_header),
+        };
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidMisbehaviour(
+                "client id mismatch".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_execute_misbehaviour_unauthorized() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let signed_header = &get_test_signed_headers()[1];
+        let msg = ExecuteMsg::Misbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: misbehaviour_bytes(&client_id, signed_header, signed_header),
+        };
+        let info = mock_info("not_ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(result, Err(ContractError::Unauthorized {}));
+    }
+
+    /// Signs `header` as the validator holding `key` and returns the header carrying the
+    /// signature, trusting `trusted_height`.
+    fn sign_header(
+        key: &SigningKey,
+        client_state: &ClientState,
+        header: BtpHeader,
+        trusted_height: u64,
+    ) -> SignedHeader {
+        let decision = header.get_network_type_section_decision_hash(
+            &client_state.src_network_id,
+            client_state.network_type_id,
+        );
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&decision).unwrap();
+        let mut signature = signature.to_bytes().to_vec();
+        signature.push(recovery_id.to_byte());
+        SignedHeader {
+            header: Some(header),
+            signatures: vec![signature],
+            trusted_height,
+            current_validators: vec![validator_address(key)],
+        }
+    }
+
+    fn validator_address(key: &SigningKey) -> Vec<u8> {
+        let pubkey = key.verifying_key().to_encoded_point(false);
+        keccak256(&pubkey.as_bytes()[1..])[12..].to_vec()
+    }
+
+    #[test]
+    fn test_execute_misbehaviour_freezes_client() {
+        let key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        let validators = vec![validator_address(&key)];
+        let mut trusted_header = get_test_headers()[0].clone();
+        trusted_header.next_validators = validators.clone();
+        trusted_header.next_proof_context_hash =
+            trusted_header.get_next_proof_context_hash(&validators);
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, &trusted_header, None);
+        let client_state = trusted_header.to_client_state(1000000, 0);
+
+        let mut header = trusted_header.clone();
+        header.main_height = trusted_header.main_height + 1;
+        header.message_root = keccak256(b"message root").to_vec();
+        let mut conflicting_header = header.clone();
+        conflicting_header.message_root = keccak256(b"conflicting message root").to_vec();
+        let header_1 = sign_header(&key, &client_state, header, trusted_header.main_height);
+        let header_2 = sign_header(
+            &key,
+            &client_state,
+            conflicting_header,
+            trusted_header.main_height,
+        );
+
+        let msg = ExecuteMsg::Misbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: misbehaviour_bytes(&client_id, &header_1, &header_2),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg).unwrap();
+
+        let frozen_height = trusted_header.main_height + 1;
+        let context = CwContext::new(deps.as_mut(), mock_env());
+        let state = context.get_client_state(&client_id).unwrap();
+        assert_eq!(state.frozen_height, frozen_height);
+
+        let msg = ExecuteMsg::UpdateClient {
+            client_id,
+            signed_header: header_1.to_any().encode_to_vec(),
+        };
+        let result = execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg);
+        assert_eq!(result, Err(ContractError::ClientStateFrozen(frozen_height)));
+    }
+
+    #[test]
+    fn test_update_client_on_frozen_client() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+        freeze_client(&mut deps, &client_id);
+
+        let signed_header = &get_test_signed_headers()[1];
+        let msg = ExecuteMsg::UpdateClient {
+            client_id,
+            signed_header: signed_header.to_any().encode_to_vec(),
+        };
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::ClientStateFrozen(start_header.main_height))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Client state frozen at 27")]
+    fn test_verify_membership_on_frozen_client() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+        freeze_client(&mut deps, &client_id);
+
+        let msg = QueryMsg::VerifyMembership {
+            client_id,
+            message_bytes: vec![1, 2, 3],
+            path: vec![4, 5, 6],
+            proofs: vec![],
+            height: start_header.main_height,
+            delay_time_period: 0,
+            delay_block_period: 0,
+        };
+        query(deps.as_ref(), mock_env(), msg).unwrap();
+    }
+
+    /// Commits the upgraded states under the upgrade paths of `client_id`'s latest height and
+    /// returns the upgrade message for that height carrying the matching proofs.
+    fn prepare_upgrade(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        client_id: &str,
+        upgraded_client_state: &ClientState,
+        upgraded_consensus_state: &ConsensusState,
+    ) -> ExecuteMsg {
+        let mut context = CwContext::new(deps.as_mut(), mock_env());
+        let upgrade_height = context.get_client_state(client_id).unwrap().latest_height;
+        let client_leaf = keccak256(
+            &[
+                keccak256(&upgraded_client_state_path(upgrade_height)),
+                keccak256(&upgraded_client_state.to_any().encode_to_vec()),
+            ]
+            .concat(),
+        );
+        let consensus_leaf = keccak256(
+            &[
+                keccak256(&upgraded_consensus_state_path(upgrade_height)),
+                keccak256(&upgraded_consensus_state.to_any().encode_to_vec()),
+            ]
+            .concat(),
+        );
+        let mut consensus_state = context
+            .get_consensus_state(client_id, upgrade_height)
+            .unwrap();
+        consensus_state.message_root = keccak256(&[client_leaf, consensus_leaf].concat()).to_vec();
+        context
+            .insert_consensus_state(client_id, upgrade_height, consensus_state)
+            .unwrap();
+
+        ExecuteMsg::UpgradeClient {
+            client_id: client_id.to_string(),
+            upgrade_height,
+            upgraded_client_state: upgraded_client_state.to_any().encode_to_vec(),
+            upgraded_consensus_state: upgraded_consensus_state.to_any().encode_to_vec(),
+            proof_upgrade_client: MerkleProofs {
+                proofs: vec![MerkleNode {
+                    dir: 1,
+                    value: consensus_leaf.to_vec(),
+                }],
+            }
+            .encode_to_vec(),
+            proof_upgrade_consensus_state: MerkleProofs {
+                proofs: vec![MerkleNode {
+                    dir: 0,
+                    value: client_leaf.to_vec(),
+                }],
+            }
+            .encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_execute_upgrade_client() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let upgraded_client_state = ClientState {
+            trusting_period: 500,
+            latest_height: 100,
+            network_id: 2,
+            ..start_header.to_client_state(1000000, 0)
+        };
+        let upgraded_consensus_state = ConsensusState {
+            message_root: vec![1, 2, 3],
+            next_proof_context_hash: vec![4, 5, 6],
+        };
+        let msg = prepare_upgrade(
+            &mut deps,
+            &client_id,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        );
+
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let stored_client_state =
+            QueryHandler::get_client_state(deps.as_ref().storage, &client_id).unwrap();
+        assert_eq!(upgraded_client_state, stored_client_state);
+
+        let stored_consensus_state =
+            QueryHandler::get_consensus_state(deps.as_ref().storage, &client_id, 100).unwrap();
+        assert_eq!(upgraded_consensus_state, stored_consensus_state);
+
+        let response: UpgradeClientResponse =
+            cosmwasm_std::from_json(result.data.unwrap()).unwrap();
+        assert_eq!(response.get_height(), "0-100");
+        assert_eq!(
+            response.client_state_commitment(),
+            upgraded_client_state.get_keccak_hash()
+        );
+    }
+
+    #[test]
+    fn test_execute_upgrade_client_invalid_proof() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let upgraded_client_state = ClientState {
+            latest_height: 100,
+            ..start_header.to_client_state(1000000, 0)
+        };
+        let upgraded_consensus_state = start_header.to_consensus_state();
+        let msg = prepare_upgrade(
+            &mut deps,
+            &client_id,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        );
+        let msg = match msg {
+            ExecuteMsg::UpgradeClient {
+                client_id,
+                upgrade_height,
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_consensus_state,
+                ..
+            } => ExecuteMsg::UpgradeClient {
+                client_id,
+                upgrade_height,
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_client: proof_upgrade_consensus_state.clone(),
+                proof_upgrade_consensus_state,
+            },
+            _ => unreachable!(),
+        };
+
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert!(matches!(result, Err(ContractError::InvalidMessageRoot(_))));
+    }
+
+    #[test]
+    fn test_execute_upgrade_client_without_consensus_state_at_upgrade_height() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let upgraded_client_state = ClientState {
+            latest_height: 100,
+            ..start_header.to_client_state(1000000, 0)
+        };
+        let upgraded_consensus_state = start_header.to_consensus_state();
+        let msg = prepare_upgrade(
+            &mut deps,
+            &client_id,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        );
+
+        for height in [start_header.main_height - 1, start_header.main_height + 1] {
+            let msg = match msg.clone() {
+                ExecuteMsg::UpgradeClient {
+                    client_id,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                    ..
+                } => ExecuteMsg::UpgradeClient {
+                    client_id,
+                    upgrade_height: height,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                },
+                _ => unreachable!(),
+            };
+
+            let info = mock_info("ibc_host", &[]);
+            let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+            assert!(matches!(result, Err(ContractError::InvalidUpgrade(_))));
+        }
+    }
+
+    #[test]
+    fn test_execute_upgrade_client_trust_level() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let trust_level = TrustLevel {
+            numerator: 3,
+            denominator: 4,
+        };
+        let upgraded_client_state = ClientState {
+            latest_height: 100,
+            trust_level: Some(trust_level.clone()),
+            ..start_header.to_client_state(1000000, 0)
+        };
+        let upgraded_consensus_state = start_header.to_consensus_state();
+        let msg = prepare_upgrade(
+            &mut deps,
+            &client_id,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        );
+
+        let info = mock_info("ibc_host", &[]);
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let stored_client_state =
+            QueryHandler::get_client_state(deps.as_ref().storage, &client_id).unwrap();
+        assert_eq!(stored_client_state.trust_level, Some(trust_level));
+    }
+
+    #[test]
+    fn test_execute_upgrade_client_invalid_trust_level() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let upgraded_client_state = ClientState {
+            latest_height: 100,
+            trust_level: Some(TrustLevel {
+                numerator: 1,
+                denominator: 1,
+            }),
+            ..start_header.to_client_state(1000000, 0)
+        };
+        let upgraded_consensus_state = start_header.to_consensus_state();
+        let msg = prepare_upgrade(
+            &mut deps,
+            &client_id,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        );
+
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidTrustLevel {
+                numerator: 1,
+                denominator: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_execute_upgrade_client_lower_height() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let mut deps = init_client(&client_id, start_header, None);
+
+        let upgraded_client_state = start_header.to_client_state(1000000, 0);
+        let upgraded_consensus_state = start_header.to_consensus_state();
+        let msg = prepare_upgrade(
+            &mut deps,
+            &client_id,
+            &upgraded_client_state,
+            &upgraded_consensus_state,
+        );
+
+        let info = mock_info("ibc_host", &[]);
+        let result = execute(deps.as_mut(), mock_env(), info, msg);
+
+        assert_eq!(
+            result,
+            Err(ContractError::InvalidUpgrade(
+                "upgraded height must be greater than latest height".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_query_client_state() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let deps = init_client(&client_id, start_header, None);
+
+        let msg = QueryMsg::GetClientState {
+            client_id: client_id.clone(),
+        };
+        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+
+        let state =
+            QueryHandler::get_client_state_any(deps.as_ref().storage, client_id.as_str()).unwrap();
+        assert_eq!(res, to_binary(&state).unwrap());
+    }
+
+    #[test]
+    fn test_query_consensus_state() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let deps = init_client(&client_id, start_header, None);
+
+        let msg = QueryMsg::GetConsensusState {
+            client_id: client_id.clone(),
+            height: start_header.main_height,
+        };
+        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+
+        let state = QueryHandler::get_consensus_state_any(
+            deps.as_ref().storage,
+            client_id.as_str(),
+            start_header.main_height,
+        )
+        .unwrap();
+        assert_eq!(res, to_binary(&state).unwrap());
+    }
+
+    #[test]
+    fn test_query_latest_height() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let deps = init_client(&client_id, start_header, None);
+
+        let msg = QueryMsg::GetLatestHeight {
+            client_id: client_id.clone(),
+        };
+        let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+
+        let height =
+            QueryHandler::get_latest_height(deps.as_ref().storage, client_id.as_str()).unwrap();
+        assert_eq!(res, to_binary(&height).unwrap());
+    }
+
+    #[test]
+    fn test_query_timestamp_at_height_not_supported() {
+        let start_header = &get_test_headers()[0];
+        let client_id = "test_client".to_string();
+        let deps = init_client(&client_id, start_header, None);
+
+        let msg = QueryMsg::GetTimestampAtHeight {
+            client_id: client_id.clone(),
+            height: start_header.main_height,
+        };
+        let err = query(deps.as_ref(), mock_env(), msg).unwrap_err();
+
+        assert_eq!(
+            err,
+            StdError::GenericErr {
+                msg: ContractError::TimestampNotSupported.to_string()
+            }
+        );
     }
 
     #[test]
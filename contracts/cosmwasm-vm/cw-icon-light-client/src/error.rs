@@ -27,6 +27,10 @@ pub enum ContractError {
 
     #[error("Failed to save consensus state")]
     FailedToSaveConsensusState,
+    #[error("Failed to save validator set")]
+    FailedToSaveValidatorSet,
+    #[error("Validator set not found for {client_id:?} at height {height:?}")]
+    ValidatorSetNotFound { height: u64, client_id: String },
     #[error("Insufficient validator signatures supplied")]
     InSuffcientQuorum,
     #[error("Clientstate already exists for {0}")]
@@ -83,6 +87,18 @@ pub enum ContractError {
 
     #[error("Height {height:?} already updated ")]
     HeightAlreadyUpdated { height: u64 },
+
+    #[error("Invalid misbehaviour {0}")]
+    InvalidMisbehaviour(String),
+
+    #[error("Invalid upgrade {0}")]
+    InvalidUpgrade(String),
+
+    #[error("Invalid trust level {numerator:?}/{denominator:?}")]
+    InvalidTrustLevel { numerator: u64, denominator: u64 },
+
+    #[error("Timestamp not supported, ICON headers carry no block time")]
+    TimestampNotSupported,
 }
 
 impl From<CwErrors> for ContractError {
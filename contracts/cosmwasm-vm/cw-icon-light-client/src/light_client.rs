@@ -1,13 +1,14 @@
-use crate::constants::TRUST_LEVEL;
+use crate::constants::{MAX_PRUNE_PER_UPDATE, TRUST_LEVEL};
 use crate::state::CwContext;
 use crate::traits::{ConsensusStateUpdate, IContext, ILightClient};
 use crate::ContractError;
 use common::icon::icon::lightclient::v1::ConsensusState;
-use common::icon::icon::lightclient::v1::{ClientState, TrustLevel};
-use common::icon::icon::types::v1::{BtpHeader, SignedHeader};
+use common::icon::icon::lightclient::v1::{ClientState, Misbehaviour, TrustLevel};
+use common::icon::icon::types::v1::{BtpHeader, MerkleProofs, SignedHeader};
 use common::traits::AnyTypes;
-use common::utils::keccak256;
+use common::utils::{calculate_root, keccak256};
 use cosmwasm_std::Addr;
+use cw_common::commitment::{upgraded_client_state_path, upgraded_consensus_state_path};
 use cw_common::cw_println;
 use prost::Message;
 
@@ -20,7 +21,29 @@ impl<'a> IconClient<'a> {
         Self { context }
     }
     pub fn has_quorum_of(n_validators: u64, votes: u64, trust_level: &TrustLevel) -> bool {
-        votes * trust_level.denominator > n_validators * trust_level.numerator
+        votes as u128 * trust_level.denominator as u128
+            > n_validators as u128 * trust_level.numerator as u128
+    }
+
+    /// Returns the trust level configured for the client, falling back to the default
+    /// two-thirds threshold for client states created without one.
+    pub fn trust_level_of(state: &ClientState) -> TrustLevel {
+        state.trust_level.clone().unwrap_or(TRUST_LEVEL)
+    }
+
+    /// A trust level must lie within [1/3, 1) so that a quorum is reachable and cannot be
+    /// satisfied by less than a third of the validators.
+    pub fn validate_trust_level(trust_level: &TrustLevel) -> Result<(), ContractError> {
+        let valid = trust_level.denominator != 0
+            && trust_level.numerator < trust_level.denominator
+            && trust_level.numerator.saturating_mul(3) >= trust_level.denominator;
+        if !valid {
+            return Err(ContractError::InvalidTrustLevel {
+                numerator: trust_level.numerator,
+                denominator: trust_level.denominator,
+            });
+        }
+        Ok(())
     }
     pub fn check_block_proof(
         &self,
@@ -31,7 +54,7 @@ impl<'a> IconClient<'a> {
     ) -> Result<bool, ContractError> {
         let mut votes = u64::default();
         let state = self.context.get_client_state(client_id)?;
-        let trust_level: &TrustLevel = &TRUST_LEVEL;
+        let trust_level: &TrustLevel = &Self::trust_level_of(&state);
         let decision = header
             .get_network_type_section_decision_hash(&state.src_network_id, state.network_type_id);
 
@@ -70,6 +93,190 @@ impl<'a> IconClient<'a> {
         Ok(true)
     }
 
+    /// Verifies that `signed_header` chains to the trusted consensus state it references and
+    /// carries a quorum of signatures from the validators committed in that state. When the
+    /// header omits its validators, the set stored for the trusted height is used. Returns the
+    /// validators that signed the header.
+    fn verify_signed_header(
+        &self,
+        client_id: &str,
+        state: &ClientState,
+        signed_header: &SignedHeader,
+        btp_header: &BtpHeader,
+    ) -> Result<Vec<Vec<u8>>, ContractError> {
+        if state.network_id != btp_header.network_id {
+            return Err(ContractError::InvalidHeaderUpdate(
+                "network id mismatch".to_string(),
+            ));
+        }
+
+        let trusted_consensus_state = self
+            .context
+            .get_consensus_state(client_id, signed_header.trusted_height)?;
+
+        let validators = if signed_header.current_validators.is_empty() {
+            self.context
+                .get_validator_set(client_id, signed_header.trusted_height)?
+        } else {
+            signed_header.current_validators.clone()
+        };
+
+        self.verify_linked_header(
+            client_id,
+            signed_header,
+            btp_header,
+            &validators,
+            &trusted_consensus_state.next_proof_context_hash,
+        )?;
+        Ok(validators)
+    }
+
+    /// Verifies that `validators` are the ones committed by `trusted_proof_context_hash` and
+    /// that they signed `btp_header` with a quorum.
+    fn verify_linked_header(
+        &self,
+        client_id: &str,
+        signed_header: &SignedHeader,
+        btp_header: &BtpHeader,
+        validators: &Vec<Vec<u8>>,
+        trusted_proof_context_hash: &[u8],
+    ) -> Result<(), ContractError> {
+        let current_proof_context_hash = btp_header.get_next_proof_context_hash(validators);
+
+        if current_proof_context_hash != trusted_proof_context_hash {
+            return Err(ContractError::InvalidProofContextHash);
+        }
+
+        self.check_block_proof(client_id, btp_header, &signed_header.signatures, validators)?;
+        Ok(())
+    }
+
+    /// Stores `validators` as the set committed at `height` unless it is already the set in
+    /// effect at that height.
+    fn record_validator_set(
+        &mut self,
+        client_id: &str,
+        height: u64,
+        validators: &[Vec<u8>],
+    ) -> Result<(), ContractError> {
+        if self
+            .context
+            .get_validator_set(client_id, height)
+            .ok()
+            .as_deref()
+            == Some(validators)
+        {
+            return Ok(());
+        }
+        cw_println!(
+            self.context,
+            "[ValidatorSet]: client {} stores {} validators at {}",
+            client_id,
+            validators.len(),
+            height
+        );
+        self.context
+            .insert_validator_set(client_id, height, validators)
+    }
+
+    /// Records the next validators of `btp_header` as the set of its height if the header
+    /// carries them and they match its next proof context.
+    fn record_next_validator_set(
+        &mut self,
+        client_id: &str,
+        btp_header: &BtpHeader,
+    ) -> Result<(), ContractError> {
+        if btp_header.next_validators.is_empty()
+            || btp_header.get_next_proof_context_hash(&btp_header.next_validators)
+                != btp_header.next_proof_context_hash
+        {
+            return Ok(());
+        }
+        self.record_validator_set(
+            client_id,
+            btp_header.main_height,
+            &btp_header.next_validators,
+        )
+    }
+
+    /// Checks that `btp_header` is within the trusting period of both its trusted height and the
+    /// latest height of the client.
+    fn check_trusting_period(
+        state: &ClientState,
+        signed_header: &SignedHeader,
+        btp_header: &BtpHeader,
+    ) -> Result<(), ContractError> {
+        if signed_header.trusted_height > btp_header.main_height {
+            return Err(ContractError::UpdateBlockOlderThanTrustedHeight);
+        }
+
+        if (btp_header.main_height - signed_header.trusted_height) > state.trusting_period {
+            return Err(ContractError::TrustingPeriodElapsed {
+                trusted_height: signed_header.trusted_height,
+                update_height: btp_header.main_height,
+            });
+        }
+
+        if btp_header.main_height < state.latest_height
+            && (state.latest_height - btp_header.main_height) > state.trusting_period
+        {
+            return Err(ContractError::UpdateBlockTooOld);
+        }
+        Ok(())
+    }
+
+    /// Removes a bounded number of consensus states that fell out of the trusting period of the
+    /// client. Such states can no longer be used as trusted height for an update.
+    fn prune_expired_consensus_states(
+        &mut self,
+        client_id: &str,
+        state: &ClientState,
+    ) -> Result<Vec<u64>, ContractError> {
+        let expired_before = state.latest_height.saturating_sub(state.trusting_period);
+        let pruned =
+            self.context
+                .prune_consensus_states(client_id, expired_before, MAX_PRUNE_PER_UPDATE)?;
+        if !pruned.is_empty() {
+            cw_println!(
+                self.context,
+                "[Prune]: client {} pruned consensus states {:?}",
+                client_id,
+                pruned
+            );
+        }
+        Ok(pruned)
+    }
+
+    /// Two valid headers are evidence of misbehaviour if they commit to different network type
+    /// sections for the same height, or if their update numbers run against their heights.
+    pub fn is_conflicting(header_1: &BtpHeader, header_2: &BtpHeader) -> bool {
+        if header_1.main_height == header_2.main_height {
+            return header_1.get_network_type_section_hash()
+                != header_2.get_network_type_section_hash();
+        }
+        let (lower, higher) = if header_1.main_height < header_2.main_height {
+            (header_1, header_2)
+        } else {
+            (header_2, header_1)
+        };
+        lower.update_number > higher.update_number
+    }
+
+    /// Verifies that `value` is committed under `path` in the message root of `consensus_state`.
+    pub fn verify_upgrade_proof(
+        consensus_state: &ConsensusState,
+        proof: &MerkleProofs,
+        path: &[u8],
+        value: &[u8],
+    ) -> Result<(), ContractError> {
+        let leaf = keccak256(&[keccak256(path), keccak256(value)].concat());
+        let message_root = calculate_root(leaf, &proof.proofs);
+        if consensus_state.message_root != message_root {
+            return Err(ContractError::InvalidMessageRoot(hex::encode(message_root)));
+        }
+        Ok(())
+    }
+
     fn validate_delay_args(
         &self,
         client_id: &str,
@@ -114,6 +321,9 @@ impl ILightClient for IconClient<'_> {
                 client_id.to_string(),
             ));
         }
+        if let Some(trust_level) = client_state.trust_level.as_ref() {
+            Self::validate_trust_level(trust_level)?;
+        }
         self.context
             .insert_client_state(client_id, client_state.clone())?;
         self.context.insert_consensus_state(
@@ -156,46 +366,16 @@ impl ILightClient for IconClient<'_> {
 
         let mut state = self.context.get_client_state(client_id)?;
 
-        if signed_header.trusted_height > btp_header.main_height {
-            return Err(ContractError::UpdateBlockOlderThanTrustedHeight);
-        }
-
-        if (btp_header.main_height - signed_header.trusted_height) > state.trusting_period {
-            return Err(ContractError::TrustingPeriodElapsed {
-                trusted_height: signed_header.trusted_height,
-                update_height: btp_header.main_height,
-            });
-        }
-
-        if btp_header.main_height < state.latest_height
-            && (state.latest_height - btp_header.main_height) > state.trusting_period
-        {
-            return Err(ContractError::UpdateBlockTooOld);
-        }
-
-        if state.network_id != btp_header.network_id {
-            return Err(ContractError::InvalidHeaderUpdate(
-                "network id mismatch".to_string(),
-            ));
+        if state.frozen_height != 0 {
+            return Err(ContractError::ClientStateFrozen(state.frozen_height));
         }
 
-        let trusted_consensus_state = self
-            .context
-            .get_consensus_state(client_id, signed_header.trusted_height)?;
+        Self::check_trusting_period(&state, &signed_header, &btp_header)?;
 
-        let current_proof_context_hash =
-            btp_header.get_next_proof_context_hash(&signed_header.current_validators);
-
-        if current_proof_context_hash != trusted_consensus_state.next_proof_context_hash {
-            return Err(ContractError::InvalidProofContextHash);
-        }
-
-        let _valid = self.check_block_proof(
-            client_id,
-            &btp_header,
-            &signed_header.signatures,
-            &signed_header.current_validators,
-        )?;
+        let validators =
+            self.verify_signed_header(client_id, &state, &signed_header, &btp_header)?;
+        self.record_validator_set(client_id, signed_header.trusted_height, &validators)?;
+        self.record_next_validator_set(client_id, &btp_header)?;
 
         if state.latest_height < btp_header.main_height {
             state.latest_height = btp_header.main_height;
@@ -212,6 +392,7 @@ impl ILightClient for IconClient<'_> {
             .insert_timestamp_at_height(client_id, btp_header.main_height)?;
         self.context
             .insert_blocknumber_at_height(client_id, btp_header.main_height)?;
+        self.prune_expired_consensus_states(client_id, &state)?;
         let commitment = keccak256(&consensus_state.encode_to_vec());
 
         Ok(ConsensusStateUpdate {
@@ -222,6 +403,315 @@ impl ILightClient for IconClient<'_> {
             height: btp_header.main_height,
         })
     }
+
+    fn update_client_batch(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        signed_headers: Vec<SignedHeader>,
+        store_heights: &[u64],
+    ) -> Result<Vec<ConsensusStateUpdate>, Self::Error> {
+        self.context.ensure_ibc_host(caller)?;
+        let mut state = self.context.get_client_state(client_id)?;
+
+        if state.frozen_height != 0 {
+            return Err(ContractError::ClientStateFrozen(state.frozen_height));
+        }
+
+        let btp_headers = signed_headers
+            .iter()
+            .map(|signed_header| {
+                signed_header.header.clone().ok_or_else(|| {
+                    ContractError::InvalidHeaderUpdate("missing btp header".to_string())
+                })
+            })
+            .collect::<Result<Vec<BtpHeader>, ContractError>>()?;
+        let last_height = btp_headers
+            .last()
+            .map(|header| header.main_height)
+            .ok_or_else(|| ContractError::InvalidHeaderUpdate("empty batch".to_string()))?;
+
+        if let Some(height) = store_heights
+            .iter()
+            .find(|height| !btp_headers.iter().any(|h| h.main_height == **height))
+        {
+            return Err(ContractError::InvalidHeaderUpdate(format!(
+                "height {height} is not in batch"
+            )));
+        }
+
+        // the first header is verified against a stored consensus state, every following
+        // header against the proof context and validators committed by the one before it
+        let mut trusted: Option<(Vec<u8>, Vec<Vec<u8>>)> = None;
+        let mut previous_height = 0;
+        for (signed_header, btp_header) in signed_headers.iter().zip(btp_headers.iter()) {
+            let validators = match trusted {
+                None => {
+                    Self::check_trusting_period(&state, signed_header, btp_header)?;
+                    let validators =
+                        self.verify_signed_header(client_id, &state, signed_header, btp_header)?;
+                    self.record_validator_set(
+                        client_id,
+                        signed_header.trusted_height,
+                        &validators,
+                    )?;
+                    validators
+                }
+                Some((ref proof_context_hash, ref committed_validators)) => {
+                    if btp_header.main_height <= previous_height {
+                        return Err(ContractError::InvalidHeaderUpdate(
+                            "headers must be in ascending order".to_string(),
+                        ));
+                    }
+                    if state.network_id != btp_header.network_id {
+                        return Err(ContractError::InvalidHeaderUpdate(
+                            "network id mismatch".to_string(),
+                        ));
+                    }
+                    let validators = if signed_header.current_validators.is_empty() {
+                        committed_validators.clone()
+                    } else {
+                        signed_header.current_validators.clone()
+                    };
+                    self.verify_linked_header(
+                        client_id,
+                        signed_header,
+                        btp_header,
+                        &validators,
+                        proof_context_hash,
+                    )?;
+                    validators
+                }
+            };
+            self.record_next_validator_set(client_id, btp_header)?;
+            let next_validators = if btp_header.next_validators.is_empty() {
+                validators
+            } else {
+                btp_header.next_validators.clone()
+            };
+            trusted = Some((
+                btp_header.to_consensus_state().next_proof_context_hash,
+                next_validators,
+            ));
+            previous_height = btp_header.main_height;
+        }
+
+        if state.latest_height < last_height {
+            state.latest_height = last_height;
+        }
+        self.context.insert_client_state(client_id, state.clone())?;
+
+        let mut updates = Vec::new();
+        for btp_header in btp_headers
+            .iter()
+            .filter(|h| h.main_height == last_height || store_heights.contains(&h.main_height))
+        {
+            let height = btp_header.main_height;
+            if self.context.get_consensus_state(client_id, height).is_ok() {
+                return Err(ContractError::HeightAlreadyUpdated { height });
+            }
+            let consensus_state = btp_header.to_consensus_state();
+            self.context
+                .insert_consensus_state(client_id, height, consensus_state.clone())?;
+            self.context.insert_timestamp_at_height(client_id, height)?;
+            self.context
+                .insert_blocknumber_at_height(client_id, height)?;
+            updates.push(ConsensusStateUpdate {
+                consensus_state_commitment: consensus_state.get_keccak_hash(),
+                client_state_commitment: state.get_keccak_hash(),
+                client_state_bytes: state.encode_to_vec(),
+                consensus_state_bytes: consensus_state.encode_to_vec(),
+                height,
+            });
+        }
+        self.prune_expired_consensus_states(client_id, &state)?;
+        cw_println!(
+            self.context,
+            "[UpdateClientBatch]: client {} updated to {} storing {} states",
+            client_id,
+            last_height,
+            updates.len()
+        );
+
+        Ok(updates)
+    }
+
+    fn prune_consensus_states(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        before_height: u64,
+        limit: u32,
+    ) -> Result<Vec<u64>, Self::Error> {
+        self.context.ensure_owner(caller)?;
+        let state = self.context.get_client_state(client_id)?;
+        let before_height = before_height.min(state.latest_height);
+        self.context
+            .prune_consensus_states(client_id, before_height, limit)
+    }
+
+    fn upgrade_client(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        upgrade_height: u64,
+        upgraded_client_state: ClientState,
+        upgraded_consensus_state: ConsensusState,
+        proof_upgrade_client: MerkleProofs,
+        proof_upgrade_consensus_state: MerkleProofs,
+    ) -> Result<ConsensusStateUpdate, Self::Error> {
+        self.context.ensure_ibc_host(caller)?;
+        let mut state = self.context.get_client_state(client_id)?;
+
+        if state.frozen_height != 0 {
+            return Err(ContractError::ClientStateFrozen(state.frozen_height));
+        }
+
+        if upgraded_client_state.frozen_height != 0 {
+            return Err(ContractError::InvalidUpgrade(
+                "upgraded client state is frozen".to_string(),
+            ));
+        }
+
+        if upgraded_client_state.latest_height <= state.latest_height {
+            return Err(ContractError::InvalidUpgrade(
+                "upgraded height must be greater than latest height".to_string(),
+            ));
+        }
+
+        if let Some(trust_level) = upgraded_client_state.trust_level.as_ref() {
+            Self::validate_trust_level(trust_level)?;
+        }
+
+        if upgrade_height > state.latest_height {
+            return Err(ContractError::InvalidUpgrade(
+                "upgrade height is beyond latest height".to_string(),
+            ));
+        }
+
+        let consensus_state = self
+            .context
+            .get_consensus_state(client_id, upgrade_height)
+            .map_err(|_| {
+                ContractError::InvalidUpgrade(format!(
+                    "no consensus state at upgrade height {upgrade_height}"
+                ))
+            })?;
+
+        Self::verify_upgrade_proof(
+            &consensus_state,
+            &proof_upgrade_client,
+            &upgraded_client_state_path(upgrade_height),
+            &upgraded_client_state.to_any().encode_to_vec(),
+        )?;
+        Self::verify_upgrade_proof(
+            &consensus_state,
+            &proof_upgrade_consensus_state,
+            &upgraded_consensus_state_path(upgrade_height),
+            &upgraded_consensus_state.to_any().encode_to_vec(),
+        )?;
+
+        state.trusting_period = upgraded_client_state.trusting_period;
+        state.src_network_id = upgraded_client_state.src_network_id;
+        state.network_id = upgraded_client_state.network_id;
+        state.network_type_id = upgraded_client_state.network_type_id;
+        state.latest_height = upgraded_client_state.latest_height;
+        state.trust_level = upgraded_client_state.trust_level;
+
+        self.context.insert_client_state(client_id, state.clone())?;
+        self.context.insert_consensus_state(
+            client_id,
+            state.latest_height,
+            upgraded_consensus_state.clone(),
+        )?;
+        self.context
+            .insert_timestamp_at_height(client_id, state.latest_height)?;
+        self.context
+            .insert_blocknumber_at_height(client_id, state.latest_height)?;
+        cw_println!(
+            self.context,
+            "[UpgradeClient]: client {} upgraded at {}",
+            client_id,
+            upgrade_height
+        );
+
+        Ok(ConsensusStateUpdate {
+            consensus_state_commitment: upgraded_consensus_state.get_keccak_hash(),
+            client_state_commitment: state.get_keccak_hash(),
+            client_state_bytes: state.encode_to_vec(),
+            consensus_state_bytes: upgraded_consensus_state.encode_to_vec(),
+            height: state.latest_height,
+        })
+    }
+
+    fn misbehaviour(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        misbehaviour: Misbehaviour,
+    ) -> Result<ClientState, Self::Error> {
+        self.context.ensure_ibc_host(caller)?;
+        if misbehaviour.client_id != client_id {
+            return Err(ContractError::InvalidMisbehaviour(
+                "client id mismatch".to_string(),
+            ));
+        }
+
+        let mut state = self.context.get_client_state(client_id)?;
+        if state.frozen_height != 0 {
+            return Err(ContractError::ClientStateFrozen(state.frozen_height));
+        }
+
+        let signed_header_1 = misbehaviour
+            .header_1
+            .and_then(|update| update.header)
+            .ok_or_else(|| ContractError::InvalidMisbehaviour("missing header 1".to_string()))?;
+        let signed_header_2 = misbehaviour
+            .header_2
+            .and_then(|update| update.header)
+            .ok_or_else(|| ContractError::InvalidMisbehaviour("missing header 2".to_string()))?;
+        let btp_header_1 = signed_header_1
+            .header
+            .clone()
+            .ok_or_else(|| ContractError::InvalidMisbehaviour("missing header 1".to_string()))?;
+        let btp_header_2 = signed_header_2
+            .header
+            .clone()
+            .ok_or_else(|| ContractError::InvalidMisbehaviour("missing header 2".to_string()))?;
+
+        if !Self::is_conflicting(&btp_header_1, &btp_header_2) {
+            return Err(ContractError::InvalidMisbehaviour(
+                "headers do not conflict".to_string(),
+            ));
+        }
+
+        for (signed_header, btp_header) in [
+            (&signed_header_1, &btp_header_1),
+            (&signed_header_2, &btp_header_2),
+        ] {
+            if signed_header.trusted_height > btp_header.main_height {
+                return Err(ContractError::UpdateBlockOlderThanTrustedHeight);
+            }
+            if (btp_header.main_height - signed_header.trusted_height) > state.trusting_period {
+                return Err(ContractError::TrustingPeriodElapsed {
+                    trusted_height: signed_header.trusted_height,
+                    update_height: btp_header.main_height,
+                });
+            }
+            self.verify_signed_header(client_id, &state, signed_header, btp_header)?;
+        }
+
+        state.frozen_height = btp_header_1.main_height.min(btp_header_2.main_height);
+        self.context.insert_client_state(client_id, state.clone())?;
+        cw_println!(
+            self.context,
+            "[Misbehaviour]: client {} frozen at {}",
+            client_id,
+            state.frozen_height
+        );
+
+        Ok(state)
+    }
 }
 
 #[cfg(test)]
@@ -265,6 +755,70 @@ mod test {
         assert_eq!(err, ContractError::NotEnoughtBlocksElapsed)
     }
 
+    #[test]
+    fn test_validate_trust_level() {
+        let level = |numerator, denominator| TrustLevel {
+            numerator,
+            denominator,
+        };
+        assert!(IconClient::validate_trust_level(&level(1, 3)).is_ok());
+        assert!(IconClient::validate_trust_level(&level(2, 3)).is_ok());
+        assert!(IconClient::validate_trust_level(&level(99, 100)).is_ok());
+
+        for (numerator, denominator) in [(0, 0), (1, 0), (1, 4), (3, 3), (4, 3)] {
+            assert_eq!(
+                IconClient::validate_trust_level(&level(numerator, denominator)),
+                Err(ContractError::InvalidTrustLevel {
+                    numerator,
+                    denominator
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_has_quorum_of_custom_trust_level() {
+        let strict = TrustLevel {
+            numerator: 3,
+            denominator: 4,
+        };
+        assert!(IconClient::has_quorum_of(4, 3, &TRUST_LEVEL));
+        assert!(!IconClient::has_quorum_of(4, 3, &strict));
+        assert!(IconClient::has_quorum_of(4, 4, &strict));
+
+        let state = ClientState {
+            trust_level: Some(strict.clone()),
+            ..ClientState::default()
+        };
+        assert_eq!(IconClient::trust_level_of(&state), strict);
+        assert_eq!(
+            IconClient::trust_level_of(&ClientState::default()),
+            TRUST_LEVEL
+        );
+    }
+
+    #[test]
+    fn test_is_conflicting_same_height() {
+        let header = get_test_headers()[1].clone();
+        assert!(!IconClient::is_conflicting(&header, &header));
+
+        let mut forged = header.clone();
+        forged.message_root = vec![1, 2, 3];
+        assert!(IconClient::is_conflicting(&header, &forged));
+    }
+
+    #[test]
+    fn test_is_conflicting_update_number_order() {
+        let headers = get_test_headers();
+        let lower = headers[1].clone();
+        let mut higher = headers[2].clone();
+        assert!(!IconClient::is_conflicting(&lower, &higher));
+
+        higher.update_number = 0;
+        assert!(IconClient::is_conflicting(&lower, &higher));
+        assert!(IconClient::is_conflicting(&higher, &lower));
+    }
+
     #[test]
     #[should_panic(expected = "InSuffcientQuorum")]
     fn check_block_proof_with_empty_validators() {
@@ -1,7 +1,11 @@
 use std::marker::PhantomData;
 
 use crate::{
-    state::{CLIENT_STATES, CONFIG, CONSENSUS_STATES, PROCESSED_HEIGHTS, PROCESSED_TIMES},
+    constants::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT},
+    state::{
+        CLIENT_STATES, CONFIG, CONSENSUS_STATES, CONSENSUS_STATE_COUNTS, PROCESSED_HEIGHTS,
+        PROCESSED_TIMES, VALIDATOR_SETS,
+    },
     traits::Config,
     ContractError,
 };
@@ -13,8 +17,8 @@ use common::{
     traits::AnyTypes,
     utils::{calculate_root, keccak256},
 };
-use cosmwasm_std::{Deps, Order, StdResult, Storage};
-use cw_common::{cw_println, hex_string::HexString};
+use cosmwasm_std::{Deps, Env, Order, StdResult, Storage};
+use cw_common::{client_response::ConsensusStateInfo, cw_println, hex_string::HexString};
 use cw_storage_plus::Bound;
 
 use prost::Message;
@@ -132,16 +136,45 @@ impl QueryHandler {
             })
     }
 
+    /// Checks that `delay_time_period` nanoseconds and `delay_block_period` blocks have passed
+    /// since the consensus state at `height` was processed by this client. A zero delay skips
+    /// the lookup so proofs of handshake messages do not depend on the processed records.
+    pub fn validate_delay_args(
+        storage: &dyn Storage,
+        env: &Env,
+        client_id: &str,
+        height: u64,
+        delay_time_period: u64,
+        delay_block_period: u64,
+    ) -> Result<(), ContractError> {
+        if delay_time_period > 0 {
+            let processed_time = Self::get_processed_time_at_height(storage, client_id, height)?;
+            if env.block.time.nanos() < processed_time.saturating_add(delay_time_period) {
+                return Err(ContractError::NotEnoughtTimeElapsed);
+            }
+        }
+        if delay_block_period > 0 {
+            let processed_height =
+                Self::get_processed_blocknumber_at_height(storage, client_id, height)?;
+            if env.block.height < processed_height.saturating_add(delay_block_period) {
+                return Err(ContractError::NotEnoughtBlocksElapsed);
+            }
+        }
+        Ok(())
+    }
+
     /**
      * @dev verifyMembership is a generic proof verification method which verifies a proof of the existence of a value at a given CommitmentPath at the specified height.
      * The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24).
      */
+    #[allow(clippy::too_many_arguments)]
     pub fn verify_membership(
         deps: Deps,
+        env: &Env,
         client_id: &str,
         height: u64,
-        _delay_time_period: u64,
-        _delay_block_period: u64,
+        delay_time_period: u64,
+        delay_block_period: u64,
         proof: &[MerkleNode],
         value: &[u8],
         path: &[u8],
@@ -161,7 +194,7 @@ impl QueryHandler {
 
         let state = Self::get_client_state(deps.storage, client_id)?;
 
-        if state.frozen_height != 0 && height > state.frozen_height {
+        if state.frozen_height != 0 {
             return Err(ContractError::ClientStateFrozen(state.frozen_height));
         }
 
@@ -170,8 +203,14 @@ impl QueryHandler {
             value_hash = keccak256(value).to_vec();
         }
 
-        // let _ =
-        //     self.validate_delay_args(client_id, height, delay_time_period, delay_block_period)?;
+        Self::validate_delay_args(
+            deps.storage,
+            env,
+            client_id,
+            height,
+            delay_time_period,
+            delay_block_period,
+        )?;
         let consensus_state: ConsensusState =
             Self::get_consensus_state(deps.storage, client_id, height)?;
         cw_println!(
@@ -213,8 +252,10 @@ impl QueryHandler {
      * @dev verifyNonMembership is a generic proof verification method which verifies the absence of a given CommitmentPath at a specified height.
      * The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24).
      */
+    #[allow(clippy::too_many_arguments)]
     pub fn verify_non_membership(
         deps: Deps,
+        env: &Env,
         client_id: &str,
         height: u64,
         delay_time_period: u64,
@@ -224,6 +265,7 @@ impl QueryHandler {
     ) -> Result<bool, ContractError> {
         Self::verify_membership(
             deps,
+            env,
             client_id,
             height,
             delay_time_period,
@@ -251,16 +293,172 @@ impl QueryHandler {
         let keys = result.into_iter().map(|t| t.0 .1).collect::<Vec<u64>>();
         Ok(keys)
     }
+
+    /// Returns the validator set in effect at `height`, i.e. the last set committed at or below
+    /// `height`, together with the height it was committed at. These validators sign the headers
+    /// trusting a consensus state at `height`.
+    pub fn get_validator_set(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<(u64, Vec<Vec<u8>>), ContractError> {
+        VALIDATOR_SETS
+            .prefix(client_id.to_string())
+            .range(
+                storage,
+                None,
+                Some(Bound::inclusive(height)),
+                Order::Descending,
+            )
+            .next()
+            .transpose()
+            .map_err(ContractError::Std)?
+            .ok_or_else(|| ContractError::ValidatorSetNotFound {
+                height,
+                client_id: client_id.to_string(),
+            })
+    }
+
+    /// Returns the number of consensus states currently stored for `client_id`.
+    pub fn get_consensus_state_count(
+        storage: &dyn Storage,
+        client_id: &str,
+    ) -> Result<u64, ContractError> {
+        Ok(CONSENSUS_STATE_COUNTS
+            .may_load(storage, client_id.to_string())?
+            .unwrap_or(0))
+    }
+
+    /// Lists the stored consensus state heights of `client_id` in the given order, starting after
+    /// `start_after` when provided.
+    pub fn list_consensus_state_heights(
+        storage: &dyn Storage,
+        client_id: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Order,
+    ) -> Result<Vec<u64>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        let (min, max) = match order {
+            Order::Ascending => (start, None),
+            Order::Descending => (None, start),
+        };
+        CONSENSUS_STATES
+            .prefix(client_id.to_string())
+            .keys(storage, min, max, order)
+            .take(limit)
+            .collect::<StdResult<Vec<u64>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Same as `list_consensus_state_heights` but returns the consensus state of each height
+    /// along with the time and block height at which it was stored. ICON consensus states carry
+    /// no block time, so `timestamp` is always empty.
+    pub fn list_consensus_states(
+        storage: &dyn Storage,
+        client_id: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Order,
+    ) -> Result<Vec<ConsensusStateInfo>, ContractError> {
+        Self::list_consensus_state_heights(storage, client_id, start_after, limit, order)?
+            .into_iter()
+            .map(|height| {
+                Ok(ConsensusStateInfo {
+                    height,
+                    timestamp: None,
+                    processed_time: Self::get_processed_time_at_height(storage, client_id, height)
+                        .ok(),
+                    processed_height: Self::get_processed_blocknumber_at_height(
+                        storage, client_id, height,
+                    )
+                    .ok(),
+                    consensus_state: Self::get_consensus_state_any(storage, client_id, height)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns up to `limit` of the lowest stored heights of `client_id` below `before_height`.
+    pub fn get_consensus_heights_before(
+        storage: &dyn Storage,
+        client_id: &str,
+        before_height: u64,
+        limit: u32,
+    ) -> Result<Vec<u64>, ContractError> {
+        CONSENSUS_STATES
+            .prefix(client_id.to_string())
+            .keys(
+                storage,
+                None,
+                Some(Bound::exclusive(before_height)),
+                Order::Ascending,
+            )
+            .take(limit as usize)
+            .collect::<StdResult<Vec<u64>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns up to `limit` heights of validator sets of `client_id` that no longer apply to any
+    /// stored consensus state, i.e. the sets replaced before the lowest remaining consensus height.
+    pub fn get_stale_validator_set_heights(
+        storage: &dyn Storage,
+        client_id: &str,
+        limit: u32,
+    ) -> Result<Vec<u64>, ContractError> {
+        let lowest = CONSENSUS_STATES
+            .prefix(client_id.to_string())
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .transpose()
+            .map_err(ContractError::Std)?;
+        let in_effect =
+            match lowest.map(|height| Self::get_validator_set(storage, client_id, height)) {
+                Some(Ok((height, _validators))) => height,
+                Some(Err(ContractError::ValidatorSetNotFound { .. })) | None => return Ok(vec![]),
+                Some(Err(e)) => return Err(e),
+            };
+        VALIDATOR_SETS
+            .prefix(client_id.to_string())
+            .keys(
+                storage,
+                None,
+                Some(Bound::exclusive(in_effect)),
+                Order::Ascending,
+            )
+            .take(limit as usize)
+            .collect::<StdResult<Vec<u64>>>()
+            .map_err(ContractError::Std)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::MockStorage;
+    use common::{icon::icon::lightclient::v1::ConsensusState, traits::AnyTypes};
+    use cosmwasm_std::{
+        testing::{mock_env, MockStorage},
+        Order,
+    };
+    use prost::Message;
 
-    use crate::state::CONSENSUS_STATES;
+    use crate::state::{CONSENSUS_STATES, PROCESSED_HEIGHTS, PROCESSED_TIMES, VALIDATOR_SETS};
+    use crate::ContractError;
 
     use super::QueryHandler;
 
+    fn save_consensus_states(store: &mut MockStorage, heights: &[u64]) {
+        for height in heights {
+            let state = ConsensusState {
+                message_root: height.to_be_bytes().to_vec(),
+                ..ConsensusState::default()
+            };
+            CONSENSUS_STATES
+                .save(store, ("test".to_string(), *height), &state.encode_to_vec())
+                .unwrap();
+        }
+    }
+
     #[test]
     fn test_previous_consensus() {
         let mut store = MockStorage::new();
@@ -278,4 +476,153 @@ mod tests {
 
         println!("{result:?}");
     }
+
+    #[test]
+    fn test_list_consensus_state_heights() {
+        let mut store = MockStorage::new();
+        save_consensus_states(&mut store, &[70, 80, 100, 120]);
+        CONSENSUS_STATES
+            .save(&mut store, ("other".to_string(), 90), &vec![])
+            .unwrap();
+
+        let result = QueryHandler::list_consensus_state_heights(
+            &store,
+            "test",
+            None,
+            None,
+            Order::Ascending,
+        )
+        .unwrap();
+        assert_eq!(result, vec![70, 80, 100, 120]);
+
+        let result = QueryHandler::list_consensus_state_heights(
+            &store,
+            "test",
+            Some(80),
+            Some(1),
+            Order::Ascending,
+        )
+        .unwrap();
+        assert_eq!(result, vec![100]);
+
+        let result = QueryHandler::list_consensus_state_heights(
+            &store,
+            "test",
+            Some(100),
+            None,
+            Order::Descending,
+        )
+        .unwrap();
+        assert_eq!(result, vec![80, 70]);
+    }
+
+    #[test]
+    fn test_list_consensus_state_heights_max_limit() {
+        let mut store = MockStorage::new();
+        save_consensus_states(&mut store, &(1..=150).collect::<Vec<u64>>());
+
+        let result = QueryHandler::list_consensus_state_heights(
+            &store,
+            "test",
+            None,
+            Some(1000),
+            Order::Ascending,
+        )
+        .unwrap();
+        assert_eq!(result.len(), 100);
+    }
+
+    #[test]
+    fn test_list_consensus_states() {
+        let mut store = MockStorage::new();
+        save_consensus_states(&mut store, &[70, 80]);
+        PROCESSED_TIMES
+            .save(&mut store, ("test".to_string(), 80), &1000)
+            .unwrap();
+        PROCESSED_HEIGHTS
+            .save(&mut store, ("test".to_string(), 80), &12)
+            .unwrap();
+
+        let result =
+            QueryHandler::list_consensus_states(&store, "test", None, None, Order::Descending)
+                .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].height, 80);
+        assert_eq!(result[0].timestamp, None);
+        assert_eq!(result[0].processed_time, Some(1000));
+        assert_eq!(result[0].processed_height, Some(12));
+        assert_eq!(
+            result[0].consensus_state,
+            QueryHandler::get_consensus_state(&store, "test", 80)
+                .unwrap()
+                .to_any()
+                .encode_to_vec()
+        );
+        assert_eq!(result[1].height, 70);
+        assert_eq!(result[1].processed_time, None);
+        assert_eq!(result[1].processed_height, None);
+    }
+
+    #[test]
+    fn test_get_validator_set() {
+        let mut store = MockStorage::new();
+        VALIDATOR_SETS
+            .save(&mut store, ("test".to_string(), 10), &vec![vec![1]])
+            .unwrap();
+        VALIDATOR_SETS
+            .save(
+                &mut store,
+                ("test".to_string(), 50),
+                &vec![vec![2], vec![3]],
+            )
+            .unwrap();
+
+        assert_eq!(
+            QueryHandler::get_validator_set(&store, "test", 30).unwrap(),
+            (10, vec![vec![1]])
+        );
+        assert_eq!(
+            QueryHandler::get_validator_set(&store, "test", 50).unwrap(),
+            (50, vec![vec![2], vec![3]])
+        );
+        assert_eq!(
+            QueryHandler::get_validator_set(&store, "test", 5),
+            Err(ContractError::ValidatorSetNotFound {
+                height: 5,
+                client_id: "test".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_delay_args() {
+        let mut store = MockStorage::new();
+        let mut env = mock_env();
+        PROCESSED_TIMES
+            .save(
+                &mut store,
+                ("test".to_string(), 80),
+                &env.block.time.nanos(),
+            )
+            .unwrap();
+        PROCESSED_HEIGHTS
+            .save(&mut store, ("test".to_string(), 80), &env.block.height)
+            .unwrap();
+
+        assert_eq!(
+            QueryHandler::validate_delay_args(&store, &env, "test", 80, 100, 0),
+            Err(ContractError::NotEnoughtTimeElapsed)
+        );
+        assert_eq!(
+            QueryHandler::validate_delay_args(&store, &env, "test", 80, 0, 2),
+            Err(ContractError::NotEnoughtBlocksElapsed)
+        );
+        // no delay does not need the processed records of the height
+        QueryHandler::validate_delay_args(&store, &env, "test", 90, 0, 0).unwrap();
+
+        env.block.time = env.block.time.plus_nanos(100);
+        env.block.height += 2;
+        QueryHandler::validate_delay_args(&store, &env, "test", 80, 100, 2).unwrap();
+    }
 }
@@ -7,6 +7,7 @@ use cosmwasm_std::Api;
 use cosmwasm_std::DepsMut;
 use cosmwasm_std::Env;
 use cosmwasm_std::Storage;
+use cosmwasm_std::{Order, StdResult};
 
 use cw_storage_plus::{Item, Map};
 use debug_print::debug_eprintln;
@@ -20,11 +21,31 @@ use crate::ContractError;
 type ClientId = String;
 pub const CLIENT_STATES: Map<String, Vec<u8>> = Map::new("CLIENT_STATES");
 pub const CONSENSUS_STATES: Map<(ClientId, u64), Vec<u8>> = Map::new("CONSENSUS_STATES");
+// number of entries of CONSENSUS_STATES per client, kept on insert and prune
+pub const CONSENSUS_STATE_COUNTS: Map<ClientId, u64> = Map::new("CONSENSUS_STATE_COUNTS");
 pub const PROCESSED_TIMES: Map<(ClientId, u64), u64> = Map::new("PROCESSED_TIMES");
 pub const PROCESSED_HEIGHTS: Map<(ClientId, u64), u64> = Map::new("PROCESSED_HEIGHTS");
+// validators committed as next proof context at the keyed height
+pub const VALIDATOR_SETS: Map<(ClientId, u64), Vec<Vec<u8>>> = Map::new("VALIDATOR_SETS");
 
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
 
+/// Counts the consensus states stored for every client into `CONSENSUS_STATE_COUNTS`, run once on
+/// migration from versions without the counter.
+pub fn init_consensus_state_counts(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let client_ids = CLIENT_STATES
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for client_id in client_ids {
+        let count = CONSENSUS_STATES
+            .prefix(client_id.clone())
+            .keys(storage, None, None, Order::Ascending)
+            .count();
+        CONSENSUS_STATE_COUNTS.save(storage, client_id, &(count as u64))?;
+    }
+    Ok(())
+}
+
 pub struct CwContext<'a> {
     pub storage: &'a mut dyn Storage,
     pub api: &'a dyn Api,
@@ -73,8 +94,15 @@ impl<'a> IContext for CwContext<'a> {
         state: ConsensusState,
     ) -> Result<(), Self::Error> {
         let data = state.encode_to_vec();
+        let key = (client_id.to_string(), height);
+        if !CONSENSUS_STATES.has(self.storage, key.clone()) {
+            let count = QueryHandler::get_consensus_state_count(self.storage, client_id)?;
+            CONSENSUS_STATE_COUNTS
+                .save(self.storage, client_id.to_string(), &(count + 1))
+                .map_err(|_e| ContractError::FailedToSaveClientState)?;
+        }
         CONSENSUS_STATES
-            .save(self.storage, (client_id.to_string(), height), &data)
+            .save(self.storage, key, &data)
             .map_err(|_e| ContractError::FailedToSaveClientState)
     }
 
@@ -156,6 +184,60 @@ impl<'a> IContext for CwContext<'a> {
         QueryHandler::get_processed_blocknumber_at_height(self.storage, client_id, height)
     }
 
+    fn get_validator_set(&self, client_id: &str, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        QueryHandler::get_validator_set(self.storage, client_id, height)
+            .map(|(_height, validators)| validators)
+    }
+
+    fn insert_validator_set(
+        &mut self,
+        client_id: &str,
+        height: u64,
+        validators: &[Vec<u8>],
+    ) -> Result<(), Self::Error> {
+        VALIDATOR_SETS
+            .save(
+                self.storage,
+                (client_id.to_string(), height),
+                &validators.to_vec(),
+            )
+            .map_err(|_e| ContractError::FailedToSaveValidatorSet)
+    }
+
+    fn prune_consensus_states(
+        &mut self,
+        client_id: &str,
+        before_height: u64,
+        limit: u32,
+    ) -> Result<Vec<u64>, Self::Error> {
+        let heights = QueryHandler::get_consensus_heights_before(
+            self.storage,
+            client_id,
+            before_height,
+            limit,
+        )?;
+        for height in heights.iter() {
+            let key = (client_id.to_string(), *height);
+            CONSENSUS_STATES.remove(self.storage, key.clone());
+            PROCESSED_TIMES.remove(self.storage, key.clone());
+            PROCESSED_HEIGHTS.remove(self.storage, key);
+        }
+        if !heights.is_empty() {
+            let count = QueryHandler::get_consensus_state_count(self.storage, client_id)?;
+            CONSENSUS_STATE_COUNTS.save(
+                self.storage,
+                client_id.to_string(),
+                &count.saturating_sub(heights.len() as u64),
+            )?;
+        }
+        // the set in effect at the lowest remaining height is kept, later sets are still ahead
+        for height in QueryHandler::get_stale_validator_set_heights(self.storage, client_id, limit)?
+        {
+            VALIDATOR_SETS.remove(self.storage, (client_id.to_string(), height));
+        }
+        Ok(heights)
+    }
+
     fn ensure_ibc_host(&self, caller: cosmwasm_std::Addr) -> Result<(), Self::Error> {
         let config = self.get_config()?;
         if caller != config.ibc_host {
@@ -633,4 +715,104 @@ mod tests {
             .get_processed_block_at_height(client_id, 3)
             .unwrap();
     }
+
+    #[test]
+    fn test_cwcontext_prune_consensus_states() {
+        let mut deps = mock_dependencies();
+        let mut contract = CwContext::new(deps.as_mut(), mock_env());
+        let client_id = "test_client";
+
+        for height in [10, 20, 30] {
+            contract
+                .insert_consensus_state(client_id, height, ConsensusState::default())
+                .unwrap();
+            contract
+                .insert_timestamp_at_height(client_id, height)
+                .unwrap();
+            contract
+                .insert_blocknumber_at_height(client_id, height)
+                .unwrap();
+        }
+        for height in [5, 15, 28] {
+            contract
+                .insert_validator_set(client_id, height, &[vec![height as u8]])
+                .unwrap();
+        }
+        contract
+            .insert_consensus_state("other_client", 5, ConsensusState::default())
+            .unwrap();
+        contract
+            .insert_validator_set("other_client", 1, &[vec![1]])
+            .unwrap();
+
+        let pruned = contract.prune_consensus_states(client_id, 25, 1).unwrap();
+        assert_eq!(pruned, vec![10]);
+        assert_eq!(
+            QueryHandler::get_consensus_state_count(contract.storage, client_id).unwrap(),
+            2
+        );
+        assert!(!VALIDATOR_SETS.has(contract.storage, (client_id.to_string(), 5)));
+        assert_eq!(
+            contract.get_validator_set(client_id, 20).unwrap(),
+            vec![vec![15]]
+        );
+
+        let pruned = contract.prune_consensus_states(client_id, 25, 10).unwrap();
+        assert_eq!(pruned, vec![20]);
+        assert!(contract.get_consensus_state(client_id, 20).is_err());
+        assert!(contract
+            .get_processed_time_at_height(client_id, 20)
+            .is_err());
+        assert!(contract
+            .get_processed_block_at_height(client_id, 20)
+            .is_err());
+        assert!(contract.get_consensus_state(client_id, 30).is_ok());
+        assert!(!VALIDATOR_SETS.has(contract.storage, (client_id.to_string(), 15)));
+        assert_eq!(
+            contract.get_validator_set(client_id, 30).unwrap(),
+            vec![vec![28]]
+        );
+        assert_eq!(
+            QueryHandler::get_consensus_state_count(contract.storage, "other_client").unwrap(),
+            1
+        );
+        assert_eq!(
+            contract.get_validator_set("other_client", 5).unwrap(),
+            vec![vec![1]]
+        );
+    }
+
+    #[test]
+    fn test_init_consensus_state_counts() {
+        let mut storage = MockStorage::new();
+        CLIENT_STATES
+            .save(&mut storage, "test_client".to_string(), &vec![])
+            .unwrap();
+        for height in [10, 20] {
+            CONSENSUS_STATES
+                .save(&mut storage, ("test_client".to_string(), height), &vec![])
+                .unwrap();
+        }
+
+        init_consensus_state_counts(&mut storage).unwrap();
+        assert_eq!(
+            QueryHandler::get_consensus_state_count(&storage, "test_client").unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_insert_consensus_state_counts_new_heights_only() {
+        let mut deps = mock_dependencies();
+        let mut contract = CwContext::new(deps.as_mut(), mock_env());
+        for height in [10, 10, 20] {
+            contract
+                .insert_consensus_state("test_client", height, ConsensusState::default())
+                .unwrap();
+        }
+        assert_eq!(
+            QueryHandler::get_consensus_state_count(contract.storage, "test_client").unwrap(),
+            2
+        );
+    }
 }
@@ -1,7 +1,8 @@
 use common::icon::icon::lightclient::v1::ClientState;
 use common::icon::icon::lightclient::v1::ConsensusState;
+use common::icon::icon::lightclient::v1::Misbehaviour;
 
-use common::icon::icon::types::v1::SignedHeader;
+use common::icon::icon::types::v1::{MerkleProofs, SignedHeader};
 use cosmwasm_std::Addr;
 
 use serde::Deserialize;
@@ -71,6 +72,61 @@ pub trait ILightClient {
         client_id: &str,
         header: SignedHeader,
     ) -> Result<ConsensusStateUpdate, Self::Error>;
+
+    /**
+     * @dev updateClientBatch updates the client corresponding to `clientId` with an ordered list of headers.
+     * The first header is verified against the trusted consensus state it references and every following
+     * header against the validators committed by its predecessor. Only the consensus states at `store_heights`
+     * and at the last header are persisted, and a commitment is returned for each of them.
+     */
+    fn update_client_batch(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        signed_headers: Vec<SignedHeader>,
+        store_heights: &[u64],
+    ) -> Result<Vec<ConsensusStateUpdate>, Self::Error>;
+
+    /**
+     * @dev upgradeClient upgrades the client corresponding to `clientId` to the given client and consensus state.
+     * Both states must be proven under the upgrade paths at `upgradeHeight` against the consensus state
+     * stored at that height. If succeeded, it returns a commitment for the upgraded state.
+     */
+    #[allow(clippy::too_many_arguments)]
+    fn upgrade_client(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        upgrade_height: u64,
+        upgraded_client_state: ClientState,
+        upgraded_consensus_state: ConsensusState,
+        proof_upgrade_client: MerkleProofs,
+        proof_upgrade_consensus_state: MerkleProofs,
+    ) -> Result<ConsensusStateUpdate, Self::Error>;
+
+    /**
+     * @dev pruneConsensusStates removes up to `limit` of the oldest consensus states of the client corresponding
+     * to `clientId` stored below `before_height`, along with their processed time and height. The consensus state
+     * at the latest height is never removed. Only the owner can prune, and the pruned heights are returned.
+     */
+    fn prune_consensus_states(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        before_height: u64,
+        limit: u32,
+    ) -> Result<Vec<u64>, Self::Error>;
+
+    /**
+     * @dev misbehaviour verifies two conflicting headers for the client corresponding to `clientId`.
+     * If both headers are valid and they conflict, the client is frozen and the frozen state is returned.
+     */
+    fn misbehaviour(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        misbehaviour: Misbehaviour,
+    ) -> Result<ClientState, Self::Error>;
 }
 
 pub trait IStoreReader {}
@@ -128,6 +184,19 @@ pub trait IContext {
         client_id: &str,
         height: u64,
     ) -> Result<u64, Self::Error>;
+    fn get_validator_set(&self, client_id: &str, height: u64) -> Result<Vec<Vec<u8>>, Self::Error>;
+    fn insert_validator_set(
+        &mut self,
+        client_id: &str,
+        height: u64,
+        validators: &[Vec<u8>],
+    ) -> Result<(), Self::Error>;
+    fn prune_consensus_states(
+        &mut self,
+        client_id: &str,
+        before_height: u64,
+        limit: u32,
+    ) -> Result<Vec<u64>, Self::Error>;
 
     fn ensure_owner(&self, caller: Addr) -> Result<(), Self::Error>;
     fn ensure_ibc_host(&self, caller: Addr) -> Result<(), Self::Error>;
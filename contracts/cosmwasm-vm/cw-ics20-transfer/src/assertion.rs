@@ -0,0 +1,24 @@
+use cosmwasm_std::{Addr, Storage};
+
+use crate::{error::ContractError, state::CwIcs20Transfer};
+
+impl<'a> CwIcs20Transfer<'a> {
+    /// This function checks that `address` is the owner of the contract.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is a reference to the storage the owner is read from.
+    /// * `address`: `address` is the sender to check.
+    ///
+    /// Returns:
+    ///
+    /// `Ok(())` for the owner and `ContractError::Unauthorized` otherwise.
+    pub fn ensure_owner(&self, store: &dyn Storage, address: &Addr) -> Result<(), ContractError> {
+        let owner = self.owner().load(store)?;
+
+        if owner != address.as_str() {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+}
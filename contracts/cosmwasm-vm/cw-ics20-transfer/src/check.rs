@@ -0,0 +1,27 @@
+use crate::error::ContractError;
+
+use cw_common::cw_types::CwOrder;
+
+/// Version negotiated on ICS-20 channels.
+pub const ICS20_VERSION: &str = "ics20-1";
+/// ICS-20 packets are delivered on unordered channels.
+pub const ICS20_ORDERING: CwOrder = CwOrder::Unordered;
+
+pub fn check_order(order: &CwOrder) -> Result<(), ContractError> {
+    if order != &ICS20_ORDERING {
+        Err(ContractError::InvalidChannelOrder {})
+    } else {
+        Ok(())
+    }
+}
+
+pub fn check_version(version: &str) -> Result<(), ContractError> {
+    if version != ICS20_VERSION {
+        Err(ContractError::InvalidVersion {
+            actual: version.to_string(),
+            expected: ICS20_VERSION.to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+use debug_print::debug_println;
+
+use crate::{
+    msg::TransferMsg,
+    state::{ChannelInfo, PORT_ID},
+    types::{config::Config, denom::VOUCHER_PREFIX, LOG_PREFIX},
+    MigrateMsg,
+};
+
+use super::*;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:cw-ics20-transfer";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl<'a> CwIcs20Transfer<'a> {
+    /// This function instantiates the contract, stores its configuration and binds the `transfer`
+    /// port on the IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+    /// querier of the contract.
+    /// * `env`: `env` contains the address of this contract, which becomes the module of the port.
+    /// * `info`: `info` contains the sender of the message, who becomes the owner of the contract.
+    /// * `msg`: `msg` is the `InstantiateMsg` with the IBC host and default timeout.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` carrying the `BindPort` message for the IBC host.
+    pub fn instantiate(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+        self.owner().save(deps.storage, &info.sender.to_string())?;
+        self.set_ibc_host(deps.storage, msg.ibc_host.clone())?;
+        self.store_config(
+            deps.storage,
+            &Config {
+                default_timeout: msg.default_timeout,
+            },
+        )?;
+
+        let bind_port = self.call_host_bind_port(&msg.ibc_host, PORT_ID, &env.contract.address)?;
+
+        Ok(Response::new()
+            .add_message(bind_port)
+            .add_attribute("action", "instantiate")
+            .add_attribute("method", "init")
+            .add_attribute("ibc_host", msg.ibc_host)
+            .add_attribute("port_id", PORT_ID))
+    }
+
+    /// This function executes the messages supported by the transfer contract. IBC callbacks are only
+    /// accepted from the IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+    /// querier of the contract.
+    /// * `env`: `env` contains information about the current block, used for packet timeouts.
+    /// * `info`: `info` contains the sender of the message and the funds attached to it.
+    /// * `msg`: `msg` is the `ExecuteMsg` to execute.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn execute(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::Transfer {
+                channel_id,
+                receiver,
+                timeout,
+                memo,
+            } => {
+                let msg = TransferMsg {
+                    channel_id,
+                    receiver,
+                    timeout,
+                    memo,
+                };
+                self.execute_transfer_native(deps, env, info, msg)
+            }
+            ExecuteMsg::TransferVoucher {
+                denom,
+                amount,
+                channel_id,
+                receiver,
+                timeout,
+                memo,
+            } => {
+                let msg = TransferMsg {
+                    channel_id,
+                    receiver,
+                    timeout,
+                    memo,
+                };
+                self.execute_transfer_voucher(deps, env, info, denom, amount, msg)
+            }
+            ExecuteMsg::Receive(msg) => self.execute_receive_cw20(deps, env, info, msg),
+            ExecuteMsg::UpdateDefaultTimeout { default_timeout } => {
+                self.ensure_owner(deps.storage, &info.sender)?;
+                self.store_config(deps.storage, &Config { default_timeout })?;
+                Ok(Response::new()
+                    .add_attribute("method", "update_default_timeout")
+                    .add_attribute("default_timeout", default_timeout.to_string()))
+            }
+
+            ExecuteMsg::IbcChannelOpen { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_open(msg)
+            }
+            ExecuteMsg::IbcChannelConnect { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_connect(deps.storage, msg)
+            }
+            ExecuteMsg::IbcChannelClose { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_close(msg)
+            }
+            ExecuteMsg::IbcPacketReceive { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_receive(deps, msg)
+            }
+            ExecuteMsg::IbcPacketAck { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_ack(deps, msg)
+            }
+            ExecuteMsg::IbcPacketTimeout { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_timeout(deps, msg)
+            }
+        }
+    }
+
+    /// The `query` function answers the queries supported by the transfer contract.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `Deps` object that provides read access to the storage and API.
+    /// * `_env`: `_env` is not used by any query.
+    /// * `msg`: `msg` is the `QueryMsg` to answer.
+    ///
+    /// Returns:
+    ///
+    /// a `StdResult<Binary>` holding the JSON encoded response of the query.
+    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::GetOwner {} => to_binary(&self.owner().load(deps.storage)?),
+            QueryMsg::GetIbcHost {} => to_binary(&self.get_ibc_host(deps.storage).map_err(to_std)?),
+            QueryMsg::GetDefaultTimeout {} => to_binary(
+                &self
+                    .get_config(deps.storage)
+                    .map_err(to_std)?
+                    .default_timeout,
+            ),
+            QueryMsg::GetChannel { channel_id } => to_binary(
+                &self
+                    .get_channel(deps.storage, &channel_id)
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetChannels {} => {
+                to_binary(&self.get_channels(deps.storage).map_err(to_std)?)
+            }
+            QueryMsg::GetDenomTrace { hash } => {
+                let hash = hash.trim_start_matches(VOUCHER_PREFIX);
+                to_binary(&self.get_denom_trace(deps.storage, hash).map_err(to_std)?)
+            }
+            QueryMsg::GetDenomTraces {} => {
+                to_binary(&self.get_denom_traces(deps.storage).map_err(to_std)?)
+            }
+            QueryMsg::GetEscrowedAmount { channel_id, denom } => to_binary(
+                &self
+                    .get_escrowed_amount(deps.storage, &channel_id, &denom)
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetVoucherBalance { address, denom } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_binary(
+                    &self
+                        .get_voucher_balance(deps.storage, &address, &denom)
+                        .map_err(to_std)?,
+                )
+            }
+            QueryMsg::GetVoucherSupply { denom } => to_binary(
+                &self
+                    .get_voucher_supply(deps.storage, &denom)
+                    .map_err(to_std)?,
+            ),
+        }
+    }
+
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        _msg: MigrateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+            .map_err(ContractError::Std)?;
+        Ok(Response::default().add_attribute("migrate", "successful"))
+    }
+}
+
+impl<'a> CwIcs20Transfer<'a> {
+    /// This function validates a channel opening on the `transfer` port: the channel must be
+    /// unordered and speak `ics20-1`.
+    ///
+    /// Arguments:
+    ///
+    /// * `msg`: `msg` is the `CwChannelOpenMsg` of either the init or the try step.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the local endpoint as data.
+    pub fn on_channel_open(&self, msg: CwChannelOpenMsg) -> Result<Response, ContractError> {
+        debug_println!("{LOG_PREFIX} Called On channel open");
+        let channel = msg.channel();
+        self.check_channel(channel)?;
+
+        if let Some(counter_version) = msg.counterparty_version() {
+            check_version(counter_version)?;
+        }
+
+        Ok(Response::new()
+            .set_data(to_binary(&channel.endpoint)?)
+            .add_attribute("method", "on_channel_open")
+            .add_attribute("version", channel.version.clone()))
+    }
+
+    /// This function records a channel once its handshake completed so transfers can be sent over it.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is the storage the `ChannelInfo` is saved to.
+    /// * `msg`: `msg` is the `CwChannelConnectMsg` of either the ack or the confirm step.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the local endpoint as data.
+    pub fn on_channel_connect(
+        &self,
+        store: &mut dyn Storage,
+        msg: CwChannelConnectMsg,
+    ) -> Result<Response, ContractError> {
+        debug_println!("{LOG_PREFIX} Called On channel connect");
+        let channel = msg.channel();
+        self.check_channel(channel)?;
+
+        if let Some(counter_version) = msg.counterparty_version() {
+            check_version(counter_version)?;
+        }
+
+        let channel_info = ChannelInfo::from(channel.clone());
+        self.store_channel(store, &channel.endpoint.channel_id, &channel_info)?;
+
+        Ok(Response::new()
+            .set_data(to_binary(&channel.endpoint)?)
+            .add_attribute("method", "on_channel_connect")
+            .add_attribute("channel_id", channel.endpoint.channel_id.clone()))
+    }
+
+    /// This function handles channel closing. Tokens may be escrowed on a transfer channel, so closing
+    /// is only accepted when initiated by the counterparty.
+    ///
+    /// Arguments:
+    ///
+    /// * `msg`: `msg` is the `CwChannelCloseMsg` of either the init or the confirm step.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`, failing for `CloseInit`.
+    pub fn on_channel_close(&self, msg: CwChannelCloseMsg) -> Result<Response, ContractError> {
+        let channel = match msg {
+            CwChannelCloseMsg::CloseInit { channel: _ } => {
+                return Err(ContractError::ChannelCloseNotAllowed {})
+            }
+            CwChannelCloseMsg::CloseConfirm { channel } => channel,
+        };
+
+        Ok(Response::new()
+            .set_data(to_binary(&channel.endpoint)?)
+            .add_attribute("method", "on_channel_close")
+            .add_attribute("channel_id", channel.endpoint.channel_id))
+    }
+
+    fn check_channel(&self, channel: &CwChannel) -> Result<(), ContractError> {
+        if channel.endpoint.port_id != PORT_ID {
+            return Err(ContractError::InvalidPortId {
+                port_id: channel.endpoint.port_id.clone(),
+            });
+        }
+        check_order(&channel.order)?;
+        check_version(&channel.version)
+    }
+}
+
+fn to_std(error: ContractError) -> StdError {
+    match error {
+        ContractError::Std(error) => error,
+        error => StdError::NotFound {
+            kind: error.to_string(),
+        },
+    }
+}
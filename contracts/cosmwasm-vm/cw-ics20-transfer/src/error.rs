@@ -0,0 +1,37 @@
+use super::*;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("Unauthorized")]
+    Unauthorized {},
+    #[error("OnlyIbcHandler")]
+    OnlyIbcHandler {},
+    #[error("Channel order miss match")]
+    InvalidChannelOrder {},
+    #[error("Invalid IBC Channel Version. Got ({actual}), expected ({expected})")]
+    InvalidVersion { actual: String, expected: String },
+    #[error("ChannelCloseNotAllowed")]
+    ChannelCloseNotAllowed {},
+    #[error("ChannelNotFound {channel_id}")]
+    ChannelNotFound { channel_id: String },
+    #[error("InvalidPortId {port_id}")]
+    InvalidPortId { port_id: String },
+    #[error("Exactly one coin must be sent")]
+    InvalidFunds {},
+    #[error("Transfer amount must be greater than zero")]
+    ZeroAmount {},
+    #[error("InvalidDenom {denom}")]
+    InvalidDenom { denom: String },
+    #[error("DenomTraceNotFound {denom}")]
+    DenomTraceNotFound { denom: String },
+    #[error("InsufficientVoucherBalance {denom}")]
+    InsufficientVoucherBalance { denom: String },
+    #[error("InsufficientEscrow {channel_id} {denom}")]
+    InsufficientEscrow { channel_id: String, denom: String },
+    #[error("InvalidReceiver {receiver}")]
+    InvalidReceiver { receiver: String },
+    #[error("DecodeFailed {error}")]
+    DecodeFailed { error: String },
+}
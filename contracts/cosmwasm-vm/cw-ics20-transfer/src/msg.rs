@@ -0,0 +1,107 @@
+use super::*;
+use cosmwasm_schema::QueryResponses;
+use cw20::Cw20ReceiveMsg;
+
+use crate::{state::ChannelInfo, types::denom::DenomTrace};
+
+/// This is a Rust struct representing a message to instantiate the transfer contract.
+///
+/// Properties:
+///
+/// * `ibc_host`: address of the IBC host contract the `transfer` port is bound on.
+/// * `default_timeout`: timeout in seconds used for outgoing packets when a transfer does not
+/// specify one.
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub ibc_host: Addr,
+    pub default_timeout: u64,
+}
+
+/// The `TransferMsg` struct describes an outgoing transfer. It is also the hook message expected in
+/// a CW20 `Send` to this contract.
+///
+/// Properties:
+///
+/// * `channel_id`: local channel the tokens are sent over.
+/// * `receiver`: address of the receiver on the counterparty chain.
+/// * `timeout`: timeout in seconds from now, falls back to the configured default.
+/// * `memo`: optional memo forwarded in the packet data.
+#[cw_serde]
+pub struct TransferMsg {
+    pub channel_id: String,
+    pub receiver: String,
+    pub timeout: Option<u64>,
+    pub memo: Option<String>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Sends the single native coin attached to the message.
+    Transfer {
+        channel_id: String,
+        receiver: String,
+        timeout: Option<u64>,
+        memo: Option<String>,
+    },
+    /// Sends a voucher held in the ledger of this contract. Vouchers are not bank or CW20 tokens,
+    /// this is the only way to move them.
+    TransferVoucher {
+        denom: String,
+        amount: Uint128,
+        channel_id: String,
+        receiver: String,
+        timeout: Option<u64>,
+        memo: Option<String>,
+    },
+    /// CW20 receive hook, `msg` is a JSON encoded `TransferMsg`.
+    Receive(Cw20ReceiveMsg),
+    UpdateDefaultTimeout {
+        default_timeout: u64,
+    },
+
+    IbcChannelOpen {
+        msg: CwChannelOpenMsg,
+    },
+    IbcChannelConnect {
+        msg: CwChannelConnectMsg,
+    },
+    IbcChannelClose {
+        msg: CwChannelCloseMsg,
+    },
+    IbcPacketReceive {
+        msg: CwPacketReceiveMsg,
+    },
+    IbcPacketAck {
+        msg: CwPacketAckMsg,
+    },
+    IbcPacketTimeout {
+        msg: CwPacketTimeoutMsg,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+/// This is a Rust enum representing the queries supported by the transfer contract.
+pub enum QueryMsg {
+    #[returns(String)]
+    GetOwner {},
+    #[returns(Addr)]
+    GetIbcHost {},
+    #[returns(u64)]
+    GetDefaultTimeout {},
+    #[returns(ChannelInfo)]
+    GetChannel { channel_id: String },
+    #[returns(Vec<ChannelInfo>)]
+    GetChannels {},
+    /// `hash` is the hex part of an `ibc/{HASH}` denom.
+    #[returns(DenomTrace)]
+    GetDenomTrace { hash: String },
+    #[returns(Vec<DenomTrace>)]
+    GetDenomTraces {},
+    #[returns(Uint128)]
+    GetEscrowedAmount { channel_id: String, denom: String },
+    #[returns(Uint128)]
+    GetVoucherBalance { address: String, denom: String },
+    #[returns(Uint128)]
+    GetVoucherSupply { denom: String },
+}
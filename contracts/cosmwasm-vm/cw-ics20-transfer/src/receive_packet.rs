@@ -0,0 +1,181 @@
+use cosmwasm_std::{from_json, BankMsg, CosmosMsg, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_common::{cw_println, types::Ack};
+
+use crate::types::{
+    denom::{denom_prefix, received_from, DenomTrace, CW20_PREFIX, VOUCHER_PREFIX},
+    packet::FungibleTokenPacketData,
+    LOG_PREFIX,
+};
+
+use super::*;
+
+impl<'a> CwIcs20Transfer<'a> {
+    /// This function handles an incoming ICS-20 packet. Tokens coming back to this chain are
+    /// released from escrow, any other token is minted as a voucher to the receiver. Vouchers are
+    /// only credited in the ledger of this contract, the receiver can send them over IBC with
+    /// `TransferVoucher` but can not hold or transfer them as bank or CW20 tokens.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and API.
+    /// * `msg`: `msg` is the `CwPacketReceiveMsg` forwarded by the IBC host.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with a successful acknowledgement as data. Errors are
+    /// written as error acknowledgement by the IBC host.
+    pub fn on_packet_receive(
+        &self,
+        deps: DepsMut,
+        msg: CwPacketReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let packet = msg.packet;
+        let data = FungibleTokenPacketData::decode(&packet.data)?;
+        let receiver =
+            deps.api
+                .addr_validate(&data.receiver)
+                .map_err(|_| ContractError::InvalidReceiver {
+                    receiver: data.receiver.clone(),
+                })?;
+        cw_println!(deps, "{LOG_PREFIX} Received {} {}", data.amount, data.denom);
+
+        let mut response = Response::new();
+        let local_denom = if received_from(&packet.src.port_id, &packet.src.channel_id, &data.denom)
+        {
+            let prefix = denom_prefix(&packet.src.port_id, &packet.src.channel_id);
+            let local_denom = DenomTrace::parse(&data.denom[prefix.len()..]).local_denom();
+            self.decrease_escrow(
+                deps.storage,
+                &packet.dest.channel_id,
+                &local_denom,
+                data.amount,
+            )?;
+            if let Some(msg) = self.release(deps.storage, &receiver, &local_denom, data.amount)? {
+                response = response.add_message(msg);
+            }
+            local_denom
+        } else {
+            let prefix = denom_prefix(&packet.dest.port_id, &packet.dest.channel_id);
+            let trace = DenomTrace::parse(&format!("{prefix}{}", data.denom));
+            self.store_denom_trace(deps.storage, &trace)?;
+            let local_denom = trace.local_denom();
+            self.mint_voucher(deps.storage, &receiver, &local_denom, data.amount)?;
+            local_denom
+        };
+
+        Ok(response
+            .set_data(to_binary(&Ack::Result(Binary::from(vec![1])))?)
+            .add_attribute("method", "on_packet_receive")
+            .add_attribute("receiver", receiver)
+            .add_attribute("denom", local_denom)
+            .add_attribute("amount", data.amount))
+    }
+
+    /// This function handles the acknowledgement of a sent packet and refunds the sender when the
+    /// counterparty failed to process the transfer.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and API.
+    /// * `ack`: `ack` is the `CwPacketAckMsg` with the original packet and its acknowledgement.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` carrying the refund if any.
+    pub fn on_packet_ack(
+        &self,
+        deps: DepsMut,
+        ack: CwPacketAckMsg,
+    ) -> Result<Response, ContractError> {
+        let acknowledgement: Ack = from_json(&ack.acknowledgement.data)?;
+        match acknowledgement {
+            Ack::Result(_) => Ok(Response::new()
+                .add_attribute("method", "on_packet_ack")
+                .add_attribute("success", "true")),
+            Ack::Error(error) => {
+                let response = self.refund_packet(deps, &ack.original_packet)?;
+                Ok(response
+                    .add_attribute("method", "on_packet_ack")
+                    .add_attribute("success", "false")
+                    .add_attribute("error", error))
+            }
+        }
+    }
+
+    /// This function refunds the sender of a packet that timed out.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and API.
+    /// * `msg`: `msg` is the `CwPacketTimeoutMsg` with the packet that timed out.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` carrying the refund.
+    pub fn on_packet_timeout(
+        &self,
+        deps: DepsMut,
+        msg: CwPacketTimeoutMsg,
+    ) -> Result<Response, ContractError> {
+        let response = self.refund_packet(deps, &msg.packet)?;
+        Ok(response.add_attribute("method", "on_packet_timeout"))
+    }
+
+    /// Reverts what sending `packet` did: burned vouchers are minted back and escrowed tokens are
+    /// returned to the sender.
+    fn refund_packet(&self, deps: DepsMut, packet: &CwPacket) -> Result<Response, ContractError> {
+        let data = FungibleTokenPacketData::decode(&packet.data)?;
+        let sender = deps.api.addr_validate(&data.sender)?;
+        let local_denom = DenomTrace::parse(&data.denom).local_denom();
+
+        let mut response = Response::new();
+        if received_from(&packet.src.port_id, &packet.src.channel_id, &data.denom) {
+            self.mint_voucher(deps.storage, &sender, &local_denom, data.amount)?;
+        } else {
+            self.decrease_escrow(
+                deps.storage,
+                &packet.src.channel_id,
+                &local_denom,
+                data.amount,
+            )?;
+            if let Some(msg) = self.release(deps.storage, &sender, &local_denom, data.amount)? {
+                response = response.add_message(msg);
+            }
+        }
+
+        Ok(response
+            .add_attribute("refund_receiver", sender)
+            .add_attribute("refund_denom", local_denom)
+            .add_attribute("refund_amount", data.amount))
+    }
+
+    /// Pays out `amount` of `local_denom` held by the contract to `recipient`. Vouchers are credited
+    /// in the ledger, native coins and CW20 tokens need the returned message.
+    fn release(
+        &self,
+        store: &mut dyn Storage,
+        recipient: &Addr,
+        local_denom: &str,
+        amount: Uint128,
+    ) -> Result<Option<CosmosMsg>, ContractError> {
+        if local_denom.starts_with(VOUCHER_PREFIX) {
+            self.give_voucher(store, recipient, local_denom, amount)?;
+            return Ok(None);
+        }
+        if let Some(contract_addr) = local_denom.strip_prefix(CW20_PREFIX) {
+            return Ok(Some(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            })));
+        }
+        Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![cosmwasm_std::Coin::new(amount.u128(), local_denom)],
+        })))
+    }
+}
@@ -0,0 +1,327 @@
+use cosmwasm_std::Order;
+
+use crate::types::{config::Config, denom::DenomTrace};
+
+use super::*;
+
+/// Port the transfer contract binds on the IBC host.
+pub const PORT_ID: &str = "transfer";
+
+/// The `ChannelInfo` struct stores an open transfer channel.
+///
+/// Properties:
+///
+/// * `endpoint`: port and channel of this chain.
+/// * `counterparty_endpoint`: port and channel on the counterparty chain.
+/// * `connection_id`: connection the channel is built on.
+#[cw_serde]
+pub struct ChannelInfo {
+    pub endpoint: CwEndPoint,
+    pub counterparty_endpoint: CwEndPoint,
+    pub connection_id: String,
+}
+
+impl From<CwChannel> for ChannelInfo {
+    fn from(channel: CwChannel) -> Self {
+        Self {
+            endpoint: channel.endpoint,
+            counterparty_endpoint: channel.counterparty_endpoint,
+            connection_id: channel.connection_id,
+        }
+    }
+}
+
+/// This is a Rust struct representing the ICS-20 transfer application.
+///
+/// Properties:
+///
+/// * `owner`: address of the owner of the contract.
+/// * `config`: the `Config` of the contract.
+/// * `ibc_host`: address of the IBC host the `transfer` port is bound on.
+/// * `channels`: `ChannelInfo` of every channel opened on the `transfer` port, by channel id.
+/// * `channel_escrow`: amount of each local denom escrowed per channel.
+/// * `denom_traces`: `DenomTrace` of every voucher minted, by hash.
+/// * `voucher_balances`: voucher ledger, by holder and `ibc/{HASH}` denom. Vouchers are not minted
+/// as bank or CW20 tokens, they only move through this ledger and over IBC.
+/// * `voucher_supply`: total amount minted of each voucher denom.
+pub struct CwIcs20Transfer<'a> {
+    owner: Item<'a, String>,
+    config: Item<'a, Config>,
+    ibc_host: Item<'a, Addr>,
+    channels: Map<'a, String, ChannelInfo>,
+    channel_escrow: Map<'a, (String, String), Uint128>,
+    denom_traces: Map<'a, String, DenomTrace>,
+    voucher_balances: Map<'a, (Addr, String), Uint128>,
+    voucher_supply: Map<'a, String, Uint128>,
+}
+
+impl<'a> Default for CwIcs20Transfer<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CwIcs20Transfer<'a> {
+    pub fn new() -> Self {
+        Self {
+            owner: Item::new(StorageKey::Owner.as_str()),
+            config: Item::new(StorageKey::Config.as_str()),
+            ibc_host: Item::new(StorageKey::IbcHost.as_str()),
+            channels: Map::new(StorageKey::Channels.as_str()),
+            channel_escrow: Map::new(StorageKey::ChannelEscrow.as_str()),
+            denom_traces: Map::new(StorageKey::DenomTraces.as_str()),
+            voucher_balances: Map::new(StorageKey::VoucherBalances.as_str()),
+            voucher_supply: Map::new(StorageKey::VoucherSupply.as_str()),
+        }
+    }
+
+    pub fn owner(&self) -> &Item<'a, String> {
+        &self.owner
+    }
+
+    pub fn get_config(&self, store: &dyn Storage) -> Result<Config, ContractError> {
+        self.config.load(store).map_err(ContractError::Std)
+    }
+
+    pub fn store_config(
+        &self,
+        store: &mut dyn Storage,
+        config: &Config,
+    ) -> Result<(), ContractError> {
+        self.config.save(store, config).map_err(ContractError::Std)
+    }
+
+    pub fn set_ibc_host(
+        &self,
+        store: &mut dyn Storage,
+        address: Addr,
+    ) -> Result<(), ContractError> {
+        self.ibc_host
+            .save(store, &address)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn store_channel(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        channel: &ChannelInfo,
+    ) -> Result<(), ContractError> {
+        self.channels
+            .save(store, channel_id.to_owned(), channel)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_channel(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
+    ) -> Result<ChannelInfo, ContractError> {
+        self.channels.may_load(store, channel_id.to_owned())?.ok_or(
+            ContractError::ChannelNotFound {
+                channel_id: channel_id.to_string(),
+            },
+        )
+    }
+
+    pub fn get_channels(&self, store: &dyn Storage) -> Result<Vec<ChannelInfo>, ContractError> {
+        self.channels
+            .range(store, None, None, Order::Ascending)
+            .map(|res| res.map(|(_, channel)| channel).map_err(ContractError::Std))
+            .collect()
+    }
+
+    pub fn get_escrowed_amount(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
+        denom: &str,
+    ) -> Result<Uint128, ContractError> {
+        Ok(self
+            .channel_escrow
+            .may_load(store, (channel_id.to_owned(), denom.to_owned()))?
+            .unwrap_or_default())
+    }
+
+    pub fn increase_escrow(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        denom: &str,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        self.channel_escrow
+            .update(
+                store,
+                (channel_id.to_owned(), denom.to_owned()),
+                |escrowed| -> StdResult<Uint128> {
+                    Ok(escrowed.unwrap_or_default().checked_add(amount)?)
+                },
+            )
+            .map_err(ContractError::Std)?;
+        Ok(())
+    }
+
+    pub fn decrease_escrow(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        denom: &str,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let escrowed = self.get_escrowed_amount(store, channel_id, denom)?;
+        let remaining =
+            escrowed
+                .checked_sub(amount)
+                .map_err(|_| ContractError::InsufficientEscrow {
+                    channel_id: channel_id.to_string(),
+                    denom: denom.to_string(),
+                })?;
+        self.channel_escrow
+            .save(store, (channel_id.to_owned(), denom.to_owned()), &remaining)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn store_denom_trace(
+        &self,
+        store: &mut dyn Storage,
+        trace: &DenomTrace,
+    ) -> Result<(), ContractError> {
+        self.denom_traces
+            .save(store, trace.hash(), trace)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_denom_trace(
+        &self,
+        store: &dyn Storage,
+        hash: &str,
+    ) -> Result<DenomTrace, ContractError> {
+        self.denom_traces
+            .may_load(store, hash.to_uppercase())?
+            .ok_or(ContractError::DenomTraceNotFound {
+                denom: hash.to_string(),
+            })
+    }
+
+    pub fn get_denom_traces(&self, store: &dyn Storage) -> Result<Vec<DenomTrace>, ContractError> {
+        self.denom_traces
+            .range(store, None, None, Order::Ascending)
+            .map(|res| res.map(|(_, trace)| trace).map_err(ContractError::Std))
+            .collect()
+    }
+
+    pub fn get_voucher_balance(
+        &self,
+        store: &dyn Storage,
+        address: &Addr,
+        denom: &str,
+    ) -> Result<Uint128, ContractError> {
+        Ok(self
+            .voucher_balances
+            .may_load(store, (address.clone(), denom.to_owned()))?
+            .unwrap_or_default())
+    }
+
+    pub fn get_voucher_supply(
+        &self,
+        store: &dyn Storage,
+        denom: &str,
+    ) -> Result<Uint128, ContractError> {
+        Ok(self
+            .voucher_supply
+            .may_load(store, denom.to_owned())?
+            .unwrap_or_default())
+    }
+
+    pub fn mint_voucher(
+        &self,
+        store: &mut dyn Storage,
+        address: &Addr,
+        denom: &str,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let balance = self.get_voucher_balance(store, address, denom)?;
+        let supply = self.get_voucher_supply(store, denom)?;
+        self.voucher_balances.save(
+            store,
+            (address.clone(), denom.to_owned()),
+            &balance.checked_add(amount).map_err(StdError::from)?,
+        )?;
+        self.voucher_supply.save(
+            store,
+            denom.to_owned(),
+            &supply.checked_add(amount).map_err(StdError::from)?,
+        )?;
+        Ok(())
+    }
+
+    pub fn burn_voucher(
+        &self,
+        store: &mut dyn Storage,
+        address: &Addr,
+        denom: &str,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        self.take_voucher(store, address, denom, amount)?;
+        let supply = self.get_voucher_supply(store, denom)?;
+        self.voucher_supply.save(
+            store,
+            denom.to_owned(),
+            &supply.checked_sub(amount).map_err(StdError::from)?,
+        )?;
+        Ok(())
+    }
+
+    /// Removes `amount` of `denom` from the ledger balance of `address` without touching the
+    /// supply, used when vouchers move into escrow.
+    pub fn take_voucher(
+        &self,
+        store: &mut dyn Storage,
+        address: &Addr,
+        denom: &str,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let balance = self.get_voucher_balance(store, address, denom)?;
+        let remaining =
+            balance
+                .checked_sub(amount)
+                .map_err(|_| ContractError::InsufficientVoucherBalance {
+                    denom: denom.to_string(),
+                })?;
+        self.voucher_balances
+            .save(store, (address.clone(), denom.to_owned()), &remaining)
+            .map_err(ContractError::Std)
+    }
+
+    /// Credits `amount` of `denom` to the ledger balance of `address` without touching the supply,
+    /// used when vouchers are released from escrow.
+    pub fn give_voucher(
+        &self,
+        store: &mut dyn Storage,
+        address: &Addr,
+        denom: &str,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let balance = self.get_voucher_balance(store, address, denom)?;
+        self.voucher_balances
+            .save(
+                store,
+                (address.clone(), denom.to_owned()),
+                &balance.checked_add(amount).map_err(StdError::from)?,
+            )
+            .map_err(ContractError::Std)
+    }
+}
+
+impl<'a> IbcHostApp for CwIcs20Transfer<'a> {
+    type Error = ContractError;
+
+    fn get_ibc_host(&self, store: &dyn Storage) -> Result<Addr, ContractError> {
+        self.ibc_host.load(store).map_err(ContractError::Std)
+    }
+
+    fn only_ibc_handler_error(&self) -> ContractError {
+        ContractError::OnlyIbcHandler {}
+    }
+}
@@ -0,0 +1,168 @@
+use cosmwasm_std::from_json;
+use cw20::Cw20ReceiveMsg;
+use cw_common::{cw_println, hex_string::HexString, raw_types::channel::RawPacket, ProstMessage};
+
+use crate::{
+    msg::TransferMsg,
+    types::{
+        denom::{received_from, CW20_PREFIX, VOUCHER_PREFIX},
+        packet::FungibleTokenPacketData,
+        LOG_PREFIX,
+    },
+};
+
+use super::*;
+
+impl<'a> CwIcs20Transfer<'a> {
+    /// This function sends the single native coin attached to the message over `msg.channel_id`.
+    /// The coin stays with the contract and is accounted as escrow of the channel.
+    pub fn execute_transfer_native(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: TransferMsg,
+    ) -> Result<Response, ContractError> {
+        if info.funds.len() != 1 {
+            return Err(ContractError::InvalidFunds {});
+        }
+        let coin = &info.funds[0];
+        // bank denoms must not collide with the local names of CW20 tokens and vouchers
+        if coin.denom.starts_with(VOUCHER_PREFIX) || coin.denom.starts_with(CW20_PREFIX) {
+            return Err(ContractError::InvalidDenom {
+                denom: coin.denom.clone(),
+            });
+        }
+
+        self.send_tokens(deps, env, info.sender, coin.denom.clone(), coin.amount, msg)
+    }
+
+    /// This function handles the CW20 receive hook. The token contract already moved the tokens to
+    /// this contract, the `msg` of the hook is the `TransferMsg` describing the transfer.
+    pub fn execute_receive_cw20(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        wrapper: Cw20ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let msg: TransferMsg = from_json(&wrapper.msg)?;
+        let sender = deps.api.addr_validate(&wrapper.sender)?;
+        let denom = format!("{CW20_PREFIX}{}", info.sender);
+
+        self.send_tokens(deps, env, sender, denom, wrapper.amount, msg)
+    }
+
+    /// This function sends `amount` of the voucher `denom` held by the sender in the ledger of this
+    /// contract.
+    pub fn execute_transfer_voucher(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        denom: String,
+        amount: Uint128,
+        msg: TransferMsg,
+    ) -> Result<Response, ContractError> {
+        if !denom.starts_with(VOUCHER_PREFIX) {
+            return Err(ContractError::InvalidDenom { denom });
+        }
+
+        self.send_tokens(deps, env, info.sender, denom, amount, msg)
+    }
+
+    /// This function escrows or burns the tokens being sent and forwards the ICS-20 packet to the
+    /// IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and querier.
+    /// * `env`: `env` contains the current block time the packet timeout is computed from.
+    /// * `sender`: `sender` is the owner of the tokens being sent.
+    /// * `local_denom`: `local_denom` is the denom as known on this chain: a bank denom,
+    /// `cw20:{address}` or `ibc/{HASH}`.
+    /// * `amount`: `amount` is the amount being sent.
+    /// * `msg`: `msg` is the `TransferMsg` describing the transfer.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the `SendPacket` submessage for the IBC host.
+    fn send_tokens(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        sender: Addr,
+        local_denom: String,
+        amount: Uint128,
+        msg: TransferMsg,
+    ) -> Result<Response, ContractError> {
+        if amount.is_zero() {
+            return Err(ContractError::ZeroAmount {});
+        }
+        if msg.receiver.is_empty() {
+            return Err(ContractError::InvalidReceiver {
+                receiver: msg.receiver,
+            });
+        }
+        let channel = self.get_channel(deps.storage, &msg.channel_id)?;
+        let src = &channel.endpoint;
+        let dst = &channel.counterparty_endpoint;
+
+        let packet_denom = if let Some(hash) = local_denom.strip_prefix(VOUCHER_PREFIX) {
+            let trace = self.get_denom_trace(deps.storage, hash)?;
+            let full_path = trace.full_path();
+            if received_from(&src.port_id, &src.channel_id, &full_path) {
+                // the voucher goes back to its source, where the original tokens get unescrowed
+                self.burn_voucher(deps.storage, &sender, &local_denom, amount)?;
+            } else {
+                self.take_voucher(deps.storage, &sender, &local_denom, amount)?;
+                self.increase_escrow(deps.storage, &src.channel_id, &local_denom, amount)?;
+            }
+            full_path
+        } else {
+            self.increase_escrow(deps.storage, &src.channel_id, &local_denom, amount)?;
+            local_denom
+        };
+
+        let config = self.get_config(deps.storage)?;
+        let timeout = msg.timeout.unwrap_or(config.default_timeout);
+        let timeout_timestamp = env.block.time.plus_seconds(timeout).nanos();
+
+        let packet_data = FungibleTokenPacketData::new(
+            packet_denom.clone(),
+            amount,
+            sender.to_string(),
+            msg.receiver.clone(),
+            msg.memo.unwrap_or_default(),
+        );
+        let sequence = self.query_host_sequence_no(deps.as_ref(), &src.port_id, &src.channel_id)?;
+
+        let packet = RawPacket {
+            sequence,
+            source_port: src.port_id.clone(),
+            source_channel: src.channel_id.clone(),
+            destination_port: dst.port_id.clone(),
+            destination_channel: dst.channel_id.clone(),
+            data: packet_data.encode()?,
+            timeout_height: None,
+            timeout_timestamp,
+        };
+        cw_println!(deps, "{LOG_PREFIX} Raw Packet Created {:?}", &packet);
+
+        let message = self.call_host_send_packet(
+            deps.storage,
+            HexString::from_bytes(&packet.encode_to_vec()),
+            vec![],
+        )?;
+
+        Ok(Response::new()
+            .add_message(message)
+            .add_attribute("action", "transfer")
+            .add_attribute("sender", sender)
+            .add_attribute("receiver", msg.receiver)
+            .add_attribute("denom", packet_denom)
+            .add_attribute("amount", amount)
+            .add_attribute("channel_id", src.channel_id.clone())
+            .add_attribute("sequence", sequence.to_string()))
+    }
+}
@@ -0,0 +1,12 @@
+use cosmwasm_schema::cw_serde;
+
+/// The `Config` struct holds the settings of the transfer contract.
+///
+/// Properties:
+///
+/// * `default_timeout`: timeout in seconds applied to outgoing packets when the sender does not
+/// provide one.
+#[cw_serde]
+pub struct Config {
+    pub default_timeout: u64,
+}
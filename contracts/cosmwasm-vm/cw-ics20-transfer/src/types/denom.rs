@@ -0,0 +1,127 @@
+use cosmwasm_schema::cw_serde;
+use sha2::{Digest, Sha256};
+
+/// Prefix of local denoms that refer to vouchers minted by this contract.
+pub const VOUCHER_PREFIX: &str = "ibc/";
+/// Prefix of local denoms that refer to CW20 token contracts.
+pub const CW20_PREFIX: &str = "cw20:";
+
+/// The `DenomTrace` struct records the path a token took to reach this chain, following ICS-20.
+///
+/// Properties:
+///
+/// * `path`: chain of `{port}/{channel}` pairs the token was transferred through, most recent hop
+/// first. Empty for tokens native to this chain.
+/// * `base_denom`: denomination of the token on its origin chain.
+#[cw_serde]
+pub struct DenomTrace {
+    pub path: String,
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Splits a full denom path into its trace and base denom. Only leading `{port}/channel-{n}`
+    /// pairs count as trace, so base denoms which contain `/` themselves are kept intact.
+    pub fn parse(full_path: &str) -> Self {
+        let segments: Vec<&str> = full_path.split('/').collect();
+        let mut trace_len = 0;
+        while trace_len + 2 < segments.len() && segments[trace_len + 1].starts_with("channel-") {
+            trace_len += 2;
+        }
+        DenomTrace {
+            path: segments[..trace_len].join("/"),
+            base_denom: segments[trace_len..].join("/"),
+        }
+    }
+
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            return self.base_denom.clone();
+        }
+        format!("{}/{}", self.path, self.base_denom)
+    }
+
+    pub fn hash(&self) -> String {
+        Sha256::digest(self.full_path().as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect()
+    }
+
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Returns the denom this trace is known by locally: the base denom for native tokens and
+    /// `ibc/{HASH}` for vouchers.
+    pub fn local_denom(&self) -> String {
+        if self.is_native() {
+            return self.base_denom.clone();
+        }
+        format!("{VOUCHER_PREFIX}{}", self.hash())
+    }
+}
+
+pub fn denom_prefix(port_id: &str, channel_id: &str) -> String {
+    format!("{port_id}/{channel_id}/")
+}
+
+/// Returns true when the token described by `denom` was received from `port_id`/`channel_id`, in
+/// which case sending it back over that channel returns it to its source.
+pub fn received_from(port_id: &str, channel_id: &str, denom: &str) -> bool {
+    denom.starts_with(&denom_prefix(port_id, channel_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_native_denom() {
+        let trace = DenomTrace::parse("uarch");
+        assert!(trace.is_native());
+        assert_eq!(trace.local_denom(), "uarch");
+    }
+
+    #[test]
+    fn test_parse_denom_with_slash_in_base() {
+        let trace = DenomTrace::parse("factory/archway1abc/token");
+        assert!(trace.is_native());
+        assert_eq!(trace.base_denom, "factory/archway1abc/token");
+    }
+
+    #[test]
+    fn test_parse_multi_hop_trace() {
+        let trace = DenomTrace::parse("transfer/channel-1/transfer/channel-0/icx");
+        assert_eq!(trace.path, "transfer/channel-1/transfer/channel-0");
+        assert_eq!(trace.base_denom, "icx");
+        assert_eq!(
+            trace.full_path(),
+            "transfer/channel-1/transfer/channel-0/icx"
+        );
+    }
+
+    #[test]
+    fn test_voucher_denom_matches_ibc_go_hash() {
+        let trace = DenomTrace::parse("transfer/channel-0/uatom");
+        assert_eq!(
+            trace.local_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn test_received_from() {
+        assert!(received_from(
+            "transfer",
+            "channel-0",
+            "transfer/channel-0/icx"
+        ));
+        assert!(!received_from(
+            "transfer",
+            "channel-1",
+            "transfer/channel-0/icx"
+        ));
+        assert!(!received_from("transfer", "channel-0", "icx"));
+    }
+}
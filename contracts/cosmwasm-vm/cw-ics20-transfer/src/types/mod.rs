@@ -0,0 +1,6 @@
+pub mod config;
+pub mod denom;
+pub mod packet;
+pub mod storage_keys;
+
+pub const LOG_PREFIX: &str = "[ics20_transfer]:";
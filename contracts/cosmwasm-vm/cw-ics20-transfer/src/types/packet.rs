@@ -0,0 +1,62 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+use crate::error::ContractError;
+
+/// The `FungibleTokenPacketData` struct is the ICS-20 packet payload. It is JSON encoded with the
+/// amount as a decimal string, as defined in
+/// https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer
+#[cw_serde]
+pub struct FungibleTokenPacketData {
+    pub denom: String,
+    pub amount: Uint128,
+    pub sender: String,
+    pub receiver: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub memo: String,
+}
+
+impl FungibleTokenPacketData {
+    pub fn new(
+        denom: String,
+        amount: Uint128,
+        sender: String,
+        receiver: String,
+        memo: String,
+    ) -> Self {
+        Self {
+            denom,
+            amount,
+            sender,
+            receiver,
+            memo,
+        }
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, ContractError> {
+        let packet_data: FungibleTokenPacketData =
+            serde_json_wasm::from_slice(data).map_err(|e| ContractError::DecodeFailed {
+                error: e.to_string(),
+            })?;
+        packet_data.validate()?;
+        Ok(packet_data)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, ContractError> {
+        serde_json_wasm::to_vec(self).map_err(|e| ContractError::DecodeFailed {
+            error: e.to_string(),
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.amount.is_zero() {
+            return Err(ContractError::ZeroAmount {});
+        }
+        if self.denom.is_empty() {
+            return Err(ContractError::InvalidDenom {
+                denom: self.denom.clone(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum StorageKey {
+    Owner,
+    Config,
+    IbcHost,
+    Channels,
+    ChannelEscrow,
+    DenomTraces,
+    VoucherBalances,
+    VoucherSupply,
+}
+
+impl StorageKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKey::Owner => "owner",
+            StorageKey::Config => "config",
+            StorageKey::IbcHost => "ibc_host",
+            StorageKey::Channels => "channels",
+            StorageKey::ChannelEscrow => "channel_escrow",
+            StorageKey::DenomTraces => "denom_traces",
+            StorageKey::VoucherBalances => "voucher_balances",
+            StorageKey::VoucherSupply => "voucher_supply",
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use cosmwasm_std::{
+    testing::{mock_env, mock_info},
+    Addr, DepsMut, IbcChannel, IbcChannelConnectMsg, IbcOrder, IbcPacket,
+};
+use cw_ics20_transfer::{
+    check::ICS20_VERSION,
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::{CwIcs20Transfer, PORT_ID},
+    types::packet::FungibleTokenPacketData,
+};
+pub use test_utils::ibc_app::{deps, ChannelEnds, IBC_HOST, NEXT_SEQUENCE, OWNER};
+
+pub const CHANNEL_ID: &str = "channel-0";
+pub const COUNTERPARTY_PORT_ID: &str = "transfer";
+pub const COUNTERPARTY_CHANNEL_ID: &str = "channel-7";
+pub const DEFAULT_TIMEOUT: u64 = 600;
+
+pub fn channel_ends() -> ChannelEnds {
+    ChannelEnds::new(
+        PORT_ID,
+        CHANNEL_ID,
+        COUNTERPARTY_PORT_ID,
+        COUNTERPARTY_CHANNEL_ID,
+    )
+}
+
+pub fn get_channel() -> IbcChannel {
+    channel_ends().channel(IbcOrder::Unordered, ICS20_VERSION, "connection-0")
+}
+
+/// Instantiates the contract with `IBC_HOST` as host.
+pub fn setup_contract(deps: DepsMut) -> CwIcs20Transfer<'static> {
+    let contract = CwIcs20Transfer::default();
+    contract
+        .instantiate(
+            deps,
+            mock_env(),
+            mock_info(OWNER, &[]),
+            InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                default_timeout: DEFAULT_TIMEOUT,
+            },
+        )
+        .unwrap();
+    contract
+}
+
+pub fn connect_channel(deps: DepsMut, contract: &CwIcs20Transfer) {
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelConnect {
+                msg: IbcChannelConnectMsg::OpenAck {
+                    channel: get_channel(),
+                    counterparty_version: ICS20_VERSION.to_string(),
+                },
+            },
+        )
+        .unwrap();
+}
+
+/// Builds a packet travelling from the counterparty to this chain.
+pub fn incoming_packet(data: &FungibleTokenPacketData) -> IbcPacket {
+    channel_ends().incoming_packet(data.encode().unwrap(), 1)
+}
+
+/// Builds a packet sent from this chain to the counterparty.
+pub fn outgoing_packet(data: &FungibleTokenPacketData) -> IbcPacket {
+    channel_ends().outgoing_packet(data.encode().unwrap(), NEXT_SEQUENCE)
+}
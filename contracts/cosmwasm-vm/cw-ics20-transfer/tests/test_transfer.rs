@@ -0,0 +1,506 @@
+pub mod setup;
+
+use cosmwasm_std::{
+    coins, from_json,
+    testing::{mock_env, mock_info},
+    to_json_binary as to_binary, Addr, BankMsg, Binary, CosmosMsg, IbcAcknowledgement,
+    IbcChannelCloseMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_common::{
+    core_msg::ExecuteMsg as CoreExecuteMsg, hex_string::HexString, raw_types::channel::RawPacket,
+    types::Ack, ProstMessage,
+};
+use cw_ics20_transfer::{
+    error::ContractError,
+    msg::{ExecuteMsg, TransferMsg},
+    state::{CwIcs20Transfer, PORT_ID},
+    types::{denom::DenomTrace, packet::FungibleTokenPacketData},
+};
+
+use setup::*;
+
+fn transfer_msg() -> TransferMsg {
+    TransferMsg {
+        channel_id: CHANNEL_ID.to_string(),
+        receiver: "hxreceiver".to_string(),
+        timeout: None,
+        memo: None,
+    }
+}
+
+fn sent_packet(res: &cosmwasm_std::Response) -> RawPacket {
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            match from_json::<CoreExecuteMsg>(msg).unwrap() {
+                CoreExecuteMsg::SendPacket { packet } => {
+                    RawPacket::decode(packet.to_bytes().unwrap().as_slice()).unwrap()
+                }
+                _ => panic!("expected SendPacket"),
+            }
+        }
+        _ => panic!("expected wasm message"),
+    }
+}
+
+fn receive(
+    deps: cosmwasm_std::DepsMut,
+    contract: &CwIcs20Transfer,
+    data: &FungibleTokenPacketData,
+) -> Result<cosmwasm_std::Response, ContractError> {
+    contract.execute(
+        deps,
+        mock_env(),
+        mock_info(IBC_HOST, &[]),
+        ExecuteMsg::IbcPacketReceive {
+            msg: IbcPacketReceiveMsg::new(incoming_packet(data), Addr::unchecked("relayer")),
+        },
+    )
+}
+
+#[test]
+fn test_instantiate_binds_transfer_port() {
+    let mut deps = deps();
+    let contract = CwIcs20Transfer::default();
+    let env = mock_env();
+    let res = contract
+        .instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(OWNER, &[]),
+            cw_ics20_transfer::msg::InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                default_timeout: DEFAULT_TIMEOUT,
+            },
+        )
+        .unwrap();
+
+    let expected = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: IBC_HOST.to_string(),
+        msg: to_binary(&CoreExecuteMsg::BindPort {
+            port_id: PORT_ID.to_string(),
+            address: env.contract.address.to_string(),
+        })
+        .unwrap(),
+        funds: vec![],
+    });
+    assert_eq!(res.messages[0].msg, expected);
+}
+
+#[test]
+fn test_channel_open_rejects_ordered_channel() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let mut channel = get_channel();
+    channel.order = IbcOrder::Ordered;
+
+    let res = contract.execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IBC_HOST, &[]),
+        ExecuteMsg::IbcChannelOpen {
+            msg: IbcChannelOpenMsg::OpenInit { channel },
+        },
+    );
+    assert!(matches!(res, Err(ContractError::InvalidChannelOrder {})));
+}
+
+#[test]
+fn test_channel_open_rejects_invalid_version() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+
+    let res = contract.execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IBC_HOST, &[]),
+        ExecuteMsg::IbcChannelOpen {
+            msg: IbcChannelOpenMsg::OpenTry {
+                channel: get_channel(),
+                counterparty_version: "ics20-2".to_string(),
+            },
+        },
+    );
+    assert!(matches!(res, Err(ContractError::InvalidVersion { .. })));
+}
+
+#[test]
+fn test_channel_open_only_ibc_host() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+
+    let res = contract.execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("relayer", &[]),
+        ExecuteMsg::IbcChannelOpen {
+            msg: IbcChannelOpenMsg::OpenInit {
+                channel: get_channel(),
+            },
+        },
+    );
+    assert!(matches!(res, Err(ContractError::OnlyIbcHandler {})));
+}
+
+#[test]
+fn test_channel_close_init_not_allowed() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+
+    let res = contract.execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IBC_HOST, &[]),
+        ExecuteMsg::IbcChannelClose {
+            msg: IbcChannelCloseMsg::CloseInit {
+                channel: get_channel(),
+            },
+        },
+    );
+    assert!(matches!(res, Err(ContractError::ChannelCloseNotAllowed {})));
+}
+
+#[test]
+fn test_transfer_native_escrows_and_sends_packet() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+    let env = mock_env();
+
+    let res = contract
+        .execute_transfer_native(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("sender", &coins(100, "uarch")),
+            transfer_msg(),
+        )
+        .unwrap();
+
+    let packet = sent_packet(&res);
+    assert_eq!(packet.sequence, NEXT_SEQUENCE);
+    assert_eq!(packet.source_port, PORT_ID);
+    assert_eq!(packet.source_channel, CHANNEL_ID);
+    assert_eq!(packet.destination_channel, COUNTERPARTY_CHANNEL_ID);
+    assert_eq!(
+        packet.timeout_timestamp,
+        env.block.time.plus_seconds(DEFAULT_TIMEOUT).nanos()
+    );
+    let data = FungibleTokenPacketData::decode(&packet.data).unwrap();
+    assert_eq!(data.denom, "uarch");
+    assert_eq!(data.amount, Uint128::new(100));
+    assert_eq!(data.sender, "sender");
+    assert_eq!(data.receiver, "hxreceiver");
+
+    let escrowed = contract
+        .get_escrowed_amount(deps.as_ref().storage, CHANNEL_ID, "uarch")
+        .unwrap();
+    assert_eq!(escrowed, Uint128::new(100));
+}
+
+#[test]
+fn test_transfer_native_unknown_channel() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+
+    let res = contract.execute_transfer_native(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("sender", &coins(100, "uarch")),
+        transfer_msg(),
+    );
+    assert!(matches!(res, Err(ContractError::ChannelNotFound { .. })));
+}
+
+#[test]
+fn test_transfer_cw20_escrows_token() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("token", &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: "sender".to_string(),
+                amount: Uint128::new(50),
+                msg: to_binary(&transfer_msg()).unwrap(),
+            }),
+        )
+        .unwrap();
+
+    let data = FungibleTokenPacketData::decode(&sent_packet(&res).data).unwrap();
+    assert_eq!(data.denom, "cw20:token");
+    let escrowed = contract
+        .get_escrowed_amount(deps.as_ref().storage, CHANNEL_ID, "cw20:token")
+        .unwrap();
+    assert_eq!(escrowed, Uint128::new(50));
+}
+
+#[test]
+fn test_receive_foreign_token_mints_voucher() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+
+    let data = FungibleTokenPacketData::new(
+        "icx".to_string(),
+        Uint128::new(10),
+        "hxsender".to_string(),
+        "receiver".to_string(),
+        String::new(),
+    );
+    let res = receive(deps.as_mut(), &contract, &data).unwrap();
+
+    let ack: Ack = from_json(res.data.unwrap()).unwrap();
+    assert!(matches!(ack, Ack::Result(_)));
+
+    let trace = DenomTrace::parse("transfer/channel-0/icx");
+    let voucher = trace.local_denom();
+    let balance = contract
+        .get_voucher_balance(
+            deps.as_ref().storage,
+            &Addr::unchecked("receiver"),
+            &voucher,
+        )
+        .unwrap();
+    assert_eq!(balance, Uint128::new(10));
+    assert_eq!(
+        contract
+            .get_denom_trace(deps.as_ref().storage, &trace.hash())
+            .unwrap(),
+        trace
+    );
+}
+
+#[test]
+fn test_receive_returning_token_releases_escrow() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+    contract
+        .execute_transfer_native(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &coins(100, "uarch")),
+            transfer_msg(),
+        )
+        .unwrap();
+
+    let data = FungibleTokenPacketData::new(
+        format!("{COUNTERPARTY_PORT_ID}/{COUNTERPARTY_CHANNEL_ID}/uarch"),
+        Uint128::new(40),
+        "hxsender".to_string(),
+        "receiver".to_string(),
+        String::new(),
+    );
+    let res = receive(deps.as_mut(), &contract, &data).unwrap();
+
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "receiver".to_string(),
+            amount: coins(40, "uarch"),
+        })
+    );
+    let escrowed = contract
+        .get_escrowed_amount(deps.as_ref().storage, CHANNEL_ID, "uarch")
+        .unwrap();
+    assert_eq!(escrowed, Uint128::new(60));
+}
+
+#[test]
+fn test_receive_returning_token_without_escrow_fails() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+
+    let data = FungibleTokenPacketData::new(
+        format!("{COUNTERPARTY_PORT_ID}/{COUNTERPARTY_CHANNEL_ID}/uarch"),
+        Uint128::new(40),
+        "hxsender".to_string(),
+        "receiver".to_string(),
+        String::new(),
+    );
+    let res = receive(deps.as_mut(), &contract, &data);
+    assert!(matches!(res, Err(ContractError::InsufficientEscrow { .. })));
+}
+
+#[test]
+fn test_voucher_transfer_burns_and_timeout_refunds() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+    let data = FungibleTokenPacketData::new(
+        "icx".to_string(),
+        Uint128::new(10),
+        "hxsender".to_string(),
+        "sender".to_string(),
+        String::new(),
+    );
+    receive(deps.as_mut(), &contract, &data).unwrap();
+    let voucher = DenomTrace::parse("transfer/channel-0/icx").local_denom();
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &[]),
+            ExecuteMsg::TransferVoucher {
+                denom: voucher.clone(),
+                amount: Uint128::new(10),
+                channel_id: CHANNEL_ID.to_string(),
+                receiver: "hxreceiver".to_string(),
+                timeout: Some(60),
+                memo: None,
+            },
+        )
+        .unwrap();
+    let packet_data = FungibleTokenPacketData::decode(&sent_packet(&res).data).unwrap();
+    assert_eq!(packet_data.denom, "transfer/channel-0/icx");
+    assert_eq!(
+        contract
+            .get_voucher_supply(deps.as_ref().storage, &voucher)
+            .unwrap(),
+        Uint128::zero()
+    );
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketTimeout {
+                msg: IbcPacketTimeoutMsg::new(
+                    outgoing_packet(&packet_data),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+    let balance = contract
+        .get_voucher_balance(deps.as_ref().storage, &Addr::unchecked("sender"), &voucher)
+        .unwrap();
+    assert_eq!(balance, Uint128::new(10));
+}
+
+#[test]
+fn test_error_ack_refunds_escrow() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("token", &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: "sender".to_string(),
+                amount: Uint128::new(50),
+                msg: to_binary(&transfer_msg()).unwrap(),
+            }),
+        )
+        .unwrap();
+    let packet_data = FungibleTokenPacketData::decode(&sent_packet(&res).data).unwrap();
+
+    let ack =
+        IbcAcknowledgement::new(to_binary(&Ack::Error("invalid receiver".to_string())).unwrap());
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketAck {
+                msg: IbcPacketAckMsg::new(
+                    ack,
+                    outgoing_packet(&packet_data),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "sender".to_string(),
+                amount: Uint128::new(50),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+    let escrowed = contract
+        .get_escrowed_amount(deps.as_ref().storage, CHANNEL_ID, "cw20:token")
+        .unwrap();
+    assert_eq!(escrowed, Uint128::zero());
+}
+
+#[test]
+fn test_success_ack_keeps_escrow() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+    let res = contract
+        .execute_transfer_native(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &coins(100, "uarch")),
+            transfer_msg(),
+        )
+        .unwrap();
+    let packet_data = FungibleTokenPacketData::decode(&sent_packet(&res).data).unwrap();
+
+    let ack = IbcAcknowledgement::new(to_binary(&Ack::Result(Binary::from(vec![1]))).unwrap());
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketAck {
+                msg: IbcPacketAckMsg::new(
+                    ack,
+                    outgoing_packet(&packet_data),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    assert!(res.messages.is_empty());
+    let escrowed = contract
+        .get_escrowed_amount(deps.as_ref().storage, CHANNEL_ID, "uarch")
+        .unwrap();
+    assert_eq!(escrowed, Uint128::new(100));
+}
+
+#[test]
+fn test_send_packet_message_targets_ibc_host() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    connect_channel(deps.as_mut(), &contract);
+    let res = contract
+        .execute_transfer_native(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &coins(1, "uarch")),
+            transfer_msg(),
+        )
+        .unwrap();
+    let packet = sent_packet(&res);
+
+    let expected = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: IBC_HOST.to_string(),
+        msg: to_binary(&CoreExecuteMsg::SendPacket {
+            packet: HexString::from_bytes(&packet.encode_to_vec()),
+        })
+        .unwrap(),
+        funds: vec![],
+    });
+    assert_eq!(res.messages[0].msg, expected);
+}
@@ -0,0 +1,27 @@
+use cosmwasm_std::{Addr, Storage};
+use debug_print::debug_println;
+
+use crate::{error::ContractError, state::CwIcs29Fee, types::LOG_PREFIX};
+
+impl<'a> CwIcs29Fee<'a> {
+    /// This function checks that `address` is the application wrapped by the middleware. Only the
+    /// application may send packets and write acknowledgements on the port.
+    ///
+    /// Arguments:
+    ///
+    /// * `store`: `store` is a reference to the storage the application is read from.
+    /// * `address`: `address` is the sender to check.
+    ///
+    /// Returns:
+    ///
+    /// `Ok(())` for the application and `ContractError::OnlyApp` otherwise.
+    pub fn ensure_app(&self, store: &dyn Storage, address: Addr) -> Result<(), ContractError> {
+        let app = self.get_app(store)?;
+
+        if app != address {
+            debug_println!("{LOG_PREFIX} Invalid App ");
+            return Err(ContractError::OnlyApp {});
+        }
+        Ok(())
+    }
+}
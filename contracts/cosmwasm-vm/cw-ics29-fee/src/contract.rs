@@ -0,0 +1,259 @@
+use cw_common::{core_msg::QueryMsg as HostQueryMsg, ibc_dapp_msg::ExecuteMsg as AppExecuteMsg};
+
+use crate::{state::APP_RECEIVE_PACKET_REPLY_ID, MigrateMsg};
+
+use super::*;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:cw-ics29-fee";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+impl<'a> CwIcs29Fee<'a> {
+    /// This function instantiates the middleware and binds `msg.port_id` to it on the IBC host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and API.
+    /// * `env`: `env` contains the address of this contract, which becomes the module of the port.
+    /// * `info`: `info` contains the sender of the message, who becomes the owner of the contract.
+    /// * `msg`: `msg` is the `InstantiateMsg` with the IBC host, the application and the port.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` carrying the `BindPort` message for the IBC host.
+    pub fn instantiate(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+        self.owner().save(deps.storage, &info.sender.to_string())?;
+        self.set_ibc_host(deps.storage, msg.ibc_host.clone())?;
+        self.set_app(deps.storage, msg.app.clone())?;
+        self.set_port_id(deps.storage, &msg.port_id)?;
+
+        let bind_port =
+            self.call_host_bind_port(&msg.ibc_host, &msg.port_id, &env.contract.address)?;
+
+        Ok(Response::new()
+            .add_message(bind_port)
+            .add_attribute("action", "instantiate")
+            .add_attribute("method", "init")
+            .add_attribute("ibc_host", msg.ibc_host)
+            .add_attribute("app", msg.app)
+            .add_attribute("port_id", msg.port_id))
+    }
+
+    /// This function executes the messages supported by the middleware. IBC callbacks are only
+    /// accepted from the IBC host and packets are only accepted from the application.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+    /// querier of the contract.
+    /// * `_env`: `_env` is not used by any message.
+    /// * `info`: `info` contains the sender of the message and the funds attached to it.
+    /// * `msg`: `msg` is the `ExecuteMsg` to execute.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn execute(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::PayPacketFee {
+                channel_id,
+                sequence,
+                fee,
+                refund_address,
+            } => self.pay_packet_fee(deps, info, channel_id, sequence, fee, refund_address),
+            ExecuteMsg::RefundPacketFees {
+                channel_id,
+                sequence,
+            } => self.refund_packet_fees(deps, channel_id, sequence),
+            ExecuteMsg::RegisterPayee { channel_id, payee } => {
+                self.register_payee(deps, info, channel_id, payee)
+            }
+            ExecuteMsg::RegisterCounterpartyPayee {
+                channel_id,
+                counterparty_payee,
+            } => self.register_counterparty_payee(deps, info, channel_id, counterparty_payee),
+
+            ExecuteMsg::SendPacket { packet } => self.send_packet(deps, info, packet),
+            ExecuteMsg::WriteAcknowledgement {
+                packet,
+                acknowledgement,
+            } => self.write_acknowledgement(deps, info, packet, acknowledgement),
+
+            ExecuteMsg::IbcChannelOpen { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_open(deps, msg)
+            }
+            ExecuteMsg::IbcChannelConnect { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_connect(deps, msg)
+            }
+            ExecuteMsg::IbcChannelClose { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_close(deps, msg)
+            }
+            ExecuteMsg::IbcPacketReceive { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_receive(deps, msg)
+            }
+            ExecuteMsg::IbcPacketAck { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_ack(deps, msg)
+            }
+            ExecuteMsg::IbcPacketTimeout { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_packet_timeout(deps, msg)
+            }
+            ExecuteMsg::IbcChannelUpgradeInit { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_upgrade(deps, AppExecuteMsg::IbcChannelUpgradeInit { msg })
+            }
+            ExecuteMsg::IbcChannelUpgradeTry { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_upgrade(deps, AppExecuteMsg::IbcChannelUpgradeTry { msg })
+            }
+            ExecuteMsg::IbcChannelUpgradeAck { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_upgrade(deps, AppExecuteMsg::IbcChannelUpgradeAck { msg })
+            }
+            ExecuteMsg::IbcChannelUpgradeOpen { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                self.on_channel_upgrade(deps, AppExecuteMsg::IbcChannelUpgradeOpen { msg })
+            }
+        }
+    }
+
+    /// The `query` function answers the queries supported by the middleware. Host queries are
+    /// forwarded to the IBC host so the application can use the middleware as its host.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `Deps` object that provides read access to the storage and querier.
+    /// * `_env`: `_env` is not used by any query.
+    /// * `msg`: `msg` is the `QueryMsg` to answer.
+    ///
+    /// Returns:
+    ///
+    /// a `StdResult<Binary>` holding the JSON encoded response of the query.
+    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::GetIbcHost {} => to_binary(&self.get_ibc_host(deps.storage).map_err(to_std)?),
+            QueryMsg::GetApp {} => to_binary(&self.get_app(deps.storage).map_err(to_std)?),
+            QueryMsg::GetPortId {} => to_binary(&self.get_port_id(deps.storage).map_err(to_std)?),
+            QueryMsg::IsFeeEnabled { channel_id } => {
+                to_binary(&self.is_fee_enabled(deps.storage, &channel_id))
+            }
+            QueryMsg::GetPacketFees {
+                channel_id,
+                sequence,
+            } => to_binary(
+                &self
+                    .get_packet_fees(deps.storage, &channel_id, sequence)
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetPayee {
+                channel_id,
+                relayer,
+            } => to_binary(
+                &self
+                    .get_payee(deps.storage, &channel_id, &Addr::unchecked(relayer))
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetCounterpartyPayee {
+                channel_id,
+                relayer,
+            } => to_binary(
+                &self
+                    .get_counterparty_payee(deps.storage, &channel_id, &Addr::unchecked(relayer))
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetNextSequenceSend {
+                port_id,
+                channel_id,
+            } => to_binary(
+                &self
+                    .query_host::<u64>(
+                        deps,
+                        &HostQueryMsg::GetNextSequenceSend {
+                            port_id,
+                            channel_id,
+                        },
+                    )
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetLatestHeight { client_id } => to_binary(
+                &self
+                    .query_host::<u64>(deps, &HostQueryMsg::GetLatestHeight { client_id })
+                    .map_err(to_std)?,
+            ),
+            QueryMsg::GetChannel {
+                port_id,
+                channel_id,
+            } => to_binary(
+                &self
+                    .query_host::<String>(
+                        deps,
+                        &HostQueryMsg::GetChannel {
+                            port_id,
+                            channel_id,
+                        },
+                    )
+                    .map_err(to_std)?,
+            ),
+        }
+    }
+
+    /// This function handles the replies of the application.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `_env`: `_env` is not used.
+    /// * `msg`: `msg` is the `Reply` of the application.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn reply(&self, deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+        match msg.id {
+            APP_RECEIVE_PACKET_REPLY_ID => self.on_app_receive_reply(deps, msg),
+            _ => Err(ContractError::ReplyError {
+                code: msg.id,
+                msg: "Unknown".to_string(),
+            }),
+        }
+    }
+
+    pub fn migrate(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        _msg: MigrateMsg,
+    ) -> Result<Response, ContractError> {
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+            .map_err(ContractError::Std)?;
+        Ok(Response::default().add_attribute("migrate", "successful"))
+    }
+}
+
+fn to_std(error: ContractError) -> StdError {
+    match error {
+        ContractError::Std(error) => error,
+        error => StdError::NotFound {
+            kind: error.to_string(),
+        },
+    }
+}
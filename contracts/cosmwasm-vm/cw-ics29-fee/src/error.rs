@@ -0,0 +1,31 @@
+use super::*;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("Unauthorized")]
+    Unauthorized {},
+    #[error("OnlyIbcHandler")]
+    OnlyIbcHandler {},
+    #[error("OnlyApp")]
+    OnlyApp {},
+    #[error("ERR_REPLY_ERROR|{code:?}|{msg:?}")]
+    ReplyError { code: u64, msg: String },
+    #[error("InvalidFeeVersion {version}")]
+    InvalidFeeVersion { version: String },
+    #[error("Fee version of counterparty does not match ({version})")]
+    FeeVersionMismatch { version: String },
+    #[error("FeeNotEnabled {channel_id}")]
+    FeeNotEnabled { channel_id: String },
+    #[error("Sent funds do not match the fee")]
+    InvalidFunds {},
+    #[error("Fee must not be empty")]
+    EmptyFee {},
+    #[error("PacketNotInFlight {channel_id} {sequence}")]
+    PacketNotInFlight { channel_id: String, sequence: u64 },
+    #[error("PacketInFlight {channel_id} {sequence}")]
+    PacketInFlight { channel_id: String, sequence: u64 },
+    #[error("DecodeFailed {error}")]
+    DecodeFailed { error: String },
+}
@@ -0,0 +1,255 @@
+use cosmwasm_std::{Api, BankMsg, CosmosMsg, Deps};
+use cw_common::core_msg::QueryMsg as HostQueryMsg;
+
+use crate::types::fee::{merge_coins, Fee, PacketFee, Payouts};
+
+use super::*;
+
+impl<'a> CwIcs29Fee<'a> {
+    /// This function escrows the funds attached to the message as fee for a packet on a fee enabled
+    /// channel. Several fees can be paid for the same packet, they are all paid out together.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+    /// querier of the contract.
+    /// * `info`: `info` contains the payer and the funds, which must match `fee.total()`.
+    /// * `channel_id`: `channel_id` is the channel the packet is sent on.
+    /// * `sequence`: `sequence` of the packet, the next sequence send of the channel when `None`.
+    /// A packet sent already must still be in flight, fees paid for it after its acknowledgement or
+    /// timeout could never be paid out.
+    /// * `fee`: `fee` is the `Fee` to escrow.
+    /// * `refund_address`: `refund_address` receives unused fees, defaults to the payer.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>`.
+    pub fn pay_packet_fee(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        channel_id: String,
+        sequence: Option<u64>,
+        fee: Fee,
+        refund_address: Option<String>,
+    ) -> Result<Response, ContractError> {
+        if !self.is_fee_enabled(deps.storage, &channel_id) {
+            return Err(ContractError::FeeNotEnabled { channel_id });
+        }
+        let total = fee.total();
+        if total.is_empty() {
+            return Err(ContractError::EmptyFee {});
+        }
+        if merge_coins(info.funds.iter()) != total {
+            return Err(ContractError::InvalidFunds {});
+        }
+        let refund_address = match refund_address {
+            Some(address) => deps.api.addr_validate(&address)?,
+            None => info.sender,
+        };
+        let port_id = self.get_port_id(deps.storage)?;
+        let next_sequence = self.query_host_sequence_no(deps.as_ref(), &port_id, &channel_id)?;
+        let sequence = sequence.unwrap_or(next_sequence);
+        if sequence < next_sequence
+            && !self.has_packet_commitment(deps.as_ref(), &port_id, &channel_id, sequence)
+        {
+            return Err(ContractError::PacketNotInFlight {
+                channel_id,
+                sequence,
+            });
+        }
+
+        self.add_packet_fee(
+            deps.storage,
+            &channel_id,
+            sequence,
+            PacketFee {
+                fee,
+                refund_address: refund_address.clone(),
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "pay_packet_fee")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("sequence", sequence.to_string())
+            .add_attribute("refund_address", refund_address))
+    }
+
+    /// Refunds the fees escrowed for a packet that is no longer in flight, i.e. sent and already
+    /// acknowledged or timed out without its fees being paid out. Anyone can trigger the refund,
+    /// the fees are returned to their refund addresses.
+    pub fn refund_packet_fees(
+        &self,
+        deps: DepsMut,
+        channel_id: String,
+        sequence: u64,
+    ) -> Result<Response, ContractError> {
+        let port_id = self.get_port_id(deps.storage)?;
+        let next_sequence = self.query_host_sequence_no(deps.as_ref(), &port_id, &channel_id)?;
+        if sequence >= next_sequence
+            || self.has_packet_commitment(deps.as_ref(), &port_id, &channel_id, sequence)
+        {
+            return Err(ContractError::PacketInFlight {
+                channel_id,
+                sequence,
+            });
+        }
+        let refunds = self.refund_fees(deps, &channel_id, sequence)?;
+
+        Ok(Response::new()
+            .add_messages(refunds)
+            .add_attribute("action", "refund_packet_fees")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("sequence", sequence.to_string()))
+    }
+
+    /// Returns the packet commitment of `sequence` is still stored on the IBC host, which answers
+    /// with an error once the packet is acknowledged or timed out.
+    fn has_packet_commitment(
+        &self,
+        deps: Deps,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> bool {
+        self.query_host::<String>(
+            deps,
+            &HostQueryMsg::GetPacketCommitment {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                sequence,
+            },
+        )
+        .is_ok()
+    }
+
+    pub fn register_payee(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        channel_id: String,
+        payee: String,
+    ) -> Result<Response, ContractError> {
+        let payee = deps.api.addr_validate(&payee)?;
+        self.store_payee(deps.storage, &channel_id, &info.sender, &payee)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "register_payee")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("relayer", info.sender)
+            .add_attribute("payee", payee))
+    }
+
+    pub fn register_counterparty_payee(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        channel_id: String,
+        counterparty_payee: String,
+    ) -> Result<Response, ContractError> {
+        if counterparty_payee.trim().is_empty() {
+            return Err(ContractError::Std(StdError::generic_err(
+                "counterparty payee must not be empty",
+            )));
+        }
+        self.store_counterparty_payee(
+            deps.storage,
+            &channel_id,
+            &info.sender,
+            &counterparty_payee,
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "register_counterparty_payee")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("relayer", info.sender)
+            .add_attribute("counterparty_payee", counterparty_payee))
+    }
+
+    /// Pays out the fees of an acknowledged packet: the receive fee goes to the forward relayer, the
+    /// ack fee to the payee of the reverse relayer and the timeout fee is refunded. The receive fee
+    /// is refunded as well when the forward relayer address is not valid on this chain.
+    pub fn distribute_ack_fees(
+        &self,
+        deps: DepsMut,
+        channel_id: &str,
+        sequence: u64,
+        forward_relayer: &str,
+        reverse_relayer: &Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        let fees = self.take_packet_fees(deps.storage, channel_id, sequence)?;
+        if fees.is_empty() {
+            return Ok(vec![]);
+        }
+        let forward_relayer = validate_relayer(deps.api, forward_relayer);
+        let reverse_payee = self.get_payee(deps.storage, channel_id, reverse_relayer)?;
+
+        let mut payouts = Payouts::default();
+        for packet_fee in fees.iter() {
+            let recv_fee_receiver = forward_relayer
+                .as_ref()
+                .unwrap_or(&packet_fee.refund_address);
+            payouts.add(recv_fee_receiver, &packet_fee.fee.recv_fee);
+            payouts.add(&reverse_payee, &packet_fee.fee.ack_fee);
+            payouts.add(&packet_fee.refund_address, &packet_fee.fee.timeout_fee);
+        }
+        Ok(to_bank_msgs(payouts))
+    }
+
+    /// Refunds every fee escrowed for a packet to its refund address.
+    pub fn refund_fees(
+        &self,
+        deps: DepsMut,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        let fees = self.take_packet_fees(deps.storage, channel_id, sequence)?;
+        let mut payouts = Payouts::default();
+        for packet_fee in fees.iter() {
+            payouts.add(&packet_fee.refund_address, &packet_fee.fee.recv_fee);
+            payouts.add(&packet_fee.refund_address, &packet_fee.fee.ack_fee);
+            payouts.add(&packet_fee.refund_address, &packet_fee.fee.timeout_fee);
+        }
+        Ok(to_bank_msgs(payouts))
+    }
+
+    /// Pays out the fees of a packet that timed out: the timeout fee goes to the payee of the
+    /// relayer and the receive and ack fees are refunded.
+    pub fn distribute_timeout_fees(
+        &self,
+        deps: DepsMut,
+        channel_id: &str,
+        sequence: u64,
+        timeout_relayer: &Addr,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        let fees = self.take_packet_fees(deps.storage, channel_id, sequence)?;
+        if fees.is_empty() {
+            return Ok(vec![]);
+        }
+        let timeout_payee = self.get_payee(deps.storage, channel_id, timeout_relayer)?;
+
+        let mut payouts = Payouts::default();
+        for packet_fee in fees.iter() {
+            payouts.add(&timeout_payee, &packet_fee.fee.timeout_fee);
+            payouts.add(&packet_fee.refund_address, &packet_fee.fee.recv_fee);
+            payouts.add(&packet_fee.refund_address, &packet_fee.fee.ack_fee);
+        }
+        Ok(to_bank_msgs(payouts))
+    }
+}
+
+fn validate_relayer(api: &dyn Api, address: &str) -> Option<Addr> {
+    if address.is_empty() {
+        return None;
+    }
+    api.addr_validate(address).ok()
+}
+
+fn to_bank_msgs(payouts: Payouts) -> Vec<CosmosMsg> {
+    payouts
+        .into_inner()
+        .into_iter()
+        .map(|(to_address, amount)| CosmosMsg::Bank(BankMsg::Send { to_address, amount }))
+        .collect()
+}
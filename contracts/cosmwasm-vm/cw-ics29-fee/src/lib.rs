@@ -0,0 +1,126 @@
+pub mod assertion;
+pub mod contract;
+pub mod error;
+pub mod fee;
+pub mod middleware;
+pub mod msg;
+pub mod state;
+pub mod types;
+
+use crate::{
+    error::ContractError,
+    msg::InstantiateMsg,
+    state::CwIcs29Fee,
+    types::{storage_keys::StorageKey, version::unwrap_version},
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    entry_point, to_json_binary as to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdError, StdResult, Storage,
+};
+
+use cw2::set_contract_version;
+use cw_common::cw_types::{
+    CwAcknowledgement, CwChannel, CwChannelCloseMsg, CwChannelConnectMsg, CwChannelOpenMsg,
+    CwPacket, CwPacketAckMsg, CwPacketReceiveMsg, CwPacketTimeoutMsg,
+};
+use cw_common::ibc_host::IbcHostApp;
+
+use cw_storage_plus::{Item, Map};
+use msg::{ExecuteMsg, QueryMsg};
+use thiserror::Error;
+
+/// This function instantiates the fee middleware and binds its port on the IBC host.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+/// querier of the contract.
+/// * `env`: `env` contains the address of this contract, which becomes the module of the port.
+/// * `info`: `info` contains the sender of the message, who becomes the owner of the contract.
+/// * `msg`: `msg` is the `InstantiateMsg` with the IBC host, the wrapped application and the port.
+///
+/// Returns:
+///
+/// The `instantiate` function returns a `Result<Response, ContractError>`.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let middleware = CwIcs29Fee::default();
+
+    middleware.instantiate(deps, env, info, msg)
+}
+
+/// This function executes a message against the fee middleware.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage, API and
+/// querier of the contract.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `info`: `info` contains the sender of the message and the funds attached to it.
+/// * `msg`: `msg` is the `ExecuteMsg` to execute.
+///
+/// Returns:
+///
+/// The `execute` function returns a `Result<Response, ContractError>`.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let middleware = CwIcs29Fee::default();
+
+    middleware.execute(deps, env, info, msg)
+}
+
+/// This function answers queries against the fee middleware. Host queries used by applications
+/// are forwarded to the IBC host.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `Deps` object that provides read access to the storage, API and querier.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `msg`: `msg` is the `QueryMsg` to answer.
+///
+/// Returns:
+///
+/// a `StdResult<Binary>` holding the JSON encoded response of the query.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let middleware = CwIcs29Fee::default();
+
+    middleware.query(deps, env, msg)
+}
+
+/// This function handles the reply of the application to a forwarded packet receive.
+///
+/// Arguments:
+///
+/// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+/// * `env`: `env` contains information about the current block and contract.
+/// * `msg`: `msg` is the `Reply` of the application.
+///
+/// Returns:
+///
+/// a `Result<Response, ContractError>` carrying the acknowledgement as data.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let middleware = CwIcs29Fee::default();
+
+    middleware.reply(deps, env, msg)
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let middleware = CwIcs29Fee::default();
+    middleware.migrate(deps, env, msg)
+}
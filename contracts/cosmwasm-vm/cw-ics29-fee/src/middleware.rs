@@ -0,0 +1,413 @@
+use cosmwasm_std::{CosmosMsg, SubMsg, SubMsgResult, WasmMsg};
+use cw_common::{
+    hex_string::HexString,
+    ibc_dapp_msg::{CwChannelUpgradeMsg, ExecuteMsg as AppExecuteMsg},
+    ibc_host::packet_receive_error_event,
+    types::Ack,
+};
+use debug_print::debug_println;
+
+use crate::{
+    state::{PendingReceive, APP_RECEIVE_PACKET_REPLY_ID},
+    types::{
+        ack::{is_success_ack, IncentivizedAcknowledgement},
+        LOG_PREFIX,
+    },
+};
+
+use super::*;
+
+impl<'a> CwIcs29Fee<'a> {
+    /// This function handles the opening of a channel on the port of the middleware. Fees are enabled
+    /// on the channel when its version is a `FeeVersion`, the application only ever sees its own
+    /// version.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `msg`: `msg` is the `CwChannelOpenMsg` of either the init or the try step.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` forwarding the callback to the application.
+    pub fn on_channel_open(
+        &self,
+        deps: DepsMut,
+        msg: CwChannelOpenMsg,
+    ) -> Result<Response, ContractError> {
+        let (fee_enabled, channel) = unwrap_channel(msg.channel())?;
+        let msg = match msg {
+            CwChannelOpenMsg::OpenInit { channel: _ } => CwChannelOpenMsg::OpenInit { channel },
+            CwChannelOpenMsg::OpenTry {
+                channel: _,
+                counterparty_version,
+            } => CwChannelOpenMsg::OpenTry {
+                channel,
+                counterparty_version: unwrap_counterparty_version(
+                    fee_enabled,
+                    &counterparty_version,
+                )?,
+            },
+        };
+        let channel_id = msg.channel().endpoint.channel_id.clone();
+        self.set_fee_enabled(deps.storage, &channel_id, fee_enabled)?;
+
+        let forward = self.call_app(deps.storage, &AppExecuteMsg::IbcChannelOpen { msg })?;
+        Ok(Response::new()
+            .add_message(forward)
+            .add_attribute("method", "on_channel_open")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("fee_enabled", fee_enabled.to_string()))
+    }
+
+    pub fn on_channel_connect(
+        &self,
+        deps: DepsMut,
+        msg: CwChannelConnectMsg,
+    ) -> Result<Response, ContractError> {
+        let (fee_enabled, channel) = unwrap_channel(msg.channel())?;
+        let msg = match msg {
+            CwChannelConnectMsg::OpenAck {
+                channel: _,
+                counterparty_version,
+            } => CwChannelConnectMsg::OpenAck {
+                channel,
+                counterparty_version: unwrap_counterparty_version(
+                    fee_enabled,
+                    &counterparty_version,
+                )?,
+            },
+            CwChannelConnectMsg::OpenConfirm { channel: _ } => {
+                CwChannelConnectMsg::OpenConfirm { channel }
+            }
+        };
+        let channel_id = msg.channel().endpoint.channel_id.clone();
+        self.set_fee_enabled(deps.storage, &channel_id, fee_enabled)?;
+
+        let forward = self.call_app(deps.storage, &AppExecuteMsg::IbcChannelConnect { msg })?;
+        Ok(Response::new()
+            .add_message(forward)
+            .add_attribute("method", "on_channel_connect")
+            .add_attribute("channel_id", channel_id))
+    }
+
+    pub fn on_channel_close(
+        &self,
+        deps: DepsMut,
+        msg: CwChannelCloseMsg,
+    ) -> Result<Response, ContractError> {
+        let msg = match msg {
+            CwChannelCloseMsg::CloseInit { channel } => CwChannelCloseMsg::CloseInit {
+                channel: unwrap_channel(&channel)?.1,
+            },
+            CwChannelCloseMsg::CloseConfirm { channel } => CwChannelCloseMsg::CloseConfirm {
+                channel: unwrap_channel(&channel)?.1,
+            },
+        };
+
+        let forward = self.call_app(deps.storage, &AppExecuteMsg::IbcChannelClose { msg })?;
+        Ok(Response::new()
+            .add_message(forward)
+            .add_attribute("method", "on_channel_close"))
+    }
+
+    /// This function forwards a received packet to the application. The acknowledgement is built in
+    /// `on_app_receive_reply` once the application answered.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage.
+    /// * `msg`: `msg` is the `CwPacketReceiveMsg`, its `relayer` is the forward relayer.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the submessage to the application.
+    pub fn on_packet_receive(
+        &self,
+        deps: DepsMut,
+        msg: CwPacketReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let fee_enabled = self.is_fee_enabled(deps.storage, &msg.packet.dest.channel_id);
+        self.store_pending_receive(
+            deps.storage,
+            &PendingReceive {
+                packet: msg.packet.clone(),
+                relayer: msg.relayer.clone(),
+                fee_enabled,
+            },
+        )?;
+
+        let forward = self.call_app(deps.storage, &AppExecuteMsg::IbcPacketReceive { msg })?;
+        Ok(Response::new()
+            .add_submessage(SubMsg::reply_always(forward, APP_RECEIVE_PACKET_REPLY_ID))
+            .add_attribute("method", "on_packet_receive"))
+    }
+
+    /// This function turns the answer of the application into the acknowledgement of the packet. A
+    /// failing application gets the fixed error acknowledgement, with the error in an event, like the
+    /// IBC host would write. On fee
+    /// enabled channels the acknowledgement is wrapped into an `IncentivizedAcknowledgement`.
+    pub fn on_app_receive_reply(
+        &self,
+        deps: DepsMut,
+        reply: Reply,
+    ) -> Result<Response, ContractError> {
+        let pending = self.take_pending_receive(deps.storage)?;
+        let channel_id = pending.packet.dest.channel_id.clone();
+        let mut error_event = None;
+        let app_ack = match reply.result {
+            SubMsgResult::Ok(response) => response.data.map(|data| data.0).unwrap_or_default(),
+            SubMsgResult::Err(error) => {
+                debug_println!("{LOG_PREFIX} App failed to receive packet {error}");
+                error_event = Some(packet_receive_error_event(&pending.packet, &error));
+                to_binary(&Ack::module_error())?.0
+            }
+        };
+
+        let mut res = Response::new().add_attribute("method", "on_app_receive_reply");
+        if let Some(event) = error_event {
+            res = res.add_event(event);
+        }
+        if !pending.fee_enabled {
+            return Ok(res.set_data(app_ack));
+        }
+
+        let forward_relayer =
+            self.get_counterparty_payee(deps.storage, &channel_id, &pending.relayer)?;
+        if app_ack.is_empty() {
+            // the application acknowledges later through `WriteAcknowledgement`
+            self.store_forward_relayer(
+                deps.storage,
+                &channel_id,
+                pending.packet.sequence,
+                &forward_relayer,
+            )?;
+            return Ok(res);
+        }
+
+        Ok(res.set_data(wrap_acknowledgement(app_ack, forward_relayer)?))
+    }
+
+    /// This function pays out the fees of an acknowledged packet and forwards the acknowledgement of
+    /// the application to it.
+    ///
+    /// Arguments:
+    ///
+    /// * `deps`: `deps` is a `DepsMut` object that provides mutable access to the storage and API.
+    /// * `msg`: `msg` is the `CwPacketAckMsg`, its `relayer` is the reverse relayer.
+    ///
+    /// Returns:
+    ///
+    /// a `Result<Response, ContractError>` with the fee payouts and the forwarded callback.
+    pub fn on_packet_ack(
+        &self,
+        mut deps: DepsMut,
+        msg: CwPacketAckMsg,
+    ) -> Result<Response, ContractError> {
+        let packet = msg.original_packet;
+        let channel_id = packet.src.channel_id.clone();
+        if !self.is_fee_enabled(deps.storage, &channel_id) {
+            let forward = self.call_app(
+                deps.storage,
+                &AppExecuteMsg::IbcPacketAck {
+                    msg: CwPacketAckMsg::new(msg.acknowledgement, packet, msg.relayer),
+                },
+            )?;
+            return Ok(Response::new()
+                .add_message(forward)
+                .add_attribute("method", "on_packet_ack"));
+        }
+
+        // the IBC host writes its error acknowledgement without the fee wrapper, it goes to the
+        // application as is and the fees are refunded as no forward relayer is known
+        let ack = match IncentivizedAcknowledgement::decode(&msg.acknowledgement.data) {
+            Ok(ack) => ack,
+            Err(_) => {
+                let refunds = self.refund_fees(deps.branch(), &channel_id, packet.sequence)?;
+                let forward = self.call_app(
+                    deps.storage,
+                    &AppExecuteMsg::IbcPacketAck {
+                        msg: CwPacketAckMsg::new(msg.acknowledgement, packet, msg.relayer),
+                    },
+                )?;
+                return Ok(Response::new()
+                    .add_message(forward)
+                    .add_messages(refunds)
+                    .add_attribute("method", "on_packet_ack"));
+            }
+        };
+        let payouts = self.distribute_ack_fees(
+            deps.branch(),
+            &channel_id,
+            packet.sequence,
+            &ack.forward_relayer_address,
+            &msg.relayer,
+        )?;
+        let forward = self.call_app(
+            deps.storage,
+            &AppExecuteMsg::IbcPacketAck {
+                msg: CwPacketAckMsg::new(
+                    CwAcknowledgement::new(ack.app_acknowledgement),
+                    packet,
+                    msg.relayer,
+                ),
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_message(forward)
+            .add_messages(payouts)
+            .add_attribute("method", "on_packet_ack")
+            .add_attribute("forward_relayer", ack.forward_relayer_address))
+    }
+
+    pub fn on_packet_timeout(
+        &self,
+        mut deps: DepsMut,
+        msg: CwPacketTimeoutMsg,
+    ) -> Result<Response, ContractError> {
+        let payouts = self.distribute_timeout_fees(
+            deps.branch(),
+            &msg.packet.src.channel_id,
+            msg.packet.sequence,
+            &msg.relayer,
+        )?;
+        let forward = self.call_app(deps.storage, &AppExecuteMsg::IbcPacketTimeout { msg })?;
+
+        Ok(Response::new()
+            .add_message(forward)
+            .add_messages(payouts)
+            .add_attribute("method", "on_packet_timeout"))
+    }
+
+    /// This function forwards a step of a channel upgrade handshake to the application with the fee
+    /// version stripped from the channel and the proposed upgrade. Whether fees are enabled follows
+    /// the upgraded version once the upgrade is open.
+    pub fn on_channel_upgrade(
+        &self,
+        deps: DepsMut,
+        msg: AppExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        let msg = match msg {
+            AppExecuteMsg::IbcChannelUpgradeInit { msg } => AppExecuteMsg::IbcChannelUpgradeInit {
+                msg: unwrap_upgrade(&msg)?,
+            },
+            AppExecuteMsg::IbcChannelUpgradeTry { msg } => AppExecuteMsg::IbcChannelUpgradeTry {
+                msg: unwrap_upgrade(&msg)?,
+            },
+            AppExecuteMsg::IbcChannelUpgradeAck { msg } => AppExecuteMsg::IbcChannelUpgradeAck {
+                msg: unwrap_upgrade(&msg)?,
+            },
+            AppExecuteMsg::IbcChannelUpgradeOpen { msg } => {
+                let (fee_enabled, _) = unwrap_version(&msg.upgrade.version)?;
+                self.set_fee_enabled(deps.storage, &msg.upgrade.endpoint.channel_id, fee_enabled)?;
+                AppExecuteMsg::IbcChannelUpgradeOpen {
+                    msg: unwrap_upgrade(&msg)?,
+                }
+            }
+            msg => msg,
+        };
+
+        let forward = self.call_app(deps.storage, &msg)?;
+        Ok(Response::new()
+            .add_message(forward)
+            .add_attribute("method", "on_channel_upgrade"))
+    }
+
+    /// This function forwards a packet sent by the application to the IBC host.
+    pub fn send_packet(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        packet: HexString,
+    ) -> Result<Response, ContractError> {
+        self.ensure_app(deps.storage, info.sender)?;
+
+        let forward = self.call_host_send_packet(deps.storage, packet, info.funds)?;
+        Ok(Response::new()
+            .add_message(forward)
+            .add_attribute("method", "send_packet"))
+    }
+
+    /// This function forwards an asynchronous acknowledgement of the application to the IBC host,
+    /// wrapping it with the forward relayer recorded when the packet was received.
+    pub fn write_acknowledgement(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        packet: CwPacket,
+        acknowledgement: HexString,
+    ) -> Result<Response, ContractError> {
+        self.ensure_app(deps.storage, info.sender)?;
+        let mut ack = acknowledgement
+            .to_bytes()
+            .map_err(|e| ContractError::DecodeFailed {
+                error: e.to_string(),
+            })?;
+
+        let channel_id = &packet.dest.channel_id;
+        if self.is_fee_enabled(deps.storage, channel_id) {
+            let forward_relayer =
+                self.take_forward_relayer(deps.storage, channel_id, packet.sequence)?;
+            ack = wrap_acknowledgement(ack, forward_relayer)?;
+        }
+
+        let forward = self.call_host_write_acknowledgement(deps.storage, packet, ack)?;
+        Ok(Response::new()
+            .add_message(forward)
+            .add_attribute("method", "write_acknowledgement"))
+    }
+
+    fn call_app(
+        &self,
+        store: &dyn Storage,
+        msg: &AppExecuteMsg,
+    ) -> Result<CosmosMsg, ContractError> {
+        let app = self.get_app(store)?;
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: app.to_string(),
+            msg: to_binary(msg)?,
+            funds: vec![],
+        }))
+    }
+}
+
+/// Returns whether `channel` is fee enabled and the channel as the application knows it.
+fn unwrap_channel(channel: &CwChannel) -> Result<(bool, CwChannel), ContractError> {
+    let (fee_enabled, app_version) = unwrap_version(&channel.version)?;
+    let mut channel = channel.clone();
+    channel.version = app_version;
+    Ok((fee_enabled, channel))
+}
+
+fn unwrap_counterparty_version(
+    fee_enabled: bool,
+    counterparty_version: &str,
+) -> Result<String, ContractError> {
+    let (counterparty_fee_enabled, app_version) = unwrap_version(counterparty_version)?;
+    if counterparty_fee_enabled != fee_enabled {
+        return Err(ContractError::FeeVersionMismatch {
+            version: counterparty_version.to_string(),
+        });
+    }
+    Ok(app_version)
+}
+
+fn unwrap_upgrade(msg: &CwChannelUpgradeMsg) -> Result<CwChannelUpgradeMsg, ContractError> {
+    Ok(CwChannelUpgradeMsg {
+        channel: unwrap_channel(&msg.channel)?.1,
+        upgrade: unwrap_channel(&msg.upgrade)?.1,
+    })
+}
+
+fn wrap_acknowledgement(
+    app_ack: Vec<u8>,
+    forward_relayer_address: String,
+) -> Result<Vec<u8>, ContractError> {
+    let ack = IncentivizedAcknowledgement {
+        underlying_app_success: is_success_ack(&app_ack),
+        app_acknowledgement: Binary::from(app_ack),
+        forward_relayer_address,
+    };
+    Ok(to_binary(&ack)?.0)
+}
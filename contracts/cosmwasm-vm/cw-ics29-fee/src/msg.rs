@@ -0,0 +1,119 @@
+use super::*;
+use cosmwasm_schema::QueryResponses;
+use cw_common::{hex_string::HexString, ibc_dapp_msg::CwChannelUpgradeMsg};
+
+use crate::types::fee::{Fee, PacketFee};
+
+/// This is a Rust struct representing a message to instantiate the fee middleware.
+///
+/// Properties:
+///
+/// * `ibc_host`: address of the IBC host the port is bound on.
+/// * `app`: address of the application wrapped by the middleware. The application uses the
+/// middleware as its IBC host.
+/// * `port_id`: port bound to the middleware on behalf of the application.
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub ibc_host: Addr,
+    pub app: Addr,
+    pub port_id: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Escrows the attached funds as relayer incentive for a packet. `sequence` defaults to the
+    /// next sequence sent on the channel and `refund_address` to the sender. Packets sent already
+    /// must still be in flight.
+    PayPacketFee {
+        channel_id: String,
+        sequence: Option<u64>,
+        fee: Fee,
+        refund_address: Option<String>,
+    },
+    /// Refunds the fees escrowed for a packet that was acknowledged or timed out without paying
+    /// them out.
+    RefundPacketFees {
+        channel_id: String,
+        sequence: u64,
+    },
+    /// Registers the address ack and timeout fees of the sending relayer are paid to.
+    RegisterPayee {
+        channel_id: String,
+        payee: String,
+    },
+    /// Registers the address on the counterparty chain the receive fee of the sending relayer is
+    /// paid to. It is written into the acknowledgement of packets it relays.
+    RegisterCounterpartyPayee {
+        channel_id: String,
+        counterparty_payee: String,
+    },
+
+    /// Same as `SendPacket` of the IBC host, called by the application.
+    SendPacket {
+        packet: HexString,
+    },
+    /// Same as `WriteAcknowledgement` of the IBC host, called by the application.
+    WriteAcknowledgement {
+        packet: CwPacket,
+        acknowledgement: HexString,
+    },
+
+    IbcChannelOpen {
+        msg: CwChannelOpenMsg,
+    },
+    IbcChannelConnect {
+        msg: CwChannelConnectMsg,
+    },
+    IbcChannelClose {
+        msg: CwChannelCloseMsg,
+    },
+    IbcPacketReceive {
+        msg: CwPacketReceiveMsg,
+    },
+    IbcPacketAck {
+        msg: CwPacketAckMsg,
+    },
+    IbcPacketTimeout {
+        msg: CwPacketTimeoutMsg,
+    },
+    IbcChannelUpgradeInit {
+        msg: CwChannelUpgradeMsg,
+    },
+    IbcChannelUpgradeTry {
+        msg: CwChannelUpgradeMsg,
+    },
+    IbcChannelUpgradeAck {
+        msg: CwChannelUpgradeMsg,
+    },
+    IbcChannelUpgradeOpen {
+        msg: CwChannelUpgradeMsg,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+/// This is a Rust enum representing the queries supported by the fee middleware. The last three
+/// variants mirror the IBC host queries applications rely on and are forwarded to it.
+pub enum QueryMsg {
+    #[returns(Addr)]
+    GetIbcHost {},
+    #[returns(Addr)]
+    GetApp {},
+    #[returns(String)]
+    GetPortId {},
+    #[returns(bool)]
+    IsFeeEnabled { channel_id: String },
+    #[returns(Vec<PacketFee>)]
+    GetPacketFees { channel_id: String, sequence: u64 },
+    #[returns(Addr)]
+    GetPayee { channel_id: String, relayer: String },
+    #[returns(String)]
+    GetCounterpartyPayee { channel_id: String, relayer: String },
+
+    #[returns(u64)]
+    GetNextSequenceSend { port_id: String, channel_id: String },
+    #[returns(u64)]
+    GetLatestHeight { client_id: String },
+    #[returns(String)]
+    GetChannel { port_id: String, channel_id: String },
+}
@@ -0,0 +1,280 @@
+use crate::types::fee::PacketFee;
+
+use super::*;
+
+pub const APP_RECEIVE_PACKET_REPLY_ID: u64 = 1;
+
+/// The `PendingReceive` struct keeps the context of a packet forwarded to the application until
+/// its reply arrives.
+///
+/// Properties:
+///
+/// * `packet`: the packet being received.
+/// * `relayer`: signer of the `MsgRecvPacket`, the forward relayer of the packet.
+/// * `fee_enabled`: whether the acknowledgement has to be wrapped.
+#[cw_serde]
+pub struct PendingReceive {
+    pub packet: CwPacket,
+    pub relayer: Addr,
+    pub fee_enabled: bool,
+}
+
+/// This is a Rust struct representing the ICS-29 fee middleware.
+///
+/// Properties:
+///
+/// * `owner`: address of the owner of the contract.
+/// * `ibc_host`: address of the IBC host the port is bound on.
+/// * `app`: address of the wrapped application.
+/// * `port_id`: port bound to the middleware.
+/// * `fee_enabled_channels`: channels negotiated with a `FeeVersion`, by channel id.
+/// * `packet_fees`: fees escrowed per packet, by channel id and sequence.
+/// * `payees`: address ack and timeout fees of a relayer are paid to, by channel id and relayer.
+/// * `counterparty_payees`: counterparty address receive fees of a relayer are paid to, by channel
+/// id and relayer.
+/// * `forward_relayers`: forward relayer of packets the application acknowledges asynchronously.
+/// * `pending_receive`: context of the packet currently forwarded to the application.
+pub struct CwIcs29Fee<'a> {
+    owner: Item<'a, String>,
+    ibc_host: Item<'a, Addr>,
+    app: Item<'a, Addr>,
+    port_id: Item<'a, String>,
+    fee_enabled_channels: Map<'a, String, bool>,
+    packet_fees: Map<'a, (String, u64), Vec<PacketFee>>,
+    payees: Map<'a, (String, String), Addr>,
+    counterparty_payees: Map<'a, (String, String), String>,
+    forward_relayers: Map<'a, (String, u64), String>,
+    pending_receive: Item<'a, PendingReceive>,
+}
+
+impl<'a> Default for CwIcs29Fee<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CwIcs29Fee<'a> {
+    pub fn new() -> Self {
+        Self {
+            owner: Item::new(StorageKey::Owner.as_str()),
+            ibc_host: Item::new(StorageKey::IbcHost.as_str()),
+            app: Item::new(StorageKey::App.as_str()),
+            port_id: Item::new(StorageKey::PortId.as_str()),
+            fee_enabled_channels: Map::new(StorageKey::FeeEnabledChannels.as_str()),
+            packet_fees: Map::new(StorageKey::PacketFees.as_str()),
+            payees: Map::new(StorageKey::Payees.as_str()),
+            counterparty_payees: Map::new(StorageKey::CounterpartyPayees.as_str()),
+            forward_relayers: Map::new(StorageKey::ForwardRelayers.as_str()),
+            pending_receive: Item::new(StorageKey::PendingReceive.as_str()),
+        }
+    }
+
+    pub fn owner(&self) -> &Item<'a, String> {
+        &self.owner
+    }
+
+    pub fn set_ibc_host(
+        &self,
+        store: &mut dyn Storage,
+        address: Addr,
+    ) -> Result<(), ContractError> {
+        self.ibc_host
+            .save(store, &address)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn set_app(&self, store: &mut dyn Storage, address: Addr) -> Result<(), ContractError> {
+        self.app.save(store, &address).map_err(ContractError::Std)
+    }
+
+    pub fn get_app(&self, store: &dyn Storage) -> Result<Addr, ContractError> {
+        self.app.load(store).map_err(ContractError::Std)
+    }
+
+    pub fn set_port_id(&self, store: &mut dyn Storage, port_id: &str) -> Result<(), ContractError> {
+        self.port_id
+            .save(store, &port_id.to_string())
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_port_id(&self, store: &dyn Storage) -> Result<String, ContractError> {
+        self.port_id.load(store).map_err(ContractError::Std)
+    }
+
+    pub fn set_fee_enabled(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        if enabled {
+            self.fee_enabled_channels
+                .save(store, channel_id.to_owned(), &true)?;
+        } else {
+            self.fee_enabled_channels
+                .remove(store, channel_id.to_owned());
+        }
+        Ok(())
+    }
+
+    pub fn is_fee_enabled(&self, store: &dyn Storage, channel_id: &str) -> bool {
+        self.fee_enabled_channels.has(store, channel_id.to_owned())
+    }
+
+    pub fn get_packet_fees(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<Vec<PacketFee>, ContractError> {
+        Ok(self
+            .packet_fees
+            .may_load(store, (channel_id.to_owned(), sequence))?
+            .unwrap_or_default())
+    }
+
+    pub fn add_packet_fee(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        sequence: u64,
+        packet_fee: PacketFee,
+    ) -> Result<(), ContractError> {
+        let mut fees = self.get_packet_fees(store, channel_id, sequence)?;
+        fees.push(packet_fee);
+        self.packet_fees
+            .save(store, (channel_id.to_owned(), sequence), &fees)
+            .map_err(ContractError::Std)
+    }
+
+    /// Removes and returns the fees escrowed for a packet.
+    pub fn take_packet_fees(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<Vec<PacketFee>, ContractError> {
+        let fees = self.get_packet_fees(store, channel_id, sequence)?;
+        self.packet_fees
+            .remove(store, (channel_id.to_owned(), sequence));
+        Ok(fees)
+    }
+
+    pub fn store_payee(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        relayer: &Addr,
+        payee: &Addr,
+    ) -> Result<(), ContractError> {
+        self.payees
+            .save(store, (channel_id.to_owned(), relayer.to_string()), payee)
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns the registered payee of `relayer`, or the relayer itself.
+    pub fn get_payee(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
+        relayer: &Addr,
+    ) -> Result<Addr, ContractError> {
+        Ok(self
+            .payees
+            .may_load(store, (channel_id.to_owned(), relayer.to_string()))?
+            .unwrap_or_else(|| relayer.clone()))
+    }
+
+    pub fn store_counterparty_payee(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        relayer: &Addr,
+        counterparty_payee: &str,
+    ) -> Result<(), ContractError> {
+        self.counterparty_payees
+            .save(
+                store,
+                (channel_id.to_owned(), relayer.to_string()),
+                &counterparty_payee.to_string(),
+            )
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns the registered counterparty payee of `relayer`, or the relayer address itself. An
+    /// address that is invalid on the counterparty gets the receive fee refunded there.
+    pub fn get_counterparty_payee(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
+        relayer: &Addr,
+    ) -> Result<String, ContractError> {
+        Ok(self
+            .counterparty_payees
+            .may_load(store, (channel_id.to_owned(), relayer.to_string()))?
+            .unwrap_or_else(|| relayer.to_string()))
+    }
+
+    pub fn store_forward_relayer(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        sequence: u64,
+        address: &str,
+    ) -> Result<(), ContractError> {
+        self.forward_relayers
+            .save(
+                store,
+                (channel_id.to_owned(), sequence),
+                &address.to_string(),
+            )
+            .map_err(ContractError::Std)
+    }
+
+    /// Removes and returns the forward relayer recorded for an asynchronously acknowledged packet.
+    pub fn take_forward_relayer(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<String, ContractError> {
+        let address = self
+            .forward_relayers
+            .may_load(store, (channel_id.to_owned(), sequence))?
+            .unwrap_or_default();
+        self.forward_relayers
+            .remove(store, (channel_id.to_owned(), sequence));
+        Ok(address)
+    }
+
+    pub fn store_pending_receive(
+        &self,
+        store: &mut dyn Storage,
+        pending: &PendingReceive,
+    ) -> Result<(), ContractError> {
+        self.pending_receive
+            .save(store, pending)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn take_pending_receive(
+        &self,
+        store: &mut dyn Storage,
+    ) -> Result<PendingReceive, ContractError> {
+        let pending = self.pending_receive.load(store)?;
+        self.pending_receive.remove(store);
+        Ok(pending)
+    }
+}
+
+impl<'a> IbcHostApp for CwIcs29Fee<'a> {
+    type Error = ContractError;
+
+    fn get_ibc_host(&self, store: &dyn Storage) -> Result<Addr, ContractError> {
+        self.ibc_host.load(store).map_err(ContractError::Std)
+    }
+
+    fn only_ibc_handler_error(&self) -> ContractError {
+        ContractError::OnlyIbcHandler {}
+    }
+}
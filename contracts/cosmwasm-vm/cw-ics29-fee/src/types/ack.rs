@@ -0,0 +1,28 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, Binary};
+use cw_common::types::Ack;
+
+use crate::error::ContractError;
+
+/// The `IncentivizedAcknowledgement` struct is written instead of the application acknowledgement
+/// on fee enabled channels. It carries the address the forward relayer wants the receive fee paid
+/// to on the sending chain.
+#[cw_serde]
+pub struct IncentivizedAcknowledgement {
+    pub app_acknowledgement: Binary,
+    pub forward_relayer_address: String,
+    pub underlying_app_success: bool,
+}
+
+impl IncentivizedAcknowledgement {
+    pub fn decode(data: &[u8]) -> Result<Self, ContractError> {
+        from_json(data).map_err(|e| ContractError::DecodeFailed {
+            error: e.to_string(),
+        })
+    }
+}
+
+/// Returns false for `{"error":...}` acknowledgements written by the IBC host or the application.
+pub fn is_success_ack(ack: &[u8]) -> bool {
+    !matches!(from_json::<Ack>(ack), Ok(Ack::Error(_)))
+}
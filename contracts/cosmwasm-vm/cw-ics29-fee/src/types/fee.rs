@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Uint128};
+
+/// The `Fee` struct holds the incentives paid for relaying a single packet.
+///
+/// Properties:
+///
+/// * `recv_fee`: paid to the relayer that delivered the packet to the counterparty.
+/// * `ack_fee`: paid to the relayer that delivered the acknowledgement back.
+/// * `timeout_fee`: paid to the relayer that delivered a timeout of the packet.
+#[cw_serde]
+#[derive(Default)]
+pub struct Fee {
+    pub recv_fee: Vec<Coin>,
+    pub ack_fee: Vec<Coin>,
+    pub timeout_fee: Vec<Coin>,
+}
+
+impl Fee {
+    /// Amount escrowed for the fee. Only one of `ack_fee` and `timeout_fee` is ever paid out, the
+    /// other one is refunded.
+    pub fn total(&self) -> Vec<Coin> {
+        merge_coins(
+            self.recv_fee
+                .iter()
+                .chain(self.ack_fee.iter())
+                .chain(self.timeout_fee.iter()),
+        )
+    }
+}
+
+/// The `PacketFee` struct is a fee escrowed for a packet together with the address the unused part
+/// of the fee is refunded to.
+#[cw_serde]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Addr,
+}
+
+/// Sums `coins` per denom, dropping zero amounts. The result is sorted by denom.
+pub fn merge_coins<'c>(coins: impl IntoIterator<Item = &'c Coin>) -> Vec<Coin> {
+    let mut amounts: BTreeMap<String, Uint128> = BTreeMap::new();
+    for coin in coins {
+        *amounts.entry(coin.denom.clone()).or_default() += coin.amount;
+    }
+    amounts
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin { denom, amount })
+        .collect()
+}
+
+/// Accumulates payouts per recipient so each address receives a single bank transfer.
+#[derive(Default)]
+pub struct Payouts(BTreeMap<String, Vec<Coin>>);
+
+impl Payouts {
+    pub fn add(&mut self, recipient: &Addr, coins: &[Coin]) {
+        self.0
+            .entry(recipient.to_string())
+            .or_default()
+            .extend(coins.iter().cloned());
+    }
+
+    pub fn into_inner(self) -> Vec<(String, Vec<Coin>)> {
+        self.0
+            .into_iter()
+            .map(|(recipient, coins)| (recipient, merge_coins(coins.iter())))
+            .filter(|(_, coins)| !coins.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coin;
+
+    use super::*;
+
+    #[test]
+    fn test_fee_total_merges_denoms() {
+        let fee = Fee {
+            recv_fee: vec![coin(10, "uarch")],
+            ack_fee: vec![coin(5, "uarch"), coin(3, "uconst")],
+            timeout_fee: vec![coin(0, "uother")],
+        };
+        assert_eq!(fee.total(), vec![coin(15, "uarch"), coin(3, "uconst")]);
+    }
+
+    #[test]
+    fn test_payouts_per_recipient() {
+        let mut payouts = Payouts::default();
+        payouts.add(&Addr::unchecked("relayer"), &[coin(10, "uarch")]);
+        payouts.add(&Addr::unchecked("relayer"), &[coin(5, "uarch")]);
+        payouts.add(&Addr::unchecked("payer"), &[]);
+        assert_eq!(
+            payouts.into_inner(),
+            vec![("relayer".to_string(), vec![coin(15, "uarch")])]
+        );
+    }
+}
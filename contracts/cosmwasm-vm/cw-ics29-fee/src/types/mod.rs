@@ -0,0 +1,6 @@
+pub mod ack;
+pub mod fee;
+pub mod storage_keys;
+pub mod version;
+
+pub const LOG_PREFIX: &str = "[ics29_fee]:";
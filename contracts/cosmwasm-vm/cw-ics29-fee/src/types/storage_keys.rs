@@ -0,0 +1,32 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum StorageKey {
+    Owner,
+    IbcHost,
+    App,
+    PortId,
+    FeeEnabledChannels,
+    PacketFees,
+    Payees,
+    CounterpartyPayees,
+    ForwardRelayers,
+    PendingReceive,
+}
+
+impl StorageKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKey::Owner => "owner",
+            StorageKey::IbcHost => "ibc_host",
+            StorageKey::App => "app",
+            StorageKey::PortId => "port_id",
+            StorageKey::FeeEnabledChannels => "fee_enabled_channels",
+            StorageKey::PacketFees => "packet_fees",
+            StorageKey::Payees => "payees",
+            StorageKey::CounterpartyPayees => "counterparty_payees",
+            StorageKey::ForwardRelayers => "forward_relayers",
+            StorageKey::PendingReceive => "pending_receive",
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use cosmwasm_schema::cw_serde;
+
+use crate::error::ContractError;
+
+/// Fee version negotiated on channels with relayer incentivization enabled.
+pub const FEE_VERSION: &str = "ics29-1";
+
+/// The `FeeVersion` struct is the channel version of fee enabled channels. It wraps the version of
+/// the underlying application as defined by ICS-29.
+#[cw_serde]
+pub struct FeeVersion {
+    pub fee_version: String,
+    pub app_version: String,
+}
+
+impl FeeVersion {
+    pub fn new(app_version: &str) -> Self {
+        Self {
+            fee_version: FEE_VERSION.to_string(),
+            app_version: app_version.to_string(),
+        }
+    }
+
+    pub fn encode(&self) -> Result<String, ContractError> {
+        serde_json_wasm::to_string(self).map_err(|e| ContractError::DecodeFailed {
+            error: e.to_string(),
+        })
+    }
+}
+
+/// Splits a channel version into whether fees are enabled and the version of the application.
+/// Versions which are not a `FeeVersion` belong to the application as is.
+pub fn unwrap_version(version: &str) -> Result<(bool, String), ContractError> {
+    match serde_json_wasm::from_str::<FeeVersion>(version) {
+        Ok(fee_version) if fee_version.fee_version == FEE_VERSION => {
+            Ok((true, fee_version.app_version))
+        }
+        Ok(fee_version) => Err(ContractError::InvalidFeeVersion {
+            version: fee_version.fee_version,
+        }),
+        Err(_) => Ok((false, version.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwrap_fee_version() {
+        let version = FeeVersion::new("ics20-1").encode().unwrap();
+        assert_eq!(
+            unwrap_version(&version).unwrap(),
+            (true, "ics20-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unwrap_plain_version() {
+        assert_eq!(
+            unwrap_version("ics20-1").unwrap(),
+            (false, "ics20-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unwrap_unknown_fee_version() {
+        let version = r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#;
+        assert!(unwrap_version(version).is_err());
+    }
+}
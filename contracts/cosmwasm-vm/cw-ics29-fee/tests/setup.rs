@@ -0,0 +1,105 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+    to_json_binary as to_binary, Addr, ContractResult, DepsMut, Empty, IbcChannel,
+    IbcChannelOpenMsg, IbcOrder, IbcPacket, OwnedDeps, SystemResult, WasmQuery,
+};
+use cw_common::core_msg::QueryMsg as HostQueryMsg;
+use cw_ics29_fee::{
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::CwIcs29Fee,
+    types::version::FeeVersion,
+};
+pub use test_utils::ibc_app::{deps, ChannelEnds, IBC_HOST, NEXT_SEQUENCE, OWNER};
+
+pub const APP: &str = "app";
+pub const PORT_ID: &str = "transfer";
+pub const CHANNEL_ID: &str = "channel-0";
+pub const COUNTERPARTY_PORT_ID: &str = "transfer";
+pub const COUNTERPARTY_CHANNEL_ID: &str = "channel-7";
+pub const APP_VERSION: &str = "ics20-1";
+
+pub fn channel_ends() -> ChannelEnds {
+    ChannelEnds::new(
+        PORT_ID,
+        CHANNEL_ID,
+        COUNTERPARTY_PORT_ID,
+        COUNTERPARTY_CHANNEL_ID,
+    )
+}
+
+pub fn fee_version() -> String {
+    FeeVersion::new(APP_VERSION).encode().unwrap()
+}
+
+pub fn get_channel(version: &str) -> IbcChannel {
+    channel_ends().channel(IbcOrder::Unordered, version, "connection-0")
+}
+
+/// Instantiates the middleware with `IBC_HOST` as host and `APP` as application.
+pub fn setup_contract(deps: DepsMut) -> CwIcs29Fee<'static> {
+    let contract = CwIcs29Fee::default();
+    contract
+        .instantiate(
+            deps,
+            mock_env(),
+            mock_info(OWNER, &[]),
+            InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                app: Addr::unchecked(APP),
+                port_id: PORT_ID.to_string(),
+            },
+        )
+        .unwrap();
+    contract
+}
+
+/// Opens `CHANNEL_ID` with `version` through the init step.
+pub fn open_channel(deps: DepsMut, contract: &CwIcs29Fee, version: &str) {
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenInit {
+                    channel: get_channel(version),
+                },
+            },
+        )
+        .unwrap();
+}
+
+/// Builds a packet travelling from the counterparty to this chain.
+pub fn incoming_packet(sequence: u64) -> IbcPacket {
+    channel_ends().incoming_packet(b"data".to_vec(), sequence)
+}
+
+/// Builds a packet sent from this chain to the counterparty.
+pub fn outgoing_packet(sequence: u64) -> IbcPacket {
+    channel_ends().outgoing_packet(b"data".to_vec(), sequence)
+}
+
+/// Makes the IBC host hold a packet commitment for each of `sequences` only, other queries are
+/// still answered with `NEXT_SEQUENCE`.
+pub fn set_packet_commitments(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    sequences: Vec<u64>,
+) {
+    deps.querier.update_wasm(move |query| {
+        let msg = match query {
+            WasmQuery::Smart { msg, .. } => from_json::<HostQueryMsg>(msg).ok(),
+            _ => None,
+        };
+        match msg {
+            Some(HostQueryMsg::GetPacketCommitment { sequence, .. }) => {
+                if sequences.contains(&sequence) {
+                    SystemResult::Ok(ContractResult::Ok(to_binary(&"0x01").unwrap()))
+                } else {
+                    SystemResult::Ok(ContractResult::Err("commitment not found".to_string()))
+                }
+            }
+            _ => SystemResult::Ok(ContractResult::Ok(to_binary(&NEXT_SEQUENCE).unwrap())),
+        }
+    });
+}
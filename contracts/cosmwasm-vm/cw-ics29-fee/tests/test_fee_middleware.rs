@@ -0,0 +1,638 @@
+pub mod setup;
+
+use cosmwasm_std::{
+    coin, coins, from_json,
+    testing::{mock_env, mock_info},
+    to_json_binary as to_binary, Addr, BankMsg, Binary, CosmosMsg, DepsMut, IbcAcknowledgement,
+    IbcChannelOpenMsg, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, Reply, Response,
+    SubMsgResponse, SubMsgResult, WasmMsg,
+};
+use cw_common::{
+    core_msg::ExecuteMsg as CoreExecuteMsg, hex_string::HexString,
+    ibc_dapp_msg::ExecuteMsg as AppExecuteMsg, types::Ack,
+};
+use cw_ics29_fee::{
+    error::ContractError,
+    msg::ExecuteMsg,
+    state::{CwIcs29Fee, APP_RECEIVE_PACKET_REPLY_ID},
+    types::{ack::IncentivizedAcknowledgement, fee::Fee},
+};
+
+use setup::*;
+
+fn fee() -> Fee {
+    Fee {
+        recv_fee: coins(10, "uarch"),
+        ack_fee: coins(5, "uarch"),
+        timeout_fee: coins(3, "uarch"),
+    }
+}
+
+fn pay_fee(deps: DepsMut, contract: &CwIcs29Fee, sequence: u64) {
+    contract
+        .execute(
+            deps,
+            mock_env(),
+            mock_info("payer", &coins(18, "uarch")),
+            ExecuteMsg::PayPacketFee {
+                channel_id: CHANNEL_ID.to_string(),
+                sequence: Some(sequence),
+                fee: fee(),
+                refund_address: None,
+            },
+        )
+        .unwrap();
+}
+
+fn app_msg(res: &Response) -> AppExecuteMsg {
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, APP);
+            from_json(msg).unwrap()
+        }
+        _ => panic!("expected wasm message"),
+    }
+}
+
+fn bank_msgs(res: &Response) -> Vec<BankMsg> {
+    res.messages
+        .iter()
+        .filter_map(|sub| match &sub.msg {
+            CosmosMsg::Bank(msg) => Some(msg.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn send(to_address: &str, amount: u128) -> BankMsg {
+    BankMsg::Send {
+        to_address: to_address.to_string(),
+        amount: coins(amount, "uarch"),
+    }
+}
+
+fn receive_with_reply(mut deps: DepsMut, contract: &CwIcs29Fee, result: SubMsgResult) -> Response {
+    contract
+        .execute(
+            deps.branch(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketReceive {
+                msg: IbcPacketReceiveMsg::new(incoming_packet(1), Addr::unchecked("relayer")),
+            },
+        )
+        .unwrap();
+    contract
+        .reply(
+            deps,
+            mock_env(),
+            Reply {
+                id: APP_RECEIVE_PACKET_REPLY_ID,
+                result,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_instantiate_binds_port() {
+    let mut deps = deps();
+    let contract = CwIcs29Fee::default();
+    let res = contract
+        .instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            cw_ics29_fee::msg::InstantiateMsg {
+                ibc_host: Addr::unchecked(IBC_HOST),
+                app: Addr::unchecked(APP),
+                port_id: PORT_ID.to_string(),
+            },
+        )
+        .unwrap();
+
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, IBC_HOST);
+            match from_json::<CoreExecuteMsg>(msg).unwrap() {
+                CoreExecuteMsg::BindPort { port_id, address } => {
+                    assert_eq!(port_id, PORT_ID);
+                    assert_eq!(address, mock_env().contract.address.to_string());
+                }
+                _ => panic!("expected BindPort"),
+            }
+        }
+        _ => panic!("expected wasm message"),
+    }
+}
+
+#[test]
+fn test_open_fee_channel_forwards_app_version() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenTry {
+                    channel: get_channel(&fee_version()),
+                    counterparty_version: fee_version(),
+                },
+            },
+        )
+        .unwrap();
+
+    assert!(contract.is_fee_enabled(deps.as_ref().storage, CHANNEL_ID));
+    match app_msg(&res) {
+        AppExecuteMsg::IbcChannelOpen {
+            msg:
+                IbcChannelOpenMsg::OpenTry {
+                    channel,
+                    counterparty_version,
+                },
+        } => {
+            assert_eq!(channel.version, APP_VERSION);
+            assert_eq!(counterparty_version, APP_VERSION);
+        }
+        _ => panic!("expected IbcChannelOpen"),
+    }
+}
+
+#[test]
+fn test_open_plain_channel_disables_fees() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, APP_VERSION);
+
+    assert!(!contract.is_fee_enabled(deps.as_ref().storage, CHANNEL_ID));
+}
+
+#[test]
+fn test_open_fee_version_mismatch() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenTry {
+                    channel: get_channel(&fee_version()),
+                    counterparty_version: APP_VERSION.to_string(),
+                },
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::FeeVersionMismatch { .. }));
+}
+
+#[test]
+fn test_callbacks_only_from_ibc_host() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            ExecuteMsg::IbcChannelOpen {
+                msg: IbcChannelOpenMsg::OpenInit {
+                    channel: get_channel(&fee_version()),
+                },
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::OnlyIbcHandler {}));
+}
+
+#[test]
+fn test_pay_packet_fee_requires_fee_channel() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, APP_VERSION);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer", &coins(18, "uarch")),
+            ExecuteMsg::PayPacketFee {
+                channel_id: CHANNEL_ID.to_string(),
+                sequence: None,
+                fee: fee(),
+                refund_address: None,
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::FeeNotEnabled { .. }));
+}
+
+#[test]
+fn test_pay_packet_fee_invalid_funds() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer", &[coin(10, "uarch"), coin(8, "uconst")]),
+            ExecuteMsg::PayPacketFee {
+                channel_id: CHANNEL_ID.to_string(),
+                sequence: None,
+                fee: fee(),
+                refund_address: None,
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::InvalidFunds {}));
+}
+
+#[test]
+fn test_pay_packet_fee_next_sequence() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer", &coins(18, "uarch")),
+            ExecuteMsg::PayPacketFee {
+                channel_id: CHANNEL_ID.to_string(),
+                sequence: None,
+                fee: fee(),
+                refund_address: None,
+            },
+        )
+        .unwrap();
+
+    let fees = contract
+        .get_packet_fees(deps.as_ref().storage, CHANNEL_ID, NEXT_SEQUENCE)
+        .unwrap();
+    assert_eq!(fees.len(), 1);
+    assert_eq!(fees[0].fee, fee());
+    assert_eq!(fees[0].refund_address, Addr::unchecked("payer"));
+}
+
+#[test]
+fn test_pay_packet_fee_rejects_packet_not_in_flight() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    set_packet_commitments(&mut deps, vec![NEXT_SEQUENCE - 1]);
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer", &coins(18, "uarch")),
+            ExecuteMsg::PayPacketFee {
+                channel_id: CHANNEL_ID.to_string(),
+                sequence: Some(NEXT_SEQUENCE - 2),
+                fee: fee(),
+                refund_address: None,
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ContractError::PacketNotInFlight { sequence, .. } if sequence == NEXT_SEQUENCE - 2
+    ));
+    pay_fee(deps.as_mut(), &contract, NEXT_SEQUENCE - 1);
+}
+
+#[test]
+fn test_refund_packet_fees() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    set_packet_commitments(&mut deps, vec![NEXT_SEQUENCE - 1]);
+    pay_fee(deps.as_mut(), &contract, NEXT_SEQUENCE - 1);
+    let refund = ExecuteMsg::RefundPacketFees {
+        channel_id: CHANNEL_ID.to_string(),
+        sequence: NEXT_SEQUENCE - 1,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            refund.clone(),
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::PacketInFlight { .. }));
+
+    set_packet_commitments(&mut deps, vec![]);
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), mock_info("anyone", &[]), refund)
+        .unwrap();
+
+    assert_eq!(bank_msgs(&res), vec![send("payer", 18)]);
+    assert!(contract
+        .get_packet_fees(deps.as_ref().storage, CHANNEL_ID, NEXT_SEQUENCE - 1)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_ack_without_fee_wrapper_refunds_fees() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    pay_fee(deps.as_mut(), &contract, NEXT_SEQUENCE);
+
+    let raw_ack = to_binary(&Ack::Error("host error".to_string())).unwrap();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketAck {
+                msg: IbcPacketAckMsg::new(
+                    IbcAcknowledgement::new(raw_ack.clone()),
+                    outgoing_packet(NEXT_SEQUENCE),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    match app_msg(&res) {
+        AppExecuteMsg::IbcPacketAck { msg } => {
+            assert_eq!(msg.acknowledgement.data, raw_ack);
+        }
+        _ => panic!("expected IbcPacketAck"),
+    }
+    assert_eq!(bank_msgs(&res), vec![send("payer", 18)]);
+}
+
+#[test]
+fn test_ack_pays_relayers() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    pay_fee(deps.as_mut(), &contract, NEXT_SEQUENCE);
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("relayer", &[]),
+            ExecuteMsg::RegisterPayee {
+                channel_id: CHANNEL_ID.to_string(),
+                payee: "payee".to_string(),
+            },
+        )
+        .unwrap();
+
+    let app_ack = to_binary(&Ack::Result(Binary::from(vec![1]))).unwrap();
+    let ack = IncentivizedAcknowledgement {
+        app_acknowledgement: app_ack.clone(),
+        forward_relayer_address: "forward".to_string(),
+        underlying_app_success: true,
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketAck {
+                msg: IbcPacketAckMsg::new(
+                    IbcAcknowledgement::new(to_binary(&ack).unwrap()),
+                    outgoing_packet(NEXT_SEQUENCE),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    match app_msg(&res) {
+        AppExecuteMsg::IbcPacketAck { msg } => {
+            assert_eq!(msg.acknowledgement.data, app_ack);
+        }
+        _ => panic!("expected IbcPacketAck"),
+    }
+    assert_eq!(
+        bank_msgs(&res),
+        vec![send("forward", 10), send("payee", 5), send("payer", 3)]
+    );
+    assert!(contract
+        .get_packet_fees(deps.as_ref().storage, CHANNEL_ID, NEXT_SEQUENCE)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_ack_refunds_recv_fee_for_invalid_forward_relayer() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    pay_fee(deps.as_mut(), &contract, NEXT_SEQUENCE);
+
+    let ack = IncentivizedAcknowledgement {
+        app_acknowledgement: Binary::from(vec![1]),
+        forward_relayer_address: String::new(),
+        underlying_app_success: true,
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketAck {
+                msg: IbcPacketAckMsg::new(
+                    IbcAcknowledgement::new(to_binary(&ack).unwrap()),
+                    outgoing_packet(NEXT_SEQUENCE),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(bank_msgs(&res), vec![send("payer", 13), send("relayer", 5)]);
+}
+
+#[test]
+fn test_timeout_pays_relayer() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    pay_fee(deps.as_mut(), &contract, NEXT_SEQUENCE);
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(IBC_HOST, &[]),
+            ExecuteMsg::IbcPacketTimeout {
+                msg: IbcPacketTimeoutMsg::new(
+                    outgoing_packet(NEXT_SEQUENCE),
+                    Addr::unchecked("relayer"),
+                ),
+            },
+        )
+        .unwrap();
+
+    assert!(matches!(
+        app_msg(&res),
+        AppExecuteMsg::IbcPacketTimeout { .. }
+    ));
+    assert_eq!(bank_msgs(&res), vec![send("payer", 15), send("relayer", 3)]);
+}
+
+#[test]
+fn test_receive_wraps_app_ack() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("relayer", &[]),
+            ExecuteMsg::RegisterCounterpartyPayee {
+                channel_id: CHANNEL_ID.to_string(),
+                counterparty_payee: "hxpayee".to_string(),
+            },
+        )
+        .unwrap();
+
+    let app_ack = Binary::from(vec![1]);
+    let res = receive_with_reply(
+        deps.as_mut(),
+        &contract,
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(app_ack.clone()),
+        }),
+    );
+
+    let ack = IncentivizedAcknowledgement::decode(&res.data.unwrap()).unwrap();
+    assert_eq!(ack.app_acknowledgement, app_ack);
+    assert_eq!(ack.forward_relayer_address, "hxpayee");
+    assert!(ack.underlying_app_success);
+}
+
+#[test]
+fn test_receive_app_error_ack() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+
+    let res = receive_with_reply(
+        deps.as_mut(),
+        &contract,
+        SubMsgResult::Err("failed".to_string()),
+    );
+
+    let ack = IncentivizedAcknowledgement::decode(&res.data.unwrap()).unwrap();
+    assert_eq!(
+        ack.app_acknowledgement,
+        to_binary(&Ack::module_error()).unwrap()
+    );
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == "receive_packet_error")
+        .unwrap();
+    assert!(event
+        .attributes
+        .iter()
+        .any(|attribute| attribute.key == "packet_error" && attribute.value == "failed"));
+    assert_eq!(ack.forward_relayer_address, "relayer");
+    assert!(!ack.underlying_app_success);
+}
+
+#[test]
+fn test_receive_plain_channel_keeps_app_ack() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, APP_VERSION);
+
+    let app_ack = Binary::from(vec![1]);
+    let res = receive_with_reply(
+        deps.as_mut(),
+        &contract,
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(app_ack.clone()),
+        }),
+    );
+
+    assert_eq!(res.data, Some(app_ack));
+}
+
+#[test]
+fn test_async_ack_is_wrapped() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    open_channel(deps.as_mut(), &contract, &fee_version());
+
+    let res = receive_with_reply(
+        deps.as_mut(),
+        &contract,
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    );
+    assert_eq!(res.data, None);
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(APP, &[]),
+            ExecuteMsg::WriteAcknowledgement {
+                packet: incoming_packet(1),
+                acknowledgement: HexString::from_bytes(&[1]),
+            },
+        )
+        .unwrap();
+
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            match from_json::<CoreExecuteMsg>(msg).unwrap() {
+                CoreExecuteMsg::WriteAcknowledgement {
+                    acknowledgement, ..
+                } => {
+                    let ack =
+                        IncentivizedAcknowledgement::decode(&acknowledgement.to_bytes().unwrap())
+                            .unwrap();
+                    assert_eq!(ack.app_acknowledgement, Binary::from(vec![1]));
+                    assert_eq!(ack.forward_relayer_address, "relayer");
+                }
+                _ => panic!("expected WriteAcknowledgement"),
+            }
+        }
+        _ => panic!("expected wasm message"),
+    }
+}
+
+#[test]
+fn test_send_packet_only_from_app() {
+    let mut deps = deps();
+    let contract = setup_contract(deps.as_mut());
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            ExecuteMsg::SendPacket {
+                packet: HexString::from_bytes(&[1]),
+            },
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::OnlyApp {}));
+}
@@ -331,6 +331,7 @@ pub fn call_configure_connection(
             counterparty_nid: NetId::from(nid),
             client_id,
             timeout_height: 10,
+            timeout_duration: 0,
         },
         &[],
     )
@@ -162,6 +162,12 @@ impl<'a> CwIbcConnection<'a> {
                     kind: error.to_string(),
                 }),
             },
+            QueryMsg::GetChannelOrder {} => match self.get_channel_order(deps.storage) {
+                Ok(order) => Ok(to_binary(&order)?),
+                Err(error) => Err(StdError::NotFound {
+                    kind: error.to_string(),
+                }),
+            },
         }
     }
     /// This function handles different types of reply messages and calls corresponding functions based on
@@ -234,11 +240,14 @@ impl<'a> CwIbcConnection<'a> {
         self.add_admin(store, info, owner)?;
         // self.set_timeout_height(store, msg.timeout_height)?;
         self.set_ibc_host(store, msg.ibc_host.clone())?;
+        let order = msg.order.unwrap_or(CwOrder::Unordered);
+        self.set_channel_order(store, &order)?;
 
         Ok(Response::new()
             .add_attribute("action", "instantiate")
             .add_attribute("method", "init")
-            .add_attribute("ibc_host", msg.ibc_host))
+            .add_attribute("ibc_host", msg.ibc_host)
+            .add_attribute("order", format!("{order:?}")))
     }
 
     /// This function handles the opening of an IBC channel and returns a response with relevant
@@ -267,6 +276,7 @@ impl<'a> CwIbcConnection<'a> {
 
         let channel = msg.channel();
         let ibc_endpoint = channel.endpoint.clone();
+        check_order(&self.get_channel_order(store)?, &channel.order)?;
 
         if let Some(counter_version) = msg.counterparty_version() {
             check_version(counter_version)?;
@@ -302,6 +312,7 @@ impl<'a> CwIbcConnection<'a> {
     ) -> Result<Response, ContractError> {
         let channel = msg.channel();
         debug_println!("[IBCConnection]: channel connect called");
+        check_order(&self.get_channel_order(store)?, &channel.order)?;
 
         if let Some(counter_version) = msg.counterparty_version() {
             check_version(counter_version)?;
@@ -461,3 +472,122 @@ impl<'a> CwIbcConnection<'a> {
         packet
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        Addr, IbcChannel, IbcChannelOpenMsg, IbcEndpoint, IbcOrder, IbcPacket, IbcPacketReceiveMsg,
+        IbcTimeout, IbcTimeoutBlock, OwnedDeps,
+    };
+
+    use crate::{
+        error::ContractError,
+        execute, instantiate,
+        msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+        query, IBC_VERSION,
+    };
+
+    const IBC_HOST: &str = "ibc_host";
+
+    fn setup(order: Option<IbcOrder>) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            ibc_host: Addr::unchecked(IBC_HOST),
+            order,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+        deps
+    }
+
+    fn endpoints() -> (IbcEndpoint, IbcEndpoint) {
+        let src = IbcEndpoint {
+            port_id: "mock".to_string(),
+            channel_id: "channel-0".to_string(),
+        };
+        let dst = IbcEndpoint {
+            port_id: "mock".to_string(),
+            channel_id: "channel-1".to_string(),
+        };
+        (src, dst)
+    }
+
+    fn open_channel(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        order: IbcOrder,
+    ) -> Result<cosmwasm_std::Response, ContractError> {
+        let (src, dst) = endpoints();
+        let channel = IbcChannel::new(src, dst, order, IBC_VERSION, "connection-0");
+        let msg = ExecuteMsg::IbcChannelOpen {
+            msg: IbcChannelOpenMsg::new_init(channel),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(IBC_HOST, &[]), msg)
+    }
+
+    fn receive_packet(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sequence: u64,
+    ) -> Result<cosmwasm_std::Response, ContractError> {
+        let (src, dst) = endpoints();
+        let timeout = IbcTimeout::with_block(IbcTimeoutBlock {
+            revision: 0,
+            height: 100,
+        });
+        let packet = IbcPacket::new(vec![1, 2, 3], dst, src, sequence, timeout);
+        let msg = ExecuteMsg::IbcPacketReceive {
+            msg: IbcPacketReceiveMsg::new(packet, Addr::unchecked("relayer")),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(IBC_HOST, &[]), msg)
+    }
+
+    #[test]
+    fn test_default_order_is_unordered() {
+        let mut deps = setup(None);
+
+        let order: IbcOrder =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::GetChannelOrder {}).unwrap())
+                .unwrap();
+        assert_eq!(order, IbcOrder::Unordered);
+
+        assert!(open_channel(&mut deps, IbcOrder::Unordered).is_ok());
+        assert!(matches!(
+            open_channel(&mut deps, IbcOrder::Ordered),
+            Err(ContractError::InvalidChannelOrder {})
+        ));
+    }
+
+    #[test]
+    fn test_ordered_mode_opens_ordered_channels_only() {
+        let mut deps = setup(Some(IbcOrder::Ordered));
+
+        assert!(matches!(
+            open_channel(&mut deps, IbcOrder::Unordered),
+            Err(ContractError::InvalidChannelOrder {})
+        ));
+        assert!(open_channel(&mut deps, IbcOrder::Ordered).is_ok());
+    }
+
+    #[test]
+    fn test_ordered_mode_rejects_sequence_gap() {
+        let mut deps = setup(Some(IbcOrder::Ordered));
+        open_channel(&mut deps, IbcOrder::Ordered).unwrap();
+
+        receive_packet(&mut deps, 1).unwrap();
+        assert!(matches!(
+            receive_packet(&mut deps, 3),
+            Err(ContractError::InvalidSequenceId { id: 3 })
+        ));
+        receive_packet(&mut deps, 2).unwrap();
+        receive_packet(&mut deps, 3).unwrap();
+    }
+
+    #[test]
+    fn test_unordered_mode_accepts_any_sequence() {
+        let mut deps = setup(None);
+        open_channel(&mut deps, IbcOrder::Unordered).unwrap();
+
+        receive_packet(&mut deps, 3).unwrap();
+        receive_packet(&mut deps, 1).unwrap();
+    }
+}
@@ -13,7 +13,7 @@ pub mod state;
 pub mod types;
 
 use crate::{
-    check::check_version,
+    check::{check_order, check_version},
     error::ContractError,
     ibc::IBC_VERSION,
     msg::InstantiateMsg,
@@ -31,7 +31,7 @@ use cw_common::cw_types::{CwTimeout, CwTimeoutBlock};
 use cw2::set_contract_version;
 use cw_common::cw_types::{
     Cw3ChannelOpenResponse, CwBasicResponse, CwChannelCloseMsg, CwChannelConnectMsg,
-    CwChannelOpenMsg, CwChannelOpenResponse, CwEndPoint, CwPacket, CwPacketAckMsg,
+    CwChannelOpenMsg, CwChannelOpenResponse, CwEndPoint, CwOrder, CwPacket, CwPacketAckMsg,
     CwPacketReceiveMsg, CwPacketTimeoutMsg, CwReceiveResponse,
 };
 
@@ -1,6 +1,6 @@
 use super::*;
 use cosmwasm_schema::QueryResponses;
-// use cw_common::cw_types::CwOrder;
+use cw_common::cw_types::CwOrder;
 
 /// This is a Rust struct representing a message to instantiate a contract with timeout height and IBC
 /// host address.
@@ -10,9 +10,12 @@ use cosmwasm_schema::QueryResponses;
 /// * `ibc_host`: `ibc_host` is a field of type `Addr` in the `InstantiateMsg` struct. It likely
 /// represents the address of the IBC host that the message is being sent to. However, without more
 /// context it's difficult to say for sure.
+/// * `order`: ordering of the channels the dapp accepts. Defaults to `Unordered` so ordered
+/// channels can be exercised by instantiating a separate dapp with `Ordered`.
 #[cw_serde]
 pub struct InstantiateMsg {
     pub ibc_host: Addr,
+    pub order: Option<CwOrder>,
 }
 
 #[cw_serde]
@@ -65,4 +68,6 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(String)]
     GetAdmin {},
+    #[returns(CwOrder)]
+    GetChannelOrder {},
 }
@@ -28,6 +28,18 @@ impl<'a> CwIbcConnection<'a> {
         _relayer: Addr,
     ) -> Result<CwReceiveResponse, ContractError> {
         debug_println!("[MockDapp]: Packet Received");
+        // ordered channels must deliver packets without gaps
+        let order = self.get_channel_order(deps.storage)?;
+        if order == CwOrder::Ordered
+            && packet.sequence > 1
+            && self
+                .get_received_packet(deps.storage, packet.sequence - 1)
+                .is_err()
+        {
+            return Err(ContractError::InvalidSequenceId {
+                id: packet.sequence.into(),
+            });
+        }
         self.store_received_packet(deps.storage, packet.sequence, packet)?;
 
         Ok(CwReceiveResponse::new())
@@ -115,6 +115,7 @@ pub struct CwIbcConnection<'a> {
     ibc_config: Item<'a, IbcConfig>,
     ibc_host: Item<'a, Addr>,
     received_packets: Map<'a, u64, CwPacket>,
+    channel_order: Item<'a, CwOrder>,
 }
 
 impl<'a> Default for CwIbcConnection<'a> {
@@ -132,6 +133,7 @@ impl<'a> CwIbcConnection<'a> {
             ibc_config: Item::new(StorageKey::IbcConfig.as_str()),
             ibc_host: Item::new(StorageKey::IbcHost.as_str()),
             received_packets: Map::new(StorageKey::ReceivedPackets.as_str()),
+            channel_order: Item::new(StorageKey::ChannelOrder.as_str()),
         }
     }
 
@@ -169,6 +171,22 @@ impl<'a> CwIbcConnection<'a> {
         self.ibc_host.load(store).map_err(ContractError::Std)
     }
 
+    pub fn set_channel_order(
+        &self,
+        store: &mut dyn Storage,
+        order: &CwOrder,
+    ) -> Result<(), ContractError> {
+        self.channel_order
+            .save(store, order)
+            .map_err(ContractError::Std)
+    }
+    pub fn get_channel_order(&self, store: &dyn Storage) -> Result<CwOrder, ContractError> {
+        Ok(self
+            .channel_order
+            .may_load(store)?
+            .unwrap_or(CwOrder::Unordered))
+    }
+
     pub fn store_received_packet(
         &self,
         store: &mut dyn Storage,
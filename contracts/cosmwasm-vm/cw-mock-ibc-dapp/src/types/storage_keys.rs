@@ -8,6 +8,7 @@ pub enum StorageKey {
     IbcHost,
     Config,
     ReceivedPackets,
+    ChannelOrder,
 }
 
 impl StorageKey {
@@ -19,6 +20,7 @@ impl StorageKey {
             StorageKey::IbcConfig => "ibcconfig",
             StorageKey::IbcHost => "ibc_host",
             StorageKey::ReceivedPackets => "received_packets",
+            StorageKey::ChannelOrder => "channel_order",
         }
     }
 }
@@ -0,0 +1,16 @@
+use common::icon::tendermint::light::Fraction;
+
+pub const CLIENT_STATE_HASH: &str = "client_state_hash";
+pub const CONSENSUS_STATE_HASH: &str = "consensus_state_hash";
+pub const HEIGHT: &str = "height";
+pub const IBC_STORE_PREFIX: &str = "ibc";
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 100;
+pub const DEFAULT_TRUST_LEVEL: Fraction = Fraction {
+    numerator: 1,
+    denominator: 3,
+};
+pub const COMMIT_TRUST_LEVEL: Fraction = Fraction {
+    numerator: 2,
+    denominator: 3,
+};
@@ -0,0 +1,890 @@
+use common::constants::TENDERMINT_CLIENT_TYPE;
+use common::icon::tendermint::light::{ClientState, ConsensusState, TmHeader};
+use common::traits::AnyTypes;
+use cosmwasm_schema::cw_serde;
+use cw_common::ibc_types::IbcHeight;
+use cw_common::to_checked_address;
+
+use crate::query_handler::QueryHandler;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary as to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult,
+};
+use cw2::set_contract_version;
+use cw_common::client_response::{CreateClientResponse, UpdateClientResponse};
+use cw_common::raw_types::Any;
+use cw_common::types::VerifyChannelState;
+
+use crate::constants::{CLIENT_STATE_HASH, CONSENSUS_STATE_HASH, HEIGHT};
+use crate::error::ContractError;
+use crate::light_client::TendermintClient;
+use crate::state::CwContext;
+use crate::traits::{Config, IContext, ILightClient};
+use cw_common::client_msg::{
+    ExecuteMsg, InstantiateMsg, LightClientPacketMessage, QueryMsg, VerifyClientConsensusState,
+    VerifyClientFullState, VerifyConnectionState,
+};
+use prost::Message;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:cw-tendermint-light-client";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+        .map_err(|_e| ContractError::FailedToInitContract)?;
+    let ibc_host = to_checked_address(deps.as_ref(), msg.ibc_host.as_ref());
+    let config = Config::new(info.sender, ibc_host);
+    let mut context = CwContext::new(deps, _env);
+    context.insert_config(&config)?;
+    Ok(Response::default())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps_mut: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::CreateClient {
+            client_id,
+            client_state,
+            consensus_state,
+        } => {
+            let context = CwContext::new(deps_mut, _env);
+            let mut client = TendermintClient::new(context);
+            let client_state_any =
+                Any::decode(client_state.as_slice()).map_err(ContractError::DecodeError)?;
+            let consensus_state_any =
+                Any::decode(consensus_state.as_slice()).map_err(ContractError::DecodeError)?;
+            let client_state = ClientState::from_any(client_state_any.clone())
+                .map_err(ContractError::DecodeError)?;
+            let consensus_state = ConsensusState::from_any(consensus_state_any.clone())
+                .map_err(ContractError::DecodeError)?;
+            let update =
+                client.create_client(info.sender, &client_id, client_state, consensus_state)?;
+
+            let mut response = Response::new()
+                .add_attribute(
+                    CLIENT_STATE_HASH,
+                    hex::encode(update.client_state_commitment),
+                )
+                .add_attribute(
+                    CONSENSUS_STATE_HASH,
+                    hex::encode(update.consensus_state_commitment),
+                )
+                .add_attribute(HEIGHT, update.height.to_string());
+
+            let client_response = CreateClientResponse::new(
+                TENDERMINT_CLIENT_TYPE.to_string(),
+                to_ibc_height(update.height)?.to_string(),
+                update.client_state_commitment.to_vec(),
+                update.consensus_state_commitment.into(),
+                client_state_any.encode_to_vec(),
+                consensus_state_any.encode_to_vec(),
+            );
+
+            response.data = to_binary(&client_response).ok();
+
+            Ok(response)
+        }
+        ExecuteMsg::UpdateClient {
+            client_id,
+            signed_header,
+        } => {
+            let context = CwContext::new(deps_mut, _env);
+            let mut client = TendermintClient::new(context);
+            let header_any =
+                Any::decode(signed_header.as_slice()).map_err(ContractError::DecodeError)?;
+            let header = TmHeader::from_any(header_any).map_err(ContractError::DecodeError)?;
+            let update = client.update_client(info.sender, &client_id, header)?;
+            let response_data = to_binary(&UpdateClientResponse {
+                height: to_ibc_height(update.height).map(|h| h.to_string())?,
+                client_id,
+                client_state_commitment: update.client_state_commitment.to_vec(),
+                consensus_state_commitment: update.consensus_state_commitment.to_vec(),
+                client_state_bytes: ClientState::any_from_value(&update.client_state_bytes)
+                    .encode_to_vec(),
+                consensus_state_bytes: ConsensusState::any_from_value(
+                    &update.consensus_state_bytes,
+                )
+                .encode_to_vec(),
+            })
+            .map_err(ContractError::Std)?;
+            Ok(Response::new()
+                .add_attribute(
+                    CLIENT_STATE_HASH,
+                    hex::encode(update.client_state_commitment),
+                )
+                .add_attribute(
+                    CONSENSUS_STATE_HASH,
+                    hex::encode(update.consensus_state_commitment),
+                )
+                .add_attribute(HEIGHT, update.height.to_string())
+                .set_data(response_data))
+        }
+        ExecuteMsg::Misbehaviour { .. } => {
+            Err(ContractError::Unsupported("misbehaviour".to_string()))
+        }
+        ExecuteMsg::UpgradeClient { .. } => {
+            Err(ContractError::Unsupported("upgrade client".to_string()))
+        }
+        ExecuteMsg::UpdateClientBatch { .. } => Err(ContractError::Unsupported(
+            "update client batch".to_string(),
+        )),
+        ExecuteMsg::PruneConsensusStates { .. } => Err(ContractError::Unsupported(
+            "prune consensus states".to_string(),
+        )),
+    }
+}
+
+pub fn validate_channel_state(
+    client_id: &str,
+    deps: Deps,
+    env: &Env,
+    state: &VerifyChannelState,
+) -> Result<bool, ContractError> {
+    let proof = QueryHandler::decode_merkle_proof(&state.proof)?;
+    let height = to_height_u64(&state.proof_height)?;
+    QueryHandler::verify_membership(
+        deps,
+        env,
+        client_id,
+        height,
+        0,
+        0,
+        &proof,
+        &state.expected_counterparty_channel_end,
+        &state.counterparty_chan_end_path,
+    )
+}
+
+pub fn validate_connection_state(
+    client_id: &str,
+    deps: Deps,
+    env: &Env,
+    state: &VerifyConnectionState,
+) -> Result<bool, ContractError> {
+    let proof = QueryHandler::decode_merkle_proof(&state.proof)?;
+    let height = to_height_u64(&state.proof_height)?;
+    QueryHandler::verify_membership(
+        deps,
+        env,
+        client_id,
+        height,
+        0,
+        0,
+        &proof,
+        &state.expected_counterparty_connection_end,
+        &state.counterparty_conn_end_path,
+    )
+}
+
+pub fn validate_client_state(
+    client_id: &str,
+    deps: Deps,
+    env: &Env,
+    state: &VerifyClientFullState,
+) -> Result<bool, ContractError> {
+    let proof = QueryHandler::decode_merkle_proof(&state.client_state_proof)?;
+    let height = to_height_u64(&state.proof_height)?;
+    QueryHandler::verify_membership(
+        deps,
+        env,
+        client_id,
+        height,
+        0,
+        0,
+        &proof,
+        &state.expected_client_state,
+        &state.client_state_path,
+    )
+}
+
+pub fn validate_consensus_state(
+    client_id: &str,
+    deps: Deps,
+    env: &Env,
+    state: &VerifyClientConsensusState,
+) -> Result<bool, ContractError> {
+    let proof = QueryHandler::decode_merkle_proof(&state.consensus_state_proof)?;
+    let height = to_height_u64(&state.proof_height)?;
+    QueryHandler::verify_membership(
+        deps,
+        env,
+        client_id,
+        height,
+        0,
+        0,
+        &proof,
+        &state.expected_conesenus_state,
+        &state.conesenus_state_path,
+    )
+}
+
+pub fn validate_next_seq_recv(
+    deps: Deps,
+    env: &Env,
+    client_id: &str,
+    state: &LightClientPacketMessage,
+    delay_time_period: u64,
+    delay_block_period: u64,
+) -> Result<bool, ContractError> {
+    match state {
+        LightClientPacketMessage::VerifyNextSequenceRecv {
+            height,
+            prefix: _,
+            proof,
+            root: _,
+            seq_recv_path,
+            sequence,
+        } => {
+            let proof = QueryHandler::decode_merkle_proof(proof)?;
+            let height = to_height_u64(height)?;
+
+            QueryHandler::verify_membership(
+                deps,
+                env,
+                client_id,
+                height,
+                delay_time_period,
+                delay_block_period,
+                &proof,
+                sequence.to_be_bytes().as_ref(),
+                seq_recv_path,
+            )
+        }
+        LightClientPacketMessage::VerifyPacketReceiptAbsence {
+            height,
+            prefix: _,
+            proof,
+            root: _,
+            receipt_path,
+        } => {
+            let proof = QueryHandler::decode_merkle_proof(proof)?;
+            let height = to_height_u64(height)?;
+
+            QueryHandler::verify_non_membership(
+                deps,
+                env,
+                client_id,
+                height,
+                delay_time_period,
+                delay_block_period,
+                &proof,
+                receipt_path,
+            )
+        }
+    }
+}
+
+/// Maps a failed proof verification to `false`, except for a frozen client which must surface
+/// as an error so callers can tell a frozen client apart from an invalid proof.
+fn to_verification_result(result: Result<bool, ContractError>) -> StdResult<bool> {
+    match result {
+        Ok(valid) => Ok(valid),
+        Err(error @ ContractError::ClientStateFrozen(_)) => {
+            Err(StdError::generic_err(error.to_string()))
+        }
+        Err(_) => Ok(false),
+    }
+}
+
+fn to_height_u64(height: &str) -> Result<u64, ContractError> {
+    let heights = height.split('-').collect::<Vec<&str>>();
+    if heights.len() != 2 {
+        return Err(ContractError::InvalidHeight);
+    }
+    heights[1]
+        .parse::<u64>()
+        .map_err(|_e| ContractError::InvalidHeight)
+}
+
+fn to_ibc_height(height: u64) -> Result<IbcHeight, ContractError> {
+    IbcHeight::new(0, height).map_err(|_e| ContractError::InvalidHeight)
+}
+
+fn to_std_error(error: ContractError) -> StdError {
+    StdError::generic_err(error.to_string())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetClientState { client_id } => to_binary(
+            &QueryHandler::get_client_state_any(deps.storage, &client_id).map_err(to_std_error)?,
+        ),
+        QueryMsg::GetConsensusState { client_id, height } => to_binary(
+            &QueryHandler::get_consensus_state_any(deps.storage, &client_id, height)
+                .map_err(to_std_error)?,
+        ),
+        QueryMsg::GetLatestHeight { client_id } => to_binary(
+            &QueryHandler::get_latest_height(deps.storage, &client_id).map_err(to_std_error)?,
+        ),
+        QueryMsg::VerifyMembership {
+            client_id,
+            message_bytes,
+            proofs,
+            path,
+            height,
+            delay_time_period,
+            delay_block_period,
+        } => {
+            let proof = QueryHandler::decode_merkle_proof(&proofs).map_err(to_std_error)?;
+            let result = to_verification_result(QueryHandler::verify_membership(
+                deps,
+                &env,
+                &client_id,
+                height,
+                delay_time_period,
+                delay_block_period,
+                &proof,
+                &message_bytes,
+                &path,
+            ))?;
+            to_binary(&result)
+        }
+        QueryMsg::VerifyNonMembership {
+            client_id,
+            proofs,
+            path,
+            height,
+            delay_time_period,
+            delay_block_period,
+        } => {
+            let proof = QueryHandler::decode_merkle_proof(&proofs).map_err(to_std_error)?;
+            let result = to_verification_result(QueryHandler::verify_non_membership(
+                deps,
+                &env,
+                &client_id,
+                height,
+                delay_time_period,
+                delay_block_period,
+                &proof,
+                &path,
+            ))?;
+            to_binary(&result)
+        }
+        QueryMsg::VerifyPacketData {
+            client_id,
+            verify_packet_data,
+            delay_time_period,
+            delay_block_period,
+        } => {
+            let result = to_verification_result(
+                QueryHandler::decode_merkle_proof(&verify_packet_data.proof).and_then(|proof| {
+                    QueryHandler::verify_membership(
+                        deps,
+                        &env,
+                        &client_id,
+                        to_height_u64(&verify_packet_data.height)?,
+                        delay_time_period,
+                        delay_block_period,
+                        &proof,
+                        &verify_packet_data.commitment,
+                        &verify_packet_data.commitment_path,
+                    )
+                }),
+            )?;
+            to_binary(&result)
+        }
+        QueryMsg::VerifyPacketAcknowledgement {
+            client_id,
+            verify_packet_acknowledge,
+            delay_time_period,
+            delay_block_period,
+        } => {
+            let result = to_verification_result(
+                QueryHandler::decode_merkle_proof(&verify_packet_acknowledge.proof).and_then(
+                    |proof| {
+                        QueryHandler::verify_membership(
+                            deps,
+                            &env,
+                            &client_id,
+                            to_height_u64(&verify_packet_acknowledge.height)?,
+                            delay_time_period,
+                            delay_block_period,
+                            &proof,
+                            &verify_packet_acknowledge.ack,
+                            &verify_packet_acknowledge.ack_path,
+                        )
+                    },
+                ),
+            )?;
+            to_binary(&result)
+        }
+        QueryMsg::VerifyOpenConfirm {
+            client_id,
+            verify_connection_state,
+        } => {
+            let result = to_verification_result(validate_connection_state(
+                &client_id,
+                deps,
+                &env,
+                &verify_connection_state,
+            ))?;
+            to_binary(&result)
+        }
+        QueryMsg::VerifyConnectionOpenTry(state) | QueryMsg::VerifyConnectionOpenAck(state) => {
+            let client_valid = to_verification_result(validate_client_state(
+                &state.client_id,
+                deps,
+                &env,
+                &state.verify_client_full_state,
+            ))?;
+            let connection_valid = to_verification_result(validate_connection_state(
+                &state.client_id,
+                deps,
+                &env,
+                &state.verify_connection_state,
+            ))?;
+            to_binary(&(client_valid && connection_valid))
+        }
+        QueryMsg::VerifyChannel {
+            verify_channel_state,
+        } => {
+            let result = to_verification_result(validate_channel_state(
+                &verify_channel_state.client_id,
+                deps,
+                &env,
+                &verify_channel_state,
+            ))?;
+            to_binary(&result)
+        }
+        QueryMsg::PacketTimeout {
+            client_id,
+            next_seq_recv_verification_result,
+            delay_time_period,
+            delay_block_period,
+        } => {
+            let sequence_valid = to_verification_result(validate_next_seq_recv(
+                deps,
+                &env,
+                &client_id,
+                &next_seq_recv_verification_result,
+                delay_time_period,
+                delay_block_period,
+            ))?;
+            to_binary(&sequence_valid)
+        }
+        QueryMsg::TimeoutOnCLose {
+            client_id,
+            verify_channel_state,
+            next_seq_recv_verification_result,
+            delay_time_period,
+            delay_block_period,
+        } => {
+            let channel_valid = to_verification_result(validate_channel_state(
+                &client_id,
+                deps,
+                &env,
+                &verify_channel_state,
+            ))?;
+            let sequence_valid = to_verification_result(validate_next_seq_recv(
+                deps,
+                &env,
+                &client_id,
+                &next_seq_recv_verification_result,
+                delay_time_period,
+                delay_block_period,
+            ))?;
+            to_binary(&(channel_valid && sequence_valid))
+        }
+        QueryMsg::GetPreviousConsensusState { client_id, height } => to_binary(
+            &QueryHandler::get_previous_consensus(deps.storage, height, client_id)
+                .map_err(to_std_error)?,
+        ),
+        QueryMsg::GetTimestampAtHeight { client_id, height } => to_binary(
+            &QueryHandler::get_consensus_timestamp_at_height(deps.storage, &client_id, height)
+                .map_err(to_std_error)?,
+        ),
+        QueryMsg::GetLatestConsensusState { client_id } => to_binary(
+            &QueryHandler::get_latest_consensus_state(deps.storage, &client_id)
+                .map_err(to_std_error)?
+                .encode_to_vec(),
+        ),
+        QueryMsg::GetConsensusStateCount { client_id } => to_binary(
+            &QueryHandler::get_consensus_state_count(deps.storage, &client_id)
+                .map_err(to_std_error)?,
+        ),
+        QueryMsg::GetValidatorSet { .. } => Err(to_std_error(ContractError::Unsupported(
+            "validator set".to_string(),
+        ))),
+        QueryMsg::ListConsensusStateHeights {
+            client_id,
+            start_after,
+            limit,
+            order,
+        } => to_binary(
+            &QueryHandler::list_consensus_state_heights(
+                deps.storage,
+                &client_id,
+                start_after,
+                limit,
+                order.map(Order::from).unwrap_or(Order::Ascending),
+            )
+            .map_err(to_std_error)?,
+        ),
+        QueryMsg::ListConsensusStates {
+            client_id,
+            start_after,
+            limit,
+            order,
+        } => to_binary(
+            &QueryHandler::list_consensus_states(
+                deps.storage,
+                &client_id,
+                start_after,
+                limit,
+                order.map(Order::from).unwrap_or(Order::Ascending),
+            )
+            .map_err(to_std_error)?,
+        ),
+    }
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
+        .map_err(ContractError::Std)?;
+    Ok(Response::default().add_attribute("migrate", "successful"))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::icon::tendermint::light::{
+        public_key::Sum, BlockId, BlockIdFlag, ClientState, Commit, CommitSig, ConsensusState,
+        Duration, LightHeader, MerkleRoot, PublicKey, SignedHeader, Timestamp, TmHeader, Validator,
+        ValidatorSet,
+    };
+    use common::traits::AnyTypes;
+    use common::utils::sha256;
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        Addr, OwnedDeps,
+    };
+    use cw_common::client_msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+    use cw_common::client_response::UpdateClientResponse;
+    use ed25519_zebra::{SigningKey, VerificationKey};
+    use prost::Message;
+
+    use super::{execute, instantiate, query};
+    use crate::query_handler::QueryHandler;
+    use crate::ContractError;
+
+    const SENDER: &str = "sender";
+    const CLIENT_ID: &str = "07-tendermint-0";
+    const CHAIN_ID: &str = "cosmoshub-4";
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from([seed; 32])
+    }
+
+    fn validator(key: &SigningKey, voting_power: i64) -> Validator {
+        let public_key: [u8; 32] = VerificationKey::from(key).into();
+        Validator {
+            address: sha256(public_key)[..20].to_vec(),
+            pub_key: Some(PublicKey {
+                sum: Some(Sum::Ed25519(public_key.to_vec())),
+            }),
+            voting_power,
+            proposer_priority: 0,
+        }
+    }
+
+    fn validator_set(keys: &[SigningKey]) -> ValidatorSet {
+        let validators = keys.iter().map(|key| validator(key, 10)).collect();
+        ValidatorSet {
+            validators,
+            proposer: None,
+            total_voting_power: 10 * keys.len() as i64,
+        }
+    }
+
+    fn time(offset_secs: i64) -> Timestamp {
+        let now = mock_env().block.time;
+        Timestamp {
+            seconds: now.seconds() as i64 + offset_secs,
+            nanos: 0,
+        }
+    }
+
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            ibc_host: Addr::unchecked("ibc_host"),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(SENDER, &[]), msg).unwrap();
+        deps
+    }
+
+    fn init_client(keys: &[SigningKey]) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = setup();
+        let client_state = ClientState {
+            chain_id: CHAIN_ID.to_string(),
+            trust_level: None,
+            trusting_period: Some(Duration {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            unbonding_period: Some(Duration {
+                seconds: 7200,
+                nanos: 0,
+            }),
+            max_clock_drift: Some(Duration {
+                seconds: 10,
+                nanos: 0,
+            }),
+            frozen_height: 0,
+            latest_height: 10,
+            allow_update_after_expiry: false,
+            allow_update_after_misbehaviour: false,
+        };
+        let consensus_state = ConsensusState {
+            timestamp: Some(time(-100)),
+            root: Some(MerkleRoot { hash: vec![1; 32] }),
+            next_validators_hash: validator_set(keys).hash(),
+        };
+        let msg = ExecuteMsg::CreateClient {
+            client_id: CLIENT_ID.to_string(),
+            client_state: client_state.to_any().encode_to_vec(),
+            consensus_state: consensus_state.to_any().encode_to_vec(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg).unwrap();
+        deps
+    }
+
+    fn signed_header(
+        keys: &[SigningKey],
+        signers: &[SigningKey],
+        height: i64,
+        trusted_height: i64,
+    ) -> TmHeader {
+        let validators = validator_set(keys);
+        let header = LightHeader {
+            chain_id: CHAIN_ID.to_string(),
+            height,
+            time: Some(time(-10)),
+            validators_hash: validators.hash(),
+            next_validators_hash: validators.hash(),
+            app_hash: vec![2; 32],
+            ..LightHeader::default()
+        };
+        let mut commit = Commit {
+            height,
+            round: 0,
+            block_id: Some(BlockId {
+                hash: header.hash(),
+                part_set_header: None,
+            }),
+            signatures: signers
+                .iter()
+                .map(|key| CommitSig {
+                    block_id_flag: BlockIdFlag::BlockIdFlagCommit as i32,
+                    validator_address: validator(key, 10).address,
+                    timestamp: Some(time(-9)),
+                    signature: vec![],
+                })
+                .collect(),
+        };
+        for (index, key) in signers.iter().enumerate() {
+            let sign_bytes = commit.vote_sign_bytes(CHAIN_ID, index).unwrap();
+            let signature: [u8; 64] = key.sign(&sign_bytes).into();
+            commit.signatures[index].signature = signature.to_vec();
+        }
+        TmHeader {
+            signed_header: Some(SignedHeader {
+                header: Some(header),
+                commit: Some(commit),
+            }),
+            validator_set: Some(validators.clone()),
+            trusted_height,
+            trusted_validators: Some(validators),
+        }
+    }
+
+    fn update_client(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        header: TmHeader,
+    ) -> Result<cosmwasm_std::Response, ContractError> {
+        let msg = ExecuteMsg::UpdateClient {
+            client_id: CLIENT_ID.to_string(),
+            signed_header: header.to_any().encode_to_vec(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg)
+    }
+
+    #[test]
+    fn test_execute_create_client() {
+        let keys = vec![signing_key(1)];
+        let deps = init_client(&keys);
+        let state = QueryHandler::get_client_state(deps.as_ref().storage, CLIENT_ID).unwrap();
+        assert_eq!(state.latest_height, 10);
+        assert_eq!(
+            QueryHandler::get_consensus_state(deps.as_ref().storage, CLIENT_ID, 10)
+                .unwrap()
+                .next_validators_hash,
+            validator_set(&keys).hash()
+        );
+    }
+
+    #[test]
+    fn test_execute_create_client_unauthorized() {
+        let mut deps = setup();
+        let msg = ExecuteMsg::CreateClient {
+            client_id: CLIENT_ID.to_string(),
+            client_state: ClientState::default().to_any().encode_to_vec(),
+            consensus_state: ConsensusState::default().to_any().encode_to_vec(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info(SENDER, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    #[test]
+    fn test_execute_update_client_adjacent() {
+        let keys = vec![signing_key(1), signing_key(2), signing_key(3)];
+        let mut deps = init_client(&keys);
+        let res = update_client(&mut deps, signed_header(&keys, &keys, 11, 10)).unwrap();
+        let data: UpdateClientResponse = from_json(res.data.unwrap()).unwrap();
+        assert_eq!(data.height, "0-11");
+
+        let state = QueryHandler::get_client_state(deps.as_ref().storage, CLIENT_ID).unwrap();
+        assert_eq!(state.latest_height, 11);
+        let consensus_state =
+            QueryHandler::get_consensus_state(deps.as_ref().storage, CLIENT_ID, 11).unwrap();
+        assert_eq!(consensus_state.root.unwrap().hash, vec![2; 32]);
+    }
+
+    #[test]
+    fn test_execute_update_client_skipping() {
+        let keys = vec![signing_key(1), signing_key(2), signing_key(3)];
+        let mut deps = init_client(&keys);
+        update_client(&mut deps, signed_header(&keys, &keys[..], 20, 10)).unwrap();
+        assert_eq!(
+            QueryHandler::get_latest_height(deps.as_ref().storage, CLIENT_ID).unwrap(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_execute_update_client_insufficient_voting_power() {
+        let keys = vec![signing_key(1), signing_key(2), signing_key(3)];
+        let mut deps = init_client(&keys);
+        let err = update_client(&mut deps, signed_header(&keys, &keys[..2], 11, 10)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InSuffcientVotingPower {
+                tallied: 20,
+                total: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_execute_update_client_invalid_signature() {
+        let keys = vec![signing_key(1)];
+        let mut deps = init_client(&keys);
+        let mut header = signed_header(&keys, &keys, 11, 10);
+        let commit = header
+            .signed_header
+            .as_mut()
+            .and_then(|signed_header| signed_header.commit.as_mut())
+            .unwrap();
+        commit.signatures[0].signature[0] ^= 1;
+        let err = update_client(&mut deps, header).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCommit(_)));
+    }
+
+    #[test]
+    fn test_execute_update_client_untrusted_validators() {
+        let keys = vec![signing_key(1)];
+        let mut deps = init_client(&keys);
+        let other_keys = vec![signing_key(4)];
+        let err =
+            update_client(&mut deps, signed_header(&other_keys, &other_keys, 11, 10)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidValidatorSet(_)));
+    }
+
+    #[test]
+    fn test_execute_update_client_chain_id_mismatch() {
+        let keys = vec![signing_key(1)];
+        let mut deps = init_client(&keys);
+        let mut header = signed_header(&keys, &keys, 11, 10);
+        header
+            .signed_header
+            .as_mut()
+            .and_then(|signed_header| signed_header.header.as_mut())
+            .unwrap()
+            .chain_id = "osmosis-1".to_string();
+        let err = update_client(&mut deps, header).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidHeaderUpdate("chain id mismatch".to_string())
+        );
+    }
+
+    #[test]
+    fn test_execute_update_client_same_height() {
+        let keys = vec![signing_key(1)];
+        let mut deps = init_client(&keys);
+        update_client(&mut deps, signed_header(&keys, &keys, 11, 10)).unwrap();
+        let err = update_client(&mut deps, signed_header(&keys, &keys, 11, 10)).unwrap_err();
+        assert_eq!(err, ContractError::HeightAlreadyUpdated { height: 11 });
+    }
+
+    #[test]
+    fn test_execute_misbehaviour_unsupported() {
+        let keys = vec![signing_key(1)];
+        let mut deps = init_client(&keys);
+        let msg = ExecuteMsg::Misbehaviour {
+            client_id: CLIENT_ID.to_string(),
+            misbehaviour: vec![],
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("ibc_host", &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::Unsupported("misbehaviour".to_string()));
+    }
+
+    #[test]
+    fn test_query_timestamp_at_height() {
+        let keys = vec![signing_key(1)];
+        let deps = init_client(&keys);
+        let msg = QueryMsg::GetTimestampAtHeight {
+            client_id: CLIENT_ID.to_string(),
+            height: 10,
+        };
+        let res: u64 = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res, time(-100).to_nanos());
+    }
+
+    #[test]
+    fn test_query_verify_membership_invalid_proof() {
+        let keys = vec![signing_key(1)];
+        let deps = init_client(&keys);
+        let msg = QueryMsg::VerifyMembership {
+            client_id: CLIENT_ID.to_string(),
+            message_bytes: b"connection".to_vec(),
+            path: b"connections/connection-0".to_vec(),
+            proofs: vec![],
+            height: 10,
+            delay_time_period: 0,
+            delay_block_period: 0,
+        };
+        let res: bool = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert!(!res);
+    }
+}
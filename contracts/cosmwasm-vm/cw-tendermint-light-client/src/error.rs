@@ -0,0 +1,95 @@
+use cosmwasm_std::StdError;
+use cw_common::errors::CwErrors;
+use prost::DecodeError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+    #[error("{0}")]
+    DecodeError(#[from] DecodeError),
+    #[error("Timestamp not found for {client_id:?} at height {height:?}")]
+    TimestampNotFound { height: u64, client_id: String },
+    #[error("Client state not found for client_id:{0}")]
+    ClientStateNotFound(String),
+    #[error("Consensusstate not found for {client_id:?} at height {height:?}")]
+    ConsensusStateNotFound { height: u64, client_id: String },
+    #[error("Failed to save client state")]
+    FailedToSaveClientState,
+    #[error("Failed to save consensus state")]
+    FailedToSaveConsensusState,
+    #[error("Insufficient voting power. Tallied {tallied:?} of {total:?}")]
+    InSuffcientVotingPower { tallied: u64, total: u64 },
+    #[error("Clientstate already exists for {0}")]
+    ClientStateAlreadyExists(String),
+    #[error("Config not found or initialized")]
+    ConfigNotFound,
+    #[error("Trusting Period elapsed. Height: {update_height:?} trusted height is at {trusted_height:?}")]
+    TrustingPeriodElapsed {
+        trusted_height: u64,
+        update_height: u64,
+    },
+    #[error("Invalid header update {0}")]
+    InvalidHeaderUpdate(String),
+    #[error("Invalid commit {0}")]
+    InvalidCommit(String),
+    #[error("Invalid validator set {0}")]
+    InvalidValidatorSet(String),
+    #[error("Trust level must be within [1/3, 1]")]
+    InvalidTrustLevel,
+    #[error("Invalid merkle proof {0}")]
+    InvalidMerkleProof(String),
+    #[error("Failed to save processed time")]
+    FailedToSaveProcessedTime,
+    #[error("Processed time not found for {client_id:?} at height {height:?}")]
+    ProcessedTimeNotFound { client_id: String, height: u64 },
+    #[error("Processed height not found for {client_id:?} at height {height:?}")]
+    ProcessedHeightNotFound { client_id: String, height: u64 },
+    #[error("Too early to process by time elapsed")]
+    NotEnoughtTimeElapsed,
+    #[error("Too early to process by block elapsed")]
+    NotEnoughtBlocksElapsed,
+    #[error("Failed to init contract")]
+    FailedToInitContract,
+    #[error("Failed to save config")]
+    FailedToSaveConfig,
+    #[error("Client state frozen at {0}")]
+    ClientStateFrozen(u64),
+
+    #[error("Invalid Client Id {0}")]
+    InvalidClientId(String),
+
+    #[error("Failed To Create ClientId")]
+    FailedToCreateClientId(String),
+
+    #[error("InvalidHeight")]
+    InvalidHeight,
+
+    #[error("UpdateBlockOlderThanTrustedHeight")]
+    UpdateBlockOlderThanTrustedHeight,
+
+    #[error("Height {height:?} already updated ")]
+    HeightAlreadyUpdated { height: u64 },
+
+    #[error("Unsupported operation {0}")]
+    Unsupported(String),
+}
+
+impl From<CwErrors> for ContractError {
+    fn from(value: CwErrors) -> Self {
+        match value {
+            CwErrors::FailedToCreateClientId {
+                client_type: _,
+                counter: _,
+                validation_error,
+            } => ContractError::FailedToCreateClientId(validation_error.to_string()),
+            CwErrors::InvalidClientId(e, _err) => ContractError::InvalidClientId(e),
+            CwErrors::DecodeError { error } => ContractError::DecodeError(DecodeError::new(error)),
+            CwErrors::FailedToConvertToPacketDataResponse(e) => ContractError::Std(e),
+        }
+    }
+}
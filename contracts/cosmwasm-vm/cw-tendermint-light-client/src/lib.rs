@@ -0,0 +1,9 @@
+mod constants;
+pub mod contract;
+mod error;
+pub mod light_client;
+pub mod query_handler;
+pub mod state;
+mod traits;
+
+pub use crate::error::ContractError;
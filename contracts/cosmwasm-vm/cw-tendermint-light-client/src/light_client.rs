@@ -0,0 +1,377 @@
+use std::collections::HashSet;
+
+use crate::constants::{COMMIT_TRUST_LEVEL, DEFAULT_TRUST_LEVEL};
+use crate::state::CwContext;
+use crate::traits::{ConsensusStateUpdate, IContext, ILightClient};
+use crate::ContractError;
+use common::icon::tendermint::light::{
+    ClientState, Commit, ConsensusState, Fraction, LightHeader, TmHeader, ValidatorSet,
+};
+use common::traits::AnyTypes;
+use cosmwasm_std::Addr;
+use cw_common::cw_println;
+use prost::Message;
+
+pub struct TendermintClient<'a> {
+    context: CwContext<'a>,
+}
+
+impl<'a> TendermintClient<'a> {
+    pub fn new(context: CwContext<'a>) -> Self {
+        Self { context }
+    }
+
+    pub fn has_quorum_of(total_power: u64, tallied_power: u64, trust_level: &Fraction) -> bool {
+        tallied_power as u128 * trust_level.denominator as u128
+            > total_power as u128 * trust_level.numerator as u128
+    }
+
+    /// A trust level must lie within [1/3, 1] to guarantee at least one honest signer.
+    pub fn is_valid_trust_level(trust_level: &Fraction) -> bool {
+        trust_level.denominator != 0
+            && trust_level.numerator <= trust_level.denominator
+            && trust_level.numerator as u128 * 3 >= trust_level.denominator as u128
+    }
+
+    /// Tallies the voting power of `validators` that signed `commit` and checks it against
+    /// `trust_level`. Signatures from validators outside the set are ignored so the same check
+    /// serves both the new validator set and the trusted one when skipping heights.
+    pub fn verify_commit(
+        &self,
+        chain_id: &str,
+        validators: &ValidatorSet,
+        commit: &Commit,
+        trust_level: &Fraction,
+    ) -> Result<(), ContractError> {
+        let total = validators.total_voting_power();
+        let mut tallied = 0_u64;
+        let mut seen: HashSet<&[u8]> = HashSet::new();
+
+        for (index, signature) in commit.signatures.iter().enumerate() {
+            if !signature.is_commit() {
+                continue;
+            }
+            let validator = match validators.find_by_address(&signature.validator_address) {
+                Some(validator) => validator,
+                None => continue,
+            };
+            if !seen.insert(validator.address.as_slice()) {
+                return Err(ContractError::InvalidCommit(format!(
+                    "double vote from {}",
+                    hex::encode(&validator.address)
+                )));
+            }
+            let public_key = validator.ed25519_pub_key().ok_or_else(|| {
+                ContractError::InvalidValidatorSet("unsupported public key".to_string())
+            })?;
+            let sign_bytes = commit
+                .vote_sign_bytes(chain_id, index)
+                .ok_or_else(|| ContractError::InvalidCommit("missing signature".to_string()))?;
+            if !self
+                .context
+                .verify_ed25519(&sign_bytes, &signature.signature, public_key)
+            {
+                return Err(ContractError::InvalidCommit(format!(
+                    "invalid signature from {}",
+                    hex::encode(&validator.address)
+                )));
+            }
+
+            tallied += validator.voting_power as u64;
+            if Self::has_quorum_of(total, tallied, trust_level) {
+                return Ok(());
+            }
+        }
+
+        cw_println!(self.context, "Insuffcient voting power detected");
+        Err(ContractError::InSuffcientVotingPower { tallied, total })
+    }
+
+    /// Checks that `header` chains to the trusted consensus state: the header is committed by
+    /// its validator set, that set hashes to the header, and the trusted validators hash to the
+    /// next validators of the trusted consensus state.
+    fn verify_header(
+        &self,
+        state: &ClientState,
+        trusted_consensus_state: &ConsensusState,
+        light_header: &LightHeader,
+        header: &TmHeader,
+    ) -> Result<(), ContractError> {
+        let commit = header
+            .signed_header
+            .as_ref()
+            .and_then(|signed_header| signed_header.commit.as_ref())
+            .ok_or_else(|| ContractError::InvalidHeaderUpdate("missing commit".to_string()))?;
+        let validator_set = header
+            .validator_set
+            .as_ref()
+            .ok_or_else(|| ContractError::InvalidHeaderUpdate("missing validators".to_string()))?;
+        let trusted_validators = header.trusted_validators.as_ref().ok_or_else(|| {
+            ContractError::InvalidHeaderUpdate("missing trusted validators".to_string())
+        })?;
+
+        if commit.height != light_header.height {
+            return Err(ContractError::InvalidCommit("height mismatch".to_string()));
+        }
+        let block_hash = commit
+            .block_id
+            .as_ref()
+            .map(|block_id| block_id.hash.clone())
+            .unwrap_or_default();
+        if block_hash != light_header.hash() {
+            return Err(ContractError::InvalidCommit(
+                "commit is not for header".to_string(),
+            ));
+        }
+        if validator_set.hash() != light_header.validators_hash {
+            return Err(ContractError::InvalidValidatorSet(
+                "validators hash mismatch".to_string(),
+            ));
+        }
+        if trusted_validators.hash() != trusted_consensus_state.next_validators_hash {
+            return Err(ContractError::InvalidValidatorSet(
+                "trusted validators hash mismatch".to_string(),
+            ));
+        }
+
+        if header.trusted_height + 1 == light_header.height {
+            if light_header.validators_hash != trusted_consensus_state.next_validators_hash {
+                return Err(ContractError::InvalidValidatorSet(
+                    "adjacent header validators do not match trusted next validators".to_string(),
+                ));
+            }
+        } else {
+            let trust_level = state.trust_level.clone().unwrap_or(DEFAULT_TRUST_LEVEL);
+            self.verify_commit(&state.chain_id, trusted_validators, commit, &trust_level)?;
+        }
+
+        self.verify_commit(&state.chain_id, validator_set, commit, &COMMIT_TRUST_LEVEL)
+    }
+
+    fn validate_header_time(
+        &self,
+        state: &ClientState,
+        trusted_consensus_state: &ConsensusState,
+        light_header: &LightHeader,
+        trusted_height: u64,
+    ) -> Result<(), ContractError> {
+        let now = self.context.get_current_block_time();
+        let trusted_time = trusted_consensus_state
+            .timestamp
+            .as_ref()
+            .map(|time| time.to_nanos())
+            .unwrap_or_default();
+        let trusting_period = state
+            .trusting_period
+            .as_ref()
+            .map(|period| period.to_nanos())
+            .unwrap_or_default();
+        let max_clock_drift = state
+            .max_clock_drift
+            .as_ref()
+            .map(|drift| drift.to_nanos())
+            .unwrap_or_default();
+
+        if trusted_time.saturating_add(trusting_period) <= now {
+            return Err(ContractError::TrustingPeriodElapsed {
+                trusted_height,
+                update_height: light_header.height as u64,
+            });
+        }
+        if light_header.timestamp() <= trusted_time {
+            return Err(ContractError::InvalidHeaderUpdate(
+                "header time is not after trusted time".to_string(),
+            ));
+        }
+        if light_header.timestamp() > now.saturating_add(max_clock_drift) {
+            return Err(ContractError::InvalidHeaderUpdate(
+                "header time is in the future".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl ILightClient for TendermintClient<'_> {
+    type Error = crate::ContractError;
+
+    fn create_client(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        client_state: ClientState,
+        consensus_state: ConsensusState,
+    ) -> Result<ConsensusStateUpdate, Self::Error> {
+        self.context.ensure_ibc_host(caller)?;
+        let exists = self.context.get_client_state(client_id).is_ok();
+        if exists {
+            return Err(ContractError::ClientStateAlreadyExists(
+                client_id.to_string(),
+            ));
+        }
+        if client_state.latest_height <= 0 {
+            return Err(ContractError::InvalidHeight);
+        }
+        if let Some(trust_level) = client_state.trust_level.as_ref() {
+            if !Self::is_valid_trust_level(trust_level) {
+                return Err(ContractError::InvalidTrustLevel);
+            }
+        }
+        let height = client_state.latest_height as u64;
+        self.context
+            .insert_client_state(client_id, client_state.clone())?;
+        self.context
+            .insert_consensus_state(client_id, height, consensus_state.clone())?;
+        self.context.insert_timestamp_at_height(client_id, height)?;
+        self.context
+            .insert_blocknumber_at_height(client_id, height)?;
+        cw_println!(
+            self.context,
+            "[CreateClient]: create client called with id {}",
+            client_id
+        );
+
+        Ok(ConsensusStateUpdate {
+            consensus_state_commitment: consensus_state.get_keccak_hash(),
+            client_state_commitment: client_state.get_keccak_hash(),
+            client_state_bytes: client_state.encode_to_vec(),
+            consensus_state_bytes: consensus_state.encode_to_vec(),
+            height,
+        })
+    }
+
+    fn update_client(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        header: TmHeader,
+    ) -> Result<ConsensusStateUpdate, Self::Error> {
+        self.context.ensure_ibc_host(caller)?;
+        let light_header = header
+            .light_header()
+            .cloned()
+            .ok_or_else(|| ContractError::InvalidHeaderUpdate("missing header".to_string()))?;
+        let height = header.height();
+        if self.context.get_consensus_state(client_id, height).is_ok() {
+            return Err(ContractError::HeightAlreadyUpdated { height });
+        }
+
+        let mut state = self.context.get_client_state(client_id)?;
+
+        if state.frozen_height != 0 {
+            return Err(ContractError::ClientStateFrozen(state.frozen_height as u64));
+        }
+
+        if light_header.chain_id != state.chain_id {
+            return Err(ContractError::InvalidHeaderUpdate(
+                "chain id mismatch".to_string(),
+            ));
+        }
+
+        if header.trusted_height >= light_header.height {
+            return Err(ContractError::UpdateBlockOlderThanTrustedHeight);
+        }
+
+        let trusted_height = header.trusted_height as u64;
+        let trusted_consensus_state = self
+            .context
+            .get_consensus_state(client_id, trusted_height)?;
+
+        self.validate_header_time(
+            &state,
+            &trusted_consensus_state,
+            &light_header,
+            trusted_height,
+        )?;
+        self.verify_header(&state, &trusted_consensus_state, &light_header, &header)?;
+
+        if state.latest_height < light_header.height {
+            state.latest_height = light_header.height;
+        }
+
+        let consensus_state = header
+            .to_consensus_state()
+            .ok_or_else(|| ContractError::InvalidHeaderUpdate("missing header".to_string()))?;
+        self.context.insert_client_state(client_id, state.clone())?;
+        self.context
+            .insert_consensus_state(client_id, height, consensus_state.clone())?;
+        self.context.insert_timestamp_at_height(client_id, height)?;
+        self.context
+            .insert_blocknumber_at_height(client_id, height)?;
+
+        Ok(ConsensusStateUpdate {
+            consensus_state_commitment: consensus_state.get_keccak_hash(),
+            client_state_commitment: state.get_keccak_hash(),
+            client_state_bytes: state.encode_to_vec(),
+            consensus_state_bytes: consensus_state.encode_to_vec(),
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn test_has_quorum_of() {
+        assert!(!TendermintClient::has_quorum_of(
+            90,
+            60,
+            &COMMIT_TRUST_LEVEL
+        ));
+        assert!(TendermintClient::has_quorum_of(90, 61, &COMMIT_TRUST_LEVEL));
+        assert!(TendermintClient::has_quorum_of(
+            90,
+            31,
+            &DEFAULT_TRUST_LEVEL
+        ));
+        assert!(!TendermintClient::has_quorum_of(0, 0, &DEFAULT_TRUST_LEVEL));
+    }
+
+    #[test]
+    fn test_is_valid_trust_level() {
+        assert!(TendermintClient::is_valid_trust_level(&DEFAULT_TRUST_LEVEL));
+        assert!(TendermintClient::is_valid_trust_level(&COMMIT_TRUST_LEVEL));
+        assert!(!TendermintClient::is_valid_trust_level(&Fraction {
+            numerator: 1,
+            denominator: 4
+        }));
+        assert!(!TendermintClient::is_valid_trust_level(&Fraction {
+            numerator: 4,
+            denominator: 3
+        }));
+        assert!(!TendermintClient::is_valid_trust_level(&Fraction {
+            numerator: 0,
+            denominator: 0
+        }));
+        assert!(TendermintClient::is_valid_trust_level(&Fraction {
+            numerator: u64::MAX,
+            denominator: u64::MAX
+        }));
+    }
+
+    #[test]
+    fn test_verify_commit_with_empty_validators() {
+        let mut deps = mock_dependencies();
+        let ctx = CwContext::new(deps.as_mut(), mock_env());
+        let client = TendermintClient::new(ctx);
+
+        let err = client
+            .verify_commit(
+                "cosmos",
+                &ValidatorSet::default(),
+                &Commit::default(),
+                &COMMIT_TRUST_LEVEL,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InSuffcientVotingPower {
+                tallied: 0,
+                total: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,569 @@
+use std::marker::PhantomData;
+
+use crate::{
+    constants::{DEFAULT_QUERY_LIMIT, IBC_STORE_PREFIX, MAX_QUERY_LIMIT},
+    state::{CLIENT_STATES, CONFIG, CONSENSUS_STATES, PROCESSED_HEIGHTS, PROCESSED_TIMES},
+    traits::Config,
+    ContractError,
+};
+use common::{
+    ibc::core::ics23_commitment::{merkle::MerkleProof, specs::ProofSpecs},
+    icon::tendermint::light::{ClientState, ConsensusState},
+    traits::AnyTypes,
+};
+use cosmwasm_std::{Deps, Env, Order, StdResult, Storage};
+use cw_common::{client_response::ConsensusStateInfo, cw_println, hex_string::HexString};
+use cw_storage_plus::Bound;
+use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof, MerkleRoot};
+
+use prost::Message;
+
+pub struct QueryHandler {}
+
+impl QueryHandler {
+    pub fn get_consensus_state(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<ConsensusState, ContractError> {
+        let data = CONSENSUS_STATES
+            .load(storage, (client_id.to_string(), height))
+            .map_err(|_e| ContractError::ConsensusStateNotFound {
+                height,
+                client_id: client_id.to_string(),
+            })?;
+        let state = ConsensusState::decode(data.as_slice()).map_err(ContractError::DecodeError)?;
+        Ok(state)
+    }
+
+    pub fn get_latest_consensus_state(
+        storage: &dyn Storage,
+        client_id: &str,
+    ) -> Result<ConsensusState, ContractError> {
+        let client_state = Self::get_client_state(storage, client_id)?;
+        QueryHandler::get_consensus_state(storage, client_id, client_state.latest_height as u64)
+    }
+
+    pub fn get_timestamp_at_height(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, ContractError> {
+        PROCESSED_TIMES
+            .load(storage, (client_id.to_string(), height))
+            .map_err(|_e| ContractError::TimestampNotFound {
+                height,
+                client_id: client_id.to_string(),
+            })
+    }
+
+    /// Returns the block time of the counterparty chain committed in the consensus state at
+    /// `height`, in nanoseconds.
+    pub fn get_consensus_timestamp_at_height(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, ContractError> {
+        let state = Self::get_consensus_state(storage, client_id, height)?;
+        Ok(state
+            .timestamp
+            .map(|time| time.to_nanos())
+            .unwrap_or_default())
+    }
+
+    pub fn get_client_state(
+        storage: &dyn Storage,
+        client_id: &str,
+    ) -> Result<ClientState, ContractError> {
+        let data = CLIENT_STATES
+            .load(storage, client_id.to_string())
+            .map_err(|_e| ContractError::ClientStateNotFound(client_id.to_string()))?;
+        let state = ClientState::decode(data.as_slice()).map_err(ContractError::DecodeError)?;
+        Ok(state)
+    }
+
+    pub fn get_config(storage: &dyn Storage) -> Result<Config, ContractError> {
+        CONFIG
+            .load(storage)
+            .map_err(|_e| ContractError::ConfigNotFound)
+    }
+
+    pub fn get_client_state_any(
+        storage: &dyn Storage,
+        client_id: &str,
+    ) -> Result<Vec<u8>, ContractError> {
+        let state = Self::get_client_state(storage, client_id)?;
+        let any_state = state.to_any();
+        Ok(any_state.encode_to_vec())
+    }
+
+    pub fn get_consensus_state_any(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<Vec<u8>, ContractError> {
+        let state = Self::get_consensus_state(storage, client_id, height)?;
+        let any_state = state.to_any();
+        Ok(any_state.encode_to_vec())
+    }
+
+    pub fn get_latest_height(storage: &dyn Storage, client_id: &str) -> Result<u64, ContractError> {
+        let state = Self::get_client_state(storage, client_id)?;
+
+        Ok(state.latest_height as u64)
+    }
+
+    pub fn get_processed_time_at_height(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, ContractError> {
+        PROCESSED_TIMES
+            .load(storage, (client_id.to_string(), height))
+            .map_err(|_e| ContractError::ProcessedTimeNotFound {
+                client_id: client_id.to_string(),
+                height,
+            })
+    }
+    pub fn get_processed_blocknumber_at_height(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, ContractError> {
+        PROCESSED_HEIGHTS
+            .load(storage, (client_id.to_string(), height))
+            .map_err(|_e| ContractError::ProcessedHeightNotFound {
+                client_id: client_id.to_string(),
+                height,
+            })
+    }
+
+    /// Decodes an `ibc.core.commitment.v1.MerkleProof` as produced by a Cosmos SDK chain.
+    pub fn decode_merkle_proof(proof: &[u8]) -> Result<MerkleProof, ContractError> {
+        let raw = RawMerkleProof::decode(proof).map_err(ContractError::DecodeError)?;
+        Ok(MerkleProof::from(raw))
+    }
+
+    /// The ICS-24 path prefixed with the IBC store key of the counterparty chain.
+    fn to_merkle_path(path: &[u8]) -> Result<MerklePath, ContractError> {
+        let path = String::from_utf8(path.to_vec())
+            .map_err(|e| ContractError::InvalidMerkleProof(e.to_string()))?;
+        Ok(MerklePath {
+            key_path: vec![IBC_STORE_PREFIX.to_string(), path],
+        })
+    }
+
+    fn get_verified_root(
+        storage: &dyn Storage,
+        client_id: &str,
+        height: u64,
+    ) -> Result<MerkleRoot, ContractError> {
+        let state = Self::get_client_state(storage, client_id)?;
+
+        if state.frozen_height != 0 {
+            return Err(ContractError::ClientStateFrozen(state.frozen_height as u64));
+        }
+
+        let consensus_state = Self::get_consensus_state(storage, client_id, height)?;
+        Ok(MerkleRoot {
+            hash: consensus_state
+                .root
+                .map(|root| root.hash)
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Checks that `delay_time_period` nanoseconds and `delay_block_period` blocks have passed
+    /// since the consensus state at `height` was processed by this client. A zero delay skips
+    /// the lookup so proofs of handshake messages do not depend on the processed records.
+    pub fn validate_delay_args(
+        storage: &dyn Storage,
+        env: &Env,
+        client_id: &str,
+        height: u64,
+        delay_time_period: u64,
+        delay_block_period: u64,
+    ) -> Result<(), ContractError> {
+        if delay_time_period > 0 {
+            let processed_time = Self::get_processed_time_at_height(storage, client_id, height)?;
+            if env.block.time.nanos() < processed_time.saturating_add(delay_time_period) {
+                return Err(ContractError::NotEnoughtTimeElapsed);
+            }
+        }
+        if delay_block_period > 0 {
+            let processed_height =
+                Self::get_processed_blocknumber_at_height(storage, client_id, height)?;
+            if env.block.height < processed_height.saturating_add(delay_block_period) {
+                return Err(ContractError::NotEnoughtBlocksElapsed);
+            }
+        }
+        Ok(())
+    }
+
+    /**
+     * @dev verifyMembership is a generic proof verification method which verifies a proof of the existence of a value at a given CommitmentPath at the specified height.
+     * The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24).
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_membership(
+        deps: Deps,
+        env: &Env,
+        client_id: &str,
+        height: u64,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        proof: &MerkleProof,
+        value: &[u8],
+        path: &[u8],
+    ) -> Result<bool, ContractError> {
+        cw_println!(
+            deps,
+            "[TendermintClient]: Path Bytes  {:?}",
+            HexString::from_bytes(path)
+        );
+        let root = Self::get_verified_root(deps.storage, client_id, height)?;
+        Self::validate_delay_args(
+            deps.storage,
+            env,
+            client_id,
+            height,
+            delay_time_period,
+            delay_block_period,
+        )?;
+        proof
+            .verify_membership(
+                &ProofSpecs::cosmos(),
+                root,
+                Self::to_merkle_path(path)?,
+                value.to_vec(),
+                0,
+            )
+            .map_err(|e| ContractError::InvalidMerkleProof(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    /**
+     * @dev verifyNonMembership is a generic proof verification method which verifies the absence of a given CommitmentPath at a specified height.
+     * The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24).
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_non_membership(
+        deps: Deps,
+        env: &Env,
+        client_id: &str,
+        height: u64,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        proof: &MerkleProof,
+        path: &[u8],
+    ) -> Result<bool, ContractError> {
+        cw_println!(
+            deps,
+            "[TendermintClient]: Path Bytes  {:?}",
+            HexString::from_bytes(path)
+        );
+        let root = Self::get_verified_root(deps.storage, client_id, height)?;
+        Self::validate_delay_args(
+            deps.storage,
+            env,
+            client_id,
+            height,
+            delay_time_period,
+            delay_block_period,
+        )?;
+        proof
+            .verify_non_membership(&ProofSpecs::cosmos(), root, Self::to_merkle_path(path)?)
+            .map_err(|e| ContractError::InvalidMerkleProof(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    pub fn get_previous_consensus(
+        storage: &dyn Storage,
+        height: u64,
+        client_id: String,
+    ) -> Result<Vec<u64>, ContractError> {
+        let key = (client_id, height);
+        let bound = Bound::Exclusive::<(String, u64)>((key, PhantomData));
+
+        let result = CONSENSUS_STATES
+            .range(storage, None, Some(bound), Order::Descending)
+            .take(1)
+            .collect::<StdResult<Vec<((String, u64), Vec<u8>)>>>()
+            .map_err(ContractError::Std)?;
+
+        let keys = result.into_iter().map(|t| t.0 .1).collect::<Vec<u64>>();
+        Ok(keys)
+    }
+
+    /// Lists the stored consensus state heights of `client_id` in the given order, starting after
+    /// `start_after` when provided.
+    pub fn list_consensus_state_heights(
+        storage: &dyn Storage,
+        client_id: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Order,
+    ) -> Result<Vec<u64>, ContractError> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        let (min, max) = match order {
+            Order::Ascending => (start, None),
+            Order::Descending => (None, start),
+        };
+        CONSENSUS_STATES
+            .prefix(client_id.to_string())
+            .keys(storage, min, max, order)
+            .take(limit)
+            .collect::<StdResult<Vec<u64>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Same as `list_consensus_state_heights` but returns the consensus state of each height
+    /// along with its block time and the time and block height at which it was stored.
+    pub fn list_consensus_states(
+        storage: &dyn Storage,
+        client_id: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order: Order,
+    ) -> Result<Vec<ConsensusStateInfo>, ContractError> {
+        Self::list_consensus_state_heights(storage, client_id, start_after, limit, order)?
+            .into_iter()
+            .map(|height| {
+                let consensus_state = Self::get_consensus_state(storage, client_id, height)?;
+                Ok(ConsensusStateInfo {
+                    height,
+                    timestamp: consensus_state
+                        .timestamp
+                        .as_ref()
+                        .map(|time| time.to_nanos()),
+                    processed_time: Self::get_processed_time_at_height(storage, client_id, height)
+                        .ok(),
+                    processed_height: Self::get_processed_blocknumber_at_height(
+                        storage, client_id, height,
+                    )
+                    .ok(),
+                    consensus_state: consensus_state.to_any().encode_to_vec(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the number of consensus states currently stored for `client_id`.
+    pub fn get_consensus_state_count(
+        storage: &dyn Storage,
+        client_id: &str,
+    ) -> Result<u64, ContractError> {
+        let count = CONSENSUS_STATES
+            .prefix(client_id.to_string())
+            .keys(storage, None, None, Order::Ascending)
+            .count();
+        Ok(count as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::icon::tendermint::light::{ClientState, ConsensusState, MerkleRoot, Timestamp};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockStorage};
+    use cosmwasm_std::Order;
+    use prost::Message;
+
+    use crate::state::{CLIENT_STATES, CONSENSUS_STATES, PROCESSED_HEIGHTS, PROCESSED_TIMES};
+    use crate::ContractError;
+
+    use super::QueryHandler;
+
+    fn save_states(storage: &mut MockStorage, frozen_height: i64) {
+        let client_state = ClientState {
+            latest_height: 100,
+            frozen_height,
+            ..ClientState::default()
+        };
+        let consensus_state = ConsensusState {
+            timestamp: Some(Timestamp {
+                seconds: 10,
+                nanos: 1,
+            }),
+            root: Some(MerkleRoot {
+                hash: vec![1, 2, 3],
+            }),
+            next_validators_hash: vec![],
+        };
+        CLIENT_STATES
+            .save(storage, "test".to_string(), &client_state.encode_to_vec())
+            .unwrap();
+        CONSENSUS_STATES
+            .save(
+                storage,
+                ("test".to_string(), 100),
+                &consensus_state.encode_to_vec(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_previous_consensus() {
+        let mut store = MockStorage::new();
+        CONSENSUS_STATES
+            .save(&mut store, ("test".to_string(), 100), &vec![1, 2, 4, 5])
+            .unwrap();
+        CONSENSUS_STATES
+            .save(&mut store, ("test".to_string(), 80), &vec![1, 2, 4, 5])
+            .unwrap();
+
+        let result = QueryHandler::get_previous_consensus(&store, 110, "test".to_string()).unwrap();
+        assert_eq!(result, vec![100]);
+    }
+
+    #[test]
+    fn test_consensus_timestamp_at_height() {
+        let mut store = MockStorage::new();
+        save_states(&mut store, 0);
+        let result = QueryHandler::get_consensus_timestamp_at_height(&store, "test", 100).unwrap();
+        assert_eq!(result, 10_000_000_001);
+        assert_eq!(
+            QueryHandler::get_latest_height(&store, "test").unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn test_verify_membership_empty_proof() {
+        let mut deps = mock_dependencies();
+        save_states(&mut deps.storage, 0);
+        let proof = QueryHandler::decode_merkle_proof(&[]).unwrap();
+        let result = QueryHandler::verify_membership(
+            deps.as_ref(),
+            &mock_env(),
+            "test",
+            100,
+            0,
+            0,
+            &proof,
+            b"value",
+            b"connections/connection-0",
+        );
+        assert!(matches!(result, Err(ContractError::InvalidMerkleProof(_))));
+    }
+
+    #[test]
+    fn test_verify_membership_on_frozen_client() {
+        let mut deps = mock_dependencies();
+        save_states(&mut deps.storage, 50);
+        let proof = QueryHandler::decode_merkle_proof(&[]).unwrap();
+        let result = QueryHandler::verify_non_membership(
+            deps.as_ref(),
+            &mock_env(),
+            "test",
+            100,
+            0,
+            0,
+            &proof,
+            b"receipts/ports/transfer/channels/channel-0/sequences/1",
+        );
+        assert_eq!(result, Err(ContractError::ClientStateFrozen(50)));
+    }
+
+    #[test]
+    fn test_verify_membership_before_delay_period() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        save_states(&mut deps.storage, 0);
+        PROCESSED_TIMES
+            .save(
+                &mut deps.storage,
+                ("test".to_string(), 100),
+                &env.block.time.nanos(),
+            )
+            .unwrap();
+        PROCESSED_HEIGHTS
+            .save(
+                &mut deps.storage,
+                ("test".to_string(), 100),
+                &(env.block.height - 1),
+            )
+            .unwrap();
+        let proof = QueryHandler::decode_merkle_proof(&[]).unwrap();
+
+        let result = QueryHandler::verify_membership(
+            deps.as_ref(),
+            &env,
+            "test",
+            100,
+            1,
+            0,
+            &proof,
+            b"value",
+            b"commitments/ports/transfer/channels/channel-0/sequences/1",
+        );
+        assert_eq!(result, Err(ContractError::NotEnoughtTimeElapsed));
+
+        let result = QueryHandler::verify_non_membership(
+            deps.as_ref(),
+            &env,
+            "test",
+            100,
+            0,
+            2,
+            &proof,
+            b"receipts/ports/transfer/channels/channel-0/sequences/1",
+        );
+        assert_eq!(result, Err(ContractError::NotEnoughtBlocksElapsed));
+
+        // once the delay has passed the proof itself is checked
+        let result = QueryHandler::verify_non_membership(
+            deps.as_ref(),
+            &env,
+            "test",
+            100,
+            0,
+            1,
+            &proof,
+            b"receipts/ports/transfer/channels/channel-0/sequences/1",
+        );
+        assert!(matches!(result, Err(ContractError::InvalidMerkleProof(_))));
+    }
+
+    #[test]
+    fn test_list_consensus_states() {
+        let mut store = MockStorage::new();
+        save_states(&mut store, 0);
+        CONSENSUS_STATES
+            .save(
+                &mut store,
+                ("test".to_string(), 90),
+                &ConsensusState::default().encode_to_vec(),
+            )
+            .unwrap();
+        PROCESSED_TIMES
+            .save(&mut store, ("test".to_string(), 100), &500)
+            .unwrap();
+
+        let heights = QueryHandler::list_consensus_state_heights(
+            &store,
+            "test",
+            None,
+            None,
+            Order::Descending,
+        )
+        .unwrap();
+        assert_eq!(heights, vec![100, 90]);
+
+        let result = QueryHandler::list_consensus_states(
+            &store,
+            "test",
+            Some(90),
+            Some(5),
+            Order::Ascending,
+        )
+        .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].height, 100);
+        assert_eq!(result[0].timestamp, Some(10_000_000_001));
+        assert_eq!(result[0].processed_time, Some(500));
+        assert_eq!(result[0].processed_height, None);
+    }
+}
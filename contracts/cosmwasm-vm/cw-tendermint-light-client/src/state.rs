@@ -0,0 +1,224 @@
+use common::icon::tendermint::light::{ClientState, ConsensusState};
+
+use cosmwasm_std::Api;
+use cosmwasm_std::DepsMut;
+use cosmwasm_std::Env;
+use cosmwasm_std::Storage;
+
+use cw_storage_plus::{Item, Map};
+use debug_print::debug_eprintln;
+
+use prost::Message;
+
+use crate::query_handler::QueryHandler;
+use crate::traits::Config;
+use crate::traits::IContext;
+use crate::ContractError;
+type ClientId = String;
+pub const CLIENT_STATES: Map<String, Vec<u8>> = Map::new("CLIENT_STATES");
+pub const CONSENSUS_STATES: Map<(ClientId, u64), Vec<u8>> = Map::new("CONSENSUS_STATES");
+pub const PROCESSED_TIMES: Map<(ClientId, u64), u64> = Map::new("PROCESSED_TIMES");
+pub const PROCESSED_HEIGHTS: Map<(ClientId, u64), u64> = Map::new("PROCESSED_HEIGHTS");
+
+pub const CONFIG: Item<Config> = Item::new("CONFIG");
+
+pub struct CwContext<'a> {
+    pub storage: &'a mut dyn Storage,
+    pub api: &'a dyn Api,
+    pub env: Env,
+}
+
+impl<'a> CwContext<'a> {
+    pub fn new(deps_mut: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            storage: deps_mut.storage,
+            api: deps_mut.api,
+            env,
+        }
+    }
+}
+
+impl<'a> IContext for CwContext<'a> {
+    type Error = ContractError;
+    fn get_client_state(&self, client_id: &str) -> Result<ClientState, Self::Error> {
+        QueryHandler::get_client_state(self.storage, client_id)
+    }
+
+    fn insert_client_state(
+        &mut self,
+        client_id: &str,
+        state: ClientState,
+    ) -> Result<(), Self::Error> {
+        let data = state.encode_to_vec();
+        CLIENT_STATES
+            .save(self.storage, client_id.to_string(), &data)
+            .map_err(|_e| ContractError::FailedToSaveClientState)
+    }
+
+    fn get_consensus_state(
+        &self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<ConsensusState, Self::Error> {
+        QueryHandler::get_consensus_state(self.storage, client_id, height)
+    }
+
+    fn insert_consensus_state(
+        &mut self,
+        client_id: &str,
+        height: u64,
+        state: ConsensusState,
+    ) -> Result<(), Self::Error> {
+        let data = state.encode_to_vec();
+        CONSENSUS_STATES
+            .save(self.storage, (client_id.to_string(), height), &data)
+            .map_err(|_e| ContractError::FailedToSaveConsensusState)
+    }
+
+    fn get_timestamp_at_height(&self, client_id: &str, height: u64) -> Result<u64, Self::Error> {
+        QueryHandler::get_timestamp_at_height(self.storage, client_id, height)
+    }
+
+    fn verify_ed25519(&self, msg: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+        self.api
+            .ed25519_verify(msg, signature, public_key)
+            .unwrap_or(false)
+    }
+
+    fn get_config(&self) -> Result<Config, Self::Error> {
+        QueryHandler::get_config(self.storage)
+    }
+
+    fn insert_config(&mut self, config: &Config) -> Result<(), Self::Error> {
+        CONFIG
+            .save(self.storage, config)
+            .map_err(|_e| ContractError::FailedToSaveConfig)
+    }
+
+    fn insert_timestamp_at_height(
+        &mut self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<(), Self::Error> {
+        let time = self.env.block.time.nanos();
+        PROCESSED_TIMES
+            .save(self.storage, (client_id.to_string(), height), &time)
+            .map_err(|_e| ContractError::FailedToSaveProcessedTime)
+    }
+
+    fn insert_blocknumber_at_height(
+        &mut self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<(), Self::Error> {
+        let block_height = self.env.block.height;
+        PROCESSED_HEIGHTS
+            .save(self.storage, (client_id.to_string(), height), &block_height)
+            .map_err(|_e| ContractError::FailedToSaveProcessedTime)
+    }
+
+    fn get_current_block_time(&self) -> u64 {
+        self.env.block.time.nanos()
+    }
+
+    fn get_current_block_height(&self) -> u64 {
+        self.env.block.height
+    }
+
+    fn get_processed_time_at_height(
+        &self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, Self::Error> {
+        QueryHandler::get_processed_time_at_height(self.storage, client_id, height)
+    }
+
+    fn get_processed_block_at_height(
+        &self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, Self::Error> {
+        QueryHandler::get_processed_blocknumber_at_height(self.storage, client_id, height)
+    }
+
+    fn ensure_ibc_host(&self, caller: cosmwasm_std::Addr) -> Result<(), Self::Error> {
+        let config = self.get_config()?;
+        if caller != config.ibc_host {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+    fn ensure_owner(&self, caller: cosmwasm_std::Addr) -> Result<(), Self::Error> {
+        let config = self.get_config()?;
+        debug_eprintln!("owner {:?} caller {}", config.owner, caller.to_string());
+        if caller != config.owner {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockStorage};
+    use ed25519_zebra::{SigningKey, VerificationKey};
+
+    #[test]
+    fn test_cwcontext_get_client_state() {
+        let mut deps = mock_dependencies();
+
+        let client_id = "07-tendermint-0";
+        let client_state = ClientState::default();
+        CwContext::new(deps.as_mut(), mock_env())
+            .insert_client_state(client_id, client_state.clone())
+            .unwrap();
+
+        let context = CwContext::new(deps.as_mut(), mock_env());
+        let result = context.get_client_state(client_id).unwrap();
+        assert_eq!(client_state, result);
+    }
+
+    #[test]
+    fn test_cwcontext_get_consensus_state() {
+        let mut deps = mock_dependencies();
+
+        let client_id = "07-tendermint-0";
+        let height = 1;
+        let consensus_state = ConsensusState::default();
+        CwContext::new(deps.as_mut(), mock_env())
+            .insert_consensus_state(client_id, height, consensus_state.clone())
+            .unwrap();
+
+        let context = CwContext::new(deps.as_mut(), mock_env());
+        let result = context.get_consensus_state(client_id, height).unwrap();
+        assert_eq!(consensus_state, result);
+    }
+
+    #[test]
+    fn test_get_consensus_state_not_found() {
+        let storage = MockStorage::new();
+        let error = QueryHandler::get_consensus_state(&storage, "07-tendermint-0", 10).unwrap_err();
+        assert_eq!(
+            error,
+            ContractError::ConsensusStateNotFound {
+                client_id: "07-tendermint-0".to_string(),
+                height: 10
+            }
+        );
+    }
+
+    #[test]
+    fn test_cwcontext_verify_ed25519_invalid_signature() {
+        let mut deps = mock_dependencies();
+        let context = CwContext::new(deps.as_mut(), mock_env());
+        let key = SigningKey::from([7u8; 32]);
+        let public_key: [u8; 32] = VerificationKey::from(&key).into();
+        let signature: [u8; 64] = key.sign(b"message").into();
+
+        assert!(context.verify_ed25519(b"message", &signature, &public_key));
+        assert!(!context.verify_ed25519(b"other message", &signature, &public_key));
+        assert!(!context.verify_ed25519(b"message", &signature[..3], &public_key));
+    }
+}
@@ -0,0 +1,122 @@
+use common::icon::tendermint::light::{ClientState, ConsensusState, TmHeader};
+use cosmwasm_std::Addr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsensusStateUpdate {
+    // commitment for updated consensusState
+    pub consensus_state_commitment: [u8; 32],
+    pub client_state_commitment: [u8; 32],
+    pub consensus_state_bytes: Vec<u8>,
+    pub client_state_bytes: Vec<u8>,
+    // updated height
+    pub height: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Config {
+    pub owner: Addr,
+    pub ibc_host: Addr,
+}
+
+impl Config {
+    pub fn new(owner: Addr, ibc_host: Addr) -> Self {
+        Self { owner, ibc_host }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            owner: Addr::unchecked("test"),
+            ibc_host: Addr::unchecked("ibc_host"),
+        }
+    }
+}
+
+pub trait ILightClient {
+    type Error;
+    /**
+     * @dev createClient creates a new client with the given state.
+     * If succeeded, it returns a commitment for the initial state.
+     */
+    fn create_client(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        client_state: ClientState,
+        consensus_state: ConsensusState,
+    ) -> Result<ConsensusStateUpdate, Self::Error>;
+
+    /**
+     * @dev updateClient verifies the commit of `header` against the validators trusted at
+     * `header.trusted_height`, either directly for adjacent heights or by trust level skipping.
+     * If succeeded, it returns a commitment for the updated state.
+     */
+    fn update_client(
+        &mut self,
+        caller: Addr,
+        client_id: &str,
+        header: TmHeader,
+    ) -> Result<ConsensusStateUpdate, Self::Error>;
+}
+
+pub trait IContext {
+    type Error;
+
+    fn get_client_state(&self, client_id: &str) -> Result<ClientState, Self::Error>;
+
+    fn insert_client_state(
+        &mut self,
+        client_id: &str,
+        state: ClientState,
+    ) -> Result<(), Self::Error>;
+
+    fn get_consensus_state(
+        &self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<ConsensusState, Self::Error>;
+    fn insert_consensus_state(
+        &mut self,
+        client_id: &str,
+        height: u64,
+        state: ConsensusState,
+    ) -> Result<(), Self::Error>;
+
+    fn get_timestamp_at_height(&self, client_id: &str, height: u64) -> Result<u64, Self::Error>;
+    fn insert_timestamp_at_height(
+        &mut self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<(), Self::Error>;
+    fn insert_blocknumber_at_height(
+        &mut self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<(), Self::Error>;
+
+    fn verify_ed25519(&self, msg: &[u8], signature: &[u8], public_key: &[u8]) -> bool;
+
+    fn get_config(&self) -> Result<Config, Self::Error>;
+
+    fn insert_config(&mut self, config: &Config) -> Result<(), Self::Error>;
+
+    fn get_current_block_time(&self) -> u64;
+    fn get_current_block_height(&self) -> u64;
+    fn get_processed_time_at_height(
+        &self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, Self::Error>;
+    fn get_processed_block_at_height(
+        &self,
+        client_id: &str,
+        height: u64,
+    ) -> Result<u64, Self::Error>;
+
+    fn ensure_owner(&self, caller: Addr) -> Result<(), Self::Error>;
+    fn ensure_ibc_host(&self, caller: Addr) -> Result<(), Self::Error>;
+}
@@ -1,17 +1,16 @@
 use cosmwasm_std::{ensure, ensure_eq, Addr, MessageInfo, Storage};
+use cw_xcall_lib::network_address::NetId;
 
-use crate::{
-    error::ContractError,
-    state::{CwIbcConnection, MAX_DATA_SIZE, MAX_ROLLBACK_SIZE},
-    types::LOG_PREFIX,
-};
+use crate::{error::ContractError, state::CwIbcConnection, types::LOG_PREFIX};
 
 impl<'a> CwIbcConnection<'a> {
-    /// This function ensures that the length of the data is not greater than the maximum allowed size and
-    /// returns an error if it is.
+    /// This function ensures that the length of the data is not greater than the maximum size allowed
+    /// for the network and returns an error if it is.
     ///
     /// Arguments:
     ///
+    /// * `store`: `store` is a reference to the storage the limits of the network are loaded from.
+    /// * `nid`: `nid` is the network the data is sent to.
     /// * `data_len`: `data_len` is a variable of type `usize` that represents the length of some data. It
     /// is used as a parameter in the `ensure_data_length` function to check if the length of the data is
     /// within the maximum allowed size. If the length of the data exceeds the maximum size,
@@ -20,31 +19,45 @@ impl<'a> CwIbcConnection<'a> {
     ///
     /// The `ensure_data_length` function returns a `Result` type with the success case containing an empty
     /// tuple `()` and the error case containing a `ContractError`.
-    pub fn ensure_data_length(&self, data_len: usize) -> Result<(), ContractError> {
+    pub fn ensure_data_length(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+        data_len: usize,
+    ) -> Result<(), ContractError> {
+        let limits = self.get_network_limits(store, nid)?;
         ensure!(
-            data_len <= MAX_DATA_SIZE as usize,
+            data_len as u64 <= limits.max_data_size,
             ContractError::MaxDataSizeExceeded
         );
 
         Ok(())
     }
-    /// This function ensures that the length of a given byte array (rollback) is not greater than a
-    /// specified maximum size.
+    /// This function ensures that the length of a given byte array (rollback) is not greater than the
+    /// maximum rollback size allowed for the network.
     ///
     /// Arguments:
     ///
+    /// * `store`: `store` is a reference to the storage the limits of the network are loaded from.
+    /// * `nid`: `nid` is the network the message carrying the rollback is sent to.
     /// * `rollback`: `rollback` is a slice of bytes (`&[u8]`) that represents the data to be rolled back in
     /// a smart contract. The function `ensure_rollback_length` checks if the length of the `rollback` slice
-    /// is within the maximum allowed size (`MAX_ROLLBACK_SIZE`) and
+    /// is within the maximum allowed size (`max_rollback_size`) and
     ///
     /// Returns:
     ///
     /// a `Result` type with the `Ok` variant containing an empty tuple `()` and the `Err` variant
     /// containing a `ContractError` if the condition in the `ensure!` macro is not met.
 
-    pub fn ensure_rollback_length(&self, rollback: &[u8]) -> Result<(), ContractError> {
+    pub fn ensure_rollback_length(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+        rollback: &[u8],
+    ) -> Result<(), ContractError> {
+        let limits = self.get_network_limits(store, nid)?;
         ensure!(
-            rollback.is_empty() || rollback.len() <= MAX_ROLLBACK_SIZE as usize,
+            rollback.is_empty() || rollback.len() as u64 <= limits.max_rollback_size,
             ContractError::MaxRollbackSizeExceeded
         );
 
@@ -148,17 +161,24 @@ impl<'a> CwIbcConnection<'a> {
 
 #[cfg(test)]
 mod test {
-    use crate::state::CwIbcConnection;
+    use crate::{error::ContractError, state::CwIbcConnection};
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_info},
         Addr,
     };
+    use cw_common::xcall_connection_msg::NetworkLimits;
+    use cw_xcall_lib::network_address::NetId;
 
     #[test]
     fn test_ensure_length() {
         let contract = CwIbcConnection::new();
+        let deps = mock_dependencies();
 
-        let res = contract.ensure_data_length(20 as usize);
+        let res = contract.ensure_data_length(
+            deps.as_ref().storage,
+            &NetId::from("icon".to_string()),
+            20 as usize,
+        );
         assert!(res.is_ok())
     }
 
@@ -166,26 +186,66 @@ mod test {
     #[should_panic(expected = "MaxDataSizeExceeded")]
     fn test_ensure_length_fail() {
         let contract = CwIbcConnection::new();
+        let deps = mock_dependencies();
 
-        contract.ensure_data_length(u64::MAX as usize).unwrap();
+        contract
+            .ensure_data_length(
+                deps.as_ref().storage,
+                &NetId::from("icon".to_string()),
+                u64::MAX as usize,
+            )
+            .unwrap();
     }
 
     #[test]
     fn test_ensure_rollback_length() {
+        let deps = mock_dependencies();
         let contract = CwIbcConnection::new();
+        let nid = NetId::from("nid".to_string());
 
         let rollback_size: Vec<u8> = Vec::new();
-        let res = contract.ensure_rollback_length(&rollback_size);
+        let res = contract.ensure_rollback_length(deps.as_ref().storage, &nid, &rollback_size);
         assert!(res.is_ok())
     }
 
     #[test]
     #[should_panic(expected = "MaxRollbackSizeExceeded")]
     fn test_ensure_rollback_length_fail() {
+        let deps = mock_dependencies();
         let contract = CwIbcConnection::new();
+        let nid = NetId::from("nid".to_string());
 
         let rollback_size: Vec<u8> = vec![0; 2048];
-        contract.ensure_rollback_length(&rollback_size).unwrap()
+        contract
+            .ensure_rollback_length(deps.as_ref().storage, &nid, &rollback_size)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ensure_rollback_length_per_network() {
+        let mut deps = mock_dependencies();
+        let contract = CwIbcConnection::new();
+        let nid = NetId::from("nid".to_string());
+        contract
+            .store_network_limits(
+                deps.as_mut().storage,
+                &nid,
+                &NetworkLimits {
+                    max_rollback_size: 4096,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let rollback_size: Vec<u8> = vec![0; 2048];
+        let res = contract.ensure_rollback_length(deps.as_ref().storage, &nid, &rollback_size);
+        assert!(res.is_ok());
+        let res = contract.ensure_rollback_length(
+            deps.as_ref().storage,
+            &NetId::from("other".to_string()),
+            &rollback_size,
+        );
+        assert!(matches!(res, Err(ContractError::MaxRollbackSizeExceeded)));
     }
 
     #[test]
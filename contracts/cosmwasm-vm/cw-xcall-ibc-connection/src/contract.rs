@@ -1,8 +1,8 @@
 use common::{
-    ibc::{core::ics04_channel::channel::State, Height},
+    ibc::Height,
     rlp::{self},
 };
-use cosmwasm_std::{coins, BankMsg, IbcChannel};
+use cosmwasm_std::{CosmosMsg, IbcChannel};
 use cw_common::raw_types::channel::RawPacket;
 use cw_xcall_lib::network_address::NetId;
 
@@ -16,7 +16,8 @@ use crate::{
     },
     types::{
         channel_config::ChannelConfig, config::Config, config_response::to_config_response,
-        connection_config::ConnectionConfig, message::Message, LOG_PREFIX,
+        connection_config::ConnectionConfig, message::Message, network_fees::DEFAULT_FEE_DENOM,
+        LOG_PREFIX,
     },
 };
 
@@ -104,6 +105,7 @@ impl<'a> CwIbcConnection<'a> {
                 // return Ok(Response::new());
                 self.send_message(deps, info, env, to, sn, msg)
             }
+            ExecuteMsg::Receive(wrapper) => self.receive_cw20(deps, env, info, wrapper),
             ExecuteMsg::SetXCallHost { address } => {
                 self.ensure_admin(deps.as_ref().storage, info.sender)?;
                 let validated_address =
@@ -117,6 +119,7 @@ impl<'a> CwIbcConnection<'a> {
                 counterparty_nid,
                 client_id,
                 timeout_height,
+                timeout_duration,
             } => {
                 self.ensure_admin(deps.as_ref().storage, info.sender)?;
                 self.configure_connection(
@@ -126,6 +129,7 @@ impl<'a> CwIbcConnection<'a> {
                     counterparty_nid,
                     client_id,
                     timeout_height,
+                    timeout_duration,
                 )?;
                 Ok(Response::new())
             }
@@ -135,6 +139,7 @@ impl<'a> CwIbcConnection<'a> {
                 counterparty_nid,
                 client_id,
                 timeout_height,
+                timeout_duration,
             } => {
                 self.ensure_owner(deps.as_ref().storage, &info)?;
                 self.override_connection(
@@ -144,20 +149,82 @@ impl<'a> CwIbcConnection<'a> {
                     counterparty_nid,
                     client_id,
                     timeout_height,
+                    timeout_duration,
                 )?;
                 Ok(Response::new())
             }
-            ExecuteMsg::ClaimFees { nid, address } => {
-                let fee_msg = self.claim_fees(deps, info, nid, address)?;
+            ExecuteMsg::SetChannelPriority { nid, channel_ids } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.set_channel_priority(deps.storage, &nid, channel_ids)?;
+                Ok(Response::new().add_attribute("method", "set_channel_priority"))
+            }
+            ExecuteMsg::RemoveChannel { nid, channel_id } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.remove_ibc_config(deps.storage, &nid, &channel_id)?;
+                Ok(Response::new()
+                    .add_attribute("method", "remove_channel")
+                    .add_attribute("channel_id", channel_id))
+            }
+            ExecuteMsg::Pause { nid } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.pause(deps.storage, nid)
+            }
+            ExecuteMsg::Unpause { nid } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.unpause(deps.storage, nid)
+            }
+            ExecuteMsg::ProcessQueued { nid, limit } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.process_queued(deps, nid, limit)
+            }
+            ExecuteMsg::SetNetworkLimits { nid, limits } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.set_network_limits(deps.storage, nid, limits)
+            }
+            ExecuteMsg::ClaimFees {
+                nid,
+                address,
+                denom,
+            } => {
+                let fee_msg = self.claim_fees(deps, info, env, nid, address, denom)?;
                 Ok(Response::new().add_submessage(fee_msg))
             }
+            ExecuteMsg::WithdrawFees {
+                nid,
+                denom,
+                recipient,
+            } => self.withdraw_fees(deps, info, nid, denom, recipient),
+            ExecuteMsg::SetFeeCrediting { enabled } => {
+                self.set_fee_crediting(deps.storage, info.sender.as_str(), enabled)?;
+                Ok(Response::new()
+                    .add_attribute("method", "set_fee_crediting")
+                    .add_attribute("address", info.sender)
+                    .add_attribute("enabled", enabled.to_string()))
+            }
             ExecuteMsg::SetFees {
                 nid,
                 packet_fee,
                 ack_fee,
+                denom,
             } => {
                 self.ensure_admin(deps.as_ref().storage, info.sender)?;
-                self.set_fee(deps.storage, nid, packet_fee, ack_fee)
+                self.set_fee(deps.storage, nid, denom, packet_fee, ack_fee)
+            }
+            ExecuteMsg::SetFeeDenomSupport { nid, supported } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                self.set_fee_denom_supported(deps.storage, &nid, supported)?;
+                Ok(Response::new()
+                    .add_attribute("method", "set_fee_denom_support")
+                    .add_attribute("nid", nid.to_string())
+                    .add_attribute("supported", supported.to_string()))
+            }
+            ExecuteMsg::RemoveFees { nid, denom } => {
+                self.ensure_admin(deps.as_ref().storage, info.sender)?;
+                let key = self.fee_denom_key(deps.storage, Some(denom.clone()))?;
+                self.remove_network_fees(deps.storage, &nid, &key);
+                Ok(Response::new()
+                    .add_attribute("method", "remove_fees")
+                    .add_attribute("denom", denom))
             }
             #[cfg(not(feature = "native_ibc"))]
             ExecuteMsg::IbcChannelOpen { msg } => {
@@ -189,6 +256,18 @@ impl<'a> CwIbcConnection<'a> {
                 self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
                 Ok(self.on_packet_timeout(deps, msg)?)
             }
+            #[cfg(not(feature = "native_ibc"))]
+            ExecuteMsg::IbcChannelUpgradeInit { msg }
+            | ExecuteMsg::IbcChannelUpgradeTry { msg }
+            | ExecuteMsg::IbcChannelUpgradeAck { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                Ok(self.on_channel_upgrade(msg)?)
+            }
+            #[cfg(not(feature = "native_ibc"))]
+            ExecuteMsg::IbcChannelUpgradeOpen { msg } => {
+                self.ensure_ibc_handler(deps.as_ref().storage, info.sender)?;
+                Ok(self.on_channel_upgrade_open(deps, msg)?)
+            }
             #[cfg(feature = "native_ibc")]
             _ => Err(ContractError::DecodeFailed {
                 error: "InvalidMessage Variant".to_string(),
@@ -229,16 +308,56 @@ impl<'a> CwIbcConnection<'a> {
                 let config = self.get_channel_config(deps.storage, &channel_id).unwrap();
                 to_binary(&config.timeout_height)
             }
+            QueryMsg::GetTimeoutDuration { channel_id } => {
+                let config =
+                    self.get_channel_config(deps.storage, &channel_id)
+                        .map_err(|error| StdError::NotFound {
+                            kind: error.to_string(),
+                        })?;
+                to_binary(&config.timeout_duration)
+            }
             QueryMsg::GetFee { nid, response } => {
-                let fees = self.get_network_fees(deps.storage, nid);
-                if response {
-                    return to_binary(&(fees.send_packet_fee + fees.ack_fee));
-                }
-                to_binary(&(fees.send_packet_fee))
+                to_binary(&self.get_fee(deps.storage, &nid, response))
             }
-            QueryMsg::GetUnclaimedFee { nid, relayer } => {
-                to_binary(&self.get_unclaimed_fee(deps.storage, nid, relayer))
+            QueryMsg::GetFeeOptions { nid } => to_binary(
+                &self
+                    .get_fee_option_list(deps.storage, &nid)
+                    .map_err(|error| StdError::NotFound {
+                        kind: error.to_string(),
+                    })?,
+            ),
+            QueryMsg::GetUnclaimedFee {
+                nid,
+                relayer,
+                denom,
+            } => to_binary(
+                &self
+                    .get_unclaimed_fee(deps.storage, nid, relayer, denom)
+                    .map_err(|error| StdError::NotFound {
+                        kind: error.to_string(),
+                    })?,
+            ),
+            QueryMsg::GetPauseStatus {} => {
+                to_binary(&self.get_pause_status(deps.storage).map_err(|error| {
+                    StdError::NotFound {
+                        kind: error.to_string(),
+                    }
+                })?)
             }
+            QueryMsg::GetNetworkLimits { nid } => to_binary(
+                &self
+                    .get_network_limits(deps.storage, &nid)
+                    .map_err(|error| StdError::NotFound {
+                        kind: error.to_string(),
+                    })?,
+            ),
+            QueryMsg::GetRelayerFeeSummary { relayer } => to_binary(
+                &self
+                    .get_relayer_fee_summary(deps.storage, relayer)
+                    .map_err(|error| StdError::NotFound {
+                        kind: error.to_string(),
+                    })?,
+            ),
             QueryMsg::GetIbcConfig { nid } => {
                 let ibc_config = self.get_ibc_config(deps.storage, &nid).unwrap();
                 let channel_config = self
@@ -246,6 +365,21 @@ impl<'a> CwIbcConnection<'a> {
                     .unwrap();
                 to_binary(&to_config_response(ibc_config, channel_config))
             }
+            QueryMsg::GetIbcConfigs { nid } => {
+                let configs = self
+                    .get_network_channels(deps.storage, &nid)
+                    .into_iter()
+                    .map(|channel_id| {
+                        let ibc_config = self.get_channel_ibc_config(deps.storage, &channel_id)?;
+                        let channel_config = self.get_channel_config(deps.storage, &channel_id)?;
+                        Ok(to_config_response(ibc_config, channel_config))
+                    })
+                    .collect::<Result<Vec<_>, ContractError>>()
+                    .map_err(|error| StdError::NotFound {
+                        kind: error.to_string(),
+                    })?;
+                to_binary(&configs)
+            }
         }
     }
     /// This function handles different types of reply messages and calls corresponding functions based on
@@ -291,6 +425,9 @@ impl<'a> CwIbcConnection<'a> {
     ) -> Result<Response, ContractError> {
         set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
             .map_err(ContractError::Std)?;
+        self.migrate_ibc_configs(deps.storage)?;
+        self.migrate_fees(deps.storage)?;
+        self.migrate_counterparty_channels(deps.storage)?;
         Ok(Response::default().add_attribute("migrate", "successful"))
     }
 }
@@ -521,6 +658,31 @@ impl<'a> CwIbcConnection<'a> {
             .add_attribute("method", "ibc_channel_close")
             .set_data(to_binary(&ibc_endpoint).unwrap()))
     }
+    /// Accepts a channel upgrade proposal as long as the upgraded channel keeps the ordering and
+    /// version xcall relies on.
+    pub fn on_channel_upgrade(&self, msg: CwChannelUpgradeMsg) -> Result<Response, ContractError> {
+        check_order(&msg.upgrade.order)?;
+        check_version(&msg.upgrade.version)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_upgrade")
+            .set_data(to_binary(&msg.upgrade.endpoint).unwrap()))
+    }
+
+    /// Rebinds the channel to the configuration of the connection it runs on once the upgrade is
+    /// open.
+    pub fn on_channel_upgrade_open(
+        &mut self,
+        deps: DepsMut,
+        msg: CwChannelUpgradeMsg,
+    ) -> Result<Response, ContractError> {
+        cw_println!(deps, "[IBCConnection]: channel upgrade open called");
+        self.setup_channel(deps, msg.upgrade.clone())?;
+
+        Ok(Response::new()
+            .add_attribute("method", "on_channel_upgrade_open")
+            .set_data(to_binary(&msg.upgrade.endpoint).unwrap()))
+    }
     /// This function receives an IBC packet and returns a response with acknowledgement and events or an
     /// error message.
     ///
@@ -577,15 +739,11 @@ impl<'a> CwIbcConnection<'a> {
 
         let submsg = self.call_xcall_handle_message(deps.storage, &nid, acknowledgement.data.0)?;
 
-        let bank_msg = self.settle_unclaimed_ack_fee(
-            deps.storage,
-            nid.as_str(),
-            seq,
-            ack.relayer.to_string(),
-        )?;
+        let fee_msgs =
+            self.settle_unclaimed_ack_fee(deps.storage, &nid, seq, ack.relayer.as_str())?;
 
         Ok(Response::new()
-            .add_messages(bank_msg)
+            .add_messages(fee_msgs)
             .add_submessage(submsg))
     }
     /// This function handles a timeout event for an IBC packet and sends a reply message with an error
@@ -621,40 +779,36 @@ impl<'a> CwIbcConnection<'a> {
         let channel_config = self.get_channel_config(deps.as_ref().storage, &channel_id)?;
         let nid = channel_config.counterparty_nid;
 
-        self.add_unclaimed_ack_fees(deps.storage, &nid, packet.sequence, n_message.fee)?;
+        let denom = n_message.denom.as_deref().unwrap_or(DEFAULT_FEE_DENOM);
+        self.add_unclaimed_ack_fees(deps.storage, &nid, packet.sequence, denom, n_message.fee)?;
         let submsg = self.call_xcall_handle_error(deps.storage, n_message.sn.0.unwrap())?;
-        let bank_msg = self.settle_unclaimed_ack_fee(
+        let fee_msgs = self.settle_unclaimed_ack_fee(
             deps.storage,
-            nid.as_str(),
+            &nid,
             packet.sequence,
-            msg.relayer.to_string(),
+            msg.relayer.as_str(),
         )?;
 
         Ok(Response::new()
-            .add_messages(bank_msg)
+            .add_messages(fee_msgs)
             .add_submessage(submsg))
     }
 
+    /// Pays the ack fee of packet `seq` to `relayer`, or holds it for the relayer when it enabled
+    /// fee crediting.
     pub fn settle_unclaimed_ack_fee(
         &self,
         store: &mut dyn Storage,
-        nid: &str,
+        nid: &NetId,
         seq: u64,
-        relayer: String,
-    ) -> Result<Vec<BankMsg>, ContractError> {
-        let ack_fee = self.get_unclaimed_ack_fee(store, nid, seq);
-        if ack_fee == 0 {
-            return Ok(vec![]);
-        }
-        self.reset_unclaimed_ack_fees(store, nid, seq)?;
-        let denom = self.get_denom(store)?;
-
-        let msg = BankMsg::Send {
-            to_address: relayer,
-            amount: coins(ack_fee, denom),
+        relayer: &str,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        let ack_fee = match self.get_unclaimed_ack_fee(store, nid.as_str(), seq) {
+            Some(ack_fee) if ack_fee.amount > 0 => ack_fee,
+            _ => return Ok(vec![]),
         };
-
-        Ok(vec![msg])
+        self.reset_unclaimed_ack_fees(store, nid.as_str(), seq);
+        self.pay_or_credit_fee(store, nid, relayer, &ack_fee.denom, ack_fee.amount)
     }
 
     pub fn setup_channel(
@@ -690,6 +844,7 @@ impl<'a> CwIbcConnection<'a> {
             &channel_id,
             &ChannelConfig {
                 timeout_height: connection_config.timeout_height,
+                timeout_duration: connection_config.timeout_duration,
                 client_id: connection_config.client_id,
                 counterparty_nid: nid,
             },
@@ -700,6 +855,11 @@ impl<'a> CwIbcConnection<'a> {
         Ok(())
     }
 
+    /// Configures the network channels opened on `connection_id` to `counterparty_port_id` belong
+    /// to. Channels already registered for the network are kept, a new channel is added with the
+    /// lowest priority once its handshake reaches the contract. Reconfiguring a connection is
+    /// refused while its network still has an open channel.
+    #[allow(clippy::too_many_arguments)]
     pub fn configure_connection(
         &self,
         deps: DepsMut,
@@ -708,24 +868,17 @@ impl<'a> CwIbcConnection<'a> {
         counterparty_nid: NetId,
         client_id: String,
         timeout_height: u64,
+        timeout_duration: u64,
     ) -> Result<(), ContractError> {
-        let cfg_res = self.get_ibc_config(deps.storage, &counterparty_nid);
-        if let Ok(cfg) = cfg_res {
-            let state_res = self.query_channel_state(
-                deps.as_ref(),
-                cfg.src_endpoint().port_id.clone(),
-                cfg.src_endpoint().channel_id.clone(),
-            );
-            if let Ok(state) = state_res {
-                if State::from_i32(state).map_or(false, |s| s.is_open()) {
-                    return Err(ContractError::ConnectionAlreadyConfigured {
-                        connection_id,
-                        port_id: counterparty_port_id,
-                    });
-                }
-            }
-
-            self.clear_ibc_config(deps.storage, &counterparty_nid)
+        if let Ok(nid) =
+            self.get_counterparty_nid(deps.storage, &connection_id, &counterparty_port_id)
+        {
+            if self.has_open_channel(deps.as_ref(), &nid) {
+                return Err(ContractError::ConnectionAlreadyConfigured {
+                    connection_id,
+                    port_id: counterparty_port_id,
+                });
+            }
         }
 
         self.store_counterparty_nid(
@@ -740,12 +893,15 @@ impl<'a> CwIbcConnection<'a> {
             &connection_id,
             &ConnectionConfig {
                 timeout_height,
+                timeout_duration,
                 client_id,
             },
         )?;
 
         Ok(())
     }
+    /// Same as `configure_connection` without checking the channels of the network.
+    #[allow(clippy::too_many_arguments)]
     pub fn override_connection(
         &self,
         store: &mut dyn Storage,
@@ -754,8 +910,8 @@ impl<'a> CwIbcConnection<'a> {
         counterparty_nid: NetId,
         client_id: String,
         timeout_height: u64,
+        timeout_duration: u64,
     ) -> Result<(), ContractError> {
-        self.clear_ibc_config(store, &counterparty_nid);
         self.store_counterparty_nid(
             store,
             &connection_id,
@@ -768,6 +924,7 @@ impl<'a> CwIbcConnection<'a> {
             &connection_id,
             &ConnectionConfig {
                 timeout_height,
+                timeout_duration,
                 client_id,
             },
         )?;
@@ -775,10 +932,42 @@ impl<'a> CwIbcConnection<'a> {
         Ok(())
     }
 
+    /// Moves `channel_ids` to the front of the channels of `nid`, in the given order.
+    pub fn set_channel_priority(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        channel_ids: Vec<String>,
+    ) -> Result<(), ContractError> {
+        let channels = self.get_network_channels(store, nid);
+        if let Some(channel_id) = channel_ids.iter().find(|id| !channels.contains(id)) {
+            return Err(ContractError::ChannelNotRegistered {
+                nid: nid.to_string(),
+                channel_id: channel_id.clone(),
+            });
+        }
+
+        let mut ordered = Vec::with_capacity(channels.len());
+        for channel_id in channel_ids.into_iter().chain(channels) {
+            if !ordered.contains(&channel_id) {
+                ordered.push(channel_id);
+            }
+        }
+        self.store_network_channels(store, nid, &ordered)
+    }
+
+    fn has_open_channel(&self, deps: Deps, nid: &NetId) -> bool {
+        self.get_network_channels(deps.storage, nid)
+            .iter()
+            .filter_map(|channel_id| self.get_channel_ibc_config(deps.storage, channel_id).ok())
+            .any(|ibc_config| self.is_channel_open(deps, &ibc_config))
+    }
+
     pub fn create_packet<T: common::rlp::Encodable>(
         &self,
         ibc_config: IbcConfig,
         timeout_height: Height,
+        timeout_timestamp: u64,
         sequence_no: u64,
         data: T,
     ) -> RawPacket {
@@ -790,7 +979,7 @@ impl<'a> CwIbcConnection<'a> {
             destination_channel: ibc_config.dst_endpoint().channel_id.clone(),
             data: rlp::encode(&data).to_vec(),
             timeout_height: Some(timeout_height.into()),
-            timeout_timestamp: 0,
+            timeout_timestamp,
         };
         packet
     }
@@ -53,4 +53,26 @@ pub enum ContractError {
     InvalidPortId,
     #[error("InsufficientFunds")]
     InsufficientFunds,
+    #[error("FeeDenomNotSupported {nid}")]
+    FeeDenomNotSupported { nid: String },
+    #[error("FeeDenomNotAccepted {denom} for {nid}")]
+    FeeDenomNotAccepted { nid: String, denom: String },
+    #[error("NetworkNotConfigured {nid}")]
+    NetworkNotConfigured { nid: String },
+    #[error("ChannelNotRegistered {channel_id} for {nid}")]
+    ChannelNotRegistered { nid: String, channel_id: String },
+    #[error("NoOpenChannel {nid}")]
+    NoOpenChannel { nid: String },
+    #[error("ContractPaused")]
+    ContractPaused,
+    #[error("NetworkPaused {nid}")]
+    NetworkPaused { nid: String },
+    #[error("QueueFull {nid}")]
+    QueueFull { nid: String },
+    #[error("MessageRateLimitExceeded {nid}")]
+    MessageRateLimitExceeded { nid: String },
+    #[error("ValueRateLimitExceeded {nid}")]
+    ValueRateLimitExceeded { nid: String },
+    #[error("InvalidNetworkLimits")]
+    InvalidNetworkLimits,
 }
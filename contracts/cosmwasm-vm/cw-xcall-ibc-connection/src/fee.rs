@@ -1,67 +1,294 @@
 use common::rlp::Nullable;
+use cosmwasm_std::{coins, BankMsg, Coin, CosmosMsg, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_common::xcall_connection_msg::{FeeOption, UnclaimedFee};
 use cw_xcall_lib::network_address::NetId;
 
-use crate::types::{message::Message, network_fees::NetworkFees};
+use crate::types::{
+    message::Message,
+    network_fees::{NetworkFees, CW20_PREFIX, DEFAULT_FEE_DENOM},
+};
 
 use super::*;
 /// This is an implementation of two methods for the `CwCallService` struct.
 
 impl<'a> CwIbcConnection<'a> {
+    /// Sends the packet fees `info.sender` accrued for delivering packets from `nid` in `denom`
+    /// back to `nid`, which pays them out to `address`. Without `denom` the fees paid in the
+    /// default denom of `nid` are claimed.
     pub fn claim_fees(
         &self,
         deps: DepsMut,
         info: MessageInfo,
+        env: Env,
         nid: NetId,
         address: String,
+        denom: Option<String>,
     ) -> Result<SubMsg, ContractError> {
+        self.ensure_not_paused(deps.as_ref().storage, &nid)?;
         let caller = info.sender;
-        let fees = self.get_unclaimed_packet_fee(deps.as_ref().storage, &nid, caller.as_ref());
+        let denom = self.fee_denom_key(deps.storage, denom)?;
+        if denom != DEFAULT_FEE_DENOM && !self.is_fee_denom_supported(deps.as_ref().storage, &nid) {
+            return Err(ContractError::FeeDenomNotSupported {
+                nid: nid.to_string(),
+            });
+        }
+        let fees =
+            self.get_unclaimed_packet_fee(deps.as_ref().storage, &nid, caller.as_ref(), &denom);
         if fees == 0 {
             return Err(ContractError::NoFeesAccrued);
         }
-        let ibc_config = self.get_ibc_config(deps.as_ref().storage, &nid)?;
+        let ibc_config = self.get_open_ibc_config(deps.as_ref(), &nid)?;
 
-        self.reset_unclaimed_packet_fees(deps.storage, &nid, caller.as_ref())?;
+        self.reset_unclaimed_packet_fees(deps.storage, &nid, caller.as_ref(), &denom);
         let sequence_no = self.query_host_sequence_no(deps.as_ref(), &ibc_config)?;
         let message = Message {
             sn: Nullable::new(None),
             fee: fees,
             data: address.as_bytes().to_vec(),
+            denom: (denom != DEFAULT_FEE_DENOM).then_some(denom),
         };
         let timeout_height =
             self.query_timeout_height(deps.as_ref(), &ibc_config.src_endpoint().channel_id)?;
-        let packet = self.create_packet(ibc_config, timeout_height, sequence_no, message);
+        let timeout_timestamp = self.get_timeout_timestamp(
+            deps.as_ref().storage,
+            &env,
+            &ibc_config.src_endpoint().channel_id,
+        )?;
+        let packet = self.create_packet(
+            ibc_config,
+            timeout_height,
+            timeout_timestamp,
+            sequence_no,
+            message,
+        );
         let sub_msg = self.call_host_send_message(deps, packet)?;
         Ok(sub_msg)
     }
 
+    /// Pays the fees held by this contract for `info.sender` on `nid` in `denom` to `recipient`.
+    /// Only the ack fees and the fees claimed from `nid` of addresses with fee crediting enabled
+    /// are held here, packet fees accrued on `nid` are claimed with `claim_fees`.
+    pub fn withdraw_fees(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        nid: NetId,
+        denom: Option<String>,
+        recipient: String,
+    ) -> Result<Response, ContractError> {
+        let recipient = CwIbcConnection::validate_address(deps.api, &recipient)?;
+        let caller = info.sender;
+        let denom = self.fee_denom_key(deps.storage, denom)?;
+        let fees = self.get_local_fee(deps.as_ref().storage, &nid, caller.as_ref(), &denom);
+        if fees == 0 {
+            return Err(ContractError::NoFeesAccrued);
+        }
+        self.set_local_fee(deps.storage, &nid, caller.as_ref(), &denom, 0)?;
+
+        let payout_denom = self.resolve_fee_denom(deps.storage, &denom)?;
+        let msg = self.fee_payout_msg(&payout_denom, fees, recipient.as_str())?;
+        Ok(Response::new()
+            .add_message(msg)
+            .add_attribute("method", "withdraw_fees")
+            .add_attribute("nid", nid.to_string())
+            .add_attribute("denom", payout_denom)
+            .add_attribute("amount", fees.to_string()))
+    }
+
+    /// Pays `amount` of the fees of `nid` in `denom` to `address`, or holds them for it when it
+    /// enabled fee crediting.
+    pub fn pay_or_credit_fee(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        address: &str,
+        denom: &str,
+        amount: u128,
+    ) -> Result<Vec<CosmosMsg>, ContractError> {
+        if self.is_fee_crediting(store, address) {
+            self.add_local_fees(store, nid, address, denom, amount)?;
+            return Ok(vec![]);
+        }
+        let payout_denom = self.resolve_fee_denom(store, denom)?;
+        Ok(vec![self.fee_payout_msg(&payout_denom, amount, address)?])
+    }
+
+    /// Returns the packet fees accrued by `relayer` on every network, grouped by denom.
+    pub fn get_relayer_fee_summary(
+        &self,
+        store: &dyn Storage,
+        relayer: String,
+    ) -> Result<Vec<UnclaimedFee>, ContractError> {
+        Ok(self
+            .get_unclaimed_packet_fees_of(store, &relayer)?
+            .into_iter()
+            .map(|(nid, denom, amount)| UnclaimedFee {
+                nid: NetId::from(nid),
+                denom: (!denom.is_empty()).then_some(denom),
+                amount,
+            })
+            .collect())
+    }
+
+    /// Sets the fees of `nid` in `denom`, the default denom when not provided. Other denoms are
+    /// only accepted for networks decoding the fee denom of messages, networks that drop it would
+    /// claim the fees back in the default denom.
     pub fn set_fee(
         &self,
         store: &mut dyn Storage,
         nid: NetId,
+        denom: Option<String>,
         packet_fee: u128,
         ack_fee: u128,
     ) -> Result<Response, ContractError> {
+        let denom = self.fee_denom_key(store, denom)?;
+        if denom != DEFAULT_FEE_DENOM && !self.is_fee_denom_supported(store, &nid) {
+            return Err(ContractError::FeeDenomNotSupported {
+                nid: nid.to_string(),
+            });
+        }
         let net_fee = NetworkFees {
             send_packet_fee: packet_fee,
             ack_fee,
         };
-        self.store_network_fees(store, nid, &net_fee)?;
+        self.store_network_fees(store, &nid, &denom, &net_fee)?;
         Ok(Response::new())
     }
 
-    pub fn get_unclaimed_fee(&self, store: &dyn Storage, nid: NetId, address: String) -> u128 {
-        self.get_unclaimed_packet_fee(store, &nid, &address)
+    pub fn get_unclaimed_fee(
+        &self,
+        store: &dyn Storage,
+        nid: NetId,
+        address: String,
+        denom: Option<String>,
+    ) -> Result<u128, ContractError> {
+        let denom = self.fee_denom_key(store, denom)?;
+        Ok(self.get_unclaimed_packet_fee(store, &nid, &address, &denom))
+    }
+
+    /// Returns the fee of a message to `nid` in the default denom, zero when the default denom is
+    /// not accepted. The ack fee is included when `with_ack` is set.
+    pub fn get_fee(&self, store: &dyn Storage, nid: &NetId, with_ack: bool) -> u128 {
+        let fees = self
+            .get_network_fees(store, nid, DEFAULT_FEE_DENOM)
+            .unwrap_or_default();
+        if with_ack {
+            return fees.send_packet_fee + fees.ack_fee;
+        }
+        fees.send_packet_fee
+    }
+
+    /// Returns the fees of `nid` in every accepted denom, the default denom first.
+    pub fn get_fee_option_list(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+    ) -> Result<Vec<FeeOption>, ContractError> {
+        self.get_fee_options(store, nid)?
+            .into_iter()
+            .map(|(denom, fees)| {
+                Ok(FeeOption {
+                    denom: self.resolve_fee_denom(store, &denom)?,
+                    send_packet_fee: fees.send_packet_fee,
+                    ack_fee: fees.ack_fee,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the first fee option of `nid` that `funds` cover, the ack fee is only charged when
+    /// `with_ack` is set. Networks without fees are free in the default denom.
+    pub fn select_fee_option(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+        funds: &[Coin],
+        with_ack: bool,
+    ) -> Result<(String, NetworkFees), ContractError> {
+        let options = self.get_fee_options(store, nid)?;
+        if options.is_empty() {
+            return Ok((DEFAULT_FEE_DENOM.to_string(), NetworkFees::default()));
+        }
+        for (denom, fees) in options {
+            let mut total_fee = fees.send_packet_fee;
+            if with_ack {
+                total_fee += fees.ack_fee;
+            }
+            let fee_denom = self.resolve_fee_denom(store, &denom)?;
+            if funds
+                .iter()
+                .filter(|coin| coin.denom == fee_denom)
+                .any(|coin| coin.amount >= Uint128::from(total_fee))
+            {
+                return Ok((denom, fees));
+            }
+        }
+        Err(ContractError::InsufficientFunds)
+    }
+
+    /// Returns the key the fees in `denom` are stored under, the default denom is stored under
+    /// `DEFAULT_FEE_DENOM` whether it is given by name or left out.
+    pub fn fee_denom_key(
+        &self,
+        store: &dyn Storage,
+        denom: Option<String>,
+    ) -> Result<String, ContractError> {
+        match denom {
+            Some(denom) if denom != self.get_denom(store)? => Ok(denom),
+            _ => Ok(DEFAULT_FEE_DENOM.to_string()),
+        }
+    }
+
+    /// Returns the denom of this chain the fees stored under `denom` are held in.
+    pub fn resolve_fee_denom(
+        &self,
+        store: &dyn Storage,
+        denom: &str,
+    ) -> Result<String, ContractError> {
+        if denom == DEFAULT_FEE_DENOM {
+            return self.get_denom(store);
+        }
+        Ok(denom.to_string())
+    }
+
+    /// Returns the message paying `amount` of `denom` held by the contract to `recipient`.
+    pub fn fee_payout_msg(
+        &self,
+        denom: &str,
+        amount: u128,
+        recipient: &str,
+    ) -> Result<CosmosMsg, ContractError> {
+        if let Some(contract_addr) = denom.strip_prefix(CW20_PREFIX) {
+            return Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount: amount.into(),
+                })?,
+                funds: vec![],
+            }));
+        }
+        Ok(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount, denom),
+        }))
     }
 }
 
 #[cfg(test)]
 mod test {
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
-    use cosmwasm_std::Response;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{coins, to_json_binary as to_binary, BankMsg, CosmosMsg, Response, WasmMsg};
+    use cw20::Cw20ExecuteMsg;
+    use cw_common::xcall_connection_msg::UnclaimedFee;
     use cw_xcall_lib::network_address::NetId;
 
     use super::CwIbcConnection;
+    use crate::{
+        error::ContractError,
+        types::{config::Config, network_fees::DEFAULT_FEE_DENOM},
+    };
 
     #[test]
     #[should_panic(expected = "NoFeesAccrued")]
@@ -73,7 +300,14 @@ mod test {
         let nid = NetId::from("default".to_string());
 
         contract
-            .claim_fees(deps.as_mut(), info, nid, "relayer".to_string())
+            .claim_fees(
+                deps.as_mut(),
+                info,
+                mock_env(),
+                nid,
+                "relayer".to_string(),
+                None,
+            )
             .unwrap();
     }
 
@@ -81,14 +315,76 @@ mod test {
     fn test_set_fee() {
         let mut deps = mock_dependencies();
         let contract = CwIbcConnection::new();
+        contract
+            .store_config(
+                deps.as_mut().storage,
+                &Config {
+                    port_id: "our-port".to_string(),
+                    denom: "arch".to_string(),
+                },
+            )
+            .unwrap();
 
         let nid = NetId::from("default".to_string());
 
         let res = contract
-            .set_fee(deps.as_mut().storage, nid, 1_000, 1_000)
+            .set_fee(
+                deps.as_mut().storage,
+                nid.clone(),
+                Some("arch".to_string()),
+                1_000,
+                1_000,
+            )
+            .unwrap();
+
+        assert_eq!(res, Response::new());
+        assert!(contract
+            .get_network_fees(deps.as_ref().storage, &nid, DEFAULT_FEE_DENOM)
+            .is_some());
+    }
+
+    #[test]
+    fn test_set_fee_in_other_denom_needs_fee_denom_support() {
+        let mut deps = mock_dependencies();
+        let contract = CwIbcConnection::new();
+        contract
+            .store_config(
+                deps.as_mut().storage,
+                &Config {
+                    port_id: "our-port".to_string(),
+                    denom: "arch".to_string(),
+                },
+            )
             .unwrap();
 
-        assert_eq!(res, Response::new())
+        let nid = NetId::from("default".to_string());
+        let res = contract.set_fee(
+            deps.as_mut().storage,
+            nid.clone(),
+            Some("uatom".to_string()),
+            1_000,
+            1_000,
+        );
+        assert!(matches!(
+            res,
+            Err(ContractError::FeeDenomNotSupported { .. })
+        ));
+
+        contract
+            .set_fee_denom_supported(deps.as_mut().storage, &nid, true)
+            .unwrap();
+        contract
+            .set_fee(
+                deps.as_mut().storage,
+                nid.clone(),
+                Some("uatom".to_string()),
+                1_000,
+                1_000,
+            )
+            .unwrap();
+        assert!(contract
+            .get_network_fees(deps.as_ref().storage, &nid, "uatom")
+            .is_some());
     }
 
     #[test]
@@ -99,17 +395,130 @@ mod test {
         let nid = NetId::from("default".to_string());
 
         contract
-            .add_unclaimed_ack_fees(deps.as_mut().storage, &nid, 1, 10)
+            .add_unclaimed_ack_fees(deps.as_mut().storage, &nid, 1, "arch", 10)
             .unwrap();
 
         let ack_fee = contract.get_unclaimed_ack_fee(deps.as_ref().storage, nid.as_str(), 1);
-        assert_eq!(ack_fee, 10);
+        assert_eq!(ack_fee.unwrap().amount, 10);
+
+        contract.reset_unclaimed_ack_fees(deps.as_mut().storage, nid.as_str(), 1);
+
+        let ack_fee = contract.get_unclaimed_ack_fee(deps.as_ref().storage, nid.as_str(), 1);
+        assert!(ack_fee.is_none())
+    }
+
+    #[test]
+    fn test_fee_payout_msg() {
+        let contract = CwIbcConnection::new();
+
+        let msg = contract.fee_payout_msg("arch", 10, "relayer").unwrap();
+        assert_eq!(
+            msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "relayer".to_string(),
+                amount: coins(10, "arch"),
+            })
+        );
 
+        let msg = contract
+            .fee_payout_msg("cw20:token", 10, "relayer")
+            .unwrap();
+        assert_eq!(
+            msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "relayer".to_string(),
+                    amount: 10_u128.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn test_withdraw_fees() {
+        let mut deps = mock_dependencies();
+        let contract = CwIbcConnection::new();
+        let nid = NetId::from("default".to_string());
+        contract
+            .store_config(
+                deps.as_mut().storage,
+                &Config {
+                    port_id: "our-port".to_string(),
+                    denom: "arch".to_string(),
+                },
+            )
+            .unwrap();
         contract
-            .reset_unclaimed_ack_fees(deps.as_mut().storage, nid.as_str(), 1)
+            .add_unclaimed_ack_fees(deps.as_mut().storage, &nid, 1, "", 10)
             .unwrap();
+        let msgs = contract
+            .settle_unclaimed_ack_fee(deps.as_mut().storage, &nid, 1, "relayer")
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "relayer".to_string(),
+                amount: coins(10, "arch"),
+            })]
+        );
 
-        let ack_fee = contract.get_unclaimed_ack_fee(deps.as_ref().storage, nid.as_str(), 1);
-        assert_eq!(ack_fee, 0)
+        contract
+            .set_fee_crediting(deps.as_mut().storage, "relayer", true)
+            .unwrap();
+        contract
+            .add_unclaimed_ack_fees(deps.as_mut().storage, &nid, 2, "", 6)
+            .unwrap();
+        let msgs = contract
+            .settle_unclaimed_ack_fee(deps.as_mut().storage, &nid, 2, "relayer")
+            .unwrap();
+        assert!(msgs.is_empty());
+        contract
+            .add_unclaimed_packet_fees(deps.as_mut().storage, &nid, "relayer", "", 20)
+            .unwrap();
+
+        let res = contract
+            .withdraw_fees(
+                deps.as_mut(),
+                mock_info("relayer", &[]),
+                nid.clone(),
+                Some("arch".to_string()),
+                "payee".to_string(),
+            )
+            .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "payee".to_string(),
+                amount: coins(6, "arch"),
+            })
+        );
+        assert_eq!(
+            contract.get_local_fee(deps.as_ref().storage, &nid, "relayer", ""),
+            0
+        );
+
+        let summary = contract
+            .get_relayer_fee_summary(deps.as_ref().storage, "relayer".to_string())
+            .unwrap();
+        assert_eq!(
+            summary,
+            vec![UnclaimedFee {
+                nid: nid.clone(),
+                denom: None,
+                amount: 20,
+            }]
+        );
+
+        let res = contract.withdraw_fees(
+            deps.as_mut(),
+            mock_info("relayer", &[]),
+            nid,
+            None,
+            "payee".to_string(),
+        );
+        assert!(matches!(res, Err(ContractError::NoFeesAccrued)));
     }
 }
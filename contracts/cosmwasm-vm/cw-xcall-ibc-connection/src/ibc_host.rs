@@ -1,15 +1,17 @@
 use crate::state::IbcConfig;
 use crate::types::LOG_PREFIX;
-use common::ibc::Height;
+use common::ibc::{core::ics04_channel::channel::State, Height};
 use cosmwasm_std::{
-    to_json_binary as to_binary, CosmosMsg, Deps, DepsMut, Storage, SubMsg, WasmMsg,
+    to_json_binary as to_binary, CosmosMsg, Deps, DepsMut, Env, Storage, SubMsg, WasmMsg,
 };
+use cw_common::core_msg::DecodedClientState;
 use cw_common::cw_types::CwPacket;
 use cw_common::query_helpers::build_smart_query;
 use cw_common::raw_types::channel::RawChannel;
 use cw_common::{hex_string::HexString, raw_types::channel::RawPacket, ProstMessage};
 
 use cw_common::cw_println;
+use cw_xcall_lib::network_address::NetId;
 
 use crate::{
     error::ContractError,
@@ -85,6 +87,25 @@ impl<'a> CwIbcConnection<'a> {
         Ok(Height::new(0, timeout_height).unwrap())
     }
 
+    /// Returns the timeout timestamp in nanoseconds for a packet sent on `channel_id` now, or zero
+    /// when the channel has no timeout duration configured.
+    pub fn get_timeout_timestamp(
+        &self,
+        store: &dyn Storage,
+        env: &Env,
+        channel_id: &str,
+    ) -> Result<u64, ContractError> {
+        let channel_config = self.get_channel_config(store, channel_id)?;
+        if channel_config.timeout_duration == 0 {
+            return Ok(0);
+        }
+        Ok(env
+            .block
+            .time
+            .plus_seconds(channel_config.timeout_duration)
+            .nanos())
+    }
+
     pub fn query_channel_state(
         &self,
         deps: Deps,
@@ -103,6 +124,62 @@ impl<'a> CwIbcConnection<'a> {
         Ok(channel.state)
     }
 
+    /// Returns whether the source channel of `ibc_config` is open, a channel the host fails to
+    /// answer for is considered closed.
+    pub fn is_channel_open(&self, deps: Deps, ibc_config: &IbcConfig) -> bool {
+        self.query_channel_state(
+            deps,
+            ibc_config.src_endpoint().port_id.clone(),
+            ibc_config.src_endpoint().channel_id.clone(),
+        )
+        .is_ok_and(|state| State::from_i32(state).is_ok_and(|s| s.is_open()))
+    }
+
+    /// Returns whether the light client `client_id` is frozen. Only ICON client states are
+    /// decoded by the host, clients of other types are never reported frozen.
+    pub fn query_client_frozen(
+        &self,
+        deps: Deps,
+        client_id: String,
+    ) -> Result<bool, ContractError> {
+        let ibc_host = self.get_ibc_host(deps.storage)?;
+        let message =
+            to_binary(&cw_common::core_msg::QueryMsg::GetDecodedClientState { client_id })
+                .map_err(ContractError::Std)?;
+        let query = build_smart_query(ibc_host.to_string(), message);
+        let client_state: DecodedClientState =
+            deps.querier.query(&query).map_err(ContractError::Std)?;
+        match client_state {
+            DecodedClientState::Icon(state) => Ok(state.frozen_height != 0),
+            DecodedClientState::Raw { .. } => Ok(false),
+        }
+    }
+
+    /// Returns the config of the first channel of `nid`, in priority order, that is open and whose
+    /// client is not frozen. Channels the host fails to answer the channel state for are skipped,
+    /// a failing client state query fails the lookup rather than passing for a frozen client.
+    pub fn get_open_ibc_config(&self, deps: Deps, nid: &NetId) -> Result<IbcConfig, ContractError> {
+        for channel_id in self.get_network_channels(deps.storage, nid) {
+            let ibc_config = self.get_channel_ibc_config(deps.storage, &channel_id)?;
+            if !self.is_channel_open(deps, &ibc_config) {
+                cw_println!(deps, "{LOG_PREFIX} Skipping channel {channel_id}, not open");
+                continue;
+            }
+            let channel_config = self.get_channel_config(deps.storage, &channel_id)?;
+            if self.query_client_frozen(deps, channel_config.client_id)? {
+                cw_println!(
+                    deps,
+                    "{LOG_PREFIX} Skipping channel {channel_id}, client frozen"
+                );
+                continue;
+            }
+            return Ok(ibc_config);
+        }
+        Err(ContractError::NoOpenChannel {
+            nid: nid.to_string(),
+        })
+    }
+
     pub fn query_host_sequence_no(
         &self,
         deps: Deps,
@@ -7,8 +7,10 @@ pub mod error;
 pub mod fee;
 pub mod ibc;
 pub mod ibc_host;
+pub mod limits;
 pub mod msg;
 pub mod owner;
+pub mod pause;
 pub mod receive_packet;
 pub mod send_message;
 pub mod state;
@@ -39,6 +41,7 @@ use cw_common::cw_types::{
     CwPacketReceiveMsg, CwPacketTimeoutMsg, CwReceiveResponse,
 };
 
+use cw_common::ibc_dapp_msg::CwChannelUpgradeMsg;
 use cw_common::xcall_connection_msg::{ExecuteMsg, QueryMsg};
 use cw_storage_plus::Item;
 use thiserror::Error;
@@ -0,0 +1,62 @@
+use cosmwasm_std::{Coin, Response, Storage};
+use cw_common::xcall_connection_msg::NetworkLimits;
+use cw_xcall_lib::network_address::NetId;
+
+use crate::{error::ContractError, state::CwIbcConnection, types::message_window::MessageWindow};
+
+impl<'a> CwIbcConnection<'a> {
+    /// Sets the outbound limits of `nid`. Window limits need a window of at least one block.
+    pub fn set_network_limits(
+        &self,
+        store: &mut dyn Storage,
+        nid: NetId,
+        limits: NetworkLimits,
+    ) -> Result<Response, ContractError> {
+        if limits.window_blocks == 0 && (limits.max_messages > 0 || limits.max_value_messages > 0) {
+            return Err(ContractError::InvalidNetworkLimits);
+        }
+        self.store_network_limits(store, &nid, &limits)?;
+        Ok(Response::new()
+            .add_attribute("method", "set_network_limits")
+            .add_attribute("nid", nid.to_string()))
+    }
+
+    /// Counts a message sent to `nid` at block `height` in the current window of `nid`, failing
+    /// when it exceeds the messages, or the messages carrying `funds`, allowed per window.
+    pub fn ensure_within_rate_limits(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        height: u64,
+        funds: &[Coin],
+    ) -> Result<(), ContractError> {
+        let limits = self.get_network_limits(store, nid)?;
+        if limits.window_blocks == 0 {
+            return Ok(());
+        }
+        let start = height - height % limits.window_blocks;
+        let mut window = self.get_message_window(store, nid)?;
+        if window.start != start {
+            window = MessageWindow {
+                start,
+                ..Default::default()
+            };
+        }
+
+        window.messages += 1;
+        if limits.max_messages > 0 && window.messages > limits.max_messages {
+            return Err(ContractError::MessageRateLimitExceeded {
+                nid: nid.to_string(),
+            });
+        }
+        if funds.iter().any(|coin| !coin.amount.is_zero()) {
+            window.value_messages += 1;
+            if limits.max_value_messages > 0 && window.value_messages > limits.max_value_messages {
+                return Err(ContractError::ValueRateLimitExceeded {
+                    nid: nid.to_string(),
+                });
+            }
+        }
+        self.store_message_window(store, nid, &window)
+    }
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::{DepsMut, Response, Storage};
+use cw_common::xcall_connection_msg::PauseStatusResponse;
+use cw_xcall_lib::network_address::NetId;
+
+use crate::{error::ContractError, state::CwIbcConnection};
+
+impl<'a> CwIbcConnection<'a> {
+    /// Stops message flow with `nid`, or with every network when `nid` is not provided. Messages
+    /// sent to a paused network are rejected and packets received from it are queued until they are
+    /// processed with `process_queued`.
+    pub fn pause(
+        &self,
+        store: &mut dyn Storage,
+        nid: Option<NetId>,
+    ) -> Result<Response, ContractError> {
+        match &nid {
+            Some(nid) => self.set_network_paused(store, nid, true)?,
+            None => self.set_paused(store, true)?,
+        }
+        Ok(Response::new()
+            .add_attribute("method", "pause")
+            .add_attribute("nid", nid.map(|nid| nid.to_string()).unwrap_or_default()))
+    }
+
+    /// Resumes message flow with `nid`, or lifts the pause of the whole contract when `nid` is not
+    /// provided. Packets queued meanwhile are delivered with `process_queued`, packets received
+    /// from a network keep being queued until its queue is empty.
+    pub fn unpause(
+        &self,
+        store: &mut dyn Storage,
+        nid: Option<NetId>,
+    ) -> Result<Response, ContractError> {
+        match &nid {
+            Some(nid) => self.set_network_paused(store, nid, false)?,
+            None => self.set_paused(store, false)?,
+        }
+        Ok(Response::new()
+            .add_attribute("method", "unpause")
+            .add_attribute("nid", nid.map(|nid| nid.to_string()).unwrap_or_default()))
+    }
+
+    /// Delivers up to `limit` packets queued for `nid` in the order they were received.
+    pub fn process_queued(
+        &self,
+        mut deps: DepsMut,
+        nid: NetId,
+        limit: u32,
+    ) -> Result<Response, ContractError> {
+        self.ensure_not_paused(deps.storage, &nid)?;
+        let queued = self.get_queued_packets(deps.storage, &nid, limit as usize)?;
+
+        let mut response = Response::new()
+            .add_attribute("method", "process_queued")
+            .add_attribute("nid", nid.to_string())
+            .add_attribute("processed", queued.len().to_string());
+        for (channel_id, sequence, queued) in queued {
+            self.remove_queued_packet(deps.storage, &nid, &channel_id, sequence)?;
+            let receive =
+                self.deliver_packet(deps.branch(), &nid, queued.packet, queued.relayer)?;
+            response = response
+                .add_submessages(receive.messages)
+                .add_attributes(receive.attributes)
+                .add_events(receive.events);
+        }
+        Ok(response)
+    }
+
+    pub fn is_paused(&self, store: &dyn Storage, nid: &NetId) -> bool {
+        self.is_contract_paused(store) || self.is_network_paused(store, nid)
+    }
+
+    pub fn ensure_not_paused(&self, store: &dyn Storage, nid: &NetId) -> Result<(), ContractError> {
+        if self.is_contract_paused(store) {
+            return Err(ContractError::ContractPaused);
+        }
+        if self.is_network_paused(store, nid) {
+            return Err(ContractError::NetworkPaused {
+                nid: nid.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn get_pause_status(
+        &self,
+        store: &dyn Storage,
+    ) -> Result<PauseStatusResponse, ContractError> {
+        Ok(PauseStatusResponse {
+            paused: self.is_contract_paused(store),
+            paused_networks: self.get_paused_networks(store)?,
+            queued_packets: self.get_total_queued_packet_count(store)?,
+        })
+    }
+}
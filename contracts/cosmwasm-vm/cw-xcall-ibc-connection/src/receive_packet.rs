@@ -1,11 +1,14 @@
 use std::str::from_utf8;
 
 use super::*;
-use crate::types::message::Message;
+use crate::types::{
+    message::Message, network_fees::DEFAULT_FEE_DENOM, queued_packet::QueuedPacket,
+};
 
 use common::rlp;
-use cosmwasm_std::{coins, BankMsg, DepsMut};
+use cosmwasm_std::DepsMut;
 use cw_common::cw_println;
+use cw_xcall_lib::network_address::NetId;
 
 impl<'a> CwIbcConnection<'a> {
     /// This function receives packet data, decodes it, and then handles either a request or a response
@@ -32,20 +35,58 @@ impl<'a> CwIbcConnection<'a> {
         relayer: Addr,
     ) -> Result<CwReceiveResponse, ContractError> {
         let channel = packet.dest.channel_id.clone();
-        let n_message: Message = rlp::decode(&packet.data.0).unwrap();
         let channel_config = self.get_channel_config(deps.as_ref().storage, &channel)?;
         let nid = channel_config.counterparty_nid;
-        let denom = self.get_denom(deps.as_ref().storage)?;
+        if self.is_paused(deps.as_ref().storage, &nid)
+            || self.get_queued_packet_count(deps.as_ref().storage, &nid) > 0
+        {
+            cw_println!(
+                deps,
+                "[IBCConnection]: {nid} is paused or has queued packets, queueing packet"
+            );
+            let sequence = packet.sequence;
+            self.store_queued_packet(deps.storage, &nid, &QueuedPacket { packet, relayer })?;
+            return Ok(CwReceiveResponse::new()
+                .add_attribute("method", "queue_packet")
+                .add_attribute("sequence", sequence.to_string()));
+        }
+        self.deliver_packet(deps, &nid, packet, relayer)
+    }
+
+    /// Delivers `packet` received from `nid`, bypassing the queue.
+    pub fn deliver_packet(
+        &self,
+        deps: DepsMut,
+        nid: &NetId,
+        packet: CwPacket,
+        relayer: Addr,
+    ) -> Result<CwReceiveResponse, ContractError> {
+        let channel = packet.dest.channel_id.clone();
+        let n_message: Message = rlp::decode(&packet.data.0).unwrap();
         if n_message.sn.is_none() {
-            let receiver_address = from_utf8(&n_message.data).unwrap();
-            let amount = n_message.fee;
-            let msg = BankMsg::Send {
-                to_address: receiver_address.to_string(),
-                amount: coins(amount, denom),
-            };
-            return Ok(CwReceiveResponse::new().add_message(msg));
+            let receiver_address =
+                from_utf8(&n_message.data).map_err(|error| ContractError::DecodeFailed {
+                    error: error.to_string(),
+                })?;
+            let receiver_address = deps.api.addr_validate(receiver_address)?;
+            let msgs = self.pay_or_credit_fee(
+                deps.storage,
+                nid,
+                receiver_address.as_str(),
+                n_message.denom.as_deref().unwrap_or(DEFAULT_FEE_DENOM),
+                n_message.fee,
+            )?;
+            return Ok(CwReceiveResponse::new()
+                .add_messages(msgs)
+                .add_attribute("method", "receive_claim"));
         }
-        self.add_unclaimed_packet_fees(deps.storage, &nid, relayer.as_str(), n_message.fee)?;
+        self.add_unclaimed_packet_fees(
+            deps.storage,
+            nid,
+            relayer.as_str(),
+            n_message.denom.as_deref().unwrap_or(DEFAULT_FEE_DENOM),
+            n_message.fee,
+        )?;
 
         if let Some(sn) = n_message.sn.0 {
             if sn > 0 {
@@ -54,7 +95,7 @@ impl<'a> CwIbcConnection<'a> {
         }
         cw_println!(deps, "[IBCConnection]: forwarding to xcall");
         let data = n_message.data;
-        let xcall_submessage = self.call_xcall_handle_message(deps.storage, &nid, data)?;
+        let xcall_submessage = self.call_xcall_handle_message(deps.storage, nid, data)?;
 
         Ok(CwReceiveResponse::new().add_submessage(xcall_submessage))
     }
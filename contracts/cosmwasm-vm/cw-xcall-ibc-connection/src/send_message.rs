@@ -1,11 +1,17 @@
 use common::rlp::Nullable;
-use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, Storage, Uint128};
+use cosmwasm_std::{from_json, Addr, Coin, DepsMut, Env, MessageInfo, Response, StdError, Storage};
+use cw20::Cw20ReceiveMsg;
+use cw_common::xcall_connection_msg::Cw20HookMsg;
 use cw_xcall_lib::network_address::NetId;
 
 use crate::{
     error::ContractError,
-    state::{CwIbcConnection, IbcConfig},
-    types::{message::Message, LOG_PREFIX},
+    state::CwIbcConnection,
+    types::{
+        message::Message,
+        network_fees::{CW20_PREFIX, DEFAULT_FEE_DENOM},
+        LOG_PREFIX,
+    },
 };
 
 impl<'a> CwIbcConnection<'a> {
@@ -13,47 +19,90 @@ impl<'a> CwIbcConnection<'a> {
         &self,
         deps: DepsMut,
         info: MessageInfo,
-        _env: Env,
+        env: Env,
         nid: NetId,
         sn: i64,
         message: Vec<u8>,
     ) -> Result<Response, ContractError> {
         self.ensure_xcall_handler(deps.as_ref().storage, info.sender)?;
+        self.send_message_with_funds(deps, env, info.funds, nid, sn, message)
+    }
 
-        println!("{LOG_PREFIX} Packet Validated");
-        let ibc_config = self.get_ibc_config(deps.as_ref().storage, &nid)?;
+    /// Handles the CW20 tokens the xcall host sends to pay the fee of a message, the token is
+    /// accepted as `cw20:{address}` fee denom. Only tokens configured as fee denom of the network
+    /// are accepted, the sender of the hook is vouched for by the token contract alone.
+    pub fn receive_cw20(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        wrapper: Cw20ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        self.ensure_xcall_handler(deps.as_ref().storage, Addr::unchecked(wrapper.sender))?;
+        let denom = format!("{CW20_PREFIX}{}", info.sender);
+        match from_json(&wrapper.msg)? {
+            Cw20HookMsg::SendMessage { to, sn, msg } => {
+                let accepted = self
+                    .get_fee_options(deps.as_ref().storage, &to)?
+                    .iter()
+                    .any(|(fee_denom, _)| fee_denom == &denom);
+                if !accepted {
+                    return Err(ContractError::FeeDenomNotAccepted {
+                        nid: to.to_string(),
+                        denom,
+                    });
+                }
+                let funds = vec![Coin::new(wrapper.amount.u128(), denom)];
+                self.send_message_with_funds(deps, env, funds, to, sn, msg)
+            }
+        }
+    }
 
+    fn send_message_with_funds(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        funds: Vec<Coin>,
+        nid: NetId,
+        sn: i64,
+        message: Vec<u8>,
+    ) -> Result<Response, ContractError> {
+        self.ensure_not_paused(deps.as_ref().storage, &nid)?;
+        self.ensure_data_length(deps.as_ref().storage, &nid, message.len())?;
+
+        println!("{LOG_PREFIX} Packet Validated");
         if sn < 0 {
-            return self.write_acknowledgement(deps.storage, &ibc_config, message, -sn);
+            return self.write_acknowledgement(deps.storage, &nid, message, -sn);
         }
+        self.ensure_within_rate_limits(deps.storage, &nid, env.block.height, &funds)?;
+
+        let ibc_config = self.get_open_ibc_config(deps.as_ref(), &nid)?;
 
         let sequence_number_host = self.query_host_sequence_no(deps.as_ref(), &ibc_config)?;
-        let network_fee = self.get_network_fees(deps.as_ref().storage, nid.clone());
-        let mut total_fee = network_fee.send_packet_fee;
+        let (fee_denom, network_fee) =
+            self.select_fee_option(deps.as_ref().storage, &nid, &funds, sn > 0)?;
 
         if sn > 0 {
-            total_fee += network_fee.ack_fee;
             self.add_unclaimed_ack_fees(
                 deps.storage,
                 &nid,
                 sequence_number_host,
+                &fee_denom,
                 network_fee.ack_fee,
             )?;
         }
-        let config = self.get_config(deps.storage)?;
-
-        let fund = get_amount_for_denom(&info.funds, config.denom);
-
-        if fund < total_fee.into() {
-            return Err(ContractError::InsufficientFunds {});
-        }
-
         let timeout_height =
             self.query_timeout_height(deps.as_ref(), &ibc_config.src_endpoint().channel_id)?;
+        let timeout_timestamp = self.get_timeout_timestamp(
+            deps.as_ref().storage,
+            &env,
+            &ibc_config.src_endpoint().channel_id,
+        )?;
         let msg = Message {
             sn: Nullable::new(Some(sn)),
             fee: network_fee.send_packet_fee,
             data: message,
+            denom: (fee_denom != DEFAULT_FEE_DENOM).then_some(fee_denom),
         };
 
         #[cfg(feature = "native_ibc")]
@@ -70,8 +119,13 @@ impl<'a> CwIbcConnection<'a> {
 
         #[cfg(not(feature = "native_ibc"))]
         {
-            let packet_data =
-                self.create_packet(ibc_config, timeout_height, sequence_number_host, msg);
+            let packet_data = self.create_packet(
+                ibc_config,
+                timeout_height,
+                timeout_timestamp,
+                sequence_number_host,
+                msg,
+            );
 
             println!("{} Raw Packet Created {:?}", LOG_PREFIX, &packet_data);
 
@@ -82,29 +136,28 @@ impl<'a> CwIbcConnection<'a> {
         }
     }
 
+    /// Acknowledges the packet `sn` on the channel of `nid` it was received on.
     fn write_acknowledgement(
         &self,
         store: &mut dyn Storage,
-        config: &IbcConfig,
+        nid: &NetId,
         msg: Vec<u8>,
         sn: i64,
     ) -> Result<Response, ContractError> {
-        let channel_id = config.src_endpoint().channel_id.clone();
-        let packet = self.get_incoming_packet(store, &channel_id, sn)?;
+        let (channel_id, packet) = self
+            .get_counterparty_channels(store, nid)?
+            .into_iter()
+            .find_map(|channel_id| {
+                self.get_incoming_packet(store, &channel_id, sn)
+                    .ok()
+                    .map(|packet| (channel_id, packet))
+            })
+            .ok_or(ContractError::Std(StdError::not_found("CwPacket")))?;
         self.remove_incoming_packet(store, &channel_id, sn);
         let submsg = self.call_host_write_acknowledgement(store, packet, msg)?;
         Ok(Response::new().add_submessage(submsg))
     }
 }
-
-fn get_amount_for_denom(funds: &Vec<Coin>, target_denom: String) -> Uint128 {
-    for coin in funds.iter() {
-        if coin.denom == target_denom {
-            return coin.amount;
-        }
-    }
-    Uint128::zero()
-}
 #[cfg(feature = "native_ibc")]
 impl<'a> CwIbcConnection<'a> {
     /// This function creates an IBC message to send a packet with a timeout to a destination endpoint.
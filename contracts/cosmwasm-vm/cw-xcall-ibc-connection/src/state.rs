@@ -1,17 +1,20 @@
+use cosmwasm_std::Order;
+use cw_common::xcall_connection_msg::NetworkLimits;
 use cw_storage_plus::Map;
 use cw_xcall_lib::network_address::NetId;
 
 use crate::types::{
-    channel_config::ChannelConfig, config::Config, connection_config::ConnectionConfig,
-    network_fees::NetworkFees,
+    channel_config::ChannelConfig,
+    config::Config,
+    connection_config::ConnectionConfig,
+    message_window::MessageWindow,
+    network_fees::{DenomFee, NetworkFees, DEFAULT_FEE_DENOM},
+    queued_packet::{QueuedPacket, MAX_QUEUED_PACKETS},
 };
 
 use super::*;
 
 /// These are constants defined in the `CwIbcConnection` struct that are used throughout the codebase.
-pub const MAX_DATA_SIZE: u64 = 2048;
-pub const MAX_ROLLBACK_SIZE: u64 = 1024;
-
 pub const ACK_FAILURE_ID: u64 = 0;
 
 pub const XCALL_HANDLE_MESSAGE_REPLY_ID: u64 = 1;
@@ -82,9 +85,11 @@ impl IbcConfig {
 /// reference to a string that represents the address of the admin of the call service. The `Item` type
 /// is a wrapper around a reference to a value of a
 ///
-/// * `ibc_config`: This property is of type `Map<'a, NetId,IbcConfig>` and represents the IBC configuration
-/// for the given network. It is likely used to define the parameters and settings for inter-blockchain
-/// communication.
+/// * `ibc_config`: This property is of type `Map<'a, String, IbcConfig>` and stores the source and
+/// destination endpoints of each channel, by channel id.
+///
+/// * `network_channels`: `network_channels` stores the channels of a network in priority order,
+/// messages are sent over the first one that is open.
 ///
 ///  * `ibc_host`: `ibc_host` is a field of type `Item<'a, Addr>` in a struct called `CwIbcConnection`. It
 /// is likely used to store the address of the IBC host that the `CwIbcConnection` interacts with. The
@@ -101,32 +106,71 @@ impl IbcConfig {
 ///
 /// * `channel_configs`: `channel_configs` stores ChannelConfig for given channel.
 ///
-/// * `network_fees`: `network_fees` stores NetworkFeesInfo for given network id.
+/// * `counterparty_channels`: `counterparty_channels` indexes the channels by the network id of
+/// their counterparty.
+///
+/// * `network_fees`: `network_fees` stores NetworkFeesInfo for given network id and accepted denom.
+///
+/// * `fee_denom_networks`: `fee_denom_networks` stores the networks decoding the fee denom of
+/// messages, other networks only accept fees in the default denom.
 ///
-/// * `unclaimed_packet_fees`: `unclaimed_packet_fees` acculumulated packet fees for given network and relayer.
+/// * `unclaimed_packet_fees`: `unclaimed_packet_fees` acculumulated packet fees for given network,
+/// relayer and denom the fees were paid in on the network.
 ///
-/// * `unclaimed_ack_fees`: `unclaimed_ack_fees` stores ack fee for given packet by networkId.
+/// * `unclaimed_ack_fees`: `unclaimed_ack_fees` stores ack fee and its denom for given packet by networkId.
+///
+/// * `local_fees`: `local_fees` stores the fees held by this contract for given network, address and
+/// denom the fees were paid in on the network, the ack fees of relayers and the fees claimed from
+/// the network.
+///
+/// * `fee_crediting_accounts`: `fee_crediting_accounts` stores the addresses whose fees are held in
+/// `local_fees` rather than paid out on arrival.
 ///
 /// * `incoming_packets`: `incoming_packets` stores incoming packets for reference.
 ///
 /// * `outgoing_packets`: `outgoing_packets` stores outgoing packets for reference.
 ///
+/// * `paused`: `paused` stops message flow with every network while set.
+///
+/// * `paused_networks`: `paused_networks` stores the networks message flow is stopped with.
+///
+/// * `queued_packets`: `queued_packets` stores packets received from paused networks by network,
+/// channel and sequence.
+///
+/// * `queued_packet_counts`: `queued_packet_counts` counts the packets queued for given network id.
+///
+/// * `network_limits`: `network_limits` stores the outbound limits set for given network id.
+///
+/// * `message_windows`: `message_windows` counts the messages sent to given network id in the
+/// current block window.
+///
 
 pub struct CwIbcConnection<'a> {
     owner: Item<'a, Addr>,
     config: Item<'a, Config>,
     admin: Item<'a, Addr>,
-    ibc_config: Map<'a, NetId, IbcConfig>,
+    ibc_config: Map<'a, String, IbcConfig>,
+    network_channels: Map<'a, NetId, Vec<String>>,
     ibc_host: Item<'a, Addr>,
     xcall_host: Item<'a, Addr>,
     configured_networks: Map<'a, (String, String), NetId>,
     connection_configs: Map<'a, String, ConnectionConfig>,
     channel_configs: Map<'a, String, ChannelConfig>,
-    network_fees: Map<'a, NetId, NetworkFees>,
-    unclaimed_packet_fees: Map<'a, (String, String), u128>,
-    unclaimed_ack_fees: Map<'a, (String, u64), u128>,
+    counterparty_channels: Map<'a, (String, String), bool>,
+    network_fees: Map<'a, (String, String), NetworkFees>,
+    fee_denom_networks: Map<'a, NetId, bool>,
+    unclaimed_packet_fees: Map<'a, (String, String, String), u128>,
+    unclaimed_ack_fees: Map<'a, (String, u64), DenomFee>,
+    local_fees: Map<'a, (String, String, String), u128>,
+    fee_crediting_accounts: Map<'a, String, bool>,
     incoming_packets: Map<'a, (String, i64), CwPacket>,
     outgoing_packets: Map<'a, (String, u64), i64>,
+    paused: Item<'a, bool>,
+    paused_networks: Map<'a, NetId, bool>,
+    queued_packets: Map<'a, (String, String, u64), QueuedPacket>,
+    queued_packet_counts: Map<'a, NetId, u64>,
+    network_limits: Map<'a, NetId, NetworkLimits>,
+    message_windows: Map<'a, NetId, MessageWindow>,
 }
 
 impl<'a> Default for CwIbcConnection<'a> {
@@ -141,17 +185,28 @@ impl<'a> CwIbcConnection<'a> {
             owner: Item::new(StorageKey::Owner.as_str()),
             config: Item::new(StorageKey::Config.as_str()),
             admin: Item::new(StorageKey::Admin.as_str()),
-            ibc_config: Map::new(StorageKey::IbcConfig.as_str()),
+            ibc_config: Map::new(StorageKey::ChannelIbcConfigs.as_str()),
+            network_channels: Map::new(StorageKey::NetworkChannels.as_str()),
             ibc_host: Item::new(StorageKey::IbcHost.as_str()),
             xcall_host: Item::new(StorageKey::XCallHost.as_str()),
             configured_networks: Map::new(StorageKey::ConfiguredNetworks.as_str()),
             channel_configs: Map::new(StorageKey::ChannelConfigs.as_str()),
             connection_configs: Map::new(StorageKey::ConnectionConfigs.as_str()),
-            network_fees: Map::new(StorageKey::NetworkFees.as_str()),
-            unclaimed_packet_fees: Map::new(StorageKey::UnclaimedPacketFees.as_str()),
-            unclaimed_ack_fees: Map::new(StorageKey::UnClaimedAckFees.as_str()),
+            counterparty_channels: Map::new(StorageKey::CounterpartyChannels.as_str()),
+            network_fees: Map::new(StorageKey::NetworkDenomFees.as_str()),
+            fee_denom_networks: Map::new(StorageKey::FeeDenomNetworks.as_str()),
+            unclaimed_packet_fees: Map::new(StorageKey::UnclaimedDenomPacketFees.as_str()),
+            unclaimed_ack_fees: Map::new(StorageKey::UnclaimedDenomAckFees.as_str()),
+            local_fees: Map::new(StorageKey::LocalFees.as_str()),
+            fee_crediting_accounts: Map::new(StorageKey::FeeCreditingAccounts.as_str()),
             incoming_packets: Map::new(StorageKey::IncomingPackets.as_str()),
             outgoing_packets: Map::new(StorageKey::OutGoingPackets.as_str()),
+            paused: Item::new(StorageKey::Paused.as_str()),
+            paused_networks: Map::new(StorageKey::PausedNetworks.as_str()),
+            queued_packets: Map::new(StorageKey::QueuedPackets.as_str()),
+            queued_packet_counts: Map::new(StorageKey::QueuedPacketCounts.as_str()),
+            network_limits: Map::new(StorageKey::NetworkLimits.as_str()),
+            message_windows: Map::new(StorageKey::MessageWindows.as_str()),
         }
     }
 
@@ -176,30 +231,156 @@ impl<'a> CwIbcConnection<'a> {
         self.config.save(store, config).map_err(ContractError::Std)
     }
 
+    /// Returns the config of the channel with the highest priority of `nid`.
     pub fn get_ibc_config(
         &self,
         store: &dyn Storage,
         nid: &NetId,
+    ) -> Result<IbcConfig, ContractError> {
+        let channels = self.get_network_channels(store, nid);
+        let channel_id = channels
+            .first()
+            .ok_or(ContractError::NetworkNotConfigured {
+                nid: nid.to_string(),
+            })?;
+        self.get_channel_ibc_config(store, channel_id)
+    }
+
+    pub fn get_channel_ibc_config(
+        &self,
+        store: &dyn Storage,
+        channel_id: &str,
     ) -> Result<IbcConfig, ContractError> {
         self.ibc_config
-            .load(store, nid.to_owned())
+            .load(store, channel_id.to_owned())
             .map_err(ContractError::Std)
     }
 
+    /// Stores the config of the source channel of `config` and registers the channel for `nid`
+    /// with the lowest priority if it is not registered yet.
     pub fn store_ibc_config(
         &self,
         store: &mut dyn Storage,
         nid: &NetId,
         config: &IbcConfig,
     ) -> Result<(), ContractError> {
+        let channel_id = config.src_endpoint().channel_id.clone();
         self.ibc_config
-            .save(store, nid.to_owned(), config)
+            .save(store, channel_id.clone(), config)
+            .map_err(ContractError::Std)?;
+
+        let mut channels = self.get_network_channels(store, nid);
+        if !channels.contains(&channel_id) {
+            channels.push(channel_id);
+            self.store_network_channels(store, nid, &channels)?;
+        }
+        Ok(())
+    }
+
+    /// Stops routing messages of `nid` over `channel_id`. Packets received on the channel are
+    /// still accepted and acknowledged since its channel config is kept.
+    pub fn remove_ibc_config(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        channel_id: &str,
+    ) -> Result<(), ContractError> {
+        let mut channels = self.get_network_channels(store, nid);
+        if !channels.iter().any(|channel| channel == channel_id) {
+            return Err(ContractError::ChannelNotRegistered {
+                nid: nid.to_string(),
+                channel_id: channel_id.to_string(),
+            });
+        }
+        channels.retain(|channel| channel != channel_id);
+        self.ibc_config.remove(store, channel_id.to_owned());
+        self.store_network_channels(store, nid, &channels)
+    }
+
+    pub fn get_network_channels(&self, store: &dyn Storage, nid: &NetId) -> Vec<String> {
+        self.network_channels
+            .load(store, nid.to_owned())
+            .unwrap_or_default()
+    }
+
+    pub fn store_network_channels(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        channels: &Vec<String>,
+    ) -> Result<(), ContractError> {
+        self.network_channels
+            .save(store, nid.to_owned(), channels)
             .map_err(ContractError::Std)
     }
 
-    pub fn clear_ibc_config(&self, store: &mut dyn Storage, nid: &NetId) {
-        self.ibc_config.remove(store, nid.to_owned());
+    /// Registers the configs stored by network before several channels could serve a network.
+    pub fn migrate_ibc_configs(&self, store: &mut dyn Storage) -> Result<(), ContractError> {
+        let legacy: Map<String, IbcConfig> = Map::new(StorageKey::IbcConfig.as_str());
+        let configs = legacy
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (nid, config) in configs {
+            self.store_ibc_config(store, &NetId::from(nid.clone()), &config)?;
+            legacy.remove(store, nid);
+        }
+        Ok(())
+    }
+
+    /// Moves the fees stored before several denoms were accepted to the default denom.
+    pub fn migrate_fees(&self, store: &mut dyn Storage) -> Result<(), ContractError> {
+        let legacy_fees: Map<String, NetworkFees> = Map::new(StorageKey::NetworkFees.as_str());
+        let fees = legacy_fees
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (nid, network_fees) in fees {
+            self.store_network_fees(
+                store,
+                &NetId::from(nid.clone()),
+                DEFAULT_FEE_DENOM,
+                &network_fees,
+            )?;
+            legacy_fees.remove(store, nid);
+        }
+
+        let legacy_packet_fees: Map<(String, String), u128> =
+            Map::new(StorageKey::UnclaimedPacketFees.as_str());
+        let packet_fees = legacy_packet_fees
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for ((nid, relayer), amount) in packet_fees {
+            if amount > 0 {
+                self.add_unclaimed_packet_fees(
+                    store,
+                    &NetId::from(nid.clone()),
+                    &relayer,
+                    DEFAULT_FEE_DENOM,
+                    amount,
+                )?;
+            }
+            legacy_packet_fees.remove(store, (nid, relayer));
+        }
+
+        let legacy_ack_fees: Map<(String, u64), u128> =
+            Map::new(StorageKey::UnClaimedAckFees.as_str());
+        let ack_fees = legacy_ack_fees
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for ((nid, sequence), amount) in ack_fees {
+            if amount > 0 {
+                self.add_unclaimed_ack_fees(
+                    store,
+                    &NetId::from(nid.clone()),
+                    sequence,
+                    DEFAULT_FEE_DENOM,
+                    amount,
+                )?;
+            }
+            legacy_ack_fees.remove(store, (nid, sequence));
+        }
+        Ok(())
     }
+
     pub fn set_ibc_host(
         &self,
         store: &mut dyn Storage,
@@ -242,11 +423,58 @@ impl<'a> CwIbcConnection<'a> {
         channel: &str,
         channel_config: &ChannelConfig,
     ) -> Result<(), ContractError> {
+        if let Some(previous) = self.channel_configs.may_load(store, channel.to_owned())? {
+            self.counterparty_channels.remove(
+                store,
+                (previous.counterparty_nid.to_string(), channel.to_owned()),
+            );
+        }
+        self.counterparty_channels.save(
+            store,
+            (
+                channel_config.counterparty_nid.to_string(),
+                channel.to_owned(),
+            ),
+            &true,
+        )?;
         self.channel_configs
             .save(store, channel.to_owned(), channel_config)
             .map_err(ContractError::Std)
     }
 
+    /// Returns the channels whose packets come from `nid`, including channels that are no longer
+    /// used to send messages.
+    pub fn get_counterparty_channels(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+    ) -> Result<Vec<String>, ContractError> {
+        self.counterparty_channels
+            .prefix(nid.to_string())
+            .keys(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Indexes the channels stored before they were indexed by counterparty network.
+    pub fn migrate_counterparty_channels(
+        &self,
+        store: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let channels = self
+            .channel_configs
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (channel_id, config) in channels {
+            self.counterparty_channels.save(
+                store,
+                (config.counterparty_nid.to_string(), channel_id),
+                &true,
+            )?;
+        }
+        Ok(())
+    }
+
     pub fn get_connection_config(
         &self,
         store: &dyn Storage,
@@ -291,37 +519,86 @@ impl<'a> CwIbcConnection<'a> {
             .map_err(ContractError::Std)
     }
 
-    pub fn get_network_fees(&self, store: &dyn Storage, nid: NetId) -> NetworkFees {
+    pub fn get_network_fees(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+        denom: &str,
+    ) -> Option<NetworkFees> {
+        self.network_fees
+            .load(store, (nid.to_string(), denom.to_owned()))
+            .ok()
+    }
+
+    /// Returns the fees of `nid` in every accepted denom, the default denom first. Only the
+    /// default denom is accepted for networks not decoding the fee denom.
+    pub fn get_fee_options(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+    ) -> Result<Vec<(String, NetworkFees)>, ContractError> {
+        let fee_denom_supported = self.is_fee_denom_supported(store, nid);
         self.network_fees
-            .load(store, nid)
-            .unwrap_or(NetworkFees::default())
+            .prefix(nid.to_string())
+            .range(store, None, None, Order::Ascending)
+            .filter(|entry| {
+                fee_denom_supported || matches!(entry, Ok((denom, _)) if denom == DEFAULT_FEE_DENOM)
+            })
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(ContractError::Std)
+    }
+
+    pub fn set_fee_denom_supported(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        supported: bool,
+    ) -> Result<(), ContractError> {
+        if supported {
+            self.fee_denom_networks.save(store, nid.clone(), &true)?;
+        } else {
+            self.fee_denom_networks.remove(store, nid.clone());
+        }
+        Ok(())
+    }
+
+    pub fn is_fee_denom_supported(&self, store: &dyn Storage, nid: &NetId) -> bool {
+        self.fee_denom_networks.has(store, nid.clone())
     }
 
     pub fn store_network_fees(
         &self,
         store: &mut dyn Storage,
-        nid: NetId,
+        nid: &NetId,
+        denom: &str,
         network_fees: &NetworkFees,
     ) -> Result<(), ContractError> {
         self.network_fees
-            .save(store, nid, network_fees)
+            .save(store, (nid.to_string(), denom.to_owned()), network_fees)
             .map_err(ContractError::Std)
     }
 
+    pub fn remove_network_fees(&self, store: &mut dyn Storage, nid: &NetId, denom: &str) {
+        self.network_fees
+            .remove(store, (nid.to_string(), denom.to_owned()));
+    }
+
+    /// `denom` is the denom the fees were paid in on `nid`, empty for its default fee denom.
     pub fn add_unclaimed_packet_fees(
         &self,
         store: &mut dyn Storage,
         nid: &NetId,
         address: &str,
+        denom: &str,
         value: u128,
     ) -> Result<(), ContractError> {
-        let mut acc = self
-            .unclaimed_packet_fees
-            .load(store, (nid.to_string(), address.to_string()))
-            .unwrap_or(0);
-        acc += value;
+        let acc = self.get_unclaimed_packet_fee(store, nid, address, denom);
         self.unclaimed_packet_fees
-            .save(store, (nid.to_string(), address.to_owned()), &acc)
+            .save(
+                store,
+                (nid.to_string(), address.to_owned(), denom.to_owned()),
+                &(acc + value),
+            )
             .map_err(ContractError::Std)
     }
 
@@ -330,21 +607,45 @@ impl<'a> CwIbcConnection<'a> {
         store: &dyn Storage,
         nid: &NetId,
         address: &str,
+        denom: &str,
     ) -> u128 {
         self.unclaimed_packet_fees
-            .load(store, (nid.to_string(), address.to_owned()))
+            .load(
+                store,
+                (nid.to_string(), address.to_owned(), denom.to_owned()),
+            )
             .unwrap_or(0)
     }
 
+    /// Returns the packet fees accrued by `address` as (nid, denom, amount) for every network.
+    pub fn get_unclaimed_packet_fees_of(
+        &self,
+        store: &dyn Storage,
+        address: &str,
+    ) -> Result<Vec<(String, String, u128)>, ContractError> {
+        self.unclaimed_packet_fees
+            .range(store, None, None, Order::Ascending)
+            .filter_map(|entry| match entry {
+                Ok(((nid, relayer, denom), amount)) if relayer == address && amount > 0 => {
+                    Some(Ok((nid, denom, amount)))
+                }
+                Ok(_) => None,
+                Err(error) => Some(Err(ContractError::Std(error))),
+            })
+            .collect()
+    }
+
     pub fn reset_unclaimed_packet_fees(
         &self,
         store: &mut dyn Storage,
         nid: &NetId,
         address: &str,
-    ) -> Result<(), ContractError> {
-        self.unclaimed_packet_fees
-            .save(store, (nid.to_string(), address.to_owned()), &0_u128)
-            .map_err(ContractError::Std)
+        denom: &str,
+    ) {
+        self.unclaimed_packet_fees.remove(
+            store,
+            (nid.to_string(), address.to_owned(), denom.to_owned()),
+        );
     }
 
     pub fn add_unclaimed_ack_fees(
@@ -352,24 +653,98 @@ impl<'a> CwIbcConnection<'a> {
         store: &mut dyn Storage,
         nid: &NetId,
         sequence: u64,
+        denom: &str,
         value: u128,
     ) -> Result<(), ContractError> {
-        let mut acc = self
-            .unclaimed_ack_fees
-            .load(store, (nid.to_string(), sequence))
-            .unwrap_or(0);
-        acc += value;
+        let amount = self
+            .get_unclaimed_ack_fee(store, nid.as_str(), sequence)
+            .map_or(0, |fee| fee.amount);
+        let fee = DenomFee {
+            denom: denom.to_owned(),
+            amount: amount + value,
+        };
         self.unclaimed_ack_fees
-            .save(store, (nid.to_string(), sequence), &acc)
+            .save(store, (nid.to_string(), sequence), &fee)
             .map_err(ContractError::Std)
     }
 
-    pub fn get_unclaimed_ack_fee(&self, store: &dyn Storage, nid: &str, sequence: u64) -> u128 {
+    pub fn get_unclaimed_ack_fee(
+        &self,
+        store: &dyn Storage,
+        nid: &str,
+        sequence: u64,
+    ) -> Option<DenomFee> {
         self.unclaimed_ack_fees
             .load(store, (nid.to_owned(), sequence))
+            .ok()
+    }
+
+    /// `denom` is the denom the fees were paid in on `nid`, empty for its default fee denom.
+    pub fn add_local_fees(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        address: &str,
+        denom: &str,
+        value: u128,
+    ) -> Result<(), ContractError> {
+        let acc = self.get_local_fee(store, nid, address, denom);
+        self.set_local_fee(store, nid, address, denom, acc + value)
+    }
+
+    pub fn get_local_fee(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+        address: &str,
+        denom: &str,
+    ) -> u128 {
+        self.local_fees
+            .load(
+                store,
+                (nid.to_string(), address.to_owned(), denom.to_owned()),
+            )
             .unwrap_or(0)
     }
 
+    pub fn set_local_fee(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        address: &str,
+        denom: &str,
+        value: u128,
+    ) -> Result<(), ContractError> {
+        let key = (nid.to_string(), address.to_owned(), denom.to_owned());
+        if value == 0 {
+            self.local_fees.remove(store, key);
+            return Ok(());
+        }
+        self.local_fees
+            .save(store, key, &value)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn set_fee_crediting(
+        &self,
+        store: &mut dyn Storage,
+        address: &str,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        if enabled {
+            self.fee_crediting_accounts
+                .save(store, address.to_owned(), &true)?;
+        } else {
+            self.fee_crediting_accounts
+                .remove(store, address.to_owned());
+        }
+        Ok(())
+    }
+
+    pub fn is_fee_crediting(&self, store: &dyn Storage, address: &str) -> bool {
+        self.fee_crediting_accounts.has(store, address.to_owned())
+    }
+
     pub fn get_denom(&self, store: &dyn Storage) -> Result<String, ContractError> {
         let config = self.get_config(store)?;
         Ok(config.denom)
@@ -380,15 +755,9 @@ impl<'a> CwIbcConnection<'a> {
         Ok(config.port_id)
     }
 
-    pub fn reset_unclaimed_ack_fees(
-        &self,
-        store: &mut dyn Storage,
-        nid: &str,
-        sequence: u64,
-    ) -> Result<(), ContractError> {
+    pub fn reset_unclaimed_ack_fees(&self, store: &mut dyn Storage, nid: &str, sequence: u64) {
         self.unclaimed_ack_fees
-            .save(store, (nid.to_owned(), sequence), &0_u128)
-            .map_err(ContractError::Std)
+            .remove(store, (nid.to_owned(), sequence));
     }
 
     pub fn get_incoming_packet(
@@ -417,4 +786,158 @@ impl<'a> CwIbcConnection<'a> {
             .save(store, (channel_id.to_owned(), sn), &packet)
             .map_err(ContractError::Std)
     }
+
+    pub fn set_paused(&self, store: &mut dyn Storage, paused: bool) -> Result<(), ContractError> {
+        self.paused.save(store, &paused).map_err(ContractError::Std)
+    }
+
+    pub fn is_contract_paused(&self, store: &dyn Storage) -> bool {
+        self.paused.load(store).unwrap_or(false)
+    }
+
+    pub fn set_network_paused(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        paused: bool,
+    ) -> Result<(), ContractError> {
+        if paused {
+            self.paused_networks.save(store, nid.clone(), &true)?;
+        } else {
+            self.paused_networks.remove(store, nid.clone());
+        }
+        Ok(())
+    }
+
+    pub fn is_network_paused(&self, store: &dyn Storage, nid: &NetId) -> bool {
+        self.paused_networks.has(store, nid.clone())
+    }
+
+    pub fn get_paused_networks(&self, store: &dyn Storage) -> Result<Vec<NetId>, ContractError> {
+        self.paused_networks
+            .keys(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(ContractError::Std)
+    }
+
+    /// Queues `queued` for `nid`, failing once `MAX_QUEUED_PACKETS` packets are queued for it.
+    pub fn store_queued_packet(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        queued: &QueuedPacket,
+    ) -> Result<(), ContractError> {
+        let count = self.get_queued_packet_count(store, nid);
+        if count >= MAX_QUEUED_PACKETS {
+            return Err(ContractError::QueueFull {
+                nid: nid.to_string(),
+            });
+        }
+        let key = (
+            nid.to_string(),
+            queued.packet.dest.channel_id.clone(),
+            queued.packet.sequence,
+        );
+        self.queued_packets.save(store, key, queued)?;
+        self.queued_packet_counts
+            .save(store, nid.clone(), &(count + 1))
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns up to `limit` queued packets of `nid` as (channel id, sequence, packet) in the
+    /// order they were received on each channel.
+    pub fn get_queued_packets(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+        limit: usize,
+    ) -> Result<Vec<(String, u64, QueuedPacket)>, ContractError> {
+        self.queued_packets
+            .sub_prefix(nid.to_string())
+            .range(store, None, None, Order::Ascending)
+            .take(limit)
+            .map(|entry| {
+                entry.map(|((channel_id, sequence), queued)| (channel_id, sequence, queued))
+            })
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_queued_packet_count(&self, store: &dyn Storage, nid: &NetId) -> u64 {
+        self.queued_packet_counts
+            .load(store, nid.clone())
+            .unwrap_or(0)
+    }
+
+    /// Returns the packets queued for every network.
+    pub fn get_total_queued_packet_count(&self, store: &dyn Storage) -> Result<u64, ContractError> {
+        self.queued_packet_counts
+            .range(store, None, None, Order::Ascending)
+            .map(|entry| entry.map(|(_, count)| count))
+            .sum::<StdResult<u64>>()
+            .map_err(ContractError::Std)
+    }
+
+    pub fn remove_queued_packet(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<(), ContractError> {
+        self.queued_packets
+            .remove(store, (nid.to_string(), channel_id.to_owned(), sequence));
+        match self.get_queued_packet_count(store, nid) {
+            0 | 1 => self.queued_packet_counts.remove(store, nid.clone()),
+            count => self
+                .queued_packet_counts
+                .save(store, nid.clone(), &(count - 1))?,
+        }
+        Ok(())
+    }
+
+    pub fn store_network_limits(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        limits: &NetworkLimits,
+    ) -> Result<(), ContractError> {
+        self.network_limits
+            .save(store, nid.clone(), limits)
+            .map_err(ContractError::Std)
+    }
+
+    /// Returns the limits set for `nid`, the defaults when none were set.
+    pub fn get_network_limits(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+    ) -> Result<NetworkLimits, ContractError> {
+        Ok(self
+            .network_limits
+            .may_load(store, nid.clone())?
+            .unwrap_or_default())
+    }
+
+    pub fn store_message_window(
+        &self,
+        store: &mut dyn Storage,
+        nid: &NetId,
+        window: &MessageWindow,
+    ) -> Result<(), ContractError> {
+        self.message_windows
+            .save(store, nid.clone(), window)
+            .map_err(ContractError::Std)
+    }
+
+    pub fn get_message_window(
+        &self,
+        store: &dyn Storage,
+        nid: &NetId,
+    ) -> Result<MessageWindow, ContractError> {
+        Ok(self
+            .message_windows
+            .may_load(store, nid.clone())?
+            .unwrap_or_default())
+    }
 }
@@ -5,5 +5,8 @@ use cw_xcall_lib::network_address::NetId;
 pub struct ChannelConfig {
     pub client_id: String,
     pub timeout_height: u64,
+    /// Seconds after the send time at which packets time out, zero disables timestamp timeouts.
+    #[serde(default)]
+    pub timeout_duration: u64,
     pub counterparty_nid: NetId,
 }
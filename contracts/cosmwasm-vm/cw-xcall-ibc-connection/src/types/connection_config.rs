@@ -4,4 +4,7 @@ use cosmwasm_schema::cw_serde;
 pub struct ConnectionConfig {
     pub client_id: String,
     pub timeout_height: u64,
+    /// Seconds after the send time at which packets time out, zero disables timestamp timeouts.
+    #[serde(default)]
+    pub timeout_duration: u64,
 }
@@ -1,26 +1,47 @@
 use common::rlp::{self, Decodable, Encodable, Nullable};
 
+/// Packet data exchanged between connections. `denom` is the denom `fee` was paid in on the
+/// sending chain, it is left out of the encoding for its default fee denom so counterparties
+/// reading three fields keep decoding the message.
 pub struct Message {
     pub sn: Nullable<i64>,
     pub fee: u128,
     pub data: Vec<u8>,
+    pub denom: Option<String>,
 }
 
 impl Encodable for Message {
     fn rlp_append(&self, stream: &mut rlp::RlpStream) {
-        stream.begin_list(3);
-        stream.append(&self.sn);
-        stream.append(&self.fee);
-        stream.append(&self.data);
+        match &self.denom {
+            Some(denom) => {
+                stream.begin_list(4);
+                stream.append(&self.sn);
+                stream.append(&self.fee);
+                stream.append(&self.data);
+                stream.append(denom);
+            }
+            None => {
+                stream.begin_list(3);
+                stream.append(&self.sn);
+                stream.append(&self.fee);
+                stream.append(&self.data);
+            }
+        }
     }
 }
 
 impl Decodable for Message {
     fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+        let denom = if rlp.item_count()? > 3 {
+            Some(rlp.val_at(3)?)
+        } else {
+            None
+        };
         Ok(Self {
             sn: rlp.val_at(0)?,
             fee: rlp.val_at(1)?,
             data: rlp.val_at(2)?,
+            denom,
         })
     }
 }
@@ -37,6 +58,7 @@ mod tests {
             sn: Nullable::new(Some(150)),
             fee: 10000000000000000000,
             data: hex::decode("74657374").unwrap(),
+            denom: None,
         };
         let rlp_bytes = rlp::encode(&message);
 
@@ -49,6 +71,7 @@ mod tests {
             sn: Nullable::new(None),
             fee: 200000000000000000000,
             data: hex::decode("7465737432").unwrap(),
+            denom: None,
         };
         let rlp_bytes = rlp::encode(&message);
 
@@ -57,4 +80,18 @@ mod tests {
             hex::encode(rlp_bytes)
         )
     }
+
+    #[test]
+    fn test_rlp_denom_roundtrip() {
+        let message = Message {
+            sn: Nullable::new(Some(1)),
+            fee: 10,
+            data: vec![1, 2],
+            denom: Some("cw20:token".to_string()),
+        };
+        let decoded: Message = rlp::decode(&rlp::encode(&message)).unwrap();
+        assert_eq!(decoded.denom, message.denom);
+        assert_eq!(decoded.fee, 10);
+        assert_eq!(decoded.data, vec![1, 2]);
+    }
 }
@@ -0,0 +1,10 @@
+use cosmwasm_schema::cw_serde;
+
+/// Messages sent to a network in the block window starting at `start`.
+#[cw_serde]
+#[derive(Default)]
+pub struct MessageWindow {
+    pub start: u64,
+    pub messages: u64,
+    pub value_messages: u64,
+}
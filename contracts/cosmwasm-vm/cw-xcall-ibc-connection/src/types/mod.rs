@@ -6,6 +6,8 @@ pub mod config;
 pub mod config_response;
 pub mod connection_config;
 pub mod message;
+pub mod message_window;
 pub mod network_fees;
+pub mod queued_packet;
 
 pub const LOG_PREFIX: &str = "[xcall_ibc_connection]:";
@@ -1,8 +1,22 @@
 use cosmwasm_schema::cw_serde;
 
+/// Prefix of fee denoms that refer to CW20 token contracts.
+pub const CW20_PREFIX: &str = "cw20:";
+
+/// Key the fees in the default denom are stored under: the denom of the config for fees paid on
+/// this chain, the default fee denom of the network for fees accrued there.
+pub const DEFAULT_FEE_DENOM: &str = "";
+
 #[cw_serde]
 #[derive(Default)]
 pub struct NetworkFees {
     pub send_packet_fee: u128,
     pub ack_fee: u128,
 }
+
+/// Fee held by the contract in `denom`, a bank denom or `cw20:{address}` for CW20 tokens.
+#[cw_serde]
+pub struct DenomFee {
+    pub denom: String,
+    pub amount: u128,
+}
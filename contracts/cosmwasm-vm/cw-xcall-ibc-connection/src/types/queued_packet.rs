@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_common::cw_types::CwPacket;
+
+/// Packets queued for a network at most, packets received beyond it are rejected until the queue
+/// is processed.
+pub const MAX_QUEUED_PACKETS: u64 = 100;
+
+/// Packet received while its network was paused, delivered by `process_queued` once the network
+/// is unpaused.
+#[cw_serde]
+pub struct QueuedPacket {
+    pub packet: CwPacket,
+    pub relayer: Addr,
+}
@@ -5,6 +5,8 @@ pub enum StorageKey {
     Owner,
     Admin,
     IbcConfig,
+    ChannelIbcConfigs,
+    NetworkChannels,
     IbcHost,
     TimeoutHeight,
     XCallHost,
@@ -13,13 +15,26 @@ pub enum StorageKey {
     Fee,
     ConfiguredNetworks,
     ChannelConfigs,
+    CounterpartyChannels,
     ConnectionConfigs,
     NetworkFees,
+    NetworkDenomFees,
     UnclaimedPacketFees,
+    UnclaimedDenomPacketFees,
     UnClaimedAckFees,
+    UnclaimedDenomAckFees,
     IncomingPackets,
     OutGoingPackets,
     Config,
+    Paused,
+    PausedNetworks,
+    QueuedPackets,
+    QueuedPacketCounts,
+    NetworkLimits,
+    MessageWindows,
+    FeeDenomNetworks,
+    LocalFees,
+    FeeCreditingAccounts,
 }
 
 impl StorageKey {
@@ -29,6 +44,8 @@ impl StorageKey {
             StorageKey::Config => "config",
             StorageKey::Admin => "admin",
             StorageKey::IbcConfig => "ibcconfig",
+            StorageKey::ChannelIbcConfigs => "channel_ibc_configs",
+            StorageKey::NetworkChannels => "network_channels",
             StorageKey::IbcHost => "ibc_host",
             StorageKey::TimeoutHeight => "timeout_height",
             StorageKey::XCallHost => "xcall_host",
@@ -37,12 +54,25 @@ impl StorageKey {
             StorageKey::Fee => "fee",
             StorageKey::ConfiguredNetworks => "configured_networks",
             StorageKey::ChannelConfigs => "channel_configs",
+            StorageKey::CounterpartyChannels => "counterparty_channels",
             StorageKey::ConnectionConfigs => "connection_configs",
             StorageKey::NetworkFees => "network_fees",
+            StorageKey::NetworkDenomFees => "network_denom_fees",
             StorageKey::UnclaimedPacketFees => "unclaimed_packet_fees",
+            StorageKey::UnclaimedDenomPacketFees => "unclaimed_denom_packet_fees",
             StorageKey::IncomingPackets => "incoming_packets",
             StorageKey::OutGoingPackets => "outgoing_packets",
             StorageKey::UnClaimedAckFees => "unclaimed_ack_fees",
+            StorageKey::UnclaimedDenomAckFees => "unclaimed_denom_ack_fees",
+            StorageKey::Paused => "paused",
+            StorageKey::PausedNetworks => "paused_networks",
+            StorageKey::QueuedPackets => "queued_packets",
+            StorageKey::QueuedPacketCounts => "queued_packet_counts",
+            StorageKey::NetworkLimits => "network_limits",
+            StorageKey::MessageWindows => "message_windows",
+            StorageKey::FeeDenomNetworks => "fee_denom_networks",
+            StorageKey::LocalFees => "local_fees",
+            StorageKey::FeeCreditingAccounts => "fee_crediting_accounts",
         }
     }
 }
@@ -15,8 +15,10 @@ use cosmwasm_std::{
 use cw_xcall_ibc_connection::{
     state::{CwIbcConnection, IbcConfig},
     types::{
-        channel_config::ChannelConfig, config::Config, connection_config::ConnectionConfig,
-        network_fees::NetworkFees,
+        channel_config::ChannelConfig,
+        config::Config,
+        connection_config::ConnectionConfig,
+        network_fees::{NetworkFees, DEFAULT_FEE_DENOM},
     },
 };
 
@@ -140,6 +142,7 @@ pub fn get_dummy_channel_config() -> ChannelConfig {
     ChannelConfig {
         client_id: "default_0".to_owned(),
         timeout_height: 100,
+        timeout_duration: 0,
         counterparty_nid: NetId::from_str("nid").unwrap(),
     }
 }
@@ -148,6 +151,7 @@ pub fn get_dummy_connection_config() -> ConnectionConfig {
     ConnectionConfig {
         client_id: "default_0".to_owned(),
         timeout_height: 100,
+        timeout_duration: 0,
     }
 }
 
@@ -288,7 +292,12 @@ impl TestContext {
 
     pub fn store_network_fees(&self, storage: &mut dyn Storage, contract: &CwIbcConnection) {
         contract
-            .store_network_fees(storage, self.network_id.clone(), &get_dummy_network_fees())
+            .store_network_fees(
+                storage,
+                &self.network_id,
+                DEFAULT_FEE_DENOM,
+                &get_dummy_network_fees(),
+            )
             .unwrap()
     }
 
@@ -309,6 +318,7 @@ impl TestContext {
                 self.network_id.clone(),
                 config.client_id,
                 config.timeout_height,
+                config.timeout_duration,
             )
             .unwrap()
     }
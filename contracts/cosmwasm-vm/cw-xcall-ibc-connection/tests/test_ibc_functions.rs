@@ -132,6 +132,7 @@ fn test_execute_configure_connection() {
         counterparty_nid: ctx.network_id,
         client_id: ctx.client_id,
         timeout_height: 100,
+        timeout_duration: 0,
     };
     let res = contract.execute(deps.as_mut(), ctx.env, ctx.info, msg);
     assert!(res.is_ok())
@@ -151,6 +152,7 @@ fn test_execute_override_connection() {
         counterparty_nid: NetId::from_str("new_nid").unwrap(),
         client_id: ctx.client_id,
         timeout_height: 200,
+        timeout_duration: 0,
     };
     let res = contract.execute(deps.as_mut(), ctx.env, ctx.info, msg);
     assert!(res.is_ok());
@@ -177,6 +179,7 @@ fn test_execute_set_fee() {
         nid: ctx.network_id,
         packet_fee: 10,
         ack_fee: 10,
+        denom: None,
     };
     let res = execute(deps.as_mut(), ctx.env, ctx.info, msg);
     assert!(res.is_ok())
@@ -210,12 +213,55 @@ fn test_query_get_unclaimed_fee() {
     let msg = QueryMsg::GetUnclaimedFee {
         nid: ctx.network_id,
         relayer: "crly".to_owned(),
+        denom: None,
     };
     let res = query(deps.as_ref(), ctx.env, msg).unwrap();
     let fee: u128 = from_binary(res).unwrap();
     assert_eq!(fee, 0)
 }
 
+#[test]
+fn test_query_relayer_fee_summary() {
+    use cw_common::xcall_connection_msg::UnclaimedFee;
+
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+
+    ctx.init_channel_open(deps.as_mut(), &contract);
+    let other_nid = NetId::from("other".to_string());
+    contract
+        .add_unclaimed_packet_fees(deps.as_mut().storage, &ctx.network_id, "crly", "", 10)
+        .unwrap();
+    contract
+        .add_unclaimed_packet_fees(deps.as_mut().storage, &other_nid, "crly", "uatom", 20)
+        .unwrap();
+    contract
+        .add_unclaimed_packet_fees(deps.as_mut().storage, &other_nid, "other", "", 30)
+        .unwrap();
+
+    let msg = QueryMsg::GetRelayerFeeSummary {
+        relayer: "crly".to_owned(),
+    };
+    let res = query(deps.as_ref(), ctx.env, msg).unwrap();
+    let summary: Vec<UnclaimedFee> = from_binary(res).unwrap();
+    assert_eq!(
+        summary,
+        vec![
+            UnclaimedFee {
+                nid: ctx.network_id,
+                denom: None,
+                amount: 10,
+            },
+            UnclaimedFee {
+                nid: other_nid,
+                denom: Some("uatom".to_string()),
+                amount: 20,
+            },
+        ]
+    );
+}
+
 #[test]
 #[cfg(not(feature = "native_ibc"))]
 fn success_on_open_channel_open_try_valid_version() {
@@ -257,6 +303,7 @@ fn success_on_open_channel_open_try_valid_version() {
             NetId::from("nid".to_string()),
             "client-id".to_string(),
             100,
+            0,
         )
         .unwrap();
     contract
@@ -381,6 +428,7 @@ fn reconfigure_non_open_channel() {
         nid,
         "client-id".to_string(),
         100,
+        0,
     );
 
     assert!(res.is_ok());
@@ -432,16 +480,35 @@ fn reconfigure_open_channel() {
     let cfg = IbcConfig::new(src, dst);
     let res = contract.store_ibc_config(deps.as_mut().storage, &nid, &cfg);
     assert!(res.is_ok());
+    contract
+        .store_counterparty_nid(deps.as_mut().storage, "newconnection", "port", &nid)
+        .unwrap();
 
     let res = contract.configure_connection(
         deps.as_mut(),
         "newconnection".to_string(),
         "port".to_string(),
-        nid,
+        nid.clone(),
         "client-id".to_string(),
         100,
+        0,
     );
     assert!(res.is_err());
+
+    let res = contract.configure_connection(
+        deps.as_mut(),
+        "otherconnection".to_string(),
+        "port".to_string(),
+        nid.clone(),
+        "client-id".to_string(),
+        100,
+        0,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        contract.get_network_channels(deps.as_ref().storage, &nid),
+        vec!["channel-1".to_string()]
+    );
 }
 
 #[test]
@@ -476,6 +543,7 @@ fn override_open_channel() {
         nid,
         client_id.clone(),
         100,
+        0,
     );
 
     assert!(res.is_ok());
@@ -598,6 +666,7 @@ fn success_receive_packet_for_call_message_request() {
         sn: Nullable::new(Some(1)),
         fee: 0,
         data: cw_xcall::types::rlp::encode(&message).to_vec(),
+        denom: None,
     };
     let message_data = Binary(rlp::encode(&message).to_vec());
 
@@ -642,6 +711,7 @@ fn success_receive_packet_for_call_message_request() {
             NetId::from("cnid".to_string()),
             "client-id".to_string(),
             100,
+            0,
         )
         .unwrap();
     contract
@@ -656,6 +726,7 @@ fn success_receive_packet_for_call_message_request() {
     let channel_config = ChannelConfig {
         client_id: "client_id".to_string(),
         timeout_height: 100,
+        timeout_duration: 0,
         counterparty_nid: NetId::from("nid".to_string()),
     };
 
@@ -668,6 +739,131 @@ fn success_receive_packet_for_call_message_request() {
     assert!(result.is_ok());
 }
 
+#[test]
+#[cfg(not(feature = "native_ibc"))]
+fn receive_packet_keeps_unclaimed_fees_per_denom() {
+    use cosmwasm_std::{CosmosMsg, WasmMsg};
+    use cw20::Cw20ExecuteMsg;
+
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+    contract
+        .set_xcall_host(deps.as_mut().storage, Addr::unchecked("xcallhost"))
+        .unwrap();
+
+    let nid = get_dummy_channel_config().counterparty_nid;
+    let src = IbcEndpoint {
+        port_id: "their-port".to_string(),
+        channel_id: "channel_dst".to_string(),
+    };
+    let dst = IbcEndpoint {
+        port_id: "our-port".to_string(),
+        channel_id: "channel_src".to_string(),
+    };
+    let packet = |sn: Option<i64>, denom: &str, data: Vec<u8>| {
+        let message = Message {
+            sn: Nullable::new(sn),
+            fee: 10,
+            data,
+            denom: Some(denom.to_string()),
+        };
+        IbcPacket::new(
+            Binary(rlp::encode(&message).to_vec()),
+            src.clone(),
+            dst.clone(),
+            1,
+            IbcTimeout::with_block(IbcTimeoutBlock {
+                revision: 0,
+                height: 0,
+            }),
+        )
+    };
+
+    contract
+        .do_packet_receive(
+            deps.as_mut(),
+            packet(Some(0), "uatom", vec![]),
+            Addr::unchecked("relayer"),
+        )
+        .unwrap();
+    let query = |denom: Option<String>| QueryMsg::GetUnclaimedFee {
+        nid: nid.clone(),
+        relayer: "relayer".to_string(),
+        denom,
+    };
+    let res = query_fee(&deps, query(Some("uatom".to_string())));
+    assert_eq!(res, 10);
+    let res = query_fee(&deps, query(None));
+    assert_eq!(res, 0);
+
+    let transfer = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "token".to_string(),
+        msg: to_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: "receiver".to_string(),
+            amount: 10_u128.into(),
+        })
+        .unwrap(),
+        funds: vec![],
+    });
+    let res = contract
+        .do_packet_receive(
+            deps.as_mut(),
+            packet(None, "cw20:token", b"receiver".to_vec()),
+            Addr::unchecked("relayer"),
+        )
+        .unwrap();
+    assert_eq!(res.messages[0].msg, transfer);
+
+    contract
+        .set_fee_crediting(deps.as_mut().storage, "receiver", true)
+        .unwrap();
+    let res = contract
+        .do_packet_receive(
+            deps.as_mut(),
+            packet(None, "cw20:token", b"receiver".to_vec()),
+            Addr::unchecked("relayer"),
+        )
+        .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        contract.get_local_fee(deps.as_ref().storage, &nid, "receiver", "cw20:token"),
+        10
+    );
+
+    let res = contract
+        .withdraw_fees(
+            deps.as_mut(),
+            create_mock_info("receiver", "umlg", 0),
+            nid,
+            Some("cw20:token".to_string()),
+            "receiver".to_string(),
+        )
+        .unwrap();
+    assert_eq!(res.messages[0].msg, transfer);
+
+    for data in [b"Receiver".to_vec(), vec![0xff, 0xfe]] {
+        let res = contract.do_packet_receive(
+            deps.as_mut(),
+            packet(None, "cw20:token", data),
+            Addr::unchecked("relayer"),
+        );
+        assert!(res.is_err());
+    }
+}
+
+fn query_fee(
+    deps: &cosmwasm_std::OwnedDeps<
+        cosmwasm_std::MemoryStorage,
+        cosmwasm_std::testing::MockApi,
+        cosmwasm_std::testing::MockQuerier,
+    >,
+    msg: QueryMsg,
+) -> u128 {
+    from_binary(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+}
+
 #[test]
 #[cfg(not(feature = "native_ibc"))]
 fn on_ack_packet() {
@@ -844,6 +1040,7 @@ fn success_on_setting_timeout_height() {
                 client_id: "client_id".to_owned(),
                 counterparty_nid: NetId::from("nid".to_string()),
                 timeout_height: 100,
+                timeout_duration: 0,
             },
         )
         .unwrap();
@@ -864,6 +1061,55 @@ fn success_on_setting_timeout_height() {
     assert_eq!(response, 100)
 }
 
+#[test]
+fn success_on_setting_timeout_duration() {
+    let mut deps = deps();
+
+    let mock_env = mock_env();
+    let mock_info = create_mock_info("alice", "umlg", 2000);
+
+    let contract = CwIbcConnection::default();
+
+    let init_message = InstantiateMsg {
+        ibc_host: Addr::unchecked("ibchostaddress"),
+        xcall_address: Addr::unchecked("xcalladdress"),
+        denom: "arch".to_string(),
+        port_id: "mock".to_string(),
+    };
+
+    contract
+        .instantiate(deps.as_mut(), mock_env.clone(), mock_info, init_message)
+        .unwrap();
+
+    contract
+        .store_channel_config(
+            deps.as_mut().storage,
+            "channel",
+            &ChannelConfig {
+                client_id: "client_id".to_owned(),
+                counterparty_nid: NetId::from("nid".to_string()),
+                timeout_height: 100,
+                timeout_duration: 300,
+            },
+        )
+        .unwrap();
+
+    let response: u64 = from_binary(
+        contract
+            .query(
+                deps.as_ref(),
+                mock_env,
+                QueryMsg::GetTimeoutDuration {
+                    channel_id: "channel".to_string(),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(response, 300)
+}
+
 #[test]
 #[should_panic(expected = "OnlyAdmin")]
 fn fails_on_configure_connection_unauthorized() {
@@ -892,6 +1138,7 @@ fn fails_on_configure_connection_unauthorized() {
 
         client_id: "client_id".to_string(),
         timeout_height: 1000,
+        timeout_duration: 0,
     };
 
     let mock_info = create_mock_info("bob", "umlg", 2000);
@@ -1075,6 +1322,7 @@ fn test_handle_response() {
         sn: Nullable::new(Some(0)),
         fee: 0,
         data: cw_xcall::types::rlp::encode(&message).to_vec(),
+        denom: None,
     };
     let message_data = Binary(rlp::encode(&message).to_vec());
 
@@ -1099,6 +1347,7 @@ fn test_handle_response() {
             &ChannelConfig {
                 client_id: "client".to_string(),
                 timeout_height: 1000,
+                timeout_duration: 0,
                 counterparty_nid: NetId::from_str("nid").unwrap(),
             },
         )
@@ -1365,3 +1614,36 @@ fn test_reply_fail_for_invalid_id() {
 
     contract.reply(deps.as_mut(), ctx.env, msg).unwrap();
 }
+
+#[test]
+fn migrate_moves_network_ibc_configs_to_channels() {
+    use cw_storage_plus::Map;
+    use cw_xcall_ibc_connection::state::IbcConfig;
+
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    let nid = NetId::from("nid".to_string());
+    let cfg = get_dummy_ibc_config();
+
+    let legacy: Map<NetId, IbcConfig> = Map::new("ibcconfig");
+    legacy
+        .save(deps.as_mut().storage, nid.clone(), &cfg)
+        .unwrap();
+
+    contract.migrate_ibc_configs(deps.as_mut().storage).unwrap();
+
+    assert_eq!(
+        contract.get_network_channels(deps.as_ref().storage, &nid),
+        vec![cfg.src_endpoint().channel_id.clone()]
+    );
+    assert_eq!(
+        contract
+            .get_ibc_config(deps.as_ref().storage, &nid)
+            .unwrap(),
+        cfg
+    );
+    assert!(legacy
+        .may_load(deps.as_ref().storage, nid)
+        .unwrap()
+        .is_none());
+}
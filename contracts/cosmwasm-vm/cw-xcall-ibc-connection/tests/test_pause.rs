@@ -0,0 +1,326 @@
+mod setup;
+
+use common::rlp::{self, Nullable};
+use cosmwasm_std::{
+    from_json, testing::mock_env, Addr, Binary, IbcEndpoint, IbcPacket, IbcPacketReceiveMsg,
+    IbcTimeout, IbcTimeoutBlock,
+};
+use cw_common::xcall_connection_msg::{ExecuteMsg, PauseStatusResponse, QueryMsg};
+use cw_xcall_ibc_connection::{
+    error::ContractError,
+    execute, query,
+    state::CwIbcConnection,
+    types::{message::Message, queued_packet::MAX_QUEUED_PACKETS},
+};
+use cw_xcall_lib::network_address::NetId;
+use setup::*;
+
+fn pause(deps: cosmwasm_std::DepsMut, ctx: &TestContext, nid: Option<NetId>) {
+    execute(
+        deps,
+        mock_env(),
+        ctx.info.clone(),
+        ExecuteMsg::Pause { nid },
+    )
+    .unwrap();
+}
+
+fn send_message(deps: cosmwasm_std::DepsMut, nid: NetId) {
+    let msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let info = create_mock_info("xcall_host", "arch", 0);
+    execute(deps, mock_env(), info, msg).unwrap();
+}
+
+fn receive_packet_msg(sequence: u64, sn: i64) -> ExecuteMsg {
+    let message = Message {
+        sn: Nullable::new(Some(sn)),
+        fee: 0,
+        data: vec![1, 2, 3],
+        denom: None,
+    };
+    let src = IbcEndpoint {
+        port_id: "port_dst".to_string(),
+        channel_id: "channel_dst".to_string(),
+    };
+    let dst = IbcEndpoint {
+        port_id: "port_src".to_string(),
+        channel_id: "channel_src".to_string(),
+    };
+    let timeout = IbcTimeout::with_block(IbcTimeoutBlock {
+        revision: 0,
+        height: 0,
+    });
+    let packet = IbcPacket::new(
+        Binary(rlp::encode(&message).to_vec()),
+        src,
+        dst,
+        sequence,
+        timeout,
+    );
+    ExecuteMsg::IbcPacketReceive {
+        msg: IbcPacketReceiveMsg::new(packet, Addr::unchecked("relayer")),
+    }
+}
+
+fn pause_status(deps: cosmwasm_std::Deps) -> PauseStatusResponse {
+    from_json(query(deps, mock_env(), QueryMsg::GetPauseStatus {}).unwrap()).unwrap()
+}
+
+#[test]
+#[should_panic(expected = "NetworkPaused")]
+fn send_message_to_paused_network() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+
+    pause(deps.as_mut(), &ctx, Some(ctx.network_id.clone()));
+    send_message(deps.as_mut(), ctx.network_id);
+}
+
+#[test]
+#[should_panic(expected = "ContractPaused")]
+fn send_message_on_paused_contract() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+
+    pause(deps.as_mut(), &ctx, None);
+    send_message(deps.as_mut(), ctx.network_id);
+}
+
+#[test]
+#[should_panic(expected = "OnlyAdmin")]
+fn pause_unauthorized() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+
+    let info = create_mock_info("relayer", "arch", 0);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::Pause { nid: None },
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_query_pause_status() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+
+    assert_eq!(
+        pause_status(deps.as_ref()),
+        PauseStatusResponse {
+            paused: false,
+            paused_networks: vec![],
+            queued_packets: 0,
+        }
+    );
+
+    pause(deps.as_mut(), &ctx, Some(ctx.network_id.clone()));
+    pause(deps.as_mut(), &ctx, None);
+    assert_eq!(
+        pause_status(deps.as_ref()),
+        PauseStatusResponse {
+            paused: true,
+            paused_networks: vec![ctx.network_id.clone()],
+            queued_packets: 0,
+        }
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        ctx.info.clone(),
+        ExecuteMsg::Unpause { nid: None },
+    )
+    .unwrap();
+    let status = pause_status(deps.as_ref());
+    assert!(!status.paused);
+    assert_eq!(status.paused_networks, vec![ctx.network_id]);
+}
+
+fn process_queued(
+    deps: cosmwasm_std::DepsMut,
+    ctx: &TestContext,
+    limit: u32,
+) -> Result<cosmwasm_std::Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        ctx.info.clone(),
+        ExecuteMsg::ProcessQueued {
+            nid: ctx.network_id.clone(),
+            limit,
+        },
+    )
+}
+
+#[test]
+#[cfg(not(feature = "native_ibc"))]
+fn receive_packet_queued_until_processed() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+    let ibc_host = create_mock_info("ibc_host", "arch", 0);
+
+    pause(deps.as_mut(), &ctx, Some(ctx.network_id.clone()));
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        ibc_host.clone(),
+        receive_packet_msg(1, 1),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert!(res.data.is_none());
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        ibc_host.clone(),
+        receive_packet_msg(2, 0),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(pause_status(deps.as_ref()).queued_packets, 2);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        ctx.info.clone(),
+        ExecuteMsg::Unpause {
+            nid: Some(ctx.network_id.clone()),
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(pause_status(deps.as_ref()).queued_packets, 2);
+
+    // Packets received while others are queued are queued behind them.
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        ibc_host,
+        receive_packet_msg(3, 2),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(pause_status(deps.as_ref()).queued_packets, 3);
+
+    let res = process_queued(deps.as_mut(), &ctx, 1).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(pause_status(deps.as_ref()).queued_packets, 2);
+    assert!(contract
+        .get_incoming_packet(deps.as_ref().storage, "channel_src", 1)
+        .is_ok());
+
+    let res = process_queued(deps.as_mut(), &ctx, 10).unwrap();
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(pause_status(deps.as_ref()).queued_packets, 0);
+    assert!(contract
+        .get_incoming_packet(deps.as_ref().storage, "channel_src", 2)
+        .is_ok());
+}
+
+#[test]
+#[cfg(not(feature = "native_ibc"))]
+fn receive_packet_stays_queued_while_contract_paused() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+    let ibc_host = create_mock_info("ibc_host", "arch", 0);
+
+    pause(deps.as_mut(), &ctx, None);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        ibc_host,
+        receive_packet_msg(1, 1),
+    )
+    .unwrap();
+
+    let res = process_queued(deps.as_mut(), &ctx, 10);
+    assert!(matches!(res, Err(ContractError::ContractPaused)));
+    assert_eq!(pause_status(deps.as_ref()).queued_packets, 1);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        ctx.info.clone(),
+        ExecuteMsg::Unpause { nid: None },
+    )
+    .unwrap();
+    let res = process_queued(deps.as_mut(), &ctx, 10).unwrap();
+    assert_eq!(res.messages.len(), 1);
+}
+
+#[test]
+#[cfg(not(feature = "native_ibc"))]
+fn receive_packet_rejected_when_queue_full() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+    let ibc_host = create_mock_info("ibc_host", "arch", 0);
+
+    pause(deps.as_mut(), &ctx, Some(ctx.network_id.clone()));
+    for sequence in 1..=MAX_QUEUED_PACKETS {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            ibc_host.clone(),
+            receive_packet_msg(sequence, 0),
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        pause_status(deps.as_ref()).queued_packets,
+        MAX_QUEUED_PACKETS
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        ibc_host,
+        receive_packet_msg(MAX_QUEUED_PACKETS + 1, 0),
+    );
+    assert!(matches!(res, Err(ContractError::QueueFull { .. })));
+    assert_eq!(
+        pause_status(deps.as_ref()).queued_packets,
+        MAX_QUEUED_PACKETS
+    );
+}
+
+#[test]
+#[should_panic(expected = "OnlyAdmin")]
+fn process_queued_unauthorized() {
+    let ctx = TestContext::default();
+    let mut deps = deps();
+    let contract = CwIbcConnection::default();
+    ctx.init_context(deps.as_mut().storage, &contract);
+
+    let info = create_mock_info("relayer", "arch", 0);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::ProcessQueued {
+            nid: ctx.network_id,
+            limit: 1,
+        },
+    )
+    .unwrap();
+}
@@ -50,6 +50,7 @@ fn test_receive_packet_for_call_message_request() {
         sn: common::rlp::Nullable::new(Some(0)),
         fee: 0,
         data: rlp::encode(&message).to_vec(),
+        denom: None,
     };
     let message_data = Binary(common::rlp::encode(&message).to_vec());
 
@@ -100,6 +101,7 @@ fn test_receive_packet_for_call_message_response() {
         sn: common::rlp::Nullable::new(Some(0)),
         fee: 0,
         data: rlp::encode(&message).to_vec(),
+        denom: None,
     };
     let message_data = Binary(common::rlp::encode(&message).to_vec());
 
@@ -6,8 +6,10 @@ use cosmwasm_std::{
     IbcTimeoutBlock,
 };
 
+use cw20::Cw20ReceiveMsg;
 use cw_xcall_ibc_connection::types::config::Config;
-use cw_xcall_ibc_connection::types::network_fees::NetworkFees;
+use cw_xcall_ibc_connection::types::message::Message;
+use cw_xcall_ibc_connection::types::network_fees::{NetworkFees, DEFAULT_FEE_DENOM};
 use cw_xcall_lib::network_address::NetId;
 
 use cw_xcall_ibc_connection::types::channel_config::ChannelConfig;
@@ -15,11 +17,129 @@ use setup::*;
 pub mod account;
 use account::alice;
 
-use cosmwasm_std::{ContractResult, Env, OwnedDeps, SystemResult, WasmQuery};
-use cw_common::xcall_connection_msg::ExecuteMsg;
+use cosmwasm_std::testing::{MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, ContractResult, CosmosMsg, Env, MemoryStorage, OwnedDeps, Response, SystemResult,
+    WasmMsg, WasmQuery,
+};
+use cw_common::core_msg::{
+    DecodedClientState, ExecuteMsg as CoreExecuteMsg, IconClientState, QueryMsg as CoreQueryMsg,
+};
+use cw_common::raw_types::channel::{RawChannel, RawPacket};
+use cw_common::xcall_connection_msg::{
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, FeeOption, NetworkLimits, QueryMsg,
+};
+use cw_common::ProstMessage;
 
+use cw_xcall_ibc_connection::error::ContractError;
 use cw_xcall_ibc_connection::state::{CwIbcConnection, IbcConfig};
 
+/// Answers the queries of the contract to the IBC host. Channels are open and clients active
+/// unless listed, the client states of `failing_clients` are not found and other queries get `10`.
+fn mock_host_queries(
+    deps: &mut OwnedDeps<MemoryStorage, MockApi, MockQuerier>,
+    closed_channels: Vec<&'static str>,
+    frozen_clients: Vec<&'static str>,
+    failing_clients: Vec<&'static str>,
+) {
+    deps.querier.update_wasm(move |r| match r {
+        WasmQuery::Smart {
+            contract_addr: _,
+            msg,
+        } => {
+            let response = match from_json::<CoreQueryMsg>(msg).unwrap() {
+                CoreQueryMsg::GetChannel { channel_id, .. } => {
+                    let state = if closed_channels.contains(&channel_id.as_str()) {
+                        4
+                    } else {
+                        3
+                    };
+                    let channel = RawChannel {
+                        state,
+                        ordering: 1,
+                        counterparty: None,
+                        connection_hops: vec![],
+                        version: "".to_string(),
+                    };
+                    to_binary(&hex::encode(channel.encode_to_vec()))
+                }
+                CoreQueryMsg::GetDecodedClientState { client_id } => {
+                    if failing_clients.contains(&client_id.as_str()) {
+                        return SystemResult::Ok(ContractResult::Err(
+                            "client state not found".to_string(),
+                        ));
+                    }
+                    let frozen_height = if frozen_clients.contains(&client_id.as_str()) {
+                        10
+                    } else {
+                        0
+                    };
+                    to_binary(&DecodedClientState::Icon(IconClientState {
+                        trusting_period: 100,
+                        frozen_height,
+                        max_clock_drift: 10,
+                        latest_height: 10,
+                        src_network_id: "0x3.icon".to_string(),
+                        network_id: 1,
+                        network_type_id: 1,
+                        trust_level: None,
+                    }))
+                }
+                _ => to_binary(&10),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => todo!(),
+    });
+}
+
+/// Registers `channel_id` as another channel of `nid` served by the light client `client_id`.
+fn add_channel(
+    contract: &CwIbcConnection,
+    deps: &mut OwnedDeps<MemoryStorage, MockApi, MockQuerier>,
+    nid: &NetId,
+    channel_id: &str,
+    client_id: &str,
+) {
+    let src = IbcEndpoint {
+        port_id: "our-port".to_string(),
+        channel_id: channel_id.to_string(),
+    };
+    let dst = IbcEndpoint {
+        port_id: "their-port".to_string(),
+        channel_id: format!("{channel_id}-counterparty"),
+    };
+    contract
+        .store_ibc_config(deps.as_mut().storage, nid, &IbcConfig::new(src, dst))
+        .unwrap();
+    contract
+        .store_channel_config(
+            deps.as_mut().storage,
+            channel_id,
+            &ChannelConfig {
+                client_id: client_id.to_string(),
+                timeout_height: 100,
+                timeout_duration: 0,
+                counterparty_nid: nid.clone(),
+            },
+        )
+        .unwrap();
+}
+
+fn sent_packet(res: &Response) -> RawPacket {
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            match from_json::<CoreExecuteMsg>(msg).unwrap() {
+                CoreExecuteMsg::SendPacket { packet } => {
+                    RawPacket::decode(packet.to_bytes().unwrap().as_slice()).unwrap()
+                }
+                msg => panic!("unexpected message {msg:?}"),
+            }
+        }
+        msg => panic!("unexpected message {msg:?}"),
+    }
+}
+
 fn send_message_setup() -> (
     CwIbcConnection<'static>,
     OwnedDeps<
@@ -53,6 +173,7 @@ fn send_message_setup() -> (
     let channel_config = ChannelConfig {
         client_id: "client_id".to_string(),
         timeout_height: 100,
+        timeout_duration: 0,
         counterparty_nid: NetId::from("nid".to_string()),
     };
     contract
@@ -76,18 +197,13 @@ fn send_message_setup() -> (
     contract
         .store_channel_config(deps.as_mut().storage, &src.channel_id, &channel_config)
         .unwrap();
-    deps.querier.update_wasm(|r| match r {
-        WasmQuery::Smart {
-            contract_addr: _,
-            msg: _,
-        } => SystemResult::Ok(ContractResult::Ok(to_binary(&10).unwrap())),
-        _ => todo!(),
-    });
+    mock_host_queries(&mut deps, vec![], vec![], vec![]);
 
     contract
         .store_network_fees(
             deps.as_mut().storage,
-            nid.clone(),
+            &nid,
+            DEFAULT_FEE_DENOM,
             &NetworkFees {
                 send_packet_fee: 10,
                 ack_fee: 10,
@@ -176,3 +292,715 @@ fn send_message_with_negative_sn() {
         .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
         .unwrap();
 }
+
+#[test]
+fn send_message_sets_timeout_timestamp_from_duration() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .store_channel_config(
+            deps.as_mut().storage,
+            "channel-1",
+            &ChannelConfig {
+                client_id: "client_id".to_string(),
+                timeout_height: 100,
+                timeout_duration: 300,
+                counterparty_nid: NetId::from("nid".to_string()),
+            },
+        )
+        .unwrap();
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+
+    let res = contract
+        .execute(deps.as_mut(), mock_env.clone(), mock_info, execute_msg)
+        .unwrap();
+
+    let packet = sent_packet(&res);
+    assert_eq!(
+        packet.timeout_timestamp,
+        mock_env.block.time.plus_seconds(300).nanos()
+    );
+    assert_eq!(packet.timeout_height.unwrap().revision_height, 110);
+}
+
+#[test]
+fn send_message_fails_over_to_next_open_channel() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    mock_host_queries(&mut deps, vec!["channel-1"], vec![], vec![]);
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+    let res = contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    let packet = sent_packet(&res);
+    assert_eq!(packet.source_channel, "channel-2");
+    assert_eq!(packet.destination_channel, "channel-2-counterparty");
+}
+
+#[test]
+fn send_message_skips_channel_with_frozen_client() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    mock_host_queries(&mut deps, vec![], vec!["client_id"], vec![]);
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+    let res = contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    assert_eq!(sent_packet(&res).source_channel, "channel-2");
+}
+
+#[test]
+#[should_panic(expected = "client state not found")]
+fn send_message_fails_when_client_state_query_fails() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    mock_host_queries(&mut deps, vec![], vec![], vec!["client_id"]);
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+    contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "NoOpenChannel")]
+fn send_message_fails_without_open_channel() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    mock_host_queries(&mut deps, vec!["channel-1"], vec!["client_id_2"], vec![]);
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+    contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+}
+
+#[test]
+fn send_message_acknowledges_on_receiving_channel() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    contract
+        .remove_ibc_config(deps.as_mut().storage, &nid, "channel-2")
+        .unwrap();
+
+    let src = IbcEndpoint {
+        port_id: "their-port".to_string(),
+        channel_id: "channel-2-counterparty".to_string(),
+    };
+    let dst = IbcEndpoint {
+        port_id: "our-port".to_string(),
+        channel_id: "channel-2".to_string(),
+    };
+    let timeout = IbcTimeout::with_block(IbcTimeoutBlock {
+        revision: 0,
+        height: 0,
+    });
+    let packet = IbcPacket::new(vec![], src, dst.clone(), 0, timeout);
+    contract
+        .store_incoming_packet(deps.as_mut().storage, &dst.channel_id, 2, packet)
+        .unwrap();
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: -2,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 0);
+    contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    assert!(contract
+        .get_incoming_packet(deps.as_ref().storage, &dst.channel_id, 2)
+        .is_err());
+}
+
+#[test]
+fn test_counterparty_channels_follow_channel_config() {
+    let (contract, mut deps, _, nid) = send_message_setup();
+    let other_nid = NetId::from_str("other").unwrap();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    add_channel(&contract, &mut deps, &other_nid, "channel-4", "client_id_2");
+    assert_eq!(
+        contract
+            .get_counterparty_channels(deps.as_ref().storage, &nid)
+            .unwrap(),
+        vec!["channel-1", "channel-2"]
+    );
+
+    add_channel(&contract, &mut deps, &other_nid, "channel-2", "client_id_2");
+    assert_eq!(
+        contract
+            .get_counterparty_channels(deps.as_ref().storage, &nid)
+            .unwrap(),
+        vec!["channel-1"]
+    );
+    assert_eq!(
+        contract
+            .get_counterparty_channels(deps.as_ref().storage, &other_nid)
+            .unwrap(),
+        vec!["channel-2", "channel-4"]
+    );
+}
+
+#[test]
+fn test_set_channel_priority() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .update_admin(deps.as_mut().storage, Addr::unchecked("admin"))
+        .unwrap();
+    add_channel(&contract, &mut deps, &nid, "channel-2", "client_id_2");
+    add_channel(&contract, &mut deps, &nid, "channel-5", "client_id_2");
+
+    let execute_msg = ExecuteMsg::SetChannelPriority {
+        nid: nid.clone(),
+        channel_ids: vec!["channel-5".to_string()],
+    };
+    let mock_info = create_mock_info("admin", "abcd", 0);
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env.clone(),
+            mock_info.clone(),
+            execute_msg,
+        )
+        .unwrap();
+
+    let query = QueryMsg::GetIbcConfigs { nid: nid.clone() };
+    let res = contract
+        .query(deps.as_ref(), mock_env.clone(), query)
+        .unwrap();
+    let configs: Vec<ConfigResponse> = from_json(res).unwrap();
+    let channels: Vec<&str> = configs.iter().map(|c| c.channel_id.as_str()).collect();
+    assert_eq!(channels, vec!["channel-5", "channel-1", "channel-2"]);
+
+    let execute_msg = ExecuteMsg::RemoveChannel {
+        nid: nid.clone(),
+        channel_id: "channel-5".to_string(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env.clone(),
+            mock_info.clone(),
+            execute_msg,
+        )
+        .unwrap();
+    let ibc_config = contract
+        .get_ibc_config(deps.as_ref().storage, &nid)
+        .unwrap();
+    assert_eq!(ibc_config.src_endpoint().channel_id, "channel-1");
+
+    let execute_msg = ExecuteMsg::SetChannelPriority {
+        nid,
+        channel_ids: vec!["channel-5".to_string()],
+    };
+    let res = contract.execute(deps.as_mut(), mock_env, mock_info, execute_msg);
+    assert!(res.is_err());
+}
+
+fn sent_message(res: &Response) -> Message {
+    common::rlp::decode(&sent_packet(res).data).unwrap()
+}
+
+#[test]
+fn send_message_pays_fee_in_accepted_denom() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .set_fee_denom_supported(deps.as_mut().storage, &nid, true)
+        .unwrap();
+    contract
+        .store_network_fees(
+            deps.as_mut().storage,
+            &nid,
+            "uatom",
+            &NetworkFees {
+                send_packet_fee: 5,
+                ack_fee: 5,
+            },
+        )
+        .unwrap();
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid.clone(),
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "uatom", 10);
+    let res = contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    let message = sent_message(&res);
+    assert_eq!(message.fee, 5);
+    assert_eq!(message.denom, Some("uatom".to_string()));
+    let ack_fee = contract
+        .get_unclaimed_ack_fee(deps.as_ref().storage, nid.as_str(), 10)
+        .unwrap();
+    assert_eq!(ack_fee.denom, "uatom");
+    assert_eq!(ack_fee.amount, 5);
+}
+
+#[test]
+#[should_panic(expected = "InsufficientFunds")]
+fn send_message_ignores_other_denoms_without_fee_denom_support() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .store_network_fees(
+            deps.as_mut().storage,
+            &nid,
+            "uatom",
+            &NetworkFees {
+                send_packet_fee: 5,
+                ack_fee: 5,
+            },
+        )
+        .unwrap();
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "uatom", 10);
+    contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+}
+
+#[test]
+fn send_message_in_default_denom_omits_denom() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+    let res = contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    let message = sent_message(&res);
+    assert_eq!(message.fee, 10);
+    assert_eq!(message.denom, None);
+}
+
+#[test]
+fn send_message_with_cw20_fee() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .set_fee_denom_supported(deps.as_mut().storage, &nid, true)
+        .unwrap();
+    contract
+        .store_network_fees(
+            deps.as_mut().storage,
+            &nid,
+            "cw20:token",
+            &NetworkFees {
+                send_packet_fee: 7,
+                ack_fee: 3,
+            },
+        )
+        .unwrap();
+
+    let execute_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "xcalladdress".to_string(),
+        amount: 10_u128.into(),
+        msg: to_binary(&Cw20HookMsg::SendMessage {
+            to: nid,
+            sn: 1,
+            msg: vec![],
+        })
+        .unwrap(),
+    });
+    let mock_info = create_mock_info("token", "abcd", 0);
+    let res = contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    let message = sent_message(&res);
+    assert_eq!(message.fee, 7);
+    assert_eq!(message.denom, Some("cw20:token".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "OnlyIbcHandler")]
+fn send_message_with_cw20_fee_from_other_sender() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    let execute_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "alice".to_string(),
+        amount: 10_u128.into(),
+        msg: to_binary(&Cw20HookMsg::SendMessage {
+            to: nid,
+            sn: 1,
+            msg: vec![],
+        })
+        .unwrap(),
+    });
+    let mock_info = create_mock_info("token", "abcd", 0);
+    contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+}
+
+#[test]
+fn send_message_with_unregistered_cw20_fails() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .set_fee_denom_supported(deps.as_mut().storage, &nid, true)
+        .unwrap();
+    contract
+        .store_network_fees(
+            deps.as_mut().storage,
+            &nid,
+            "cw20:token",
+            &NetworkFees {
+                send_packet_fee: 7,
+                ack_fee: 3,
+            },
+        )
+        .unwrap();
+
+    let execute_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "xcalladdress".to_string(),
+        amount: 10_u128.into(),
+        msg: to_binary(&Cw20HookMsg::SendMessage {
+            to: nid,
+            sn: 1,
+            msg: vec![],
+        })
+        .unwrap(),
+    });
+    let mock_info = create_mock_info("fake_token", "abcd", 0);
+    let res = contract.execute(deps.as_mut(), mock_env, mock_info, execute_msg);
+
+    assert!(matches!(
+        res,
+        Err(ContractError::FeeDenomNotAccepted { denom, .. }) if denom == "cw20:fake_token"
+    ));
+}
+
+#[test]
+fn claim_fees_in_explicit_default_denom() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .add_unclaimed_packet_fees(
+            deps.as_mut().storage,
+            &nid,
+            "relayer",
+            DEFAULT_FEE_DENOM,
+            10,
+        )
+        .unwrap();
+
+    let execute_msg = ExecuteMsg::ClaimFees {
+        nid: nid.clone(),
+        address: "relayer_address".to_string(),
+        denom: Some("abcd".to_string()),
+    };
+    let mock_info = create_mock_info("relayer", "abcd", 0);
+    let res = contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+
+    let message = sent_message(&res);
+    assert_eq!(message.fee, 10);
+    assert_eq!(message.denom, None);
+    assert_eq!(
+        contract.get_unclaimed_packet_fee(
+            deps.as_ref().storage,
+            &nid,
+            "relayer",
+            DEFAULT_FEE_DENOM
+        ),
+        0
+    );
+}
+
+#[test]
+fn test_query_fee_options() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    contract
+        .update_admin(deps.as_mut().storage, Addr::unchecked("admin"))
+        .unwrap();
+    let mock_info = create_mock_info("admin", "abcd", 0);
+    let execute_msg = ExecuteMsg::SetFees {
+        nid: nid.clone(),
+        packet_fee: 1,
+        ack_fee: 2,
+        denom: Some("aaaa".to_string()),
+    };
+    let res = contract.execute(
+        deps.as_mut(),
+        mock_env.clone(),
+        mock_info.clone(),
+        execute_msg.clone(),
+    );
+    assert!(matches!(
+        res,
+        Err(ContractError::FeeDenomNotSupported { .. })
+    ));
+
+    let support_msg = ExecuteMsg::SetFeeDenomSupport {
+        nid: nid.clone(),
+        supported: true,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env.clone(),
+            mock_info.clone(),
+            support_msg,
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env.clone(),
+            mock_info.clone(),
+            execute_msg,
+        )
+        .unwrap();
+
+    let query = QueryMsg::GetFeeOptions { nid: nid.clone() };
+    let res = contract
+        .query(deps.as_ref(), mock_env.clone(), query)
+        .unwrap();
+    let options: Vec<FeeOption> = from_json(res).unwrap();
+    assert_eq!(
+        options,
+        vec![
+            FeeOption {
+                denom: "abcd".to_string(),
+                send_packet_fee: 10,
+                ack_fee: 10,
+            },
+            FeeOption {
+                denom: "aaaa".to_string(),
+                send_packet_fee: 1,
+                ack_fee: 2,
+            },
+        ]
+    );
+
+    let fee_query = QueryMsg::GetFee {
+        nid: nid.clone(),
+        response: true,
+    };
+    let res = contract
+        .query(deps.as_ref(), mock_env.clone(), fee_query.clone())
+        .unwrap();
+    let fee: u128 = from_json(res).unwrap();
+    assert_eq!(fee, 20);
+
+    let execute_msg = ExecuteMsg::RemoveFees {
+        nid,
+        denom: "abcd".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), mock_env.clone(), mock_info, execute_msg)
+        .unwrap();
+
+    // fees in other denoms are only listed as fee options
+    let res = contract.query(deps.as_ref(), mock_env, fee_query).unwrap();
+    let fee: u128 = from_json(res).unwrap();
+    assert_eq!(fee, 0);
+}
+
+fn set_network_limits(
+    contract: &mut CwIbcConnection,
+    deps: &mut OwnedDeps<MemoryStorage, MockApi, MockQuerier>,
+    nid: &NetId,
+    limits: NetworkLimits,
+) {
+    contract
+        .update_admin(deps.as_mut().storage, Addr::unchecked("admin"))
+        .unwrap();
+    let execute_msg = ExecuteMsg::SetNetworkLimits {
+        nid: nid.clone(),
+        limits,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            create_mock_info("admin", "abcd", 0),
+            execute_msg,
+        )
+        .unwrap();
+}
+
+fn send_message_at(
+    contract: &mut CwIbcConnection,
+    deps: &mut OwnedDeps<MemoryStorage, MockApi, MockQuerier>,
+    nid: &NetId,
+    height: u64,
+) -> Result<Response, ContractError> {
+    let mut env = mock_env();
+    env.block.height = height;
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid.clone(),
+        sn: 0,
+        msg: vec![],
+    };
+    contract.execute(
+        deps.as_mut(),
+        env,
+        create_mock_info("xcalladdress", "abcd", 10),
+        execute_msg,
+    )
+}
+
+#[test]
+#[should_panic(expected = "MaxDataSizeExceeded")]
+fn send_message_exceeds_network_data_size() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    set_network_limits(
+        &mut contract,
+        &mut deps,
+        &nid,
+        NetworkLimits {
+            max_data_size: 4,
+            ..Default::default()
+        },
+    );
+
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 1,
+        msg: vec![1, 2, 3, 4, 5],
+    };
+    let mock_info = create_mock_info("xcalladdress", "abcd", 20);
+    contract
+        .execute(deps.as_mut(), mock_env, mock_info, execute_msg)
+        .unwrap();
+}
+
+#[test]
+fn send_message_rate_limited_per_window() {
+    let (mut contract, mut deps, _, nid) = send_message_setup();
+    set_network_limits(
+        &mut contract,
+        &mut deps,
+        &nid,
+        NetworkLimits {
+            window_blocks: 10,
+            max_messages: 2,
+            ..Default::default()
+        },
+    );
+
+    send_message_at(&mut contract, &mut deps, &nid, 100).unwrap();
+    send_message_at(&mut contract, &mut deps, &nid, 109).unwrap();
+    let err = send_message_at(&mut contract, &mut deps, &nid, 109).unwrap_err();
+    assert_eq!(err.to_string(), "MessageRateLimitExceeded nid");
+
+    send_message_at(&mut contract, &mut deps, &nid, 110).unwrap();
+}
+
+#[test]
+fn send_message_value_rate_limited_per_window() {
+    let (mut contract, mut deps, _, nid) = send_message_setup();
+    set_network_limits(
+        &mut contract,
+        &mut deps,
+        &nid,
+        NetworkLimits {
+            window_blocks: 10,
+            max_messages: 5,
+            max_value_messages: 1,
+            ..Default::default()
+        },
+    );
+
+    send_message_at(&mut contract, &mut deps, &nid, 100).unwrap();
+    let err = send_message_at(&mut contract, &mut deps, &nid, 101).unwrap_err();
+    assert_eq!(err.to_string(), "ValueRateLimitExceeded nid");
+
+    // messages to a network without fees carry no funds
+    contract.remove_network_fees(deps.as_mut().storage, &nid, DEFAULT_FEE_DENOM);
+    let mut env = mock_env();
+    env.block.height = 101;
+    let execute_msg = ExecuteMsg::SendMessage {
+        to: nid,
+        sn: 0,
+        msg: vec![],
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            env,
+            create_mock_info("xcalladdress", "abcd", 0),
+            execute_msg,
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_query_network_limits() {
+    let (mut contract, mut deps, mock_env, nid) = send_message_setup();
+    let query = QueryMsg::GetNetworkLimits { nid: nid.clone() };
+    let res = contract
+        .query(deps.as_ref(), mock_env.clone(), query.clone())
+        .unwrap();
+    let limits: NetworkLimits = from_json(res).unwrap();
+    assert_eq!(limits, NetworkLimits::default());
+    assert_eq!(limits.max_data_size, 2048);
+
+    let expected = NetworkLimits {
+        max_data_size: 512,
+        max_rollback_size: 256,
+        window_blocks: 5,
+        max_messages: 20,
+        max_value_messages: 10,
+    };
+    set_network_limits(&mut contract, &mut deps, &nid, expected.clone());
+    let res = contract.query(deps.as_ref(), mock_env, query).unwrap();
+    let limits: NetworkLimits = from_json(res).unwrap();
+    assert_eq!(limits, expected);
+}
+
+#[test]
+#[should_panic(expected = "InvalidNetworkLimits")]
+fn set_network_limits_without_window() {
+    let (mut contract, mut deps, _, nid) = send_message_setup();
+    set_network_limits(
+        &mut contract,
+        &mut deps,
+        &nid,
+        NetworkLimits {
+            max_messages: 1,
+            ..Default::default()
+        },
+    );
+}
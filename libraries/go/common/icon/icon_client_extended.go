@@ -30,6 +30,7 @@ func NewClientState(
 		SrcNetworkId,
 		NetworkId,
 		NetworkTypeId,
+		nil,
 	}
 
 }
@@ -24,13 +24,14 @@ var _ = math.Inf
 const _ = proto.GoGoProtoPackageIsVersion3 // please upgrade the proto package
 
 type ClientState struct {
-	TrustingPeriod uint64 `protobuf:"varint,1,opt,name=trusting_period,json=trustingPeriod,proto3" json:"trusting_period,omitempty"`
-	FrozenHeight   uint64 `protobuf:"varint,2,opt,name=frozen_height,json=frozenHeight,proto3" json:"frozen_height,omitempty"`
-	MaxClockDrift  uint64 `protobuf:"varint,3,opt,name=max_clock_drift,json=maxClockDrift,proto3" json:"max_clock_drift,omitempty"`
-	LatestHeight   uint64 `protobuf:"varint,4,opt,name=latest_height,json=latestHeight,proto3" json:"latest_height,omitempty"`
-	SrcNetworkId   string `protobuf:"bytes,5,opt,name=src_network_id,json=srcNetworkId,proto3" json:"src_network_id,omitempty"`
-	NetworkId      uint64 `protobuf:"varint,6,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
-	NetworkTypeId  uint64 `protobuf:"varint,7,opt,name=network_type_id,json=networkTypeId,proto3" json:"network_type_id,omitempty"`
+	TrustingPeriod uint64      `protobuf:"varint,1,opt,name=trusting_period,json=trustingPeriod,proto3" json:"trusting_period,omitempty"`
+	FrozenHeight   uint64      `protobuf:"varint,2,opt,name=frozen_height,json=frozenHeight,proto3" json:"frozen_height,omitempty"`
+	MaxClockDrift  uint64      `protobuf:"varint,3,opt,name=max_clock_drift,json=maxClockDrift,proto3" json:"max_clock_drift,omitempty"`
+	LatestHeight   uint64      `protobuf:"varint,4,opt,name=latest_height,json=latestHeight,proto3" json:"latest_height,omitempty"`
+	SrcNetworkId   string      `protobuf:"bytes,5,opt,name=src_network_id,json=srcNetworkId,proto3" json:"src_network_id,omitempty"`
+	NetworkId      uint64      `protobuf:"varint,6,opt,name=network_id,json=networkId,proto3" json:"network_id,omitempty"`
+	NetworkTypeId  uint64      `protobuf:"varint,7,opt,name=network_type_id,json=networkTypeId,proto3" json:"network_type_id,omitempty"`
+	TrustLevel     *TrustLevel `protobuf:"bytes,8,opt,name=trust_level,json=trustLevel,proto3" json:"trust_level,omitempty"`
 }
 
 func (m *ClientState) Reset()         { *m = ClientState{} }
@@ -285,46 +286,46 @@ func init() {
 func init() { proto.RegisterFile("icon/lightclient/v1/light.proto", fileDescriptor_5ae86e09394aefe7) }
 
 var fileDescriptor_5ae86e09394aefe7 = []byte{
-	// 617 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x94, 0x93, 0x41, 0x4f, 0xdb, 0x3e,
-	0x18, 0xc6, 0x9b, 0xc2, 0x1f, 0xa8, 0x5b, 0x40, 0x0a, 0xfc, 0x45, 0x07, 0xa3, 0x74, 0xdd, 0xc4,
-	0x38, 0x25, 0x4a, 0xd1, 0x2e, 0xe5, 0xd6, 0x4e, 0x1a, 0x95, 0xca, 0x54, 0x05, 0x86, 0xa6, 0x09,
-	0x29, 0x32, 0x89, 0x49, 0x3c, 0x12, 0xbf, 0x91, 0xed, 0x76, 0xb0, 0x4f, 0xb0, 0xe3, 0xee, 0xbb,
-	0x4c, 0x93, 0x76, 0xd9, 0x27, 0x99, 0x38, 0x71, 0xdc, 0x71, 0x2a, 0xb7, 0x7d, 0x8a, 0xc9, 0x76,
-	0xd9, 0x72, 0xe8, 0x65, 0x97, 0xca, 0xfe, 0xbd, 0xcf, 0xfb, 0xc4, 0x7d, 0x5e, 0x1b, 0xed, 0xd0,
-	0x10, 0x98, 0x9b, 0xd2, 0x38, 0x91, 0x61, 0x4a, 0x09, 0x93, 0xee, 0xd8, 0x33, 0x5b, 0x27, 0xe7,
-	0x20, 0xc1, 0x5e, 0x53, 0x02, 0xa7, 0x20, 0x70, 0xc6, 0xde, 0xe6, 0x03, 0xdd, 0x25, 0xaf, 0x73,
-	0x22, 0x94, 0x5e, 0x2f, 0x8c, 0x7e, 0x73, 0x3d, 0x86, 0x18, 0xf4, 0xd2, 0x55, 0x2b, 0x43, 0x5b,
-	0x9f, 0xca, 0xa8, 0xda, 0xd3, 0xed, 0xc7, 0x12, 0x4b, 0x62, 0x3f, 0x45, 0xab, 0x92, 0x8f, 0x84,
-	0xa4, 0x2c, 0x0e, 0x72, 0xc2, 0x29, 0x44, 0x75, 0xab, 0x69, 0xed, 0xcd, 0xfb, 0x2b, 0xf7, 0x78,
-	0xa8, 0xa9, 0xfd, 0x18, 0x2d, 0x5f, 0x70, 0x78, 0x4f, 0x58, 0x90, 0x10, 0x75, 0x86, 0x7a, 0x59,
-	0xcb, 0x6a, 0x06, 0x1e, 0x6a, 0x66, 0xef, 0xa2, 0xd5, 0x0c, 0x5f, 0x05, 0x61, 0x0a, 0xe1, 0x65,
-	0x10, 0x71, 0x7a, 0x21, 0xeb, 0x73, 0x5a, 0xb6, 0x9c, 0xe1, 0xab, 0x9e, 0xa2, 0xcf, 0x15, 0x54,
-	0x66, 0x29, 0x96, 0x44, 0xc8, 0x7b, 0xb3, 0x79, 0x63, 0x66, 0xe0, 0xd4, 0xec, 0x09, 0x5a, 0x11,
-	0x3c, 0x0c, 0x18, 0x91, 0xef, 0x80, 0x5f, 0x06, 0x34, 0xaa, 0xff, 0xd7, 0xb4, 0xf6, 0x2a, 0x7e,
-	0x4d, 0xf0, 0xf0, 0xa5, 0x81, 0xfd, 0xc8, 0xde, 0x46, 0xa8, 0xa0, 0x58, 0xd0, 0x3e, 0x15, 0xf6,
-	0xa7, 0xbc, 0x8b, 0x56, 0xef, 0xcb, 0x2a, 0x1c, 0xa5, 0x59, 0x34, 0x27, 0x9a, 0xe2, 0x93, 0xeb,
-	0x9c, 0xf4, 0xa3, 0xce, 0xfc, 0x87, 0xcf, 0x3b, 0xa5, 0xd6, 0x5b, 0xb4, 0xd2, 0x03, 0x26, 0x08,
-	0x13, 0x23, 0x61, 0xf2, 0x79, 0x84, 0x6a, 0x19, 0x11, 0x02, 0xc7, 0x24, 0xe0, 0x00, 0x52, 0x87,
-	0x53, 0xf3, 0xab, 0x53, 0xe6, 0x03, 0x48, 0xfb, 0x19, 0xda, 0x60, 0xe4, 0x4a, 0x06, 0x39, 0x07,
-	0xb8, 0x08, 0x42, 0x60, 0x52, 0xed, 0x12, 0x2c, 0x12, 0x9d, 0x51, 0xcd, 0x5f, 0x57, 0xe5, 0xa1,
-	0xaa, 0xf6, 0x4c, 0xf1, 0x10, 0x8b, 0xa4, 0xd5, 0x45, 0xd5, 0xae, 0x4a, 0xe4, 0x55, 0x1e, 0xa9,
-	0x0f, 0xed, 0xa3, 0x85, 0x84, 0xe0, 0x88, 0x70, 0xfd, 0x89, 0x6a, 0x7b, 0xcb, 0xd1, 0xf3, 0x36,
-	0x13, 0x1d, 0x7b, 0xce, 0x31, 0x8d, 0x19, 0x89, 0x0e, 0xb5, 0xc4, 0x9f, 0x4a, 0x5b, 0x03, 0x84,
-	0x4e, 0xd4, 0x98, 0x06, 0x64, 0x4c, 0x52, 0xfb, 0x21, 0xaa, 0xb0, 0x51, 0x46, 0x38, 0x96, 0xc0,
-	0xa7, 0x53, 0xfc, 0x0b, 0xec, 0x26, 0xaa, 0x46, 0x84, 0x41, 0x46, 0x99, 0xae, 0x9b, 0xf1, 0x15,
-	0x51, 0xeb, 0xab, 0x85, 0x6a, 0x47, 0x54, 0x9c, 0x93, 0x04, 0x8f, 0x29, 0x8c, 0xb8, 0xbd, 0x85,
-	0x2a, 0xe6, 0xaa, 0xa9, 0xd8, 0x2c, 0x1d, 0xfe, 0x92, 0x01, 0xfd, 0xc8, 0x3e, 0x40, 0x4b, 0xe6,
-	0x14, 0x81, 0xa7, 0xcd, 0xaa, 0xed, 0xa6, 0x33, 0xe3, 0x8a, 0x3a, 0x85, 0x3f, 0xe9, 0x2f, 0x9a,
-	0x0e, 0xaf, 0xd0, 0xdc, 0xd6, 0x37, 0xe4, 0x1f, 0x9a, 0xdb, 0xdd, 0x1b, 0xeb, 0xfb, 0xa4, 0x61,
-	0xdd, 0x4e, 0x1a, 0xd6, 0xcf, 0x49, 0xc3, 0xfa, 0x78, 0xd7, 0x28, 0xdd, 0xde, 0x35, 0x4a, 0x3f,
-	0xee, 0x1a, 0x25, 0xb4, 0x11, 0x42, 0x36, 0xcb, 0xa8, 0x8b, 0x06, 0x6a, 0x3f, 0x54, 0x6f, 0x60,
-	0x68, 0xbd, 0xd9, 0x4e, 0xe9, 0x39, 0xc7, 0x9c, 0x12, 0xe1, 0xc6, 0xe0, 0x86, 0x90, 0x65, 0xc0,
-	0x5c, 0xd5, 0x76, 0xa0, 0x7e, 0xbe, 0x94, 0xe7, 0xfa, 0x83, 0xd7, 0xdf, 0xca, 0x6b, 0x7d, 0x65,
-	0x34, 0x28, 0x18, 0x9d, 0x7a, 0x37, 0x86, 0x9e, 0x15, 0xe8, 0xd9, 0xa9, 0x37, 0x29, 0xef, 0xcc,
-	0xa0, 0x67, 0x2f, 0x86, 0xdd, 0x23, 0x22, 0x71, 0x84, 0x25, 0xfe, 0x55, 0xfe, 0x5f, 0x29, 0x3a,
-	0x9d, 0x82, 0xa4, 0xd3, 0x39, 0xf5, 0xce, 0x17, 0xf4, 0xbb, 0xdc, 0xff, 0x1d, 0x00, 0x00, 0xff,
-	0xff, 0x48, 0x03, 0x41, 0x96, 0x00, 0x04, 0x00, 0x00,
+	// 623 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x95, 0x93, 0x4f, 0x6f, 0xd3, 0x30,
+	0x18, 0xc6, 0x97, 0xac, 0x74, 0xad, 0x93, 0x75, 0x52, 0x36, 0xb4, 0xb2, 0xb1, 0xb5, 0x14, 0x04,
+	0x9c, 0x5a, 0xa5, 0x13, 0x97, 0xee, 0x82, 0x5a, 0x24, 0x5a, 0xa9, 0x43, 0x55, 0x36, 0x2a, 0x84,
+	0x2a, 0x45, 0x69, 0xe2, 0x26, 0x66, 0x89, 0x5d, 0x39, 0x6e, 0x19, 0x7c, 0x02, 0x8e, 0x7c, 0x04,
+	0x84, 0xc4, 0x85, 0x4f, 0x82, 0x76, 0xda, 0x91, 0x0b, 0x12, 0x1a, 0x37, 0x3e, 0x05, 0xfe, 0xd3,
+	0x6e, 0x39, 0xf4, 0xc2, 0xc1, 0x91, 0xfd, 0x7b, 0x9f, 0xf7, 0xf5, 0xeb, 0x27, 0x36, 0xa8, 0x20,
+	0x9f, 0xe0, 0x46, 0x8c, 0xc2, 0x88, 0xf9, 0x31, 0x82, 0x98, 0x35, 0xe6, 0xb6, 0x5a, 0xd6, 0xa7,
+	0x94, 0x30, 0x62, 0x6d, 0x0b, 0x41, 0x3d, 0x23, 0xa8, 0xcf, 0xed, 0xbd, 0x7b, 0x32, 0x8b, 0x7d,
+	0x98, 0xc2, 0x54, 0xe8, 0xe5, 0x44, 0xe9, 0xf7, 0x76, 0x42, 0x12, 0x12, 0x39, 0x6d, 0x88, 0x99,
+	0xa2, 0xb5, 0x5f, 0x3a, 0x30, 0x3a, 0x32, 0xfd, 0x94, 0x79, 0x0c, 0x5a, 0x4f, 0xc0, 0x16, 0xa3,
+	0xb3, 0x94, 0x21, 0x1c, 0xba, 0x53, 0x48, 0x11, 0x09, 0xca, 0x5a, 0x55, 0x7b, 0x9a, 0x73, 0x4a,
+	0x4b, 0x3c, 0x90, 0xd4, 0x7a, 0x08, 0x36, 0x27, 0x94, 0x7c, 0x84, 0xd8, 0x8d, 0xa0, 0xe8, 0xa1,
+	0xac, 0x4b, 0x99, 0xa9, 0x60, 0x57, 0x32, 0xeb, 0x31, 0xd8, 0x4a, 0xbc, 0x0b, 0xd7, 0x8f, 0x89,
+	0x7f, 0xee, 0x06, 0x14, 0x4d, 0x58, 0x79, 0x5d, 0xca, 0x36, 0x39, 0xee, 0x08, 0xfa, 0x42, 0x40,
+	0x51, 0x2c, 0xe6, 0xbb, 0xa7, 0x6c, 0x59, 0x2c, 0xa7, 0x8a, 0x29, 0xb8, 0x28, 0xf6, 0x08, 0x94,
+	0x52, 0xea, 0xbb, 0x18, 0xb2, 0xf7, 0x84, 0x9e, 0xbb, 0x28, 0x28, 0xdf, 0xe1, 0xaa, 0xa2, 0x63,
+	0x72, 0xfa, 0x4a, 0xc1, 0x5e, 0x60, 0x1d, 0x00, 0x90, 0x51, 0xe4, 0x65, 0x9d, 0x22, 0xbe, 0x09,
+	0xf3, 0x8e, 0x96, 0x61, 0x61, 0x8e, 0xd0, 0x6c, 0xa8, 0x8e, 0x16, 0xf8, 0x8c, 0x53, 0xae, 0x7b,
+	0x0e, 0x0c, 0x79, 0x60, 0x37, 0x86, 0x73, 0x18, 0x97, 0x0b, 0x5c, 0x63, 0x34, 0x2b, 0xf5, 0x15,
+	0x9e, 0xd7, 0xcf, 0x84, 0xae, 0x2f, 0x64, 0x0e, 0x60, 0x37, 0xf3, 0x56, 0xee, 0xd3, 0x97, 0xca,
+	0x5a, 0xed, 0x1d, 0x28, 0x75, 0x08, 0x4e, 0x21, 0x4e, 0x67, 0xa9, 0x72, 0xf8, 0x01, 0x30, 0x13,
+	0x98, 0xa6, 0x5e, 0x08, 0x5d, 0x4a, 0x08, 0x93, 0xf6, 0x9a, 0x8e, 0xb1, 0x60, 0x0e, 0x47, 0xd6,
+	0x33, 0xb0, 0x8b, 0xe1, 0x05, 0x73, 0xf9, 0x2f, 0x22, 0x13, 0x97, 0x6f, 0xc9, 0xc4, 0x2a, 0xf2,
+	0xd2, 0x48, 0xba, 0x6c, 0x3a, 0x3b, 0x22, 0x3c, 0x10, 0xd1, 0x8e, 0x0a, 0x76, 0x79, 0xac, 0xd6,
+	0x06, 0x46, 0x5b, 0x78, 0xfa, 0x7a, 0x1a, 0x88, 0x8d, 0x8e, 0x40, 0x3e, 0x82, 0x5e, 0x00, 0xa9,
+	0xdc, 0xc2, 0x68, 0xee, 0xab, 0xee, 0xd5, 0x9d, 0xe0, 0x7d, 0x9f, 0xa2, 0x10, 0xc3, 0xa0, 0x2b,
+	0x25, 0xce, 0x42, 0x5a, 0xeb, 0x03, 0x70, 0x7b, 0x1e, 0xeb, 0x3e, 0x28, 0xe2, 0x59, 0x02, 0xa9,
+	0xc7, 0x08, 0x5d, 0xdc, 0x83, 0x5b, 0x60, 0x55, 0x81, 0x11, 0x40, 0x4c, 0x12, 0x84, 0x65, 0x5c,
+	0x5d, 0x80, 0x2c, 0xaa, 0x7d, 0xd3, 0x80, 0x79, 0x82, 0xd2, 0x31, 0x8c, 0xbc, 0x39, 0x22, 0x33,
+	0x6a, 0xed, 0x83, 0xa2, 0x32, 0x4e, 0x18, 0xaf, 0xc9, 0xdf, 0x57, 0x50, 0x80, 0x7b, 0x7e, 0x0c,
+	0x0a, 0xaa, 0x0b, 0xd7, 0x96, 0xc5, 0x8c, 0x66, 0x75, 0xa5, 0xe1, 0x99, 0x43, 0x3a, 0x1b, 0x2a,
+	0xc3, 0xce, 0x24, 0x37, 0xe5, 0x1d, 0xfb, 0x8f, 0xe4, 0x66, 0xfb, 0x52, 0xfb, 0x71, 0x7d, 0xa8,
+	0x5d, 0xf1, 0xf1, 0x9b, 0x8f, 0xcf, 0x7f, 0x0e, 0xd7, 0xae, 0xf8, 0xf8, 0xc9, 0x07, 0xd8, 0xf5,
+	0x49, 0xb2, 0xaa, 0x50, 0x1b, 0xf4, 0xc5, 0x7a, 0x20, 0x5e, 0xd1, 0x40, 0x7b, 0x7b, 0x10, 0xa3,
+	0x31, 0xf5, 0x28, 0xe2, 0xef, 0x2e, 0x24, 0x0d, 0x9e, 0x93, 0xf0, 0x77, 0x28, 0xd2, 0x8e, 0xc5,
+	0xe7, 0xab, 0xbe, 0xde, 0xeb, 0xbf, 0xf9, 0xae, 0x6f, 0xf7, 0x44, 0xa1, 0x7e, 0xa6, 0xd0, 0xd0,
+	0xbe, 0x54, 0x74, 0x94, 0xa1, 0xa3, 0xa1, 0x7d, 0xad, 0x57, 0x56, 0xd0, 0xd1, 0xcb, 0x41, 0xfb,
+	0x04, 0x32, 0x8f, 0x77, 0xef, 0xfd, 0xd5, 0xef, 0x0a, 0x45, 0xab, 0x95, 0x91, 0xb4, 0x5a, 0x43,
+	0x7b, 0x9c, 0x97, 0x2f, 0xfb, 0xe8, 0x1f, 0x95, 0xf3, 0xbd, 0x7e, 0x42, 0x04, 0x00, 0x00,
 }
 
 func (m *ClientState) Marshal() (dAtA []byte, err error) {
@@ -347,6 +348,18 @@ func (m *ClientState) MarshalToSizedBuffer(dAtA []byte) (int, error) {
 	_ = i
 	var l int
 	_ = l
+	if m.TrustLevel != nil {
+		{
+			size, err := m.TrustLevel.MarshalToSizedBuffer(dAtA[:i])
+			if err != nil {
+				return 0, err
+			}
+			i -= size
+			i = encodeVarintLight(dAtA, i, uint64(size))
+		}
+		i--
+		dAtA[i] = 0x42
+	}
 	if m.NetworkTypeId != 0 {
 		i = encodeVarintLight(dAtA, i, uint64(m.NetworkTypeId))
 		i--
@@ -585,6 +598,10 @@ func (m *ClientState) Size() (n int) {
 	if m.NetworkTypeId != 0 {
 		n += 1 + sovLight(uint64(m.NetworkTypeId))
 	}
+	if m.TrustLevel != nil {
+		l = m.TrustLevel.Size()
+		n += 1 + l + sovLight(uint64(l))
+	}
 	return n
 }
 
@@ -835,6 +852,42 @@ func (m *ClientState) Unmarshal(dAtA []byte) error {
 					break
 				}
 			}
+		case 8:
+			if wireType != 2 {
+				return fmt.Errorf("proto: wrong wireType = %d for field TrustLevel", wireType)
+			}
+			var msglen int
+			for shift := uint(0); ; shift += 7 {
+				if shift >= 64 {
+					return ErrIntOverflowLight
+				}
+				if iNdEx >= l {
+					return io.ErrUnexpectedEOF
+				}
+				b := dAtA[iNdEx]
+				iNdEx++
+				msglen |= int(b&0x7F) << shift
+				if b < 0x80 {
+					break
+				}
+			}
+			if msglen < 0 {
+				return ErrInvalidLengthLight
+			}
+			postIndex := iNdEx + msglen
+			if postIndex < 0 {
+				return ErrInvalidLengthLight
+			}
+			if postIndex > l {
+				return io.ErrUnexpectedEOF
+			}
+			if m.TrustLevel == nil {
+				m.TrustLevel = &TrustLevel{}
+			}
+			if err := m.TrustLevel.Unmarshal(dAtA[iNdEx:postIndex]); err != nil {
+				return err
+			}
+			iNdEx = postIndex
 		default:
 			iNdEx = preIndex
 			skippy, err := skipLight(dAtA[iNdEx:])
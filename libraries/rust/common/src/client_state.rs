@@ -2,12 +2,14 @@ use std::time::Duration;
 
 use crate::constants::{
     DEFAULT_NETWORK_ID, DEFAULT_NETWORK_TYPE_ID, DEFAULT_SRC_NETWORK_ID, ICON_CLIENT_TYPE,
+    TENDERMINT_CLIENT_TYPE,
 };
 use crate::ibc::core::ics02_client::error::ClientError;
 
 use crate::ibc::core::ics02_client::client_type::ClientType as IbcClientType;
 use crate::ibc::Height as IbcHeight;
 
+use crate::icon::tendermint::light::ClientState as TmClientState;
 use crate::traits::AnyTypes;
 use crate::{constants::ICON_CLIENT_STATE_TYPE_URL, icon::icon::lightclient::v1::ClientState};
 use dyn_clone::DynClone;
@@ -38,6 +40,7 @@ impl ClientState {
             network_id,
             network_type_id,
             src_network_id,
+            trust_level: None,
         })
     }
 }
@@ -112,6 +115,40 @@ impl IClientState for ClientState {
         self.get_keccak_hash().to_vec()
     }
 }
+
+impl IClientState for TmClientState {
+    fn latest_height(&self) -> crate::ibc::Height {
+        IbcHeight::new(0, self.latest_height as u64).unwrap()
+    }
+
+    fn frozen_height(&self) -> Option<crate::ibc::Height> {
+        if self.frozen_height == 0 {
+            return None;
+        }
+        Some(IbcHeight::new(0, self.frozen_height as u64).unwrap())
+    }
+
+    fn expired(&self, elapsed: std::time::Duration) -> bool {
+        let trusting_period = self
+            .trusting_period
+            .as_ref()
+            .map(|period| Duration::from_nanos(period.to_nanos()))
+            .unwrap_or_default();
+        elapsed > trusting_period
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen_height > 0
+    }
+
+    fn client_type(&self) -> IbcClientType {
+        IbcClientType::new(TENDERMINT_CLIENT_TYPE.to_string())
+    }
+
+    fn hash(&self) -> Vec<u8> {
+        self.get_keccak_hash().to_vec()
+    }
+}
 dyn_clone::clone_trait_object!(IClientState);
 
 pub fn get_default_icon_client_state() -> ClientState {
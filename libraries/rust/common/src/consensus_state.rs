@@ -11,6 +11,7 @@ use dyn_clone::DynClone;
 use ibc_proto::{google::protobuf::Any, protobuf::Protobuf};
 use prost::Message;
 
+use crate::icon::tendermint::light::ConsensusState as TmConsensusState;
 use crate::{
     constants::ICON_CONSENSUS_STATE_TYPE_URL, icon::icon::lightclient::v1::ConsensusState,
 };
@@ -100,4 +101,39 @@ impl IConsensusState for ConsensusState {
         self.get_keccak_hash().to_vec()
     }
 }
+
+impl IConsensusState for TmConsensusState {
+    fn root(&self) -> CommitmentRoot {
+        CommitmentRoot::from(
+            self.root
+                .as_ref()
+                .map(|root| root.hash.clone())
+                .unwrap_or_default(),
+        )
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        let nanos = self
+            .timestamp
+            .as_ref()
+            .map(|time| time.to_nanos())
+            .unwrap_or_default();
+        Timestamp::from_nanoseconds(nanos).unwrap()
+    }
+
+    fn as_bytes(&self) -> Vec<u8> {
+        self.encode_to_vec()
+    }
+
+    fn into_box(self) -> Box<dyn IConsensusState>
+    where
+        Self: Sized,
+    {
+        Box::new(self)
+    }
+
+    fn hash(&self) -> Vec<u8> {
+        self.get_keccak_hash().to_vec()
+    }
+}
 dyn_clone::clone_trait_object!(IConsensusState);
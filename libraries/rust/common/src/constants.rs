@@ -3,8 +3,14 @@ pub const ICON_CONSENSUS_STATE_TYPE_URL: &str = "/icon.lightclient.v1.ConsensusS
 pub const ICON_SIGNED_HEADER_TYPE_URL: &str = "/icon.types.v1.SignedHeader";
 pub const ICON_BTP_HEADER_TYPE_URL: &str = "/icon.types.v1.BtpHeader";
 pub const ICON_MERKLE_TYPE_URL: &str = "/icon.types.v1.MerkleNode";
+pub const ICON_MISBEHAVIOUR_TYPE_URL: &str = "/icon.lightclient.v1.Misbehaviour";
 pub const ICON_CLIENT_TYPE: &str = "iconclient";
 
+pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/tendermint.light.ClientState";
+pub const TENDERMINT_CONSENSUS_STATE_TYPE_URL: &str = "/tendermint.light.ConsensusState";
+pub const TENDERMINT_HEADER_TYPE_URL: &str = "/tendermint.light.TmHeader";
+pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
+
 pub const DEFAULT_SRC_NETWORK_ID: &str = "0x3.icon";
 pub const DEFAULT_NETWORK_TYPE_ID: u64 = 1;
 pub const DEFAULT_NETWORK_ID: u64 = 1;
@@ -84,6 +84,20 @@ pub enum ChannelError {
     InvalidProof,
     /// identifier error: `{0}`
     Identifier(ValidationError),
+    /// no upgrade in progress for the channel end (`{port_id}`, `{channel_id}`)
+    UpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// counterparty upgrade sequence `{counterparty_sequence}` is behind the channel upgrade sequence `{sequence}`
+    InvalidUpgradeSequence {
+        sequence: u64,
+        counterparty_sequence: u64,
+    },
+    /// counterparty channel should not be in state `{state}`
+    InvalidCounterpartyChannelState { state: State },
+    /// invalid channel upgrade: `{description}`
+    InvalidUpgrade { description: String },
 }
 
 #[derive(Debug, Display)]
@@ -139,6 +153,11 @@ pub enum PacketError {
     },
     /// Packet acknowledgement exists for the packet with the sequence `{sequence}`
     AcknowledgementExists { sequence: Sequence },
+    /// Packet `{sequence}` was sent after the counterparty started flushing at `{counterparty_next_sequence_send}`
+    PacketSentAfterUpgradeFlush {
+        sequence: Sequence,
+        counterparty_next_sequence_send: u64,
+    },
     /// Acknowledgment cannot be empty
     InvalidAcknowledgement,
     /// Acknowledgment for the packet `{sequence}` not found
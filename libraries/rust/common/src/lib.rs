@@ -2,7 +2,9 @@ use std::str::FromStr;
 
 use crate::ibc::core::ics24_host::identifier::ClientId;
 use constants::{
-    ICON_CLIENT_STATE_TYPE_URL, ICON_CONSENSUS_STATE_TYPE_URL, ICON_SIGNED_HEADER_TYPE_URL,
+    ICON_CLIENT_STATE_TYPE_URL, ICON_CONSENSUS_STATE_TYPE_URL, ICON_MISBEHAVIOUR_TYPE_URL,
+    ICON_SIGNED_HEADER_TYPE_URL, TENDERMINT_CLIENT_STATE_TYPE_URL,
+    TENDERMINT_CONSENSUS_STATE_TYPE_URL, TENDERMINT_HEADER_TYPE_URL,
 };
 use cosmwasm_std::{StdError, StdResult};
 use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
@@ -12,9 +14,12 @@ use ibc::core::{
     ics26_routing::context::ModuleId,
 };
 use icon::icon::{
-    lightclient::v1::{ClientState, ConsensusState},
+    lightclient::v1::{ClientState, ConsensusState, Misbehaviour},
     types::v1::SignedHeader,
 };
+use icon::tendermint::light::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState, TmHeader,
+};
 use traits::AnyTypes;
 pub mod btp_header;
 pub mod client_state;
@@ -24,6 +29,7 @@ pub mod ibc;
 pub mod icon;
 pub mod rlp;
 pub mod signed_header;
+pub mod tendermint_header;
 pub mod traits;
 pub mod types;
 pub mod utils;
@@ -46,6 +52,30 @@ impl AnyTypes for SignedHeader {
     }
 }
 
+impl AnyTypes for Misbehaviour {
+    fn get_type_url() -> String {
+        ICON_MISBEHAVIOUR_TYPE_URL.to_string()
+    }
+}
+
+impl AnyTypes for TmClientState {
+    fn get_type_url() -> String {
+        TENDERMINT_CLIENT_STATE_TYPE_URL.to_string()
+    }
+}
+
+impl AnyTypes for TmConsensusState {
+    fn get_type_url() -> String {
+        TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string()
+    }
+}
+
+impl AnyTypes for TmHeader {
+    fn get_type_url() -> String {
+        TENDERMINT_HEADER_TYPE_URL.to_string()
+    }
+}
+
 impl<'a> PrimaryKey<'a> for &ClientId {
     type Prefix = ();
 
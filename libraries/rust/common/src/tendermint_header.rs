@@ -0,0 +1,236 @@
+use prost::encoding::{encode_key, encode_varint, WireType};
+use prost::Message;
+
+use crate::icon::tendermint::light::{
+    BlockIdFlag, CanonicalVote, Commit, CommitSig, ConsensusState, Duration, LightHeader,
+    MerkleRoot, SignedMsgType, SimpleValidator, Timestamp, TmHeader, Validator, ValidatorSet,
+};
+use crate::utils::sha256;
+
+/// Computes the RFC-6962 merkle root used by CometBFT for header and validator set hashes.
+pub fn simple_hash_from_byte_vectors(items: &[Vec<u8>]) -> Vec<u8> {
+    match items.len() {
+        0 => sha256(b""),
+        1 => leaf_hash(&items[0]),
+        n => {
+            let split = split_point(n);
+            let left = simple_hash_from_byte_vectors(&items[..split]);
+            let right = simple_hash_from_byte_vectors(&items[split..]);
+            inner_hash(&left, &right)
+        }
+    }
+}
+
+fn leaf_hash(leaf: &[u8]) -> Vec<u8> {
+    sha256([&[0x00][..], leaf].concat())
+}
+
+fn inner_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    sha256([&[0x01][..], left, right].concat())
+}
+
+// largest power of two strictly less than n
+fn split_point(n: usize) -> usize {
+    let mut split = 1;
+    while split * 2 < n {
+        split *= 2;
+    }
+    split
+}
+
+// cdcEncode of a []byte / string field, i.e. the gogoproto BytesValue/StringValue wrapper
+fn encode_bytes_value(value: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    if !value.is_empty() {
+        encode_key(1, WireType::LengthDelimited, &mut buf);
+        encode_varint(value.len() as u64, &mut buf);
+        buf.extend_from_slice(value);
+    }
+    buf
+}
+
+// cdcEncode of an int64 field, i.e. the gogoproto Int64Value wrapper
+fn encode_int64_value(value: i64) -> Vec<u8> {
+    let mut buf = Vec::new();
+    if value != 0 {
+        encode_key(1, WireType::Varint, &mut buf);
+        encode_varint(value as u64, &mut buf);
+    }
+    buf
+}
+
+impl Timestamp {
+    pub fn to_nanos(&self) -> u64 {
+        (self.seconds as u64)
+            .saturating_mul(1_000_000_000)
+            .saturating_add(self.nanos as u64)
+    }
+}
+
+impl Duration {
+    pub fn to_nanos(&self) -> u64 {
+        (self.seconds as u64)
+            .saturating_mul(1_000_000_000)
+            .saturating_add(self.nanos as u64)
+    }
+}
+
+impl LightHeader {
+    pub fn hash(&self) -> Vec<u8> {
+        let fields = vec![
+            self.version.clone().unwrap_or_default().encode_to_vec(),
+            encode_bytes_value(self.chain_id.as_bytes()),
+            encode_int64_value(self.height),
+            self.time.clone().unwrap_or_default().encode_to_vec(),
+            self.last_block_id
+                .clone()
+                .unwrap_or_default()
+                .encode_to_vec(),
+            encode_bytes_value(&self.last_commit_hash),
+            encode_bytes_value(&self.data_hash),
+            encode_bytes_value(&self.validators_hash),
+            encode_bytes_value(&self.next_validators_hash),
+            encode_bytes_value(&self.consensus_hash),
+            encode_bytes_value(&self.app_hash),
+            encode_bytes_value(&self.last_results_hash),
+            encode_bytes_value(&self.evidence_hash),
+            encode_bytes_value(&self.proposer_address),
+        ];
+        simple_hash_from_byte_vectors(&fields)
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        self.time
+            .as_ref()
+            .map(|time| time.to_nanos())
+            .unwrap_or_default()
+    }
+}
+
+impl Validator {
+    pub fn ed25519_pub_key(&self) -> Option<&[u8]> {
+        use crate::icon::tendermint::light::public_key::Sum;
+        match self.pub_key.as_ref().and_then(|key| key.sum.as_ref()) {
+            Some(Sum::Ed25519(key)) => Some(key),
+            _ => None,
+        }
+    }
+}
+
+impl ValidatorSet {
+    pub fn hash(&self) -> Vec<u8> {
+        let validators = self
+            .validators
+            .iter()
+            .map(|validator| {
+                SimpleValidator {
+                    pub_key: validator.pub_key.clone(),
+                    voting_power: validator.voting_power,
+                }
+                .encode_to_vec()
+            })
+            .collect::<Vec<Vec<u8>>>();
+        simple_hash_from_byte_vectors(&validators)
+    }
+
+    pub fn total_voting_power(&self) -> u64 {
+        self.validators
+            .iter()
+            .map(|validator| validator.voting_power as u64)
+            .sum()
+    }
+
+    pub fn find_by_address(&self, address: &[u8]) -> Option<&Validator> {
+        self.validators
+            .iter()
+            .find(|validator| validator.address == address)
+    }
+}
+
+impl CommitSig {
+    pub fn is_commit(&self) -> bool {
+        self.block_id_flag == BlockIdFlag::BlockIdFlagCommit as i32
+    }
+}
+
+impl Commit {
+    /// Returns the length-prefixed `CanonicalVote` bytes signed by the validator at `index`.
+    pub fn vote_sign_bytes(&self, chain_id: &str, index: usize) -> Option<Vec<u8>> {
+        let signature = self.signatures.get(index)?;
+        let vote = CanonicalVote {
+            r#type: SignedMsgType::SignedMsgTypePrecommit as i32,
+            height: self.height,
+            round: self.round as i64,
+            block_id: self.block_id.clone(),
+            timestamp: signature.timestamp.clone(),
+            chain_id: chain_id.to_string(),
+        };
+        Some(vote.encode_length_delimited_to_vec())
+    }
+}
+
+impl TmHeader {
+    pub fn height(&self) -> u64 {
+        self.light_header()
+            .map(|header| header.height as u64)
+            .unwrap_or_default()
+    }
+
+    pub fn light_header(&self) -> Option<&LightHeader> {
+        self.signed_header
+            .as_ref()
+            .and_then(|signed_header| signed_header.header.as_ref())
+    }
+
+    pub fn to_consensus_state(&self) -> Option<ConsensusState> {
+        self.light_header().map(|header| ConsensusState {
+            timestamp: header.time.clone(),
+            root: Some(MerkleRoot {
+                hash: header.app_hash.clone(),
+            }),
+            next_validators_hash: header.next_validators_hash.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_hash_empty_and_single() {
+        assert_eq!(simple_hash_from_byte_vectors(&[]), sha256(b""));
+        assert_eq!(
+            simple_hash_from_byte_vectors(&[vec![1, 2, 3]]),
+            sha256([0u8, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_simple_hash_unbalanced_tree() {
+        let items = vec![vec![1], vec![2], vec![3]];
+        let left = inner_hash(&leaf_hash(&[1]), &leaf_hash(&[2]));
+        let expected = inner_hash(&left, &leaf_hash(&[3]));
+        assert_eq!(simple_hash_from_byte_vectors(&items), expected);
+    }
+
+    #[test]
+    fn test_cdc_encode_wrappers() {
+        assert_eq!(encode_bytes_value(&[]), Vec::<u8>::new());
+        assert_eq!(
+            encode_bytes_value(&[0xaa, 0xbb]),
+            vec![0x0a, 0x02, 0xaa, 0xbb]
+        );
+        assert_eq!(encode_int64_value(0), Vec::<u8>::new());
+        assert_eq!(encode_int64_value(300), vec![0x08, 0xac, 0x02]);
+    }
+
+    #[test]
+    fn test_timestamp_to_nanos() {
+        let time = Timestamp {
+            seconds: 2,
+            nanos: 5,
+        };
+        assert_eq!(time.to_nanos(), 2_000_000_005);
+    }
+}
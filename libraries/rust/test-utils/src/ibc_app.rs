@@ -0,0 +1,84 @@
+//! Fixtures shared by the tests of the applications bound to the IBC host.
+use cosmwasm_std::{
+    testing::{mock_dependencies, MockApi, MockQuerier, MockStorage},
+    to_json_binary as to_binary, Binary, ContractResult, Empty, IbcChannel, IbcEndpoint, IbcOrder,
+    IbcPacket, IbcTimeout, OwnedDeps, SystemResult, Timestamp,
+};
+
+pub const IBC_HOST: &str = "ibc_host";
+pub const OWNER: &str = "owner";
+pub const NEXT_SEQUENCE: u64 = 5;
+
+/// Mock dependencies with the IBC host answering every query with `NEXT_SEQUENCE`, the sequence
+/// of the next packet sent.
+pub fn deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+    deps.querier
+        .update_wasm(|_| SystemResult::Ok(ContractResult::Ok(to_binary(&NEXT_SEQUENCE).unwrap())));
+    deps
+}
+
+/// The two ends of a channel: `endpoint` on this chain and `counterparty_endpoint` on the
+/// counterparty chain.
+#[derive(Clone, Debug)]
+pub struct ChannelEnds {
+    pub endpoint: IbcEndpoint,
+    pub counterparty_endpoint: IbcEndpoint,
+}
+
+impl ChannelEnds {
+    pub fn new(
+        port_id: &str,
+        channel_id: &str,
+        counterparty_port_id: &str,
+        counterparty_channel_id: &str,
+    ) -> Self {
+        Self {
+            endpoint: IbcEndpoint {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            },
+            counterparty_endpoint: IbcEndpoint {
+                port_id: counterparty_port_id.to_string(),
+                channel_id: counterparty_channel_id.to_string(),
+            },
+        }
+    }
+
+    pub fn channel(
+        &self,
+        order: IbcOrder,
+        version: impl Into<String>,
+        connection_id: impl Into<String>,
+    ) -> IbcChannel {
+        IbcChannel::new(
+            self.endpoint.clone(),
+            self.counterparty_endpoint.clone(),
+            order,
+            version,
+            connection_id,
+        )
+    }
+
+    /// Builds a packet travelling from the counterparty to this chain.
+    pub fn incoming_packet(&self, data: impl Into<Binary>, sequence: u64) -> IbcPacket {
+        IbcPacket::new(
+            data,
+            self.counterparty_endpoint.clone(),
+            self.endpoint.clone(),
+            sequence,
+            IbcTimeout::with_timestamp(Timestamp::from_seconds(0)),
+        )
+    }
+
+    /// Builds a packet sent from this chain to the counterparty.
+    pub fn outgoing_packet(&self, data: impl Into<Binary>, sequence: u64) -> IbcPacket {
+        IbcPacket::new(
+            data,
+            self.endpoint.clone(),
+            self.counterparty_endpoint.clone(),
+            sequence,
+            IbcTimeout::with_timestamp(Timestamp::from_seconds(0)),
+        )
+    }
+}
@@ -1,3 +1,5 @@
+pub mod ibc_app;
+
 use std::{
     collections::HashMap,
     env,
@@ -9,7 +9,7 @@ RUSTC_VERS="1.78.0"
 
 MAX_WASM_SIZE=800 # 800 KB
 
-PROJECTS=("cw-common" "cw-ibc-core" "cw-icon-light-client" "cw-integration" "cw-mock-ibc-core" "cw-xcall-ibc-connection")
+PROJECTS=("cw-common" "cw-ibc-core" "cw-icon-light-client" "cw-integration" "cw-tendermint-light-client" "cw-mock-ibc-core" "cw-xcall-ibc-connection")
 
 
 # Install wasm-opt binary
@@ -48,6 +48,7 @@ done
 # check all generated wasm files
 cosmwasm-check artifacts/archway/cw_ibc_core.wasm
 cosmwasm-check artifacts/archway/cw_icon_light_client.wasm
+cosmwasm-check artifacts/archway/cw_tendermint_light_client.wasm
 cosmwasm-check artifacts/archway/cw_xcall_ibc_connection.wasm
 
 